@@ -35,21 +35,12 @@ async fn dump_events(client: Arc<Client>, event_manager: &EventManager) -> Resul
     
 
     let filter = &EventFilterSpec {
-        entity: None,
         time: Some(EventFilterSpecByTime {
             begin_time: Some(thirty_minutes_ago.to_rfc3339()),
-            end_time: None,
+            ..Default::default()
         }),
-        user_name: None,
-        event_chain_id: None,
-        alarm: None,
-        scheduled_task: None,
         disable_full_message: Some(false),
-        category: None,
-        r#type: None,
-        tag: None,
-        event_type_id: None,
-        max_count: None,
+        ..Default::default()
     };
 
     let collector = event_manager.create_collector_for_events(filter).await?;
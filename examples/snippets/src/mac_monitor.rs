@@ -144,14 +144,14 @@ impl VmChangeDetector {
         };
         let view_mgr_id = view_mgr_id.value.clone();
         let view_mgr = ViewManager::new(client.clone(), &view_mgr_id);
-        let view_ref = view_mgr.create_container_view(&client.service_content().root_folder, Some(&[Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string()]), true).await?;
+        let view_ref = view_mgr.create_container_view(&client.service_content().root_folder, Some(&[MoTypesEnum::VirtualMachine]), true).await?;
         let spec = PropertyFilterSpec {
             object_set: vec![structs::ObjectSpec {
                 obj: view_ref.clone(),
                 skip: Some(false),
                 select_set: Some(vec![Box::new(structs::TraversalSpec {
                     name: Some("traverseEntities".to_string()), 
-                    r#type: Into::<&str>::into(MoTypesEnum::ContainerView).to_string(), 
+                    r#type: MoTypesEnum::ContainerView, 
                     path: "view".to_string(), 
                     skip: Some(false), 
                     select_set: None,
@@ -164,7 +164,7 @@ impl VmChangeDetector {
                     "config.hardware.device".to_string(),
                     "guest.net".to_string(),
                     ]),
-                r#type: Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string(),
+                r#type: MoTypesEnum::VirtualMachine,
             }],
             report_missing_objects_in_results: None,
         };
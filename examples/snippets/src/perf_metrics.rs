@@ -62,7 +62,7 @@ async fn main() -> Result<()> {
     let view_manager = ViewManager::new(vim_client.clone(), &view_manager_moref.value.clone());
 
     let root_fld = vim_client.service_content().root_folder.clone();
-    let vm_type = Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string();
+    let vm_type = MoTypesEnum::VirtualMachine;
     let view_moref = view_manager.create_container_view(&root_fld, Some(&[vm_type]), true).await?;
     let view = ContainerView::new(vim_client.clone(), &view_moref.value);
     let vms = view.view().await?;
@@ -30,7 +30,7 @@
         
         let view_moref = view_manager.create_container_view(
             &content.root_folder,
-            Some(&[Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string()]),
+            Some(&[MoTypesEnum::VirtualMachine]),
             true,
         ).await?;
 
@@ -44,7 +44,7 @@
                 skip: Some(false),
                 select_set: Some(vec![Box::new(structs::TraversalSpec {
                     name: Some("traverseEntities".to_string()), 
-                    r#type: Into::<&str>::into(MoTypesEnum::ContainerView).to_string(), 
+                    r#type: MoTypesEnum::ContainerView, 
                     path: "view".to_string(), 
                     skip: Some(false), 
                     select_set: None,
@@ -53,7 +53,7 @@
             prop_set: vec![structs::PropertySpec {
                 all: Some(false),
                 path_set: Some(vec!["name".to_string()]),
-                r#type: Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string(),
+                r#type: MoTypesEnum::VirtualMachine,
             }],
             report_missing_objects_in_results: Some(true),
         }];
@@ -98,15 +98,13 @@ fn get_filter_update(updates: Vec<PropertyFilterUpdate>, filter: &ManagedObjectR
     None
 }
 
-type StaticStr = &'static str;
-
 pub fn obj_spec_for_view(view_moref: ManagedObjectReference) -> Vec<ObjectSpec> {
     vec![ObjectSpec {
         obj: view_moref.clone(),
         skip: Some(false),
         select_set: Some(vec![Box::new(TraversalSpec {
             name: Some("traverseEntities".to_string()),
-            r#type: StaticStr::from(MoTypesEnum::ContainerView).to_string(),
+            r#type: MoTypesEnum::ContainerView,
             path: "view".to_string(),
             skip: Some(false),
             select_set: None,
@@ -34,7 +34,7 @@ impl VirtualMachine {
                 "overallStatus".into(),
                 "runtime.powerState".into(),
             ]),
-            r#type: Into::<&str>::into(MoTypesEnum::VirtualMachine).to_string(),
+            r#type: MoTypesEnum::VirtualMachine,
         }
     }
 
@@ -2,6 +2,7 @@
 
 use std::fmt::Debug;
 
+use super::resolve::ComponentKind;
 use super::schema::SchemaType;
 
 
@@ -15,7 +16,30 @@ pub enum Error {
     SchemaInvalidEnumValue {
         index: usize,
         value: String,
-    }
+    },
+    #[error("Invalid reference: {0}")]
+    InvalidReference(String),
+    #[error("Reference {reference} does not point to {expected}")]
+    ReferenceKindMismatch {
+        reference: String,
+        expected: ComponentKind,
+    },
+    #[error("Reference {0} does not resolve")]
+    UnresolvedReference(String),
+    #[error("Reference {0} is part of a reference cycle")]
+    ReferenceCycle(String),
+    #[error("Schema {0} is part of an allOf cycle")]
+    AllOfCycle(String),
+    #[error("Operation id {0} is not unique")]
+    DuplicateOperationId(String),
+    #[error("Parameter {0} is defined more than once")]
+    DuplicateParameter(String),
+    #[error("Path parameter {0} is not defined")]
+    MissingPathParameter(String),
+    #[error("Path parameter {0} does not appear in the path")]
+    UnknownPathParameter(String),
+    #[error("Invalid field: {0}")]
+    InvalidField(String),
 }
 
 pub trait Validate {
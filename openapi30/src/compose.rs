@@ -0,0 +1,286 @@
+// Helpers for the composition and inheritance constructs of the Schema Object. A schema inherits
+// from the schemas it references in `allOf`. Descendants of a schema with a discriminator are
+// told apart by the discriminator property as described in
+// https://spec.openapis.org/oas/v3.0.3#composition-and-inheritance-polymorphism
+
+use indexmap::{IndexMap, IndexSet};
+use super::*;
+
+impl Schema {
+    /// References listed in `allOf`.
+    pub fn all_of_references(&self) -> impl Iterator<Item = &str> {
+        self.all_of.iter().flatten().filter_map(|schema| match schema {
+            RefOr::Ref { reference, .. } => Some(reference.as_str()),
+            RefOr::Val(_) => None,
+        })
+    }
+
+    /// The reference to the parent schema if `allOf` references exactly one schema.
+    pub fn parent_reference(&self) -> Option<&str> {
+        let mut references = self.all_of_references();
+        let parent = references.next()?;
+        match references.next() {
+            None => Some(parent),
+            Some(_) => None,
+        }
+    }
+}
+
+/// The properties of an object schema including the ones contributed by `allOf`.
+#[derive(Debug, Default, PartialEq)]
+pub struct ObjectShape<'a> {
+    /// The properties in inheritance order i.e. the properties of the parents come first. A schema
+    /// redefining a property replaces the definition of its parent.
+    pub properties: IndexMap<&'a str, &'a RefOr<Schema>>,
+    /// The names of the required properties.
+    pub required: IndexSet<&'a str>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Names of the schemas a named schema inherits from through `allOf`, closest first.
+    pub fn ancestors(&self, name: &str) -> Result<Vec<&'a str>, Error> {
+        let (name, schema) = self.resolve_name::<Schema>(name)?;
+        let mut ancestors = Vec::new();
+        self.collect_ancestors(name, schema, &mut vec![name], &mut ancestors)?;
+        Ok(ancestors)
+    }
+
+    fn collect_ancestors(
+        &self,
+        name: &'a str,
+        schema: &'a Schema,
+        path: &mut Vec<&'a str>,
+        ancestors: &mut Vec<&'a str>,
+    ) -> Result<(), Error> {
+        let mut parents = Vec::new();
+        for reference in schema.all_of_references() {
+            let (parent_name, parent) = self.resolve_reference::<Schema>(reference)?;
+            if path.contains(&parent_name) {
+                return Err(Error::AllOfCycle(name.to_string()));
+            }
+            if !ancestors.contains(&parent_name) {
+                ancestors.push(parent_name);
+            }
+            parents.push((parent_name, parent));
+        }
+        for (parent_name, parent) in parents {
+            path.push(parent_name);
+            self.collect_ancestors(parent_name, parent, path, ancestors)?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    /// Names of the schemas inheriting from a named schema directly or indirectly, in the order of
+    /// the document. Errors in the `allOf` chains of other schemas, i.e. references that do not
+    /// resolve and cycles, end the chain rather than fail the call. `validate_document` reports
+    /// them.
+    pub fn descendants(&self, name: &str) -> Result<Vec<&'a str>, Error> {
+        let (name, _) = self.resolve_name::<Schema>(name)?;
+        let mut descendants = Vec::new();
+        for (candidate, schema) in self.iter::<Schema>() {
+            let Ok(schema) = self.resolve(schema) else {
+                continue;
+            };
+            if candidate != name && self.inherits_from(schema, name, &mut vec![candidate]) {
+                descendants.push(candidate);
+            }
+        }
+        Ok(descendants)
+    }
+
+    /// Whether the `allOf` chain of a schema reaches the named schema. `path` holds the schemas
+    /// visited to cut cycles.
+    fn inherits_from(&self, schema: &'a Schema, name: &str, path: &mut Vec<&'a str>) -> bool {
+        for reference in schema.all_of_references() {
+            let Ok((parent_name, parent)) = self.resolve_reference::<Schema>(reference) else {
+                continue;
+            };
+            if parent_name == name {
+                return true;
+            }
+            if path.contains(&parent_name) {
+                continue;
+            }
+            path.push(parent_name);
+            let found = self.inherits_from(parent, name, path);
+            path.pop();
+            if found {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// The properties of a schema merged with the properties of the schemas in `allOf`. Referenced
+    /// and inline `allOf` schemas are both merged.
+    pub fn object_shape(&self, schema: &'a Schema) -> Result<ObjectShape<'a>, Error> {
+        let mut shape = ObjectShape::default();
+        self.merge_shape(schema, &mut Vec::new(), &mut shape)?;
+        Ok(shape)
+    }
+
+    fn merge_shape(&self, schema: &'a Schema, path: &mut Vec<&'a str>, shape: &mut ObjectShape<'a>) -> Result<(), Error> {
+        for part in schema.all_of.iter().flatten() {
+            match part {
+                RefOr::Ref { reference, .. } => {
+                    let (name, parent) = self.resolve_reference::<Schema>(reference)?;
+                    if path.contains(&name) {
+                        return Err(Error::AllOfCycle(name.to_string()));
+                    }
+                    path.push(name);
+                    self.merge_shape(parent, path, shape)?;
+                    path.pop();
+                }
+                RefOr::Val(inline) => self.merge_shape(inline, path, shape)?,
+            }
+        }
+        for (name, property) in schema.properties.iter().flatten() {
+            shape.properties.insert(name.as_str(), property);
+        }
+        shape.required.extend(schema.required.iter().flatten().map(String::as_str));
+        Ok(())
+    }
+
+    /// The discriminator of a named schema. It is declared on the schema itself or inherited from
+    /// the closest ancestor declaring one. Returns the name of the declaring schema together with
+    /// the discriminator.
+    pub fn discriminator(&self, name: &str) -> Result<Option<(&'a str, &'a Discriminator)>, Error> {
+        let (name, schema) = self.resolve_name::<Schema>(name)?;
+        if let Some(discriminator) = &schema.discriminator {
+            return Ok(Some((name, discriminator)));
+        }
+        for ancestor in self.ancestors(name)? {
+            let (ancestor, schema) = self.resolve_name::<Schema>(ancestor)?;
+            if let Some(discriminator) = &schema.discriminator {
+                return Ok(Some((ancestor, discriminator)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The discriminator values of the schemas that a payload described by the named schema may
+    /// hold, mapped to the schema names. Explicit mappings of the discriminator come first. They may
+    /// name schemas outside the hierarchy. The schema itself and its descendants follow with their
+    /// names as implicit values. Returns an empty map if the schema has no discriminator.
+    pub fn discriminator_mapping(&self, name: &str) -> Result<IndexMap<&'a str, &'a str>, Error> {
+        let mut mapping = IndexMap::new();
+        let Some((declaring, discriminator)) = self.discriminator(name)? else {
+            return Ok(mapping);
+        };
+        let (name, _) = self.resolve_name::<Schema>(name)?;
+        let candidates: IndexSet<&'a str> = std::iter::once(name).chain(self.descendants(name)?).collect();
+        for (value, target) in discriminator.mapping.iter().flatten() {
+            let (target, _) = if target.starts_with('#') {
+                self.resolve_reference::<Schema>(target)?
+            } else {
+                self.resolve_name::<Schema>(target)?
+            };
+            // Mappings inherited from an ancestor apply only to the schemas below `name`
+            if declaring == name || candidates.contains(target) {
+                mapping.insert(value.as_str(), target);
+            }
+        }
+        let explicit: IndexSet<&'a str> = mapping.values().copied().collect();
+        for candidate in candidates {
+            if !explicit.contains(candidate) {
+                mapping.entry(candidate).or_insert(candidate);
+            }
+        }
+        Ok(mapping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn components() -> Components {
+        serde_json::from_str(
+            r##"{
+            "schemas": {
+                "Pet": {
+                    "type": "object",
+                    "required": ["petType"],
+                    "properties": {"petType": {"type": "string"}, "name": {"type": "string"}},
+                    "discriminator": {"propertyName": "petType", "mapping": {"dog": "#/components/schemas/Dog"}}
+                },
+                "Dog": {"allOf": [
+                    {"$ref": "#/components/schemas/Pet"},
+                    {"type": "object", "required": ["bark"], "properties": {"bark": {"type": "boolean"}}}
+                ]},
+                "Puppy": {"allOf": [{"$ref": "#/components/schemas/Dog"}], "properties": {"age": {"type": "integer"}}},
+                "Cat": {"allOf": [{"$ref": "#/components/schemas/Pet"}]}
+            }
+        }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inheritance() {
+        let components = components();
+        let resolver = Resolver::from_components(&components);
+        let (_, puppy) = resolver.resolve_name::<Schema>("Puppy").unwrap();
+        assert_eq!(puppy.parent_reference(), Some("#/components/schemas/Dog"));
+        assert_eq!(resolver.ancestors("Puppy").unwrap(), vec!["Dog", "Pet"]);
+        assert_eq!(resolver.descendants("Pet").unwrap(), vec!["Dog", "Puppy", "Cat"]);
+        let shape = resolver.object_shape(puppy).unwrap();
+        assert_eq!(shape.properties.keys().copied().collect::<Vec<_>>(), vec!["petType", "name", "bark", "age"]);
+        assert_eq!(shape.required.iter().copied().collect::<Vec<_>>(), vec!["petType", "bark"]);
+    }
+
+    #[test]
+    fn test_discriminator() {
+        let components = components();
+        let resolver = Resolver::from_components(&components);
+        let (declaring, discriminator) = resolver.discriminator("Puppy").unwrap().unwrap();
+        assert_eq!(declaring, "Pet");
+        assert_eq!(discriminator.property_name, "petType");
+        let mapping = resolver.discriminator_mapping("Pet").unwrap();
+        assert_eq!(
+            mapping.into_iter().collect::<Vec<_>>(),
+            vec![("dog", "Dog"), ("Pet", "Pet"), ("Puppy", "Puppy"), ("Cat", "Cat")]
+        );
+        // Only the schema and its descendants are candidates
+        let mapping = resolver.discriminator_mapping("Puppy").unwrap();
+        assert_eq!(mapping.into_iter().collect::<Vec<_>>(), vec![("Puppy", "Puppy")]);
+    }
+
+    #[test]
+    fn test_descendants_skip_broken_schemas() {
+        let mut components = components();
+        let schemas = components.schemas.as_mut().unwrap();
+        let broken: [(&str, serde_json::Value); 3] = [
+            ("Ghost", json!({"allOf": [{"$ref": "#/components/schemas/Missing"}]})),
+            ("Hybrid", json!({"allOf": [{"$ref": "#/components/schemas/Missing"}, {"$ref": "#/components/schemas/Cat"}]})),
+            ("Loop", json!({"allOf": [{"$ref": "#/components/schemas/Loop"}]})),
+        ];
+        for (name, schema) in broken {
+            schemas.insert(name.to_string(), serde_json::from_value(schema).unwrap());
+        }
+        let resolver = Resolver::from_components(&components);
+        assert!(resolver.ancestors("Ghost").is_err());
+        assert_eq!(resolver.descendants("Pet").unwrap(), vec!["Dog", "Puppy", "Cat", "Hybrid"]);
+        assert!(resolver.descendants("Missing").is_err());
+    }
+
+    #[test]
+    fn test_all_of_cycle() {
+        let components: Components = serde_json::from_value(json!({
+            "schemas": {
+                "A": {"allOf": [{"$ref": "#/components/schemas/B"}]},
+                "B": {"allOf": [{"$ref": "#/components/schemas/A"}], "discriminator": {"propertyName": "kind"}}
+            }
+        }))
+        .unwrap();
+        let resolver = Resolver::from_components(&components);
+        assert_eq!(resolver.ancestors("A"), Err(Error::AllOfCycle("B".to_string())));
+        // The chain of A reaches B before it closes the cycle
+        assert_eq!(resolver.descendants("B").unwrap(), vec!["A"]);
+        assert!(resolver.discriminator_mapping("A").is_err());
+        let (_, a) = resolver.resolve_name::<Schema>("A").unwrap();
+        assert_eq!(resolver.object_shape(a), Err(Error::AllOfCycle("B".to_string())));
+    }
+}
@@ -1,5 +1,6 @@
 mod aux;
 mod callback;
+mod compose;
 mod example;
 mod ext_doc;
 mod header;
@@ -12,13 +13,17 @@ mod style;
 mod param;
 mod path_item;
 mod ref_or;
+mod resolve;
 mod schema;
 mod sec;
 mod server;
+mod validate;
+mod visit;
 
 
 pub use aux::*;
 pub use callback::*;
+pub use compose::*;
 pub use example::*;
 pub use ext_doc::*;
 pub use header::*;
@@ -31,6 +36,9 @@ pub use style::*;
 pub use param::*;
 pub use path_item::*;
 pub use ref_or::*;
+pub use resolve::*;
 pub use schema::*;
 pub use sec::*;
-pub use server::*;
\ No newline at end of file
+pub use server::*;
+pub use validate::*;
+pub use visit::*;
@@ -45,4 +45,23 @@ pub struct PathItem {
     /// Spec Extensions
     #[serde(flatten)]
     pub extensions: IndexMap<String, serde_json::Value>,
-}
\ No newline at end of file
+}
+
+impl PathItem {
+    /// Iterate over the operations defined on this path together with their lower case HTTP
+    /// method names.
+    pub fn operations(&self) -> impl Iterator<Item = (&'static str, &Operation)> {
+        [
+            ("get", &self.get),
+            ("put", &self.put),
+            ("post", &self.post),
+            ("delete", &self.delete),
+            ("options", &self.options),
+            ("head", &self.head),
+            ("patch", &self.patch),
+            ("trace", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+    }
+}
@@ -0,0 +1,314 @@
+// Resolution of local references e.g. `#/components/schemas/Pet` against the components of a
+// document. References to other documents are not supported.
+
+use indexmap::IndexMap;
+use std::borrow::Cow;
+use std::fmt::Display;
+use super::*;
+
+const COMPONENTS_PREFIX: &str = "#/components/";
+
+/// The kinds of reusable objects held in the Components Object. The names are the field names of
+/// the Components Object as they appear in references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+    Schemas,
+    Responses,
+    Parameters,
+    Examples,
+    RequestBodies,
+    Headers,
+    SecuritySchemes,
+    Links,
+    Callbacks,
+}
+
+impl ComponentKind {
+    /// Name of the field in the Components Object.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComponentKind::Schemas => "schemas",
+            ComponentKind::Responses => "responses",
+            ComponentKind::Parameters => "parameters",
+            ComponentKind::Examples => "examples",
+            ComponentKind::RequestBodies => "requestBodies",
+            ComponentKind::Headers => "headers",
+            ComponentKind::SecuritySchemes => "securitySchemes",
+            ComponentKind::Links => "links",
+            ComponentKind::Callbacks => "callbacks",
+        }
+    }
+
+    /// Kind for the name of a field in the Components Object.
+    pub fn from_name(name: &str) -> Option<ComponentKind> {
+        match name {
+            "schemas" => Some(ComponentKind::Schemas),
+            "responses" => Some(ComponentKind::Responses),
+            "parameters" => Some(ComponentKind::Parameters),
+            "examples" => Some(ComponentKind::Examples),
+            "requestBodies" => Some(ComponentKind::RequestBodies),
+            "headers" => Some(ComponentKind::Headers),
+            "securitySchemes" => Some(ComponentKind::SecuritySchemes),
+            "links" => Some(ComponentKind::Links),
+            "callbacks" => Some(ComponentKind::Callbacks),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ComponentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A parsed local reference to a component e.g. `#/components/schemas/Pet`. The name is unescaped
+/// as defined by JSON Pointer i.e. `~1` stands for `/` and `~0` for `~`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentRef<'a> {
+    /// The kind of the referenced component.
+    pub kind: ComponentKind,
+    /// The name of the referenced component.
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> ComponentRef<'a> {
+    /// Parse a reference of the form `#/components/{kind}/{name}`.
+    pub fn parse(reference: &'a str) -> Result<ComponentRef<'a>, Error> {
+        let invalid = || Error::InvalidReference(reference.to_string());
+        let path = reference.strip_prefix(COMPONENTS_PREFIX).ok_or_else(invalid)?;
+        let (kind, name) = path.split_once('/').ok_or_else(invalid)?;
+        let kind = ComponentKind::from_name(kind).ok_or_else(invalid)?;
+        if name.is_empty() || name.contains('/') {
+            return Err(invalid());
+        }
+        let name = if name.contains('~') {
+            Cow::Owned(name.replace("~1", "/").replace("~0", "~"))
+        } else {
+            Cow::Borrowed(name)
+        };
+        Ok(ComponentRef { kind, name })
+    }
+
+    /// Parse a reference that must point to a component of the given kind and return the name of
+    /// the component.
+    pub fn name_of(reference: &'a str, kind: ComponentKind) -> Result<Cow<'a, str>, Error> {
+        let component = ComponentRef::parse(reference)?;
+        if component.kind != kind {
+            return Err(Error::ReferenceKindMismatch { reference: reference.to_string(), expected: kind });
+        }
+        Ok(component.name)
+    }
+}
+
+impl Display for ComponentRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}/{}", COMPONENTS_PREFIX, self.kind, self.name.replace('~', "~0").replace('/', "~1"))
+    }
+}
+
+/// Objects that can be defined once in the Components Object and referenced elsewhere.
+pub trait Component: Sized {
+    /// The field of the Components Object holding objects of this type.
+    const KIND: ComponentKind;
+    /// The objects of this type defined in `components`.
+    fn components(components: &Components) -> Option<&IndexMap<String, RefOr<Self>>>;
+}
+
+macro_rules! component {
+    ($type:ty, $kind:ident, $field:ident) => {
+        impl Component for $type {
+            const KIND: ComponentKind = ComponentKind::$kind;
+            fn components(components: &Components) -> Option<&IndexMap<String, RefOr<Self>>> {
+                components.$field.as_ref()
+            }
+        }
+    };
+}
+
+component!(Schema, Schemas, schemas);
+component!(Response, Responses, responses);
+component!(Parameter, Parameters, parameters);
+component!(Example, Examples, examples);
+component!(RequestBody, RequestBodies, request_bodies);
+component!(Header, Headers, headers);
+component!(SecurityScheme, SecuritySchemes, security_schemes);
+component!(Link, Links, links);
+component!(Callback, Callbacks, callbacks);
+
+/// Looks up the components of a document by name or reference. Components that are references
+/// themselves are followed until a value is found. Reference cycles are reported as errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Resolver<'a> {
+    components: Option<&'a Components>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Resolver for the components of the document.
+    pub fn new(openapi: &'a OpenAPI) -> Self {
+        Resolver { components: openapi.components.as_ref() }
+    }
+
+    /// Resolver for a Components Object.
+    pub fn from_components(components: &'a Components) -> Self {
+        Resolver { components: Some(components) }
+    }
+
+    /// The component with the given name as defined i.e. without following references. The
+    /// returned name has the lifetime of the document.
+    pub fn get<T: Component>(&self, name: &str) -> Option<(&'a str, &'a RefOr<T>)> {
+        let (name, value) = T::components(self.components?)?.get_key_value(name)?;
+        Some((name.as_str(), value))
+    }
+
+    /// Iterate over the components of type `T` in the order of the document.
+    pub fn iter<T: Component + 'a>(&self) -> impl Iterator<Item = (&'a str, &'a RefOr<T>)> {
+        self.components
+            .and_then(T::components)
+            .into_iter()
+            .flat_map(|components| components.iter().map(|(name, value)| (name.as_str(), value)))
+    }
+
+    /// Resolve a value that may be a reference.
+    pub fn resolve<T: Component>(&self, value: &'a RefOr<T>) -> Result<&'a T, Error> {
+        match value {
+            RefOr::Val(value) => Ok(value),
+            RefOr::Ref { reference, .. } => Ok(self.resolve_reference::<T>(reference)?.1),
+        }
+    }
+
+    /// Resolve a reference and return the name of the component at the end of the reference chain
+    /// together with its value.
+    pub fn resolve_reference<T: Component>(&self, reference: &str) -> Result<(&'a str, &'a T), Error> {
+        let mut seen: Vec<&'a str> = Vec::new();
+        let mut current = ComponentRef::name_of(reference, T::KIND)?.into_owned();
+        loop {
+            let Some((name, value)) = self.get::<T>(&current) else {
+                return Err(Error::UnresolvedReference(
+                    ComponentRef { kind: T::KIND, name: Cow::Borrowed(&current) }.to_string(),
+                ));
+            };
+            if seen.contains(&name) {
+                return Err(Error::ReferenceCycle(reference.to_string()));
+            }
+            seen.push(name);
+            match value {
+                RefOr::Val(value) => return Ok((name, value)),
+                RefOr::Ref { reference: next, .. } => current = ComponentRef::name_of(next, T::KIND)?.into_owned(),
+            }
+        }
+    }
+
+    /// Resolve a component by name following references.
+    pub fn resolve_name<T: Component>(&self, name: &str) -> Result<(&'a str, &'a T), Error> {
+        self.resolve_reference::<T>(&ComponentRef { kind: T::KIND, name: Cow::Borrowed(name) }.to_string())
+    }
+
+    /// Check that a reference resolves to a component of the given kind.
+    pub fn verify(&self, reference: &str, kind: ComponentKind) -> Result<(), Error> {
+        match kind {
+            ComponentKind::Schemas => self.resolve_reference::<Schema>(reference).map(|_| ()),
+            ComponentKind::Responses => self.resolve_reference::<Response>(reference).map(|_| ()),
+            ComponentKind::Parameters => self.resolve_reference::<Parameter>(reference).map(|_| ()),
+            ComponentKind::Examples => self.resolve_reference::<Example>(reference).map(|_| ()),
+            ComponentKind::RequestBodies => self.resolve_reference::<RequestBody>(reference).map(|_| ()),
+            ComponentKind::Headers => self.resolve_reference::<Header>(reference).map(|_| ()),
+            ComponentKind::SecuritySchemes => self.resolve_reference::<SecurityScheme>(reference).map(|_| ()),
+            ComponentKind::Links => self.resolve_reference::<Link>(reference).map(|_| ()),
+            ComponentKind::Callbacks => self.resolve_reference::<Callback>(reference).map(|_| ()),
+        }
+    }
+
+    /// Resolve a schema that may be a reference.
+    pub fn schema(&self, schema: &'a RefOr<Schema>) -> Result<&'a Schema, Error> {
+        self.resolve(schema)
+    }
+
+    /// Resolve a parameter that may be a reference.
+    pub fn parameter(&self, parameter: &'a RefOr<Parameter>) -> Result<&'a Parameter, Error> {
+        self.resolve(parameter)
+    }
+
+    /// Resolve a response that may be a reference.
+    pub fn response(&self, response: &'a RefOr<Response>) -> Result<&'a Response, Error> {
+        self.resolve(response)
+    }
+
+    /// Resolve a request body that may be a reference.
+    pub fn request_body(&self, request_body: &'a RefOr<RequestBody>) -> Result<&'a RequestBody, Error> {
+        self.resolve(request_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn components() -> Components {
+        serde_json::from_value(json!({
+            "schemas": {
+                "Pet": {"type": "object", "properties": {"name": {"type": "string"}}},
+                "Animal": {"$ref": "#/components/schemas/Pet"},
+                "A/B": {"type": "string"},
+                "Loop1": {"$ref": "#/components/schemas/Loop2"},
+                "Loop2": {"$ref": "#/components/schemas/Loop1"}
+            },
+            "parameters": {
+                "limit": {"name": "limit", "in": "query", "schema": {"type": "integer"}}
+            },
+            "requestBodies": {
+                "PetBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}}
+            },
+            "responses": {
+                "NotFound": {"description": "Not found"},
+                "Missing": {"$ref": "#/components/responses/Gone"}
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_reference() {
+        let component = ComponentRef::parse("#/components/requestBodies/PetBody").unwrap();
+        assert_eq!(component.kind, ComponentKind::RequestBodies);
+        assert_eq!(component.name, "PetBody");
+        let component = ComponentRef::parse("#/components/schemas/A~1B~0").unwrap();
+        assert_eq!(component.name, "A/B~");
+        assert_eq!(component.to_string(), "#/components/schemas/A~1B~0");
+        assert!(ComponentRef::parse("#/definitions/Pet").is_err());
+        assert!(ComponentRef::parse("#/components/schemas/").is_err());
+        assert!(ComponentRef::parse("#/components/schemas/Pet/properties/name").is_err());
+        assert_eq!(
+            ComponentRef::name_of("#/components/parameters/limit", ComponentKind::Schemas),
+            Err(Error::ReferenceKindMismatch {
+                reference: "#/components/parameters/limit".to_string(),
+                expected: ComponentKind::Schemas
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let components = components();
+        let resolver = Resolver::from_components(&components);
+        let (name, schema) = resolver.resolve_reference::<Schema>("#/components/schemas/Animal").unwrap();
+        assert_eq!(name, "Pet");
+        assert_eq!(schema.schema_type, Some(SchemaType::Object));
+        assert!(resolver.resolve_name::<Schema>("A/B").is_ok());
+        let parameter = resolver.resolve_reference::<Parameter>("#/components/parameters/limit").unwrap().1;
+        assert_eq!(parameter.location, Location::Query);
+        assert!(resolver.verify("#/components/requestBodies/PetBody", ComponentKind::RequestBodies).is_ok());
+        assert_eq!(
+            resolver.resolve_reference::<Response>("#/components/responses/Missing").unwrap_err(),
+            Error::UnresolvedReference("#/components/responses/Gone".to_string())
+        );
+        assert_eq!(
+            resolver.resolve_reference::<Schema>("#/components/schemas/Loop1").unwrap_err(),
+            Error::ReferenceCycle("#/components/schemas/Loop1".to_string())
+        );
+        let inline = RefOr::Val(Box::new(Schema::default()));
+        assert!(resolver.schema(&inline).is_ok());
+        assert_eq!(resolver.iter::<Schema>().count(), 5);
+    }
+}
@@ -0,0 +1,273 @@
+// Structural validation of a complete document. Unlike `Validate` that checks a single object the
+// validator checks the references and the rules spanning several objects and reports all the
+// problems found with their locations.
+
+use super::*;
+
+/// A problem found in a document.
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("{pointer}: {error}")]
+pub struct ValidationError {
+    /// JSON Pointer to the offending value.
+    pub pointer: String,
+    pub error: Error,
+}
+
+/// Validate the document and return the problems found in document order. The following is
+/// checked:
+/// - references resolve to a component of the expected kind without cycles
+/// - schemas with a type pass `Validate` and `allOf` hierarchies have no cycles
+/// - discriminator properties are defined and mappings resolve
+/// - paths start with `/` and their templates match the path parameters of each operation
+/// - path parameters are required and parameters define either `schema` or a single `content`
+/// - operation ids are unique, parameters are not repeated and responses are not empty
+pub fn validate_document(openapi: &OpenAPI) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        resolver: Resolver::new(openapi),
+        operation_ids: Vec::new(),
+        errors: Vec::new(),
+    };
+    walk_openapi(openapi, &mut validator);
+    validator.errors
+}
+
+struct Validator<'a> {
+    resolver: Resolver<'a>,
+    operation_ids: Vec<&'a str>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn report(&mut self, pointer: &JsonPointer, error: Error) {
+        self.errors.push(ValidationError { pointer: pointer.to_string(), error });
+    }
+
+    fn check_path(&mut self, pointer: &JsonPointer, path: &str, path_item: &PathItem) {
+        let template: Vec<&str> = path
+            .split('{')
+            .skip(1)
+            .filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+            .collect();
+        let mut item_parameters = Vec::new();
+        for (index, parameter) in path_item.parameters.iter().flatten().enumerate() {
+            if parameter.location == Location::Path {
+                self.check_path_parameter(&pointer.join("parameters").join(&index.to_string()), parameter, &template);
+                item_parameters.push(parameter.name.as_str());
+            }
+        }
+        for (method, operation) in path_item.operations() {
+            let pointer = pointer.join(method);
+            let mut defined = item_parameters.clone();
+            for (index, parameter) in operation.parameters.iter().flatten().enumerate() {
+                let Ok(parameter) = self.resolver.parameter(parameter) else {
+                    continue;
+                };
+                if parameter.location == Location::Path {
+                    self.check_path_parameter(&pointer.join("parameters").join(&index.to_string()), parameter, &template);
+                    defined.push(parameter.name.as_str());
+                }
+            }
+            for name in &template {
+                if !defined.contains(name) {
+                    self.report(&pointer, Error::MissingPathParameter(name.to_string()));
+                }
+            }
+        }
+    }
+
+    fn check_path_parameter(&mut self, pointer: &JsonPointer, parameter: &Parameter, template: &[&str]) {
+        if !template.contains(&parameter.name.as_str()) {
+            self.report(&pointer.join("name"), Error::UnknownPathParameter(parameter.name.clone()));
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for Validator<'a> {
+    fn visit_reference(&mut self, pointer: &JsonPointer, reference: &'a str, kind: ComponentKind) {
+        if let Err(e) = self.resolver.verify(reference, kind) {
+            self.report(&pointer.join("$ref"), e);
+        }
+    }
+
+    fn visit_path_item(&mut self, pointer: &JsonPointer, path: &'a str, path_item: &'a PathItem) {
+        if path.starts_with('/') {
+            self.check_path(pointer, path, path_item);
+        } else if pointer.as_str().starts_with("/paths/") {
+            self.report(pointer, Error::InvalidField(format!("path {path} does not start with /")));
+        }
+    }
+
+    fn visit_operation(&mut self, pointer: &JsonPointer, _method: &'static str, operation: &'a Operation) {
+        if let Some(operation_id) = &operation.operation_id {
+            if self.operation_ids.contains(&operation_id.as_str()) {
+                self.report(&pointer.join("operationId"), Error::DuplicateOperationId(operation_id.clone()));
+            } else {
+                self.operation_ids.push(operation_id);
+            }
+        }
+        let mut seen: Vec<&Parameter> = Vec::new();
+        for (index, parameter) in operation.parameters.iter().flatten().enumerate() {
+            let Ok(parameter) = self.resolver.parameter(parameter) else {
+                continue;
+            };
+            if seen.iter().any(|other| other.name == parameter.name && other.location == parameter.location) {
+                let pointer = pointer.join("parameters").join(&index.to_string());
+                self.report(&pointer, Error::DuplicateParameter(parameter.name.clone()));
+            }
+            seen.push(parameter);
+        }
+        let responses = &operation.responses;
+        if responses.default_response.is_none() && responses.responses.is_empty() {
+            self.report(&pointer.join("responses"), Error::MissingField("responses".to_string()));
+        }
+    }
+
+    fn visit_parameter(&mut self, pointer: &JsonPointer, parameter: &'a Parameter) {
+        if parameter.location == Location::Path && parameter.required != Some(true) {
+            self.report(
+                &pointer.join("required"),
+                Error::InvalidField(format!("path parameter {} must be required", parameter.name)),
+            );
+        }
+        match (&parameter.schema, &parameter.content) {
+            (Some(_), None) => {}
+            (None, Some(content)) if content.len() == 1 => {}
+            (None, Some(_)) => {
+                self.report(&pointer.join("content"), Error::InvalidField("content must have one entry".to_string()));
+            }
+            _ => self.report(pointer, Error::InvalidField("either schema or content must be defined".to_string())),
+        }
+    }
+
+    fn visit_schema(&mut self, pointer: &JsonPointer, schema: &'a Schema) {
+        if schema.schema_type.is_some() {
+            if let Err(e) = schema.validate() {
+                self.report(pointer, e);
+            }
+        }
+        let shape = match self.resolver.object_shape(schema) {
+            Ok(shape) => Some(shape),
+            Err(e @ Error::AllOfCycle(_)) => {
+                self.report(&pointer.join("allOf"), e);
+                None
+            }
+            // Unresolved references are reported where they are visited
+            Err(_) => None,
+        };
+        let Some(discriminator) = &schema.discriminator else {
+            return;
+        };
+        let pointer = pointer.join("discriminator");
+        if shape.is_some_and(|shape| !shape.properties.contains_key(discriminator.property_name.as_str())) {
+            self.report(
+                &pointer.join("propertyName"),
+                Error::InvalidField(format!("{} is not a property of the schema", discriminator.property_name)),
+            );
+        }
+        for (value, target) in discriminator.mapping.iter().flatten() {
+            let result = if target.starts_with('#') {
+                self.resolver.resolve_reference::<Schema>(target)
+            } else {
+                self.resolver.resolve_name::<Schema>(target)
+            };
+            if let Err(e) = result {
+                self.report(&pointer.join("mapping").join(value), e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_document() {
+        let openapi: OpenAPI = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": {"title": "Pets", "version": "1.0"},
+            "paths": {
+                "/pets/{id}": {
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                    "get": {
+                        "operationId": "getPet",
+                        "responses": {"200": {"$ref": "#/components/responses/Pet"}}
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {"kind": {"type": "string"}},
+                        "discriminator": {"propertyName": "kind", "mapping": {"dog": "Dog"}}
+                    },
+                    "Dog": {"allOf": [{"$ref": "#/components/schemas/Pet"}]}
+                },
+                "responses": {
+                    "Pet": {"description": "A pet", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}}
+                }
+            }
+        }))
+        .unwrap();
+        assert_eq!(validate_document(&openapi), vec![]);
+    }
+
+    #[test]
+    fn test_invalid_document() {
+        let openapi: OpenAPI = serde_json::from_str(
+            r##"{
+            "openapi": "3.0.3",
+            "info": {"title": "Pets", "version": "1.0"},
+            "paths": {
+                "/pets/{id}": {
+                    "get": {
+                        "operationId": "getPet",
+                        "parameters": [
+                            {"name": "limit", "in": "query", "schema": {"$ref": "#/components/schemas/Limit"}},
+                            {"name": "limit", "in": "query", "schema": {"type": "integer"}},
+                            {"name": "name", "in": "path", "schema": {"type": "string"}}
+                        ],
+                        "responses": {}
+                    },
+                    "put": {
+                        "operationId": "getPet",
+                        "parameters": [{"$ref": "#/components/schemas/Pet"}],
+                        "responses": {"204": {"description": "Updated"}}
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "string",
+                        "items": {"type": "string"},
+                        "discriminator": {"propertyName": "kind"}
+                    },
+                    "A": {"allOf": [{"$ref": "#/components/schemas/A"}]}
+                }
+            }
+        }"##,
+        )
+        .unwrap();
+        let errors: Vec<String> = validate_document(&openapi).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "/paths/~1pets~1{id}/get/parameters/2/name: Path parameter name does not appear in the path",
+                "/paths/~1pets~1{id}/get: Path parameter id is not defined",
+                "/paths/~1pets~1{id}/put: Path parameter id is not defined",
+                "/paths/~1pets~1{id}/get/parameters/1: Parameter limit is defined more than once",
+                "/paths/~1pets~1{id}/get/responses: Missing field: responses",
+                "/paths/~1pets~1{id}/get/parameters/0/schema/$ref: Reference #/components/schemas/Limit does not resolve",
+                "/paths/~1pets~1{id}/get/parameters/2/required: Invalid field: path parameter name must be required",
+                "/paths/~1pets~1{id}/put/operationId: Operation id getPet is not unique",
+                "/paths/~1pets~1{id}/put/parameters/0/$ref: Reference #/components/schemas/Pet does not point to parameters",
+                "/components/schemas/Pet: Schema of type String has invalid field: items",
+                "/components/schemas/Pet/discriminator/propertyName: Invalid field: kind is not a property of the schema",
+                "/components/schemas/A/allOf: Schema A is part of an allOf cycle",
+            ]
+        );
+    }
+}
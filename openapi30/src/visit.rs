@@ -0,0 +1,420 @@
+// Traversal of a document with the JSON Pointer location of each visited object. The walk covers
+// the document tree only. References are reported to the visitor and never followed, so the walk
+// terminates for recursive schemas. `Resolver::reachable_schemas` follows schema references and
+// visits each named schema once.
+
+use indexmap::IndexMap;
+use std::fmt::Display;
+use super::*;
+
+/// A JSON Pointer (RFC 6901) into the document e.g. `/paths/~1pets/get/responses/200`. The empty
+/// pointer refers to the whole document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonPointer {
+    pointer: String,
+    marks: Vec<usize>,
+}
+
+impl JsonPointer {
+    /// Pointer to the whole document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a reference token escaping `~` and `/`.
+    pub fn push(&mut self, token: &str) {
+        self.marks.push(self.pointer.len());
+        self.pointer.push('/');
+        for c in token.chars() {
+            match c {
+                '~' => self.pointer.push_str("~0"),
+                '/' => self.pointer.push_str("~1"),
+                c => self.pointer.push(c),
+            }
+        }
+    }
+
+    /// Append an array index.
+    pub fn push_index(&mut self, index: usize) {
+        self.push(&index.to_string());
+    }
+
+    /// Remove the last reference token.
+    pub fn pop(&mut self) {
+        if let Some(mark) = self.marks.pop() {
+            self.pointer.truncate(mark);
+        }
+    }
+
+    /// Pointer appended with a reference token.
+    pub fn join(&self, token: &str) -> JsonPointer {
+        let mut pointer = self.clone();
+        pointer.push(token);
+        pointer
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pointer
+    }
+}
+
+impl Display for JsonPointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pointer)
+    }
+}
+
+/// Callbacks for `walk_openapi` and `walk_schema`. All methods do nothing by default. The pointer
+/// gives the location of the visited object. Objects given as references are reported with
+/// `visit_reference` instead of the object type callback.
+#[allow(unused_variables)]
+pub trait Visitor<'a> {
+    /// A Reference Object pointing to a component of the given kind.
+    fn visit_reference(&mut self, pointer: &JsonPointer, reference: &'a str, kind: ComponentKind) {}
+    /// A Path Item Object. `path` is the key in the Paths Object or the expression in a Callback
+    /// Object.
+    fn visit_path_item(&mut self, pointer: &JsonPointer, path: &'a str, path_item: &'a PathItem) {}
+    /// An Operation Object with the lower case HTTP method.
+    fn visit_operation(&mut self, pointer: &JsonPointer, method: &'static str, operation: &'a Operation) {}
+    fn visit_parameter(&mut self, pointer: &JsonPointer, parameter: &'a Parameter) {}
+    fn visit_request_body(&mut self, pointer: &JsonPointer, request_body: &'a RequestBody) {}
+    fn visit_response(&mut self, pointer: &JsonPointer, response: &'a Response) {}
+    fn visit_header(&mut self, pointer: &JsonPointer, header: &'a Header) {}
+    fn visit_media_type(&mut self, pointer: &JsonPointer, media_type: &'a MediaType) {}
+    /// A Schema Object. Nested schemas are visited after their parent.
+    fn visit_schema(&mut self, pointer: &JsonPointer, schema: &'a Schema) {}
+}
+
+/// Visit the objects of the document in document order: the paths first and the components after.
+pub fn walk_openapi<'a, V: Visitor<'a>>(openapi: &'a OpenAPI, visitor: &mut V) {
+    let mut walk = Walk { pointer: JsonPointer::new(), visitor };
+    walk.map("paths", &openapi.paths, |walk, path, path_item| walk.path_item(path, path_item));
+    if let Some(components) = &openapi.components {
+        walk.pointer.push("components");
+        walk.components(components);
+        walk.pointer.pop();
+    }
+}
+
+/// Visit a schema and its nested schemas. `pointer` is the location of the schema.
+pub fn walk_schema<'a, V: Visitor<'a>>(pointer: JsonPointer, schema: &'a RefOr<Schema>, visitor: &mut V) {
+    let mut walk = Walk { pointer, visitor };
+    walk.ref_or(schema, Walk::schema);
+}
+
+struct Walk<'v, V> {
+    pointer: JsonPointer,
+    visitor: &'v mut V,
+}
+
+impl<'a, V: Visitor<'a>> Walk<'_, V> {
+    fn components(&mut self, components: &'a Components) {
+        self.ref_or_map("schemas", &components.schemas, Self::schema);
+        self.ref_or_map("responses", &components.responses, Self::response);
+        self.ref_or_map("parameters", &components.parameters, Self::parameter);
+        self.ref_or_map("examples", &components.examples, |_, _| {});
+        self.ref_or_map("requestBodies", &components.request_bodies, Self::request_body);
+        self.ref_or_map("headers", &components.headers, Self::header);
+        self.ref_or_map("securitySchemes", &components.security_schemes, |_, _| {});
+        self.ref_or_map("links", &components.links, |_, _| {});
+        self.ref_or_map("callbacks", &components.callbacks, Self::callback);
+    }
+
+    fn path_item(&mut self, path: &'a str, path_item: &'a PathItem) {
+        self.visitor.visit_path_item(&self.pointer, path, path_item);
+        if let Some(parameters) = &path_item.parameters {
+            self.pointer.push("parameters");
+            for (index, parameter) in parameters.iter().enumerate() {
+                self.pointer.push_index(index);
+                self.parameter(parameter);
+                self.pointer.pop();
+            }
+            self.pointer.pop();
+        }
+        for (method, operation) in path_item.operations() {
+            self.pointer.push(method);
+            self.operation(method, operation);
+            self.pointer.pop();
+        }
+    }
+
+    fn operation(&mut self, method: &'static str, operation: &'a Operation) {
+        self.visitor.visit_operation(&self.pointer, method, operation);
+        self.ref_or_list("parameters", &operation.parameters, Self::parameter);
+        if let Some(request_body) = &operation.request_body {
+            self.pointer.push("requestBody");
+            self.ref_or(request_body, Self::request_body);
+            self.pointer.pop();
+        }
+        self.pointer.push("responses");
+        if let Some(response) = &operation.responses.default_response {
+            self.pointer.push("default");
+            self.ref_or(response, Self::response);
+            self.pointer.pop();
+        }
+        for (code, response) in &operation.responses.responses {
+            self.pointer.push(code);
+            self.ref_or(response, Self::response);
+            self.pointer.pop();
+        }
+        self.pointer.pop();
+        self.ref_or_map("callbacks", &operation.callbacks, Self::callback);
+    }
+
+    fn callback(&mut self, callback: &'a Callback) {
+        for (expression, path_item) in &callback.callbacks {
+            self.pointer.push(expression);
+            self.path_item(expression, path_item);
+            self.pointer.pop();
+        }
+    }
+
+    fn parameter(&mut self, parameter: &'a Parameter) {
+        self.visitor.visit_parameter(&self.pointer, parameter);
+        self.optional_schema(&parameter.schema);
+        self.ref_or_map("examples", &parameter.examples, |_, _| {});
+        self.content(&parameter.content);
+    }
+
+    fn request_body(&mut self, request_body: &'a RequestBody) {
+        self.visitor.visit_request_body(&self.pointer, request_body);
+        self.map("content", &request_body.content, |walk, _, media_type| walk.media_type(media_type));
+    }
+
+    fn response(&mut self, response: &'a Response) {
+        self.visitor.visit_response(&self.pointer, response);
+        self.ref_or_map("headers", &response.headers, Self::header);
+        self.content(&response.content);
+        self.ref_or_map("links", &response.links, |_, _| {});
+    }
+
+    fn header(&mut self, header: &'a Header) {
+        self.visitor.visit_header(&self.pointer, header);
+        self.optional_schema(&header.schema);
+        self.ref_or_map("examples", &header.examples, |_, _| {});
+    }
+
+    fn content(&mut self, content: &'a Option<IndexMap<String, MediaType>>) {
+        if let Some(content) = content {
+            self.map("content", content, |walk, _, media_type| walk.media_type(media_type));
+        }
+    }
+
+    fn media_type(&mut self, media_type: &'a MediaType) {
+        self.visitor.visit_media_type(&self.pointer, media_type);
+        self.optional_schema(&media_type.schema);
+        self.ref_or_map("examples", &media_type.examples, |_, _| {});
+        if let Some(encoding) = &media_type.encoding {
+            self.map("encoding", encoding, |walk, _, encoding| {
+                walk.ref_or_map("headers", &encoding.headers, Self::header);
+            });
+        }
+    }
+
+    fn optional_schema(&mut self, schema: &'a Option<RefOr<Schema>>) {
+        if let Some(schema) = schema {
+            self.pointer.push("schema");
+            self.ref_or(schema, Self::schema);
+            self.pointer.pop();
+        }
+    }
+
+    fn schema(&mut self, schema: &'a Schema) {
+        self.visitor.visit_schema(&self.pointer, schema);
+        self.ref_or_list("allOf", &schema.all_of, Self::schema);
+        self.ref_or_list("oneOf", &schema.one_of, Self::schema);
+        self.ref_or_list("anyOf", &schema.any_of, Self::schema);
+        for (name, nested) in [
+            ("not", &schema.not),
+            ("items", &schema.items),
+            ("additionalProperties", &schema.additional_properties),
+        ] {
+            if let Some(nested) = nested {
+                self.pointer.push(name);
+                self.ref_or(nested, Self::schema);
+                self.pointer.pop();
+            }
+        }
+        self.ref_or_map("properties", &schema.properties, Self::schema);
+    }
+
+    fn ref_or<T: Component>(&mut self, value: &'a RefOr<T>, visit: impl FnOnce(&mut Self, &'a T)) {
+        match value {
+            RefOr::Ref { reference, .. } => self.visitor.visit_reference(&self.pointer, reference, T::KIND),
+            RefOr::Val(value) => visit(self, value),
+        }
+    }
+
+    fn ref_or_list<T: Component>(
+        &mut self,
+        field: &str,
+        values: &'a Option<Vec<RefOr<T>>>,
+        visit: impl Fn(&mut Self, &'a T),
+    ) {
+        let Some(values) = values else {
+            return;
+        };
+        self.pointer.push(field);
+        for (index, value) in values.iter().enumerate() {
+            self.pointer.push_index(index);
+            self.ref_or(value, &visit);
+            self.pointer.pop();
+        }
+        self.pointer.pop();
+    }
+
+    fn ref_or_map<T: Component>(
+        &mut self,
+        field: &str,
+        values: &'a Option<IndexMap<String, RefOr<T>>>,
+        visit: impl Fn(&mut Self, &'a T),
+    ) {
+        if let Some(values) = values {
+            self.map(field, values, |walk, _, value| walk.ref_or(value, &visit));
+        }
+    }
+
+    fn map<T>(&mut self, field: &str, values: &'a IndexMap<String, T>, mut visit: impl FnMut(&mut Self, &'a str, &'a T)) {
+        self.pointer.push(field);
+        for (key, value) in values {
+            self.pointer.push(key);
+            visit(self, key, value);
+            self.pointer.pop();
+        }
+        self.pointer.pop();
+    }
+}
+
+impl<'a> Resolver<'a> {
+    /// Named schemas reachable from the given schemas through references, in the order they are
+    /// first reached. Each named schema is visited once so recursive schemas are supported.
+    pub fn reachable_schemas(
+        &self,
+        roots: impl IntoIterator<Item = &'a RefOr<Schema>>,
+    ) -> Result<IndexMap<&'a str, &'a Schema>, Error> {
+        let mut collector = SchemaReferences::default();
+        for root in roots {
+            walk_schema(JsonPointer::new(), root, &mut collector);
+        }
+        let mut reachable = IndexMap::new();
+        let mut next = 0;
+        while next < collector.references.len() {
+            let (name, schema) = self.resolve_reference::<Schema>(collector.references[next])?;
+            next += 1;
+            if reachable.contains_key(name) {
+                continue;
+            }
+            reachable.insert(name, schema);
+            let (_, value) = self.get::<Schema>(name).ok_or_else(|| Error::UnresolvedReference(name.to_string()))?;
+            walk_schema(JsonPointer::new(), value, &mut collector);
+        }
+        Ok(reachable)
+    }
+}
+
+#[derive(Default)]
+struct SchemaReferences<'a> {
+    references: Vec<&'a str>,
+}
+
+impl<'a> Visitor<'a> for SchemaReferences<'a> {
+    fn visit_reference(&mut self, _pointer: &JsonPointer, reference: &'a str, kind: ComponentKind) {
+        if kind == ComponentKind::Schemas {
+            self.references.push(reference);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        visited: Vec<String>,
+    }
+
+    impl<'a> Visitor<'a> for Recorder {
+        fn visit_reference(&mut self, pointer: &JsonPointer, reference: &'a str, _kind: ComponentKind) {
+            self.visited.push(format!("{pointer} -> {reference}"));
+        }
+        fn visit_operation(&mut self, pointer: &JsonPointer, method: &'static str, _operation: &'a Operation) {
+            self.visited.push(format!("{pointer} {method}"));
+        }
+        fn visit_schema(&mut self, pointer: &JsonPointer, _schema: &'a Schema) {
+            self.visited.push(pointer.to_string());
+        }
+    }
+
+    fn openapi() -> OpenAPI {
+        serde_json::from_str(
+            r##"{
+            "openapi": "3.0.3",
+            "info": {"title": "Pets", "version": "1.0"},
+            "paths": {
+                "/pets/{id}": {
+                    "get": {
+                        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "responses": {
+                            "200": {"description": "A pet", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}}
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Pet": {"type": "object", "properties": {"owner": {"$ref": "#/components/schemas/Person"}}},
+                    "Person": {"type": "object", "properties": {"pets": {"type": "array", "items": {"$ref": "#/components/schemas/Pet"}}}},
+                    "Unused": {"type": "string"}
+                }
+            }
+        }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let mut pointer = JsonPointer::new();
+        pointer.push("paths");
+        pointer.push("/pets/{id}");
+        assert_eq!(pointer.as_str(), "/paths/~1pets~1{id}");
+        pointer.push_index(0);
+        pointer.pop();
+        assert_eq!(pointer.join("a~b").as_str(), "/paths/~1pets~1{id}/a~0b");
+        pointer.pop();
+        pointer.pop();
+        pointer.pop();
+        assert_eq!(pointer.as_str(), "");
+    }
+
+    #[test]
+    fn test_walk_openapi() {
+        let openapi = openapi();
+        let mut recorder = Recorder::default();
+        walk_openapi(&openapi, &mut recorder);
+        assert_eq!(
+            recorder.visited,
+            vec![
+                "/paths/~1pets~1{id}/get get",
+                "/paths/~1pets~1{id}/get/parameters/0/schema",
+                "/paths/~1pets~1{id}/get/responses/200/content/application~1json/schema -> #/components/schemas/Pet",
+                "/components/schemas/Pet",
+                "/components/schemas/Pet/properties/owner -> #/components/schemas/Person",
+                "/components/schemas/Person",
+                "/components/schemas/Person/properties/pets",
+                "/components/schemas/Person/properties/pets/items -> #/components/schemas/Pet",
+                "/components/schemas/Unused",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reachable_schemas() {
+        let openapi = openapi();
+        let resolver = Resolver::new(&openapi);
+        let root = RefOr::Ref { reference: "#/components/schemas/Pet".to_string(), description: None, nullable: None };
+        let reachable = resolver.reachable_schemas([&root]).unwrap();
+        assert_eq!(reachable.keys().copied().collect::<Vec<_>>(), vec!["Pet", "Person"]);
+    }
+}
@@ -0,0 +1,465 @@
+//! Comparison of two versions of the API to review a new VI/JSON specification before the bindings
+//! are regenerated.
+//!
+//! A change is breaking if code using the bindings generated from the old specification may no
+//! longer compile against the new ones. Added enum variants are breaking as the generated enums
+//! are exhaustive and matches without a wildcard arm no longer compile. Added optional properties
+//! are not breaking as structs can be created with `..Default::default()` or builders. Changes of
+//! data types whose hierarchy is pruned are never breaking as the types are not generated.
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::generator::{self, load_openapi_specs};
+use crate::vim_model::{self, DataType, EmitMode, Field, Method, Model, ModelFilter, Struct};
+
+/// Changes between two versions of the API.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct ApiDiff {
+    pub changes: Vec<Change>,
+}
+
+/// Single change of an element of the API.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub element: Element,
+    /// Name of the element e.g. `VirtualMachine.PowerOnVM_Task.host` for a parameter or
+    /// `VirtualMachineConfigInfo.uuid` for a property.
+    pub path: String,
+    /// Signature of the element before the change e.g. `string?` for an optional string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    /// Signature of the element after the change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+    pub breaking: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Element {
+    ManagedObject,
+    Method,
+    Parameter,
+    DataType,
+    Property,
+    Parent,
+    Enum,
+    EnumVariant,
+}
+
+impl ApiDiff {
+    /// Number of breaking changes.
+    pub fn breaking_count(&self) -> usize {
+        self.changes.iter().filter(|change| change.breaking).count()
+    }
+
+    fn push(&mut self, kind: ChangeKind, element: Element, path: String, old: Option<String>, new: Option<String>, breaking: bool) {
+        self.changes.push(Change { kind, element, path, old, new, breaking });
+    }
+}
+
+/// Human readable report with a line per change. Breaking changes are marked with `!`.
+impl fmt::Display for ApiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} changes, {} breaking", self.changes.len(), self.breaking_count())?;
+        for change in &self.changes {
+            let marker = if change.breaking { '!' } else { ' ' };
+            write!(f, "{marker} {} {} {}", change.kind, change.element, change.path)?;
+            match (&change.old, &change.new) {
+                (Some(old), Some(new)) => writeln!(f, ": {old} -> {new}")?,
+                (Some(signature), None) | (None, Some(signature)) => writeln!(f, ": {signature}")?,
+                (None, None) => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        };
+        f.write_str(kind)
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let element = match self {
+            Element::ManagedObject => "managed object",
+            Element::Method => "method",
+            Element::Parameter => "parameter",
+            Element::DataType => "data type",
+            Element::Property => "property",
+            Element::Parent => "parent of",
+            Element::Enum => "enum",
+            Element::EnumVariant => "enum variant",
+        };
+        f.write_str(element)
+    }
+}
+
+/// Load the two specifications and compare their models. The hierarchies of `pruned_types` are
+/// pruned as by the generator.
+pub fn diff_specs(old_spec: &Path, new_spec: &Path, pruned_types: &[&str]) -> Result<ApiDiff, generator::Error> {
+    let load = |spec: &Path| -> Result<Model, generator::Error> {
+        let openapi = load_openapi_specs(&[spec])?;
+        Ok(vim_model::load_vim_model(&openapi, Some(pruned_types), &ModelFilter::default())?)
+    };
+    Ok(diff_models(&load(old_spec)?, &load(new_spec)?))
+}
+
+/// Compare the managed objects, data types and enums of two models.
+pub fn diff_models(old: &Model, new: &Model) -> ApiDiff {
+    let mut diff = ApiDiff::default();
+    diff_managed_objects(old, new, &mut diff);
+    diff_structs(old, new, &mut diff);
+    diff_enums(old, new, &mut diff);
+    diff
+}
+
+fn diff_managed_objects<'a>(old: &'a Model, new: &'a Model, diff: &mut ApiDiff) {
+    // Managed objects without methods have no module
+    let with_methods = |model: &'a Model| -> IndexMap<String, IndexMap<String, &'a Method>> {
+        model
+            .managed_objects
+            .iter()
+            .filter(|(_, mo)| !mo.methods.is_empty())
+            .map(|(name, mo)| {
+                let methods = mo
+                    .methods
+                    .iter()
+                    .map(|method| (vim_model::method_filter_name(name, method), method))
+                    .collect();
+                (name.clone(), methods)
+            })
+            .collect()
+    };
+    let (old_mos, new_mos) = (with_methods(old), with_methods(new));
+    for name in old_mos.keys().filter(|name| !new_mos.contains_key(*name)) {
+        diff.push(ChangeKind::Removed, Element::ManagedObject, name.clone(), None, None, true);
+    }
+    for (name, new_methods) in &new_mos {
+        let Some(old_methods) = old_mos.get(name) else {
+            diff.push(ChangeKind::Added, Element::ManagedObject, name.clone(), None, None, false);
+            continue;
+        };
+        for method_name in old_methods.keys().filter(|method_name| !new_methods.contains_key(*method_name)) {
+            let signature = method_signature(old, old_methods[method_name]);
+            diff.push(ChangeKind::Removed, Element::Method, method_name.clone(), Some(signature), None, true);
+        }
+        for (method_name, new_method) in new_methods {
+            match old_methods.get(method_name) {
+                Some(old_method) => diff_method(old, new, method_name, old_method, new_method, diff),
+                None => {
+                    let signature = method_signature(new, new_method);
+                    diff.push(ChangeKind::Added, Element::Method, method_name.clone(), None, Some(signature), false);
+                }
+            }
+        }
+    }
+}
+
+fn diff_method(old: &Model, new: &Model, method_name: &str, old_method: &Method, new_method: &Method, diff: &mut ApiDiff) {
+    let (old_output, new_output) = (return_signature(old_method), return_signature(new_method));
+    if old_output != new_output {
+        let path = format!("{method_name} result");
+        diff.push(ChangeKind::Changed, Element::Method, path, Some(old_output), Some(new_output), true);
+    }
+    // Parameters are passed by position so any change of the list breaks the callers
+    let (old_params, new_params) = (parameters(old, old_method), parameters(new, new_method));
+    diff_fields(method_name, Element::Parameter, &old_params, &new_params, true, |_| true, diff);
+    let order = |params: &IndexMap<String, Field>, others: &IndexMap<String, Field>| -> Vec<String> {
+        params.keys().filter(|name| others.contains_key(*name)).cloned().collect()
+    };
+    let (old_order, new_order) = (order(&old_params, &new_params), order(&new_params, &old_params));
+    if old_order != new_order {
+        let path = format!("{method_name} parameter order");
+        diff.push(ChangeKind::Changed, Element::Method, path, Some(old_order.join(", ")), Some(new_order.join(", ")), true);
+    }
+}
+
+fn diff_structs(old: &Model, new: &Model, diff: &mut ApiDiff) {
+    for (name, old_struct) in &old.structs {
+        if !new.structs.contains_key(name) {
+            let breaking = is_generated(&old_struct.borrow());
+            diff.push(ChangeKind::Removed, Element::DataType, name.clone(), None, None, breaking);
+        }
+    }
+    for (name, new_struct) in &new.structs {
+        let new_struct = new_struct.borrow();
+        let Some(old_struct) = old.structs.get(name) else {
+            diff.push(ChangeKind::Added, Element::DataType, name.clone(), None, None, false);
+            continue;
+        };
+        let old_struct = old_struct.borrow();
+        let breaking = is_generated(&old_struct) && is_generated(&new_struct);
+        if old_struct.parent != new_struct.parent {
+            let parent = |struct_type: &Struct| struct_type.parent.clone().unwrap_or_default();
+            diff.push(ChangeKind::Changed, Element::Parent, name.clone(), Some(parent(&old_struct)), Some(parent(&new_struct)), breaking);
+        }
+        diff_fields(name, Element::Property, &old_struct.fields, &new_struct.fields, breaking, |field| breaking && !field.optional, diff);
+    }
+}
+
+/// Compare the properties of a data type or the parameters of a method. `breaking` tells if
+/// removing or changing a field breaks existing code and `added_breaking` if adding it does.
+fn diff_fields(
+    owner: &str,
+    element: Element,
+    old_fields: &IndexMap<String, Field>,
+    new_fields: &IndexMap<String, Field>,
+    breaking: bool,
+    added_breaking: impl Fn(&Field) -> bool,
+    diff: &mut ApiDiff,
+) {
+    for (name, old_field) in old_fields {
+        if !new_fields.contains_key(name) {
+            diff.push(ChangeKind::Removed, element, format!("{owner}.{name}"), Some(field_signature(old_field)), None, breaking);
+        }
+    }
+    for (name, new_field) in new_fields {
+        let path = format!("{owner}.{name}");
+        match old_fields.get(name) {
+            None => diff.push(ChangeKind::Added, element, path, None, Some(field_signature(new_field)), added_breaking(new_field)),
+            Some(old_field) => {
+                let (old_signature, new_signature) = (field_signature(old_field), field_signature(new_field));
+                if old_signature != new_signature {
+                    diff.push(ChangeKind::Changed, element, path, Some(old_signature), Some(new_signature), breaking);
+                }
+            }
+        }
+    }
+}
+
+fn diff_enums(old: &Model, new: &Model, diff: &mut ApiDiff) {
+    for name in old.enums.keys().filter(|name| !new.enums.contains_key(*name)) {
+        diff.push(ChangeKind::Removed, Element::Enum, name.clone(), None, None, true);
+    }
+    for (name, new_enum) in &new.enums {
+        let Some(old_enum) = old.enums.get(name) else {
+            diff.push(ChangeKind::Added, Element::Enum, name.clone(), None, None, false);
+            continue;
+        };
+        let old_variants: HashSet<&String> = old_enum.variants.iter().collect();
+        let new_variants: HashSet<&String> = new_enum.variants.iter().collect();
+        for variant in old_enum.variants.iter().filter(|variant| !new_variants.contains(variant)) {
+            diff.push(ChangeKind::Removed, Element::EnumVariant, format!("{name}.{variant}"), None, None, true);
+        }
+        for variant in new_enum.variants.iter().filter(|variant| !old_variants.contains(variant)) {
+            diff.push(ChangeKind::Added, Element::EnumVariant, format!("{name}.{variant}"), None, None, true);
+        }
+    }
+}
+
+/// Check if the generator emits the data type i.e. it is not a descendant of a pruned type.
+fn is_generated(struct_type: &Struct) -> bool {
+    !matches!(struct_type.emit_mode, EmitMode::Skip(_))
+}
+
+/// Parameters of a method. The fields of the request type or a single `body` parameter.
+fn parameters(model: &Model, method: &Method) -> IndexMap<String, Field> {
+    match &method.input {
+        Some(DataType::Reference(input)) if model.request_types.contains_key(input) => model.request_types[input]
+            .borrow()
+            .fields
+            .iter()
+            .map(|(name, field)| (name.clone(), field_signature_only(field)))
+            .collect(),
+        Some(input) => {
+            let field = Field {
+                name: "body".to_string(),
+                description: None,
+                optional: false,
+                vim_type: input.clone(),
+                require_box: false,
+            };
+            IndexMap::from([("body".to_string(), field)])
+        }
+        None => IndexMap::new(),
+    }
+}
+
+/// Copy of the parts of a field that make up its signature.
+fn field_signature_only(field: &Field) -> Field {
+    Field {
+        name: field.name.clone(),
+        description: None,
+        optional: field.optional,
+        vim_type: field.vim_type.clone(),
+        require_box: false,
+    }
+}
+
+/// Signature of a method e.g. `(spec: VirtualMachineConfigSpec) -> ManagedObjectReference`.
+fn method_signature(model: &Model, method: &Method) -> String {
+    let params: Vec<String> = parameters(model, method)
+        .values()
+        .map(|field| format!("{}: {}", field.name, field_signature(field)))
+        .collect();
+    format!("({}) -> {}", params.join(", "), return_signature(method))
+}
+
+fn return_signature(method: &Method) -> String {
+    match &method.output {
+        Some(output) if method.optional_response => format!("{}?", type_name(output)),
+        Some(output) => type_name(output),
+        None => "void".to_string(),
+    }
+}
+
+/// Type of a field with `?` appended if it is optional e.g. `string[]?`.
+fn field_signature(field: &Field) -> String {
+    let optional = if field.optional { "?" } else { "" };
+    format!("{}{optional}", type_name(&field.vim_type))
+}
+
+/// Name of a data type as in the API reference e.g. `long` or `VirtualDevice[]`.
+fn type_name(vim_type: &DataType) -> String {
+    match vim_type {
+        DataType::Boolean => "boolean".to_string(),
+        DataType::String => "string".to_string(),
+        DataType::Int8 => "byte".to_string(),
+        DataType::Int16 => "short".to_string(),
+        DataType::Int32 => "int".to_string(),
+        DataType::Int64 => "long".to_string(),
+        DataType::Float => "float".to_string(),
+        DataType::Double => "double".to_string(),
+        DataType::DateTime => "dateTime".to_string(),
+        DataType::Binary => "binary".to_string(),
+        DataType::Array(item_type) => format!("{}[]", type_name(item_type)),
+        DataType::Reference(name) => name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi30::OpenAPI;
+    use serde_json::{json, Value};
+
+    /// Model of a specification with a `VirtualMachine` managed object and the given data types.
+    fn model(schemas: Value, params: Value, required: Value) -> Model {
+        let mut spec = json!({
+            "openapi": "3.0.3",
+            "info": {"title": "test", "version": "1.0.0"},
+            "tags": [{"name": "VirtualMachine"}],
+            "paths": {
+                "/VirtualMachine/{moId}/PowerOnVM_Task": {"post": {
+                    "tags": ["VirtualMachine"],
+                    "operationId": "VirtualMachine_PowerOnVM_Task",
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/PowerOnVMRequestType"}}}},
+                    "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"type": "string"}}}}}
+                }}
+            },
+            "components": {"schemas": {
+                "Any": {
+                    "type": "object",
+                    "properties": {"_typeName": {"type": "string"}},
+                    "required": ["_typeName"],
+                    "discriminator": {"propertyName": "_typeName", "mapping": {}}
+                },
+                "DataObject": {"type": "object", "allOf": [{"$ref": "#/components/schemas/Any"}]},
+                "PowerOnVMRequestType": {"type": "object", "properties": params, "required": required}
+            }}
+        });
+        let Value::Object(schemas) = schemas else { panic!("schemas must be an object") };
+        spec["components"]["schemas"].as_object_mut().unwrap().extend(schemas);
+        let openapi: OpenAPI = serde_json::from_value(spec).unwrap();
+        vim_model::load_vim_model(&openapi, Some(&["Event"]), &ModelFilter::default()).unwrap()
+    }
+
+    fn find<'a>(diff: &'a ApiDiff, path: &str) -> &'a Change {
+        diff.changes.iter().find(|change| change.path == path).unwrap_or_else(|| panic!("no change of {path} in\n{diff}"))
+    }
+
+    #[test]
+    fn test_diff_models() {
+        let old = model(
+            json!({
+                "Info": {"type": "object", "allOf": [{"$ref": "#/components/schemas/DataObject"}], "properties": {
+                    "name": {"type": "string"},
+                    "size": {"type": "integer", "format": "int32"},
+                    "note": {"type": "string"}
+                }, "required": ["name", "size"]},
+                "Event": {"type": "object", "allOf": [{"$ref": "#/components/schemas/DataObject"}]},
+                "VmEvent": {"type": "object", "allOf": [{"$ref": "#/components/schemas/Event"}], "properties": {"vm": {"type": "string"}}},
+                "State_enum": {"type": "string", "enum": ["on", "off", "standby"]}
+            }),
+            json!({"host": {"type": "string"}}),
+            json!([]),
+        );
+        let new = model(
+            json!({
+                "Info": {"type": "object", "allOf": [{"$ref": "#/components/schemas/DataObject"}], "properties": {
+                    "name": {"type": "string"},
+                    "size": {"type": "integer", "format": "int64"},
+                    "label": {"type": "string"},
+                    "owner": {"type": "string"}
+                }, "required": ["name", "size", "owner"]},
+                "Event": {"type": "object", "allOf": [{"$ref": "#/components/schemas/DataObject"}]},
+                "VmEvent": {"type": "object", "allOf": [{"$ref": "#/components/schemas/Event"}]},
+                "State_enum": {"type": "string", "enum": ["on", "off", "suspended"]}
+            }),
+            json!({"host": {"type": "string"}, "priority": {"type": "string"}}),
+            json!(["host"]),
+        );
+        let diff = diff_models(&old, &new);
+
+        let size = find(&diff, "Info.size");
+        assert_eq!((size.kind, size.element), (ChangeKind::Changed, Element::Property));
+        assert_eq!((size.old.as_deref(), size.new.as_deref()), (Some("int"), Some("long")));
+        assert!(size.breaking);
+        assert!(find(&diff, "Info.note").breaking);
+        assert!(!find(&diff, "Info.label").breaking);
+        assert!(find(&diff, "Info.owner").breaking);
+        // Descendants of pruned types are not generated
+        assert!(!find(&diff, "VmEvent.vm").breaking);
+        assert!(find(&diff, "State_enum.standby").breaking);
+        assert!(find(&diff, "State_enum.suspended").breaking);
+
+        let host = find(&diff, "VirtualMachine.PowerOnVM_Task.host");
+        assert_eq!((host.old.as_deref(), host.new.as_deref()), (Some("string?"), Some("string")));
+        assert!(host.breaking);
+        let priority = find(&diff, "VirtualMachine.PowerOnVM_Task.priority");
+        assert_eq!((priority.kind, priority.element), (ChangeKind::Added, Element::Parameter));
+        assert!(priority.breaking);
+        assert_eq!(diff.breaking_count(), 7);
+
+        let report = diff.to_string();
+        assert!(report.contains("! changed property Info.size: int -> long\n"), "{report}");
+        assert!(report.contains("! added enum variant State_enum.suspended\n"), "{report}");
+        let json = serde_json::to_value(&diff).unwrap();
+        let expected = json!({"kind": "changed", "element": "property", "path": "Info.size", "old": "int", "new": "long", "breaking": true});
+        assert!(json["changes"].as_array().unwrap().contains(&expected));
+    }
+
+    #[test]
+    fn test_diff_managed_objects() {
+        let old = model(json!({}), json!({}), json!([]));
+        let mut new = model(json!({}), json!({}), json!([]));
+        new.managed_objects["VirtualMachine"].methods.clear();
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes.len(), 1);
+        let change = &diff.changes[0];
+        assert_eq!((change.kind, change.element, change.path.as_str()), (ChangeKind::Removed, Element::ManagedObject, "VirtualMachine"));
+        assert!(change.breaking);
+        assert!(diff_models(&new, &old).changes.iter().all(|change| !change.breaking));
+    }
+}
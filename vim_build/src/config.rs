@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::vim_model::ModelFilter;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Cannot read config file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    #[error("Invalid TOML config file {0}: {1}")]
+    Toml(PathBuf, toml::de::Error),
+    #[error("Invalid JSON config file {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Types whose hierarchies are pruned from the model by default. The listed types will be
+/// generated. Their descendants will not. This means that:
+/// 1. struct types for the descendants will not be emitted
+/// 2. traits for the listed types and their descendants will not be emitted
+/// 3. additional 2 fields will be added to the listed types
+///     - type_name_ : String - holding the discriminator of the deserialized types
+///     - extra_fields_ : HashMap<String, serde_json::Value> - holding the extra fields that are not
+///       part of the base type schema
+/// 4. deserialization for the descendant types will be handled by the parent type Visitor. The
+///    parent type Visitor will optionally accept the discriminator during creation to populate the
+///    correct type_name_ for the descendant types.
+pub const PRUNED_TYPES: [&str; 2] = ["MethodFault", "Event"];
+
+/// Generator configuration read from a TOML or JSON file. Missing settings take the values used to
+/// generate vim_rs from within the `vim_build` folder. Relative paths are resolved against the
+/// folder of the config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// OpenAPI specifications to generate from. Later specifications are merged into the first one
+    /// e.g. to add the types and methods of an extension.
+    pub specs: Vec<PathBuf>,
+    /// Source folder of the generated crate. The modules of the `layout` are written below it.
+    pub output: PathBuf,
+    /// Modules of the generated data types and managed objects.
+    pub layout: Layout,
+    /// Emit a cargo feature per managed object into the `Cargo.toml` next to `output` and the
+    /// feature groups read by the build script. Without features all the types are compiled.
+    pub features: bool,
+    /// Types whose descendants are not generated. See `PRUNED_TYPES`.
+    pub pruned_types: Vec<String>,
+    /// Managed objects, methods and data types to generate.
+    pub filter: ModelFilter,
+    /// Additional derives of the generated types.
+    pub derives: Derives,
+}
+
+/// Module paths of the generated code relative to the crate root e.g. `types` or `vim::types`. Each
+/// module is written to the matching folder below `output` e.g. `vim/types`. The parent modules of
+/// nested paths are declared by hand. The client core in vim_rs refers to the default layout.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layout {
+    /// Module of the data types.
+    pub types: String,
+    /// Module of the managed object stubs.
+    pub mo: String,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout { types: "types".to_string(), mo: "mo".to_string() }
+    }
+}
+
+impl Layout {
+    /// Path of the data types module in the generated code e.g. `crate::types`.
+    pub fn types_path(&self) -> String {
+        format!("crate::{}", self.types)
+    }
+
+    /// Folder of the data types module below the source folder.
+    pub fn types_folder(&self, output: &Path) -> PathBuf {
+        module_folder(output, &self.types)
+    }
+
+    /// Folder of the managed objects module below the source folder.
+    pub fn mo_folder(&self, output: &Path) -> PathBuf {
+        module_folder(output, &self.mo)
+    }
+}
+
+fn module_folder(output: &Path, module: &str) -> PathBuf {
+    module.split("::").fold(output.to_path_buf(), |folder, name| folder.join(name))
+}
+
+/// Derive macros added to the generated data types e.g. `Hash` or `schemars::JsonSchema`. The
+/// generated code implements `Debug`, `Default` and the serde traits itself while `Clone` and
+/// `PartialEq` are derived with the crate features of the same names.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Derives {
+    pub structs: Vec<String>,
+    pub enums: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            specs: vec![PathBuf::from("data/vi_json_openapi_specification_v8_0_2_0.json")],
+            output: PathBuf::from("../vim_rs/src/"),
+            layout: Layout::default(),
+            features: true,
+            pruned_types: PRUNED_TYPES.iter().map(|name| name.to_string()).collect(),
+            filter: ModelFilter::default(),
+            derives: Derives::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Read the configuration from a file. Files with the `json` extension are read as JSON, all
+    /// others as TOML.
+    pub fn load(path: &Path) -> Result<Config> {
+        let data = std::fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        let mut config: Config = if path.extension().is_some_and(|extension| extension == "json") {
+            serde_json::from_str(&data).map_err(|e| Error::Json(path.to_path_buf(), e))?
+        } else {
+            toml::from_str(&data).map_err(|e| Error::Toml(path.to_path_buf(), e))?
+        };
+        if let Some(folder) = path.parent() {
+            config.specs = config.specs.iter().map(|spec| folder.join(spec)).collect();
+            config.output = folder.join(&config.output);
+        }
+        Ok(config)
+    }
+
+    /// Pruned types as expected by the model loader.
+    pub fn pruned_types(&self) -> Vec<&str> {
+        self.pruned_types.iter().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_toml() {
+        let path = std::env::temp_dir().join(format!("vim_build_config_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+            specs = ["spec.json", "/specs/extension.json"]
+            output = "out/src"
+            pruned_types = ["MethodFault"]
+
+            [filter]
+            types = { include = ["VirtualMachine", "PropertyCollector"] }
+            methods = { exclude = ["*.Destroy_Task"] }
+            drop_unreachable_types = true
+
+            [layout]
+            types = "vim::types"
+
+            [derives]
+            enums = ["Hash"]
+            "#,
+        )
+        .unwrap();
+        let config = Config::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let folder = std::env::temp_dir();
+        assert_eq!(config.specs, vec![folder.join("spec.json"), PathBuf::from("/specs/extension.json")]);
+        assert_eq!(config.output, folder.join("out/src"));
+        assert!(config.features);
+        assert_eq!(config.pruned_types(), vec!["MethodFault"]);
+        assert!(config.filter.types.selects("VirtualMachine"));
+        assert!(!config.filter.types.selects("HostSystem"));
+        assert!(config.filter.methods.excludes("VirtualMachine.Destroy_Task"));
+        assert!(config.filter.drop_unreachable_types);
+        assert_eq!(config.layout.types_path(), "crate::vim::types");
+        assert_eq!(config.layout.types_folder(&config.output), folder.join("out/src/vim/types"));
+        assert_eq!(config.layout.mo_folder(&config.output), folder.join("out/src/mo"));
+        assert_eq!(config.derives.enums, vec!["Hash"]);
+        assert!(config.derives.structs.is_empty());
+    }
+
+    #[test]
+    fn test_load_json_unknown_setting() {
+        let path = std::env::temp_dir().join(format!("vim_build_config_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"specs": ["spec.json"], "pruned": []}"#).unwrap();
+        let result = Config::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(Error::Json(..))));
+    }
+}
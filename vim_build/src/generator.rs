@@ -1,4 +1,6 @@
+use super::config::Config;
 use super::printer::{self, FilePrinter, Printer};
+use super::rest;
 use super::rs_emitter;
 use super::vim_model;
 use crate::rs_emitter::deser::DeserializationGenerator;
@@ -8,7 +10,7 @@ use rs_emitter::library::emit_library;
 use std::path::PathBuf;
 use std::{io::Read, path::Path, time::Instant};
 use openapi30::OpenAPI;
-use crate::vim_model::{EmitMode, Model};
+use crate::vim_model::{EmitMode, Model, NameFilter};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -24,43 +26,120 @@ pub enum Error {
     Emit(#[from] rs_emitter::errors::Error),
     #[error("VimModelError: {0}")]
     VimModel(#[from] vim_model::Error),
+    #[error("REST bindings error: {0}")]
+    Rest(#[from] rest::Error),
+    #[error("Cargo manifest error: {0}")]
+    Manifest(String),
+    #[error("No OpenAPI specification configured")]
+    NoSpecification,
 }
 
 type Result<T> = std::result::Result<T, Error>;
 
-pub fn load_openapi<P: AsRef<Path>>(path: P) -> Result<openapi30::OpenAPI> {
+/// Load and merge the OpenAPI specifications. Objects are merged key by key, arrays get the
+/// elements they do not contain yet and other values of later specifications replace the earlier
+/// ones. This adds the schemas, paths and tags of later specifications and the entries of the
+/// discriminator mapping of `Any`.
+pub fn load_openapi_specs<P: AsRef<Path>>(paths: &[P]) -> Result<openapi30::OpenAPI> {
+    let Some((first, others)) = paths.split_first() else {
+        return Err(Error::NoSpecification);
+    };
+    let mut spec = load_json(first)?;
+    for path in others {
+        merge_json(&mut spec, load_json(path)?);
+    }
+    let openapi: openapi30::OpenAPI = serde_json::from_value(spec)?;
+    Ok(openapi)
+}
+
+fn load_json<P: AsRef<Path>>(path: P) -> Result<serde_json::Value> {
     let mut file = std::fs::File::open(path)?;
     let mut data = String::new();
     file.read_to_string(&mut data)?;
-    let openapi: openapi30::OpenAPI = serde_json::from_str(&data)?;
-    Ok(openapi)
+    Ok(serde_json::from_str(&data)?)
+}
+
+fn merge_json(target: &mut serde_json::Value, source: serde_json::Value) {
+    use serde_json::Value;
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(source)) => {
+            for value in source {
+                if !target.contains(&value) {
+                    target.push(value);
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
 }
 
-pub fn emit_vim_bindings(vi_json_spec_path: &Path, root_folder: &Path, pruned_types: Option<&[&str]>) -> Result<()> {
+pub fn emit_vim_bindings(config: &Config) -> Result<()> {
     let start_load = Instant::now();
-    let model = load_openapi(vi_json_spec_path)?;
+    let model = load_openapi_specs(&config.specs)?;
     println!("Time to load OpenAPI: {:?}", start_load.elapsed());
-    let vim_model = transform_model(&model, pruned_types)?;
-    generate_bindings(vim_model, root_folder)
+    let vim_model = transform_model(&model, config)?;
+    generate_bindings(vim_model, config)
 }
 
-pub fn generate_bindings(vim_model: Model, root_folder: &Path) -> Result<()> {
+/// Generate the vSphere Automation REST API bindings for the paths selected by `filter` into the
+/// `output` source folder i.e. `models.rs` and the `services` module.
+pub fn emit_rest_bindings<P: AsRef<Path>>(specs: &[P], output: &Path, filter: &NameFilter) -> Result<()> {
+    let start = Instant::now();
+    let openapi = load_openapi_specs(specs)?;
+    let model = rest::load_rest_model(&openapi, filter)?;
+    println!("Time to load the REST model: {:?}", start.elapsed());
+
+    let services_folder = output.join("services");
+    std::fs::create_dir_all(&services_folder)?;
+    let mut printer = FilePrinter::new(std::fs::File::create(output.join("models.rs"))?, None, None);
+    rest::emit_models(&model, &mut printer)?;
+    for service in model.services.values() {
+        let file = std::fs::File::create(services_folder.join(format!("{}.rs", rest::service_module_name(service))))?;
+        let mut printer = FilePrinter::new(file, None, None);
+        rest::emit_service(&model, service, &mut printer)?;
+    }
+    let mut printer = FilePrinter::new(std::fs::File::create(services_folder.join("mod.rs"))?, None, None);
+    rest::emit_services_module(&model, &mut printer)?;
+    println!("Generated {} services and {} models", model.services.len(), model.types.len());
+    Ok(())
+}
+
+pub fn generate_bindings(vim_model: Model, config: &Config) -> Result<()> {
+    let root_folder = config.output.as_path();
     let start_emit = Instant::now();
-    emit_types(root_folder, &vim_model)?;
+    emit_types(root_folder, &vim_model, config)?;
     println!("Time to emit types: {:?}", start_emit.elapsed());
 
     let start_emit_mo = Instant::now();
-    emit_managed_objects(root_folder, &vim_model)?;
+    emit_managed_objects(root_folder, &vim_model, config)?;
     println!(
         "Time to emit managed objects: {:?}",
         start_emit_mo.elapsed()
     );
+
+    if config.features {
+        emit_features(root_folder, &vim_model)?;
+    }
     Ok(())
 }
 
-fn transform_model(model: &OpenAPI, pruned_types: Option<&[&str]>) -> Result<Model> {
+fn transform_model(model: &OpenAPI, config: &Config) -> Result<Model> {
     let start_model_transform = Instant::now();
-    let vim_model = vim_model::load_vim_model(model, pruned_types)?;
+    let mut vim_model = vim_model::load_vim_model(model, Some(&config.pruned_types()), &config.filter)?;
+    if !config.features {
+        // Without features all the types are compiled
+        vim_model.feature_groups = vim_model::FeatureGroups::default();
+    }
     println!(
         "Time to transform OpenAPI model: {:?}",
         start_model_transform.elapsed()
@@ -68,8 +147,9 @@ fn transform_model(model: &OpenAPI, pruned_types: Option<&[&str]>) -> Result<Mod
     Ok(vim_model)
 }
 
-fn emit_managed_objects(root_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
-    let mo_folder = root_folder.join("mo");
+fn emit_managed_objects(root_folder: &Path, vim_model: &vim_model::Model, config: &Config) -> Result<()> {
+    let mo_folder = config.layout.mo_folder(root_folder);
+    let features = config.features;
     std::fs::create_dir_all(&mo_folder).expect("Could not create mo folder");
 
     let mut modules = Vec::new();
@@ -78,12 +158,13 @@ fn emit_managed_objects(root_folder: &Path, vim_model: &vim_model::Model) -> Res
             continue; // Skip managed objects without methods
         }
         let file_name = mo_type.to_case(Case::Snake);
-        modules.push(file_name.clone());
+        let feature = (features && !vim_model::is_core_managed_object(mo_type)).then(|| vim_model::mo_feature_name(mo_type));
+        modules.push((file_name.clone(), feature));
         let file_path = mo_folder.join(format!("{}.rs", file_name));
         let file = std::fs::File::create(&file_path)
             .unwrap_or_else(|_| panic!("Could not create {} file", file_path.display()));
         let mut printer = printer::FilePrinter::new(file, None, None);
-        let mut emitter = rs_emitter::ManagedObjectEmitter::new(mo, &mut printer, vim_model);
+        let mut emitter = rs_emitter::ManagedObjectEmitter::new(mo, &mut printer, vim_model, config.layout.types_path());
         emitter.emit()?;
     }
     // Generate mod.rs
@@ -94,17 +175,61 @@ fn emit_managed_objects(root_folder: &Path, vim_model: &vim_model::Model) -> Res
     Ok(())
 }
 
-fn emit_types(root_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
-    let types_folder = root_folder.join("types");
+/// Markers of the generated managed object features in the vim_rs `Cargo.toml`.
+const FEATURES_BEGIN: &str = "# BEGIN managed object features generated by vim_build";
+const FEATURES_END: &str = "# END managed object features";
+
+/// Emit the cargo features of the managed objects into the crate manifest next to `root_folder`
+/// and the table the build script uses to enable the feature groups of the data types.
+fn emit_features(root_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
+    let crate_folder = root_folder.join("..");
+    let features: Vec<String> = vim_model
+        .managed_objects
+        .iter()
+        .filter(|(mo_type, mo)| !mo.methods.is_empty() && !vim_model::is_core_managed_object(mo_type))
+        .map(|(mo_type, _)| vim_model::mo_feature_name(mo_type))
+        .collect();
+
+    let manifest_path = crate_folder.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&manifest_path)?;
+    let (Some(begin), Some(end)) = (manifest.find(FEATURES_BEGIN), manifest.find(FEATURES_END)) else {
+        return Err(Error::Manifest(format!("{} has no managed object features section", manifest_path.display())));
+    };
+    // The tokio runtime support is a hand written feature enabled by default as well
+    let mut section = format!("{FEATURES_BEGIN}\ndefault = [\"tokio\", \"full\"]\n");
+    let quoted: Vec<String> = features.iter().map(|feature| format!("    \"{feature}\",")).collect();
+    section.push_str(&format!("full = [\n{}\n]\n", quoted.join("\n")));
+    for feature in &features {
+        section.push_str(&format!("{feature} = []\n"));
+    }
+    let manifest = format!("{}{section}{}", &manifest[..begin], &manifest[end..]);
+    std::fs::write(&manifest_path, manifest)?;
+
+    let mut printer = printer_for_file(crate_folder.join("type_groups.txt"))?;
+    printer.println("# Generated by vim_build. Read by build.rs.")?;
+    printer.println("# Managed object features enabling each `vim_group` of data types, one line per group. Groups")?;
+    printer.println("# without features are enabled by the `full` feature only.")?;
+    for mo_types in &vim_model.feature_groups.groups {
+        let group: Vec<String> = mo_types.iter().map(|mo_type| vim_model::mo_feature_name(mo_type)).collect();
+        printer.println(&group.join(" "))?;
+    }
+    Ok(())
+}
+
+fn emit_types(root_folder: &Path, vim_model: &vim_model::Model, config: &Config) -> Result<()> {
+    let types_folder = config.layout.types_folder(root_folder);
     std::fs::create_dir_all(&types_folder).expect("Could not create types folder");
 
     emit_ser(&types_folder, vim_model)?;
     emit_de(&types_folder, vim_model)?;
     emit_struct_enum(&types_folder, vim_model)?;
     emit_boxed_types(&types_folder, vim_model)?;
+    emit_type_tables(&types_folder, vim_model)?;
+    emit_json_schema(root_folder, vim_model)?;
+    emit_arbitrary(root_folder, &types_folder, vim_model, &config.layout.types)?;
 
-    emit_enums(&types_folder, vim_model)?;
-    emit_structs(&types_folder, vim_model)?;
+    emit_enums(&types_folder, vim_model, &config.derives.enums)?;
+    emit_structs(&types_folder, vim_model, &config.derives.structs)?;
 
     // Emit traits
     delete_trait_files(&types_folder)?;
@@ -173,6 +298,35 @@ fn emit_struct_enum(types_folder: &Path, vim_model: &vim_model::Model) -> Result
     Ok(())
 }
 
+fn emit_type_tables(types_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
+    let mut printer = printer_for_file(types_folder.join("type_tables.rs"))?;
+    rs_emitter::reflection::generate_type_tables(vim_model, &mut printer)?;
+    Ok(())
+}
+
+/// Write the JSON Schema of the data types to `schema/vim.schema.json` next to the crate sources
+/// where `types::json_schema` embeds it.
+fn emit_json_schema(root_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
+    let schema_folder = root_folder.join("../schema");
+    std::fs::create_dir_all(&schema_folder)?;
+    let schema = rs_emitter::json_schema::generate_json_schema(vim_model)?;
+    let file = std::fs::File::create(schema_folder.join("vim.schema.json"))?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), &schema)?;
+    Ok(())
+}
+
+/// Write the `Arbitrary` implementations of the `arbitrary` feature and the round trip test using
+/// them to `tests/round_trip.rs` of the crate.
+fn emit_arbitrary(root_folder: &Path, types_folder: &Path, vim_model: &vim_model::Model, types_module: &str) -> Result<()> {
+    let mut printer = printer_for_file(types_folder.join("arbitrary.rs"))?;
+    rs_emitter::arbitrary::generate_arbitrary(vim_model, &mut printer)?;
+    let tests_folder = root_folder.join("../tests");
+    std::fs::create_dir_all(&tests_folder)?;
+    let mut printer = printer_for_file(tests_folder.join("round_trip.rs"))?;
+    rs_emitter::arbitrary::generate_round_trip_tests(vim_model, types_module, &mut printer)?;
+    Ok(())
+}
+
 fn emit_ser(types_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
     let file = std::fs::File::create(types_folder.join("dyn_serialize.rs"))
         .expect("Could not create dyn_serialize.rs file");
@@ -187,6 +341,7 @@ fn emit_de(types_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
     printer.println("use serde::Deserializer;")?;
     printer.println("use serde::de;")?;
     printer.println("use super::boxed_types::ValueElements;")?;
+    printer.println("use super::map_access::{FieldKey, MapPrefix, PrefixedMapAccess};")?;
     printer.println("use super::struct_enum;")?;
     printer.println("use super::structs::*;")?;
     printer.println("use super::vim_any::VimAny;")?;
@@ -196,20 +351,20 @@ fn emit_de(types_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
     Ok(())
 }
 
-fn emit_enums(types_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
+fn emit_enums(types_folder: &Path, vim_model: &vim_model::Model, derives: &[String]) -> Result<()> {
     let file = std::fs::File::create(types_folder.join("enums.rs"))
         .expect("Could not create enums.rs file");
     let mut printer = printer::FilePrinter::new(file, None, None);
-    rs_emitter::enums::emit_enums(vim_model, &mut printer)?;
+    rs_emitter::enums::emit_enums(vim_model, derives, &mut printer)?;
     Ok(())
 }
 
-fn emit_structs(root_folder: &Path, vim_model: &vim_model::Model) -> Result<()> {
+fn emit_structs(root_folder: &Path, vim_model: &vim_model::Model, derives: &[String]) -> Result<()> {
     use crate::rs_emitter::TypesEmitter;
     let file = std::fs::File::create(root_folder.join("structs.rs"))
         .expect("Could not create structs file");
     let mut printer = printer::FilePrinter::new(file, None, None);
-    let mut emitter = TypesEmitter::new(vim_model, &mut printer);
+    let mut emitter = TypesEmitter::new(vim_model, derives, &mut printer);
     emitter.emit_data_types()?;
     Ok(())
 }
@@ -225,7 +380,21 @@ fn emit_mod_rs(types_folder: &std::path::Path) -> Result<()> {
     p.println("pub mod boxed_types;")?;
     p.println("pub mod vim_any;")?;
     p.println("pub mod as_any;")?;
+    p.println("#[cfg(feature = \"arbitrary\")]")?;
+    p.println("pub mod arbitrary;")?;
+    p.println("pub mod builder;")?;
     p.println("pub mod convert;")?;
+    p.println("pub mod date_time;")?;
+    p.println("pub mod diff;")?;
+    p.println("pub mod json;")?;
+    p.println("#[cfg(feature = \"json-schema\")]")?;
+    p.println("pub mod json_schema;")?;
+    p.println("mod map_access;")?;
+    p.println("pub mod path;")?;
+    p.println("#[cfg(feature = \"reflection\")]")?;
+    p.println("pub mod reflection;")?;
+    p.println("#[cfg(feature = \"reflection\")]")?;
+    p.println("mod type_tables;")?;
     p.println("pub mod vim_object_trait;")?;
     p.newline()?;
     Ok(())
@@ -235,3 +404,31 @@ fn printer_for_file(file_path: PathBuf) -> Result<FilePrinter> {
     let file = std::fs::File::create(file_path)?;
     Ok(printer::FilePrinter::new(file, None, None))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_json() {
+        let mut spec = json!({
+            "tags": [{"name": "VirtualMachine"}],
+            "components": {"schemas": {
+                "Any": {"discriminator": {"mapping": {"VirtualDisk": "#/components/schemas/VirtualDisk"}}},
+                "VirtualDisk": {"type": "object", "description": "Disk"}
+            }}
+        });
+        merge_json(&mut spec, json!({
+            "tags": [{"name": "VirtualMachine"}, {"name": "VsanObjectSystem"}],
+            "components": {"schemas": {
+                "Any": {"discriminator": {"mapping": {"VsanObjectInfo": "#/components/schemas/VsanObjectInfo"}}},
+                "VirtualDisk": {"description": "Virtual disk"}
+            }}
+        }));
+        assert_eq!(spec["tags"], json!([{"name": "VirtualMachine"}, {"name": "VsanObjectSystem"}]));
+        let schemas = &spec["components"]["schemas"];
+        assert_eq!(schemas["Any"]["discriminator"]["mapping"].as_object().unwrap().len(), 2);
+        assert_eq!(schemas["VirtualDisk"], json!({"type": "object", "description": "Virtual disk"}));
+    }
+}
@@ -1,37 +1,223 @@
+mod api_diff;
+mod config;
 mod generator;
 mod printer;
+mod rest;
 pub mod rs_emitter;
 mod vim_model;
 
-use generator::emit_vim_bindings;
-use std::{path::Path, time::Instant};
-
-/// Types whose hierarchies are pruned from the model. The listed types will be generated. Their
-/// descendants will not. This means that:
-/// 1. struct types for the descendants will not be emitted
-/// 2. traits for the listed types and their descendants will not be emitted
-/// 3. additional 2 fields will be added to the listed types 
-///     - type_name_ : String - holding the discriminator of the deserialized types 
-///     - extra_fields_ : HashMap<String, serde_json::Value> - holding the extra fields that are not
-///       part of the base type schema
-/// 4. deserialization for the descendant types will be handled by the parent type Visitor. The
-/// parent type Visitor will optionally accept the discriminator during creation to populate the
-/// correct type_name_ for the descendant types.
-static PRUNED_TYPES: [&str; 2] = ["MethodFault", "Event"];
-
-fn main() {
-    let root_folder = Path::new("../vim_rs/src/");
-    let vi_json_spec_path = Path::new("data/vi_json_openapi_specification_v8_0_2_0.json");
-
-    //generate_to_console(vi_json_spec_path).unwrap();
+use clap::{Parser, Subcommand};
+use config::Config;
+use generator::{emit_rest_bindings, emit_vim_bindings};
+use std::{path::PathBuf, process::ExitCode, time::Instant};
+
+/// Generate Rust bindings for the vSphere Web Services API from the VI JSON OpenAPI specification.
+///
+/// Without arguments vim_rs is generated from within the `vim_build` folder. The settings of the
+/// config file can be overridden with the options. List options replace the lists of the config
+/// file.
+#[derive(Debug, Parser)]
+#[command(name = "generate", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    args: Args,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Report the changes between two versions of the specification and flag the ones breaking
+    /// code that uses the generated bindings
+    Diff(DiffArgs),
+    /// Generate the vim_rest bindings for the vSphere Automation REST API from its OpenAPI
+    /// specification
+    Rest(RestArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct DiffArgs {
+    /// Specification the current bindings are generated from
+    old: PathBuf,
+    /// New specification
+    new: PathBuf,
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+    /// Type whose descendants are not generated. Defaults to the types pruned in vim_rs
+    #[arg(long = "prune", value_name = "TYPE")]
+    pruned_types: Vec<String>,
+    /// Exit with an error status if there are breaking changes
+    #[arg(long)]
+    fail_on_breaking: bool,
+}
+
+#[derive(Debug, clap::Args)]
+struct RestArgs {
+    /// OpenAPI specification of the Automation API. Repeat to merge specifications in order
+    #[arg(long = "spec", value_name = "FILE", default_value = "data/automation_cis_tagging.json")]
+    specs: Vec<PathBuf>,
+    /// Source folder of the generated crate
+    #[arg(short, long, value_name = "DIR", default_value = "../vim_rest/src/")]
+    output: PathBuf,
+    /// Pattern of the paths to generate e.g. `/api/vcenter/vm*`
+    #[arg(long, value_name = "PATTERN")]
+    include_path: Vec<String>,
+    /// Pattern of the paths to leave out
+    #[arg(long, value_name = "PATTERN")]
+    exclude_path: Vec<String>,
+}
+
+#[derive(Debug, clap::Args)]
+struct Args {
+    /// Config file in TOML or JSON format
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+    /// OpenAPI specification. Repeat to merge specifications in order
+    #[arg(long = "spec", value_name = "FILE")]
+    specs: Vec<PathBuf>,
+    /// Source folder of the generated crate
+    #[arg(short, long, value_name = "DIR")]
+    output: Option<PathBuf>,
+    /// Module of the data types relative to the crate root e.g. `vim::types`
+    #[arg(long, value_name = "PATH")]
+    types_module: Option<String>,
+    /// Module of the managed object stubs relative to the crate root
+    #[arg(long, value_name = "PATH")]
+    mo_module: Option<String>,
+    /// Do not emit a cargo feature per managed object
+    #[arg(long)]
+    no_features: bool,
+    /// Type whose descendants are not generated
+    #[arg(long = "prune", value_name = "TYPE")]
+    pruned_types: Vec<String>,
+    /// Pattern of the managed object types to generate
+    #[arg(long, value_name = "PATTERN")]
+    include_type: Vec<String>,
+    /// Pattern of the managed object or data types to leave out
+    #[arg(long, value_name = "PATTERN")]
+    exclude_type: Vec<String>,
+    /// Pattern of the methods to generate e.g. `VirtualMachine.*`
+    #[arg(long, value_name = "PATTERN")]
+    include_method: Vec<String>,
+    /// Pattern of the methods to leave out e.g. `*.Destroy_Task`
+    #[arg(long, value_name = "PATTERN")]
+    exclude_method: Vec<String>,
+    /// Leave out the data types the generated methods cannot reach
+    #[arg(long)]
+    drop_unreachable_types: bool,
+    /// Additional derive of the generated structs
+    #[arg(long, value_name = "DERIVE")]
+    derive_struct: Vec<String>,
+    /// Additional derive of the generated enums
+    #[arg(long, value_name = "DERIVE")]
+    derive_enum: Vec<String>,
+}
+
+impl Args {
+    /// Apply the options given on the command line to the config.
+    fn apply(self, config: &mut Config) {
+        let replace = |target: &mut Vec<String>, values: Vec<String>| {
+            if !values.is_empty() {
+                *target = values;
+            }
+        };
+        if !self.specs.is_empty() {
+            config.specs = self.specs;
+        }
+        if let Some(output) = self.output {
+            config.output = output;
+        }
+        if let Some(types) = self.types_module {
+            config.layout.types = types;
+        }
+        if let Some(mo) = self.mo_module {
+            config.layout.mo = mo;
+        }
+        if self.no_features {
+            config.features = false;
+        }
+        replace(&mut config.pruned_types, self.pruned_types);
+        replace(&mut config.filter.types.include, self.include_type);
+        replace(&mut config.filter.types.exclude, self.exclude_type);
+        replace(&mut config.filter.methods.include, self.include_method);
+        replace(&mut config.filter.methods.exclude, self.exclude_method);
+        if self.drop_unreachable_types {
+            config.filter.drop_unreachable_types = true;
+        }
+        replace(&mut config.derives.structs, self.derive_struct);
+        replace(&mut config.derives.enums, self.derive_enum);
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Diff(args)) => diff(args),
+        Some(Command::Rest(args)) => rest(args),
+        None => generate(cli.args),
+    }
+}
+
+fn generate(args: Args) -> ExitCode {
+    let mut config = match &args.config {
+        Some(path) => match Config::load(path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Config::default(),
+    };
+    args.apply(&mut config);
+
     let start = Instant::now();
-    emit_vim_bindings(vi_json_spec_path, root_folder, Some(&PRUNED_TYPES)).unwrap();
+    if let Err(e) = emit_vim_bindings(&config) {
+        eprintln!("Generation failed: {e}");
+        return ExitCode::FAILURE;
+    }
     println!("Total time in generation: {:?}", start.elapsed());
+    ExitCode::SUCCESS
 }
 
-// fn generate_to_console(vi_json_spec_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
-//     let model = load_openapi(vi_json_spec_path)?;
-//     let vim_model = vim_model::load_vim_model(&model)?;
-//     let mut printer = printer::StdoutPrinter::new(None, None);
-//     Ok(())
-// }
+fn rest(args: RestArgs) -> ExitCode {
+    let filter = vim_model::NameFilter { include: args.include_path, exclude: args.exclude_path };
+    let start = Instant::now();
+    if let Err(e) = emit_rest_bindings(&args.specs, &args.output, &filter) {
+        eprintln!("Generation failed: {e}");
+        return ExitCode::FAILURE;
+    }
+    println!("Total time in generation: {:?}", start.elapsed());
+    ExitCode::SUCCESS
+}
+
+fn diff(args: DiffArgs) -> ExitCode {
+    let pruned_types: Vec<&str> = if args.pruned_types.is_empty() {
+        config::PRUNED_TYPES.to_vec()
+    } else {
+        args.pruned_types.iter().map(String::as_str).collect()
+    };
+    let diff = match api_diff::diff_specs(&args.old, &args.new, &pruned_types) {
+        Ok(diff) => diff,
+        Err(e) => {
+            eprintln!("Comparison failed: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.json {
+        match serde_json::to_string_pretty(&diff) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print!("{diff}");
+    }
+    if args.fail_on_breaking && diff.breaking_count() > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
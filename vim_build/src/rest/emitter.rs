@@ -0,0 +1,493 @@
+use std::collections::BTreeSet;
+
+use super::model::{Param, RestModel, RestOperation, RestType, Service, TypeDef, TypeKind};
+use super::Result;
+use crate::printer::Printer;
+use crate::rs_emitter::common::emit_description;
+use crate::rs_emitter::{to_enum_variant, to_field_name, to_fn_name, to_module_name, to_type_name};
+
+/// Rust name of a service struct e.g. `TaggingTag` for the `tagging/tag` tag.
+pub fn service_type_name(service: &Service) -> String {
+    to_type_name(&identifier_words(&service.name))
+}
+
+/// Module of a service e.g. `tagging_tag` for the `tagging/tag` tag.
+pub fn service_module_name(service: &Service) -> String {
+    to_module_name(&identifier_words(&service.name))
+}
+
+/// Rust name of a model e.g. `CisTaggingTagModel`.
+pub fn model_type_name(name: &str) -> String {
+    to_type_name(&identifier_words(name))
+}
+
+fn identifier_words(name: &str) -> String {
+    name.replace(|c: char| !c.is_ascii_alphanumeric(), " ")
+}
+
+/// Emit the models in the order of their Rust names.
+pub fn emit_models(model: &RestModel, printer: &mut dyn Printer) -> Result<()> {
+    let mut types: Vec<&TypeDef> = model.types.values().collect();
+    types.sort_by_key(|type_def| model_type_name(&type_def.name));
+    for type_def in types {
+        emit_description(printer, &type_def.description)?;
+        let type_name = model_type_name(&type_def.name);
+        match &type_def.kind {
+            TypeKind::Struct(properties) => {
+                if is_defaultable(model, &RestType::Named(type_def.name.clone()), &mut vec![]) {
+                    printer.println("#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]")?;
+                } else {
+                    printer.println("#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]")?;
+                }
+                printer.println(&format!("pub struct {type_name} {{"))?;
+                printer.indent();
+                for property in properties {
+                    emit_description(printer, &property.description)?;
+                    let field_name = to_field_name(&property.name);
+                    if field_name.trim_start_matches("r#") != property.name {
+                        printer.println(&format!("#[serde(rename = \"{}\")]", property.name))?;
+                    }
+                    let mut field_type = rust_type(&property.rest_type);
+                    if property.boxed {
+                        field_type = format!("Box<{field_type}>");
+                    }
+                    if !property.required {
+                        printer.println(r#"#[serde(default, skip_serializing_if = "Option::is_none")]"#)?;
+                        field_type = format!("Option<{field_type}>");
+                    }
+                    printer.println(&format!("pub {field_name}: {field_type},"))?;
+                }
+                printer.dedent();
+                printer.println("}")?;
+            }
+            TypeKind::Enum(values) => {
+                printer.println("#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]")?;
+                printer.println(&format!("pub enum {type_name} {{"))?;
+                printer.indent();
+                let mut variants = Vec::new();
+                for value in values {
+                    let mut variant = to_enum_variant(&identifier_words(value));
+                    if !variant.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                        variant = format!("V{variant}");
+                    }
+                    if variants.contains(&variant) || variant == "Other_" {
+                        continue;
+                    }
+                    if value != &variant {
+                        printer.println(&format!("#[serde(rename = \"{value}\")]"))?;
+                    }
+                    printer.println(&format!("{variant},"))?;
+                    variants.push(variant);
+                }
+                // Make enums open i.e. handle unknown values possibly from future API servers
+                printer.println("/// This variant handles values not known at compile time.")?;
+                printer.println("#[serde(untagged)]")?;
+                printer.println("Other_(String),")?;
+                printer.dedent();
+                printer.println("}")?;
+            }
+            TypeKind::Alias(alias) => {
+                printer.println(&format!("pub type {type_name} = {};", rust_type(alias)))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Emit the service struct with an async method per operation.
+pub fn emit_service(model: &RestModel, service: &Service, printer: &mut dyn Printer) -> Result<()> {
+    printer.println("use std::sync::Arc;")?;
+    printer.println("use vim_rs::core::client::{Client, Result};")?;
+    if service.operations.iter().any(|operation| !operation.path_params.is_empty()) {
+        printer.println("use vim_rs::core::automation::encode_component;")?;
+    }
+    let mut imports = BTreeSet::new();
+    for operation in &service.operations {
+        // Path parameters are passed as strings or primitives
+        let params = operation.query_params.iter().chain(&operation.body);
+        for rest_type in params.map(|param| &param.rest_type).chain(&operation.output) {
+            collect_named(rest_type, &mut imports);
+        }
+    }
+    for import in &imports {
+        printer.println(&format!("use crate::models::{import};"))?;
+    }
+    emit_description(printer, &service.description)?;
+    let struct_name = service_type_name(service);
+    printer.println(&format!("pub struct {struct_name} {{"))?;
+    printer.indent();
+    printer.println("client: Arc<Client>,")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.println(&format!("impl {struct_name} {{"))?;
+    printer.indent();
+    printer.println("pub fn new(client: Arc<Client>) -> Self {")?;
+    printer.indent();
+    printer.println("Self { client }")?;
+    printer.dedent();
+    printer.println("}")?;
+    for operation in &service.operations {
+        emit_operation(model, operation, printer)?;
+    }
+    printer.dedent();
+    printer.println("}")?;
+    Ok(())
+}
+
+fn emit_operation(model: &RestModel, operation: &RestOperation, printer: &mut dyn Printer) -> Result<()> {
+    emit_description(printer, &operation.description)?;
+    let params: Vec<&Param> = operation.path_params.iter().chain(&operation.query_params).chain(&operation.body).collect();
+    if params.iter().any(|param| param.description.is_some()) {
+        printer.println("///")?;
+        printer.println("/// ## Parameters:")?;
+        for param in &params {
+            printer.println("///")?;
+            printer.println(&format!("/// ### {}", to_field_name(&param.name)))?;
+            emit_description(printer, &param.description)?;
+        }
+    }
+    if operation.deprecated {
+        printer.println("#[deprecated]")?;
+    }
+
+    let mut arguments = Vec::new();
+    for param in &operation.path_params {
+        let argument_type = if is_path_primitive(model, &param.rest_type) { rust_type(&param.rest_type) } else { "&str".to_string() };
+        arguments.push(format!("{}: {argument_type}", to_field_name(&param.name)));
+    }
+    for param in operation.query_params.iter().chain(&operation.body) {
+        arguments.push(format!("{}: {}", to_field_name(&param.name), param_type(model, param)));
+    }
+    let output = match &operation.output {
+        Some(output) => rust_type(output),
+        None => "()".to_string(),
+    };
+    printer.print_indent()?;
+    printer.print(&format!("pub async fn {}(&self", to_fn_name(&identifier_words(&operation.name))))?;
+    for argument in &arguments {
+        printer.print(&format!(", {argument}"))?;
+    }
+    printer.print(&format!(") -> Result<{output}> {{"))?;
+    printer.newline()?;
+    printer.indent();
+
+    // Path parameters are substituted in the order they appear in the template
+    let mut template = String::new();
+    let mut values = Vec::new();
+    let mut rest = operation.path.as_str();
+    while let Some((before, after)) = rest.split_once('{') {
+        let Some((name, after)) = after.split_once('}') else {
+            break;
+        };
+        template.push_str(before);
+        template.push_str("{}");
+        let value = to_field_name(name);
+        let is_primitive = operation
+            .path_params
+            .iter()
+            .find(|param| param.name == name)
+            .is_some_and(|param| is_path_primitive(model, &param.rest_type));
+        if is_primitive {
+            values.push(format!("encode_component(&{value}.to_string())"));
+        } else {
+            values.push(format!("encode_component({value})"));
+        }
+        rest = after;
+    }
+    template.push_str(rest);
+    let path = if values.is_empty() {
+        format!("\"{template}\"")
+    } else {
+        printer.println(&format!("let path = format!(\"{template}\", {});", values.join(", ")))?;
+        "&path".to_string()
+    };
+
+    let optional_body = operation.body.as_ref().filter(|body| !body.required);
+    let binding = if optional_body.is_some() { "let mut req" } else { "let req" };
+    printer.print_indent()?;
+    printer.print(&format!(
+        "{binding} = self.client.api_request(http::Method::{}, {path})",
+        operation.method
+    ))?;
+    if !values.is_empty() {
+        // Keeps the identifiers out of span names and metric labels
+        printer.print(&format!(".path_template(\"{}\")", operation.path))?;
+    }
+    for param in &operation.query_params {
+        printer.print(&format!(".query(\"{}\", &{})", param.name, to_field_name(&param.name)))?;
+    }
+    if let Some(body) = operation.body.as_ref().filter(|body| body.required) {
+        printer.print(&format!(".json({})", to_field_name(&body.name)))?;
+    }
+    printer.print(";")?;
+    printer.newline()?;
+    if let Some(body) = optional_body {
+        let name = to_field_name(&body.name);
+        printer.println(&format!("if let Some({name}) = {name} {{"))?;
+        printer.indent();
+        printer.println(&format!("req = req.json({name});"))?;
+        printer.dedent();
+        printer.println("}")?;
+    }
+    if operation.output.is_some() {
+        printer.println("self.client.execute(req).await")?;
+    } else {
+        printer.println("self.client.execute_void(req).await")?;
+    }
+    printer.dedent();
+    printer.println("}")?;
+    Ok(())
+}
+
+/// Emit the module declarations and re-exports of the services.
+pub fn emit_services_module(model: &RestModel, printer: &mut dyn Printer) -> Result<()> {
+    let mut modules: Vec<String> = model.services.values().map(service_module_name).collect();
+    modules.sort();
+    for module in &modules {
+        printer.println(&format!("pub mod {module};"))?;
+    }
+    printer.println("")?;
+    for module in &modules {
+        printer.println(&format!("pub use {module}::*;"))?;
+    }
+    Ok(())
+}
+
+/// Rust type of a value stored in a model or returned from an operation.
+fn rust_type(rest_type: &RestType) -> String {
+    match rest_type {
+        RestType::String => "String".to_string(),
+        RestType::Int32 => "i32".to_string(),
+        RestType::Int64 => "i64".to_string(),
+        RestType::Float => "f32".to_string(),
+        RestType::Double => "f64".to_string(),
+        RestType::Boolean => "bool".to_string(),
+        RestType::Any => "serde_json::Value".to_string(),
+        RestType::Array(item) => format!("Vec<{}>", rust_type(item)),
+        RestType::Map(value) => format!("std::collections::HashMap<String, {}>", rust_type(value)),
+        RestType::Named(name) => model_type_name(name),
+    }
+}
+
+/// Rust type of a query parameter or request body. Values other than primitives are borrowed.
+fn param_type(model: &RestModel, param: &Param) -> String {
+    let param_type = match model.resolve_alias(&param.rest_type) {
+        RestType::String => "&str".to_string(),
+        RestType::Array(item) => format!("&[{}]", rust_type(item)),
+        RestType::Int32 | RestType::Int64 | RestType::Float | RestType::Double | RestType::Boolean => {
+            rust_type(&param.rest_type)
+        }
+        _ => format!("&{}", rust_type(&param.rest_type)),
+    };
+    if param.required {
+        param_type
+    } else {
+        format!("Option<{param_type}>")
+    }
+}
+
+/// Numbers and booleans are passed by value and formatted into the path. Other values are passed
+/// as strings.
+fn is_path_primitive(model: &RestModel, rest_type: &RestType) -> bool {
+    matches!(
+        model.resolve_alias(rest_type),
+        RestType::Int32 | RestType::Int64 | RestType::Float | RestType::Double | RestType::Boolean
+    )
+}
+
+fn collect_named(rest_type: &RestType, imports: &mut BTreeSet<String>) {
+    match rest_type {
+        RestType::Array(inner) | RestType::Map(inner) => collect_named(inner, imports),
+        RestType::Named(name) => {
+            imports.insert(model_type_name(name));
+        }
+        _ => {}
+    }
+}
+
+/// Check if the type implements `Default` i.e. it is not an enum and contains no required enum.
+fn is_defaultable<'a>(model: &'a RestModel, rest_type: &'a RestType, visited: &mut Vec<&'a str>) -> bool {
+    let RestType::Named(name) = rest_type else {
+        return true;
+    };
+    if visited.contains(&name.as_str()) {
+        return true;
+    }
+    visited.push(name);
+    match model.types.get(name).map(|type_def| &type_def.kind) {
+        Some(TypeKind::Struct(properties)) => properties
+            .iter()
+            .filter(|property| property.required)
+            .all(|property| is_defaultable(model, &property.rest_type, visited)),
+        Some(TypeKind::Alias(alias)) => is_defaultable(model, alias, visited),
+        Some(TypeKind::Enum(_)) | None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::printer::StringPrinter;
+    use crate::rest::model::Property;
+
+    fn model() -> RestModel {
+        let mut model = RestModel::default();
+        model.types.insert(
+            "CisTaggingTagModel".to_string(),
+            TypeDef {
+                name: "CisTaggingTagModel".to_string(),
+                description: Some("A tag".to_string()),
+                kind: TypeKind::Struct(vec![
+                    Property {
+                        name: "id".to_string(),
+                        description: None,
+                        rest_type: RestType::String,
+                        required: true,
+                        boxed: false,
+                    },
+                    Property {
+                        name: "type".to_string(),
+                        description: Some("Kind of tag".to_string()),
+                        rest_type: RestType::Named("Kind".to_string()),
+                        required: false,
+                        boxed: false,
+                    },
+                    Property {
+                        name: "usedBy".to_string(),
+                        description: None,
+                        rest_type: RestType::Array(Box::new(RestType::String)),
+                        required: true,
+                        boxed: false,
+                    },
+                ]),
+            },
+        );
+        model.types.insert(
+            "Kind".to_string(),
+            TypeDef { name: "Kind".to_string(), description: None, kind: TypeKind::Enum(vec!["USER".to_string(), "1x".to_string()]) },
+        );
+        model.types.insert(
+            "TagId".to_string(),
+            TypeDef { name: "TagId".to_string(), description: None, kind: TypeKind::Alias(RestType::String) },
+        );
+        let param = |name: &str, rest_type: RestType, required: bool| Param {
+            name: name.to_string(),
+            description: None,
+            rest_type,
+            required,
+        };
+        model.services.insert(
+            "tagging/tag".to_string(),
+            Service {
+                name: "tagging/tag".to_string(),
+                description: Some("Tags".to_string()),
+                operations: vec![
+                    RestOperation {
+                        name: "get".to_string(),
+                        description: Some("Get a tag".to_string()),
+                        method: "GET".to_string(),
+                        path: "/api/cis/tagging/tag/{tag_id}".to_string(),
+                        path_params: vec![Param {
+                            description: Some("Identifier of the tag".to_string()),
+                            ..param("tag_id", RestType::Named("TagId".to_string()), true)
+                        }],
+                        query_params: vec![],
+                        body: None,
+                        output: Some(RestType::Named("CisTaggingTagModel".to_string())),
+                        deprecated: false,
+                    },
+                    RestOperation {
+                        name: "list-used".to_string(),
+                        description: None,
+                        method: "POST".to_string(),
+                        path: "/api/cis/tagging/tag/{page}?action=list-used".to_string(),
+                        path_params: vec![param("page", RestType::Int64, true)],
+                        query_params: vec![param("names", RestType::Array(Box::new(RestType::String)), false)],
+                        body: Some(param("spec", RestType::Named("CisTaggingTagModel".to_string()), false)),
+                        output: None,
+                        deprecated: true,
+                    },
+                ],
+            },
+        );
+        model
+    }
+
+    #[test]
+    fn test_emit_models() {
+        let mut printer = StringPrinter::new(None, None);
+        emit_models(&model(), &mut printer).unwrap();
+        assert_eq!(
+            printer.to_string(),
+            r#"/// A tag
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingTagModel {
+    pub id: String,
+    /// Kind of tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Kind>,
+    #[serde(rename = "usedBy")]
+    pub used_by: Vec<String>,
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum Kind {
+    #[serde(rename = "USER")]
+    User,
+    #[serde(rename = "1x")]
+    V1X,
+    /// This variant handles values not known at compile time.
+    #[serde(untagged)]
+    Other_(String),
+}
+pub type TagId = String;
+"#
+        );
+    }
+
+    #[test]
+    fn test_emit_service() {
+        let model = model();
+        let mut printer = StringPrinter::new(None, None);
+        emit_service(&model, &model.services["tagging/tag"], &mut printer).unwrap();
+        assert_eq!(
+            printer.to_string(),
+            r#"use std::sync::Arc;
+use vim_rs::core::client::{Client, Result};
+use vim_rs::core::automation::encode_component;
+use crate::models::CisTaggingTagModel;
+/// Tags
+pub struct TaggingTag {
+    client: Arc<Client>,
+}
+impl TaggingTag {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+    /// Get a tag
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag
+    pub async fn get(&self, tag_id: &str) -> Result<CisTaggingTagModel> {
+        let path = format!("/api/cis/tagging/tag/{}", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::GET, &path).path_template("/api/cis/tagging/tag/{tag_id}");
+        self.client.execute(req).await
+    }
+    #[deprecated]
+    pub async fn list_used(&self, page: i64, names: Option<&[String]>, spec: Option<&CisTaggingTagModel>) -> Result<()> {
+        let path = format!("/api/cis/tagging/tag/{}?action=list-used", encode_component(&page.to_string()));
+        let mut req = self.client.api_request(http::Method::POST, &path).path_template("/api/cis/tagging/tag/{page}?action=list-used").query("names", &names);
+        if let Some(spec) = spec {
+            req = req.json(spec);
+        }
+        self.client.execute_void(req).await
+    }
+}
+"#
+        );
+        let mut printer = StringPrinter::new(None, None);
+        emit_services_module(&model, &mut printer).unwrap();
+        assert_eq!(printer.to_string(), "pub mod tagging_tag;\n\npub use tagging_tag::*;\n");
+    }
+}
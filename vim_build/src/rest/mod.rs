@@ -0,0 +1,20 @@
+//! Bindings generator for the vSphere Automation REST API. The bindings are emitted as the
+//! `vim_rest` crate with models in `models.rs` and a service per tag in `services/`. The services
+//! call the API through `vim_rs::core::client::Client::api_request`.
+mod emitter;
+mod model;
+
+pub use emitter::*;
+pub use model::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("OpenAPI error: {0}")]
+    OpenApi(#[from] openapi30::Error),
+    #[error("Printer error: {0}")]
+    Print(#[from] crate::printer::Error),
+    #[error("Emit error: {0}")]
+    Emit(#[from] crate::rs_emitter::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
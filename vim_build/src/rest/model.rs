@@ -0,0 +1,526 @@
+/// Logic in this module turns the vSphere Automation REST API OpenAPI specification into the model
+/// of the `vim_rest` bindings. Operations are grouped into services by their first tag. Named
+/// schemas become models and inline object and enum schemas become models named after the place
+/// they are declared at. Only the schemas reachable from the selected operations are kept.
+use convert_case::{Case, Casing};
+use indexmap::IndexMap;
+use log::warn;
+use openapi30::{
+    DataFormat, Location, MediaType, OpenAPI, Operation, Parameter, PathItem, RefOr, Resolver, Schema, SchemaType,
+};
+
+use super::Result;
+use crate::vim_model::NameFilter;
+
+/// Type of a value exchanged with the REST API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RestType {
+    String,
+    Int32,
+    Int64,
+    Float,
+    Double,
+    Boolean,
+    /// Free-form value or a value the bindings cannot describe e.g. `oneOf`
+    Any,
+    Array(Box<RestType>),
+    /// Object with arbitrary keys
+    Map(Box<RestType>),
+    /// Model with the given schema name
+    Named(String),
+}
+
+/// Model i.e. a data type of the bindings.
+#[derive(Debug, PartialEq)]
+pub struct TypeDef {
+    /// Schema name or the name derived from the declaring place of an inline schema.
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: TypeKind,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeKind {
+    Struct(Vec<Property>),
+    /// String enumeration with its values
+    Enum(Vec<String>),
+    Alias(RestType),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Property {
+    /// Name on the wire
+    pub name: String,
+    pub description: Option<String>,
+    pub rest_type: RestType,
+    pub required: bool,
+    /// The property refers back to the model directly or indirectly and needs to be boxed.
+    pub boxed: bool,
+}
+
+/// Operations sharing a tag.
+#[derive(Debug, PartialEq)]
+pub struct Service {
+    /// The tag or the path of the first operation if the operation has no tag.
+    pub name: String,
+    pub description: Option<String>,
+    pub operations: Vec<RestOperation>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RestOperation {
+    /// The operation id made unique within the service.
+    pub name: String,
+    pub description: Option<String>,
+    /// HTTP method in upper case e.g. `GET`
+    pub method: String,
+    /// Path template e.g. `/api/cis/tagging/tag/{tag_id}`. May contain a query e.g. `?action=attach`.
+    pub path: String,
+    pub path_params: Vec<Param>,
+    pub query_params: Vec<Param>,
+    pub body: Option<Param>,
+    /// The type of the JSON content of the success response if any.
+    pub output: Option<RestType>,
+    pub deprecated: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Param {
+    /// Name on the wire
+    pub name: String,
+    pub description: Option<String>,
+    pub rest_type: RestType,
+    pub required: bool,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct RestModel {
+    pub types: IndexMap<String, TypeDef>,
+    pub services: IndexMap<String, Service>,
+}
+
+impl RestModel {
+    /// Follow type aliases to the type they stand for.
+    pub fn resolve_alias<'a>(&'a self, rest_type: &'a RestType) -> &'a RestType {
+        let mut rest_type = rest_type;
+        while let RestType::Named(name) = rest_type {
+            match self.types.get(name) {
+                Some(TypeDef { kind: TypeKind::Alias(alias), .. }) => rest_type = alias,
+                _ => break,
+            }
+        }
+        rest_type
+    }
+}
+
+/// Load the services of the paths selected by `filter` and the models they use.
+pub fn load_rest_model(openapi: &OpenAPI, filter: &NameFilter) -> Result<RestModel> {
+    let mut loader = Loader { resolver: Resolver::new(openapi), model: RestModel::default() };
+    for (path, path_item) in &openapi.paths {
+        if !filter.selects(path) {
+            continue;
+        }
+        for (method, operation) in path_item.operations() {
+            loader.add_operation(path, path_item, method, operation)?;
+        }
+    }
+    for tag in openapi.tags.iter().flatten() {
+        if let Some(service) = loader.model.services.get_mut(&tag.name) {
+            service.description.clone_from(&tag.description);
+        }
+    }
+    let mut model = loader.model;
+    mark_boxed_properties(&mut model);
+    Ok(model)
+}
+
+struct Loader<'a> {
+    resolver: Resolver<'a>,
+    model: RestModel,
+}
+
+impl<'a> Loader<'a> {
+    fn add_operation(&mut self, path: &str, path_item: &'a PathItem, method: &str, operation: &'a Operation) -> Result<()> {
+        let service_name = match operation.tags.iter().flatten().next() {
+            Some(tag) => tag.clone(),
+            None => path_service_name(path),
+        };
+        let operation_name = operation.operation_id.clone().unwrap_or_else(|| format!("{method} {path}"));
+        let operation_name = self.unique_operation_name(&service_name, operation_name);
+        let context = format!("{}{}", type_part(&service_name), type_part(&operation_name));
+
+        // Operation parameters override the parameters of the path item with the same name
+        let mut parameters: IndexMap<(String, String), &'a Parameter> = IndexMap::new();
+        for parameter in path_item.parameters.iter().flatten() {
+            parameters.insert((parameter.name.clone(), format!("{:?}", parameter.location)), parameter);
+        }
+        for parameter in operation.parameters.iter().flatten() {
+            let parameter = self.resolver.parameter(parameter)?;
+            parameters.insert((parameter.name.clone(), format!("{:?}", parameter.location)), parameter);
+        }
+        let mut path_params = Vec::new();
+        let mut query_params = Vec::new();
+        for parameter in parameters.into_values() {
+            let schema = parameter.schema.as_ref().or_else(|| json_schema(parameter.content.as_ref()?));
+            let rest_type = match schema {
+                Some(schema) => self.rest_type(schema, &format!("{context}{}", type_part(&parameter.name)))?,
+                None => RestType::Any,
+            };
+            let param = Param {
+                name: parameter.name.clone(),
+                description: parameter.description.clone(),
+                rest_type,
+                required: parameter.required.unwrap_or(false),
+            };
+            match parameter.location {
+                Location::Path => path_params.push(param),
+                Location::Query => query_params.push(param),
+                _ => warn!("Parameter {} of {method} {path} in {:?} is not supported", parameter.name, parameter.location),
+            }
+        }
+
+        let body = match &operation.request_body {
+            Some(request_body) => {
+                let request_body = self.resolver.request_body(request_body)?;
+                let name = match request_body.extensions.get("x-codegen-request-body-name") {
+                    Some(serde_json::Value::String(name)) => name.clone(),
+                    _ => "body".to_string(),
+                };
+                let rest_type = match json_schema(&request_body.content) {
+                    Some(schema) => self.rest_type(schema, &format!("{context}Body"))?,
+                    None => RestType::Any,
+                };
+                Some(Param {
+                    name,
+                    description: request_body.description.clone(),
+                    rest_type,
+                    required: request_body.required.unwrap_or(false),
+                })
+            }
+            None => None,
+        };
+
+        let mut output = None;
+        if let Some((_, response)) = operation.responses.responses.iter().find(|(status, _)| status.starts_with('2')) {
+            let response = self.resolver.response(response)?;
+            if let Some(schema) = response.content.as_ref().and_then(json_schema) {
+                output = Some(self.rest_type(schema, &format!("{context}Result"))?);
+            }
+        }
+
+        let description = match (&operation.summary, &operation.description) {
+            (Some(summary), Some(description)) if summary != description => Some(format!("{summary}\n\n{description}")),
+            (summary, description) => description.clone().or_else(|| summary.clone()),
+        };
+        let rest_operation = RestOperation {
+            name: operation_name,
+            description,
+            method: method.to_uppercase(),
+            path: path.to_string(),
+            path_params,
+            query_params,
+            body,
+            output,
+            deprecated: operation.deprecated.unwrap_or(false),
+        };
+        self.model
+            .services
+            .entry(service_name.clone())
+            .or_insert_with(|| Service { name: service_name, description: None, operations: Vec::new() })
+            .operations
+            .push(rest_operation);
+        Ok(())
+    }
+
+    /// Operation ids may repeat across services and are not always present. Make the name unique
+    /// within the service by appending a counter.
+    fn unique_operation_name(&self, service_name: &str, name: String) -> String {
+        let Some(service) = self.model.services.get(service_name) else {
+            return name;
+        };
+        let taken = |candidate: &str| {
+            service.operations.iter().any(|operation| operation.name.to_case(Case::Snake) == candidate.to_case(Case::Snake))
+        };
+        if !taken(&name) {
+            return name;
+        }
+        (2..).map(|counter| format!("{name}_{counter}")).find(|candidate| !taken(candidate)).unwrap_or(name)
+    }
+
+    /// The type of a value. Referenced schemas are added to the model. Inline object and enum
+    /// schemas are added to the model with the `context` name.
+    fn rest_type(&mut self, schema: &'a RefOr<Schema>, context: &str) -> Result<RestType> {
+        match schema {
+            RefOr::Ref { reference, .. } => {
+                let (name, schema) = self.resolver.resolve_reference::<Schema>(reference)?;
+                self.define(name.to_string(), schema)?;
+                Ok(RestType::Named(name.to_string()))
+            }
+            RefOr::Val(schema) => {
+                if is_enum(schema) || is_struct(schema) {
+                    let name = self.unique_type_name(context);
+                    self.define(name.clone(), schema)?;
+                    Ok(RestType::Named(name))
+                } else {
+                    self.value_type(schema, context)
+                }
+            }
+        }
+    }
+
+    /// The type of a schema that is neither an object with properties nor an enum.
+    fn value_type(&mut self, schema: &'a Schema, context: &str) -> Result<RestType> {
+        Ok(match &schema.schema_type {
+            Some(SchemaType::String) => RestType::String,
+            Some(SchemaType::Integer) if schema.format == Some(DataFormat::Int32) => RestType::Int32,
+            Some(SchemaType::Integer) => RestType::Int64,
+            Some(SchemaType::Number) if schema.format == Some(DataFormat::Float) => RestType::Float,
+            Some(SchemaType::Number) => RestType::Double,
+            Some(SchemaType::Boolean) => RestType::Boolean,
+            Some(SchemaType::Array) => match &schema.items {
+                Some(items) => RestType::Array(Box::new(self.rest_type(items, &format!("{context}Item"))?)),
+                None => RestType::Array(Box::new(RestType::Any)),
+            },
+            Some(SchemaType::Object) | None if schema.one_of.is_none() && schema.any_of.is_none() => {
+                match &schema.additional_properties {
+                    Some(values) => RestType::Map(Box::new(self.rest_type(values, &format!("{context}Value"))?)),
+                    None => RestType::Any,
+                }
+            }
+            _ => RestType::Any,
+        })
+    }
+
+    /// Add a model for the schema unless already present. The model is registered before its
+    /// properties are loaded so recursive schemas terminate.
+    fn define(&mut self, name: String, schema: &'a Schema) -> Result<()> {
+        if self.model.types.contains_key(&name) {
+            return Ok(());
+        }
+        let placeholder = TypeDef { name: name.clone(), description: schema.description.clone(), kind: TypeKind::Alias(RestType::Any) };
+        self.model.types.insert(name.clone(), placeholder);
+        let kind = if is_enum(schema) {
+            let values = schema.enumeration.iter().flatten().filter_map(|value| value.as_str().map(str::to_string)).collect();
+            TypeKind::Enum(values)
+        } else if is_struct(schema) {
+            let shape = self.resolver.object_shape(schema)?;
+            let mut properties = Vec::new();
+            for (property, property_schema) in shape.properties {
+                let rest_type = self.rest_type(property_schema, &format!("{name}{}", type_part(property)))?;
+                let nullable = match property_schema {
+                    RefOr::Ref { nullable, .. } => nullable.unwrap_or(false),
+                    RefOr::Val(schema) => schema.nullable.unwrap_or(false),
+                };
+                properties.push(Property {
+                    name: property.to_string(),
+                    description: property_description(property_schema),
+                    rest_type,
+                    required: shape.required.contains(property) && !nullable,
+                    boxed: false,
+                });
+            }
+            TypeKind::Struct(properties)
+        } else {
+            TypeKind::Alias(self.value_type(schema, &name)?)
+        };
+        if let Some(type_def) = self.model.types.get_mut(&name) {
+            type_def.kind = kind;
+        }
+        Ok(())
+    }
+
+    /// Name for an inline schema that does not clash with a schema name.
+    fn unique_type_name(&self, context: &str) -> String {
+        let taken = |name: &str| self.model.types.contains_key(name) || self.resolver.get::<Schema>(name).is_some();
+        if !taken(context) {
+            return context.to_string();
+        }
+        (2..).map(|counter| format!("{context}{counter}")).find(|name| !taken(name)).unwrap_or_default()
+    }
+}
+
+fn is_enum(schema: &Schema) -> bool {
+    schema.enumeration.as_ref().is_some_and(|values| !values.is_empty() && values.iter().all(serde_json::Value::is_string))
+}
+
+fn is_struct(schema: &Schema) -> bool {
+    schema.properties.as_ref().is_some_and(|properties| !properties.is_empty()) || schema.all_of.is_some()
+}
+
+/// The schema of the JSON media type of a content map.
+fn json_schema(content: &IndexMap<String, MediaType>) -> Option<&RefOr<Schema>> {
+    content.iter().find(|(media_type, _)| media_type.contains("json")).and_then(|(_, media_type)| media_type.schema.as_ref())
+}
+
+fn property_description(schema: &RefOr<Schema>) -> Option<String> {
+    match schema {
+        RefOr::Ref { description, .. } => description.clone(),
+        RefOr::Val(schema) => schema.description.clone(),
+    }
+}
+
+/// Pascal case part of a generated name from a tag, operation id or property name.
+fn type_part(name: &str) -> String {
+    name.replace(|c: char| !c.is_ascii_alphanumeric(), " ").to_case(Case::Pascal)
+}
+
+/// Service name of an untagged operation from the constant segments of its path e.g.
+/// `cis/tagging/tag` for `/api/cis/tagging/tag/{tag_id}?action=attach`.
+fn path_service_name(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    path.split('/')
+        .filter(|segment| !segment.is_empty() && *segment != "api" && !segment.starts_with('{'))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Box the struct properties through which a model contains itself.
+fn mark_boxed_properties(model: &mut RestModel) {
+    let mut boxed = Vec::new();
+    for (name, type_def) in &model.types {
+        let TypeKind::Struct(properties) = &type_def.kind else {
+            continue;
+        };
+        for (index, property) in properties.iter().enumerate() {
+            if let Some(target) = contained_struct(model, &property.rest_type) {
+                if contains(model, target, name, &mut vec![]) {
+                    boxed.push((name.clone(), index));
+                }
+            }
+        }
+    }
+    for (name, index) in boxed {
+        if let Some(TypeDef { kind: TypeKind::Struct(properties), .. }) = model.types.get_mut(&name) {
+            properties[index].boxed = true;
+        }
+    }
+}
+
+/// The struct a value of the type is stored in place of. Arrays and maps store their values on the
+/// heap.
+fn contained_struct<'a>(model: &'a RestModel, rest_type: &'a RestType) -> Option<&'a str> {
+    match model.resolve_alias(rest_type) {
+        RestType::Named(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Check if the struct `name` contains the struct `target` in place.
+fn contains<'a>(model: &'a RestModel, name: &'a str, target: &str, visited: &mut Vec<&'a str>) -> bool {
+    if name == target {
+        return true;
+    }
+    if visited.contains(&name) {
+        return false;
+    }
+    visited.push(name);
+    let Some(TypeDef { kind: TypeKind::Struct(properties), .. }) = model.types.get(name) else {
+        return false;
+    };
+    properties
+        .iter()
+        .filter_map(|property| contained_struct(model, &property.rest_type))
+        .any(|next| contains(model, next, target, visited))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn openapi() -> OpenAPI {
+        serde_json::from_str(
+            r##"{
+            "openapi": "3.0.0",
+            "info": {"title": "Tagging", "version": "8.0"},
+            "tags": [{"name": "tagging/tag", "description": "Tags"}],
+            "paths": {
+                "/api/cis/tagging/tag/{tag_id}": {
+                    "get": {
+                        "tags": ["tagging/tag"],
+                        "operationId": "get",
+                        "parameters": [{"name": "tag_id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "responses": {"200": {"description": "Tag", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Tag"}}}}}
+                    },
+                    "patch": {
+                        "tags": ["tagging/tag"],
+                        "operationId": "get",
+                        "parameters": [{"name": "tag_id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                        "requestBody": {"required": true, "content": {"application/json": {"schema": {
+                            "type": "object", "properties": {"name": {"type": "string"}}
+                        }}}},
+                        "responses": {"204": {"description": "Updated"}}
+                    }
+                },
+                "/api/cis/tagging/tag": {
+                    "get": {
+                        "parameters": [{"name": "limit", "in": "query", "schema": {"type": "integer", "format": "int32"}}],
+                        "responses": {"200": {"description": "Tags", "content": {"application/json": {"schema": {
+                            "type": "array", "items": {"type": "string"}
+                        }}}}}
+                    }
+                },
+                "/api/vcenter/vm": {
+                    "get": {"responses": {"200": {"description": "VMs"}}}
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Tag": {
+                        "type": "object",
+                        "required": ["id", "parent"],
+                        "properties": {
+                            "id": {"type": "string"},
+                            "state": {"type": "string", "enum": ["ACTIVE", "REMOVED"]},
+                            "parent": {"$ref": "#/components/schemas/Tag", "nullable": true},
+                            "child": {"$ref": "#/components/schemas/Child"},
+                            "labels": {"type": "object", "additionalProperties": {"type": "string"}}
+                        }
+                    },
+                    "Child": {"type": "object", "properties": {"owner": {"$ref": "#/components/schemas/Tag"}}},
+                    "Unused": {"type": "string"}
+                }
+            }
+        }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_load_rest_model() {
+        let openapi = openapi();
+        let filter = NameFilter { include: vec!["/api/cis/*".to_string()], exclude: vec![] };
+        let model = load_rest_model(&openapi, &filter).unwrap();
+        assert_eq!(model.services.keys().collect::<Vec<_>>(), vec!["tagging/tag", "cis/tagging/tag"]);
+
+        let service = &model.services["tagging/tag"];
+        assert_eq!(service.description.as_deref(), Some("Tags"));
+        let names: Vec<&str> = service.operations.iter().map(|operation| operation.name.as_str()).collect();
+        assert_eq!(names, vec!["get", "get_2"]);
+        let get = &service.operations[0];
+        assert_eq!(get.method, "GET");
+        assert_eq!(get.path_params[0].rest_type, RestType::String);
+        assert_eq!(get.output, Some(RestType::Named("Tag".to_string())));
+        let update = &service.operations[1];
+        assert_eq!(update.body.as_ref().unwrap().rest_type, RestType::Named("TaggingTagGet2Body".to_string()));
+        assert_eq!(update.output, None);
+
+        let list = &model.services["cis/tagging/tag"].operations[0];
+        assert_eq!(list.name, "get /api/cis/tagging/tag");
+        assert_eq!(list.query_params[0].rest_type, RestType::Int32);
+        assert!(!list.query_params[0].required);
+        assert_eq!(list.output, Some(RestType::Array(Box::new(RestType::String))));
+
+        assert_eq!(model.types.keys().collect::<Vec<_>>(), vec!["Tag", "TagState", "Child", "TaggingTagGet2Body"]);
+        let TypeKind::Struct(properties) = &model.types["Tag"].kind else {
+            panic!("Tag is not a struct");
+        };
+        let summary: Vec<(&str, bool, bool)> =
+            properties.iter().map(|property| (property.name.as_str(), property.required, property.boxed)).collect();
+        assert_eq!(
+            summary,
+            vec![("id", true, false), ("state", false, false), ("parent", false, true), ("child", false, true), ("labels", false, false)]
+        );
+        assert_eq!(properties[4].rest_type, RestType::Map(Box::new(RestType::String)));
+        assert_eq!(model.types["TagState"].kind, TypeKind::Enum(vec!["ACTIVE".to_string(), "REMOVED".to_string()]));
+    }
+}
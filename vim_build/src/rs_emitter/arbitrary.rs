@@ -0,0 +1,552 @@
+use std::collections::BTreeSet;
+
+use crate::printer::Printer;
+use crate::rs_emitter::errors::{Error, Result};
+use crate::rs_emitter::structs::ANY;
+use crate::vim_model::{DataType, EmitMode, Model, Struct};
+
+use super::common::emit_cfg;
+use super::names::{to_enum_variant, to_field_name, to_fn_name, to_type_name, TypeDefResolver};
+
+/// Generates `arbitrary::Arbitrary` implementations for the data types backing the `arbitrary`
+/// feature.
+///
+/// Structs fill every field and leave the extra fields of pruned types empty. Trait objects choose
+/// among the type and its descendants, `VimAny` among all data types and the `ValueElements`
+/// variants. Enums also produce unknown values in `Other_`. Floats are finite and date and time
+/// values valid so that every value survives a JSON round trip.
+///
+/// Data objects are nested at most `MAX_DEPTH` levels deep. Below that optional data objects are
+/// left unset, arrays of them empty and polymorphic values take their declared type, so recursive
+/// types such as `VimAny` properties terminate regardless of the input.
+pub fn generate_arbitrary(vim_model: &Model, printer: &mut dyn Printer) -> Result<()> {
+    printer.println("use ::arbitrary::{Arbitrary, Result, Unstructured};")?;
+    printer.println("use super::boxed_types::ValueElements;")?;
+    printer.println("use super::structs::*;")?;
+    printer.println("use super::vim_any::VimAny;")?;
+    printer.println("use super::vim_object_trait::VimObjectTrait;")?;
+    printer.newline()?;
+    printer.println("/// Constructor of one of the alternatives of a polymorphic value.")?;
+    printer.println("type Constructor<T> = for<'b> fn(&mut Unstructured<'b>) -> Result<T>;")?;
+    printer.newline()?;
+    printer.println(DEPTH_BUDGET.trim())?;
+    printer.newline()?;
+
+    let mut emitter = ArbitraryEmitter { vim_model, printer, tdf: TypeDefResolver::new(vim_model), helpers: BTreeSet::new() };
+    emitter.emit_structs()?;
+    emitter.emit_traits()?;
+    emitter.emit_any()?;
+    emitter.emit_value_elements()?;
+    emitter.emit_enums()?;
+    emitter.emit_helpers()
+}
+
+/// Generates the `tests/round_trip.rs` integration test. Values of every data type are serialized
+/// and deserialized both as the type itself and as `Box<dyn VimObjectTrait>` and must yield the
+/// same data type and JSON. `types_module` is the path of the data types module in vim_rs.
+pub fn generate_round_trip_tests(vim_model: &Model, types_module: &str, printer: &mut dyn Printer) -> Result<()> {
+    printer.println("// Generated by vim_build. Run with `cargo test --features arbitrary --test round_trip`.")?;
+    printer.println(r#"#![cfg(feature = "arbitrary")]"#)?;
+    printer.newline()?;
+    printer.println("use arbitrary::{Arbitrary, Unstructured};")?;
+    printer.println(&format!("use vim_rs::{types_module}::struct_enum::StructType;"))?;
+    printer.println(&format!("use vim_rs::{types_module}::structs::*;"))?;
+    printer.println(&format!("use vim_rs::{types_module}::vim_any::VimAny;"))?;
+    printer.println(&format!("use vim_rs::{types_module}::vim_object_trait::VimObjectTrait;"))?;
+    printer.newline()?;
+    printer.println(ROUND_TRIP_HARNESS.trim_start())?;
+    for struct_type in emitted_structs(vim_model) {
+        let struct_type = struct_type.borrow();
+        printer.newline()?;
+        printer.println("#[test]")?;
+        emit_cfg(printer, vim_model.cfg_attribute(&struct_type.name))?;
+        printer.println(&format!("fn {}() {{", to_fn_name(&struct_type.name)))?;
+        printer.indent();
+        let rust_name = struct_type.rust_name();
+        printer.println(&format!("check_round_trip::<{rust_name}>(StructType::{rust_name});"))?;
+        printer.dedent();
+        printer.println("}")?;
+    }
+    Ok(())
+}
+
+const ROUND_TRIP_HARNESS: &str = r#"
+/// Values checked per data type, each generated from a different amount of data.
+const ITERATIONS: u64 = 32;
+
+/// Deterministic pseudo random data of up to 1 KiB (splitmix64).
+fn pseudo_random_data(seed: u64) -> Vec<u8> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    let len = next() % 1024;
+    (0..len).map(|_| next() as u8).collect()
+}
+
+/// Serialize a value to JSON text and parse it back as responses are parsed by the client.
+fn check_round_trip<T>(struct_type: StructType)
+where
+    T: for<'a> Arbitrary<'a> + VimObjectTrait + serde::Serialize + serde::de::DeserializeOwned,
+{
+    for seed in 0..ITERATIONS {
+        let data = pseudo_random_data(seed);
+        let value = T::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(value.data_type(), struct_type);
+        let text = serde_json::to_string(&value).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        let typed: T = serde_json::from_str(&text).unwrap_or_else(|e| panic!("{e}: {text}"));
+        assert_eq!(typed.data_type(), struct_type);
+        assert_eq!(serde_json::to_value(&typed).unwrap(), json);
+
+        let object: Box<dyn VimObjectTrait> = serde_json::from_str(&text).unwrap_or_else(|e| panic!("{e}: {text}"));
+        assert_eq!(object.data_type(), struct_type);
+        assert_eq!(serde_json::to_value(&object).unwrap(), json);
+    }
+}
+
+#[test]
+fn vim_any_round_trip() {
+    for seed in 0..ITERATIONS * 8 {
+        let data = pseudo_random_data(seed);
+        let value = VimAny::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let text = serde_json::to_string(&value).unwrap();
+        let any: VimAny = serde_json::from_str(&text).unwrap_or_else(|e| panic!("{e}: {text}"));
+        assert_eq!(serde_json::to_string(&any).unwrap(), text);
+    }
+}
+
+#[test]
+fn vim_any_depth_budget() {
+    // Repeating two byte patterns include ones asking for ever deeper nesting which without the
+    // depth budget of 8 data objects overflow the stack
+    for first in 0..4 {
+        for second in 0..=u8::MAX {
+            let data: Vec<u8> = [first, second].into_iter().cycle().take(1 << 12).collect();
+            let value = VimAny::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let json = serde_json::to_value(&value).unwrap();
+            assert!(json_depth(&json) <= 36, "{json}");
+        }
+    }
+}
+
+/// Nesting of JSON objects and arrays.
+fn json_depth(json: &serde_json::Value) -> usize {
+    match json {
+        serde_json::Value::Object(map) => 1 + map.values().map(json_depth).max().unwrap_or(0),
+        serde_json::Value::Array(array) => 1 + array.iter().map(json_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}"#;
+
+/// The data types emitted as Rust structs.
+fn emitted_structs(vim_model: &Model) -> impl Iterator<Item = &std::cell::RefCell<Struct>> {
+    vim_model.structs.values().filter(|struct_type| {
+        let struct_type = struct_type.borrow();
+        struct_type.name != ANY && !struct_type.emit_mode.is_skip()
+    })
+}
+
+struct ArbitraryEmitter<'a> {
+    vim_model: &'a Model,
+    printer: &'a mut dyn Printer,
+    tdf: TypeDefResolver<'a>,
+    /// Helper functions referenced by the emitted code.
+    helpers: BTreeSet<&'static str>,
+}
+
+impl ArbitraryEmitter<'_> {
+    fn emit_structs(&mut self) -> Result<()> {
+        for struct_type in emitted_structs(self.vim_model) {
+            let struct_type = struct_type.borrow();
+            let mut fields = vec![];
+            for ancestor in self.vim_model.inheritance_chain(&struct_type.name)? {
+                for field in ancestor.borrow().fields.values() {
+                    let value = self.field_value(&field.vim_type, field.optional, field.require_box)?;
+                    fields.push(format!("{}: {value},", to_field_name(&field.name)));
+                }
+            }
+            // Types without fields leave the input unused
+            let input = if fields.is_empty() { "_u" } else { "u" };
+            let struct_name = struct_type.rust_name();
+            emit_cfg(self.printer, self.vim_model.cfg_attribute(&struct_type.name))?;
+            self.printer.println(&format!("impl<'a> Arbitrary<'a> for {struct_name} {{"))?;
+            self.printer.indent();
+            self.printer.println(&format!("fn arbitrary({input}: &mut Unstructured<'a>) -> Result<Self> {{"))?;
+            self.printer.indent();
+            if struct_type.emit_mode == EmitMode::Prune {
+                fields.push("type_: None,".to_string());
+                fields.push("extra_fields_: Default::default(),".to_string());
+            }
+            if fields.is_empty() {
+                self.printer.println(&format!("Ok({struct_name} {{}})"))?;
+            } else {
+                self.printer.println("nested(u, |u| {")?;
+                self.printer.indent();
+                self.printer.println(&format!("Ok({struct_name} {{"))?;
+                self.printer.indent();
+                for field in &fields {
+                    self.printer.println(field)?;
+                }
+                self.printer.dedent();
+                self.printer.println("})")?;
+                self.printer.dedent();
+                self.printer.println("})")?;
+            }
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.newline()?;
+        }
+        Ok(())
+    }
+
+    /// Trait objects are represented by the type or one of its descendants.
+    fn emit_traits(&mut self) -> Result<()> {
+        for struct_type in emitted_structs(self.vim_model) {
+            let struct_type = struct_type.borrow();
+            if struct_type.emit_mode != EmitMode::Emit || !struct_type.has_children() {
+                continue;
+            }
+            let trait_type = format!("Box<dyn super::traits::{}Trait>", struct_type.rust_name());
+            emit_cfg(self.printer, self.vim_model.cfg_attribute(&struct_type.name))?;
+            self.printer.println(&format!("impl<'a> Arbitrary<'a> for {trait_type} {{"))?;
+            self.printer.indent();
+            self.printer.println("fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {")?;
+            self.printer.indent();
+            self.printer.println(&format!("static TYPES: &[Constructor<{trait_type}>] = &["))?;
+            self.printer.indent();
+            for descendant in self.vim_model.children(&struct_type.name)? {
+                self.emit_constructor(&descendant.borrow())?;
+            }
+            self.printer.dedent();
+            self.printer.println("];")?;
+            self.printer.println("choose(u, TYPES)")?;
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.newline()?;
+        }
+        Ok(())
+    }
+
+    fn emit_any(&mut self) -> Result<()> {
+        self.printer.println("impl<'a> Arbitrary<'a> for VimAny {")?;
+        self.printer.indent();
+        self.printer.println("fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {")?;
+        self.printer.indent();
+        self.printer.println("if exhausted() || u.arbitrary()? {")?;
+        self.printer.indent();
+        self.printer.println("Ok(VimAny::Value(u.arbitrary()?))")?;
+        self.printer.dedent();
+        self.printer.println("} else {")?;
+        self.printer.indent();
+        self.printer.println("Ok(VimAny::Object(arbitrary_object(u)?))")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.newline()?;
+
+        self.printer.println("/// Data object of any type.")?;
+        self.printer.println("pub fn arbitrary_object(u: &mut Unstructured<'_>) -> Result<Box<dyn VimObjectTrait>> {")?;
+        self.printer.indent();
+        self.printer.println("static TYPES: &[Constructor<Box<dyn VimObjectTrait>>] = &[")?;
+        self.printer.indent();
+        for struct_type in emitted_structs(self.vim_model) {
+            self.emit_constructor(&struct_type.borrow())?;
+        }
+        self.printer.dedent();
+        self.printer.println("];")?;
+        self.printer.println("choose(u, TYPES)")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.newline()?;
+        Ok(())
+    }
+
+    fn emit_constructor(&mut self, struct_type: &Struct) -> Result<()> {
+        if struct_type.emit_mode.is_skip() {
+            return Ok(());
+        }
+        emit_cfg(self.printer, self.vim_model.cfg_attribute(&struct_type.name))?;
+        self.printer.println(&format!("|u| Ok(Box::new({}::arbitrary(u)?)),", struct_type.rust_name()))?;
+        Ok(())
+    }
+
+    fn emit_value_elements(&mut self) -> Result<()> {
+        self.printer.println("impl<'a> Arbitrary<'a> for ValueElements {")?;
+        self.printer.indent();
+        self.printer.println("fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {")?;
+        self.printer.indent();
+        self.printer.println("static VARIANTS: &[Constructor<ValueElements>] = &[")?;
+        self.printer.indent();
+        for box_type in self.vim_model.any_value_types.values() {
+            let value = self.field_value(&box_type.property_type, false, false)?;
+            emit_cfg(self.printer, self.vim_model.data_type_cfg_attribute(&box_type.property_type))?;
+            self.printer.println(&format!("|u| Ok(ValueElements::{}({value})),", to_type_name(&box_type.name)))?;
+        }
+        self.printer.dedent();
+        self.printer.println("];")?;
+        self.printer.println("u.choose(VARIANTS)?(u)")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.newline()?;
+        Ok(())
+    }
+
+    fn emit_enums(&mut self) -> Result<()> {
+        for vim_enum in self.vim_model.enums.values() {
+            let enum_name = to_type_name(&vim_enum.name);
+            self.printer.println(&format!("impl<'a> Arbitrary<'a> for super::enums::{enum_name} {{"))?;
+            self.printer.indent();
+            self.printer.println("fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {")?;
+            self.printer.indent();
+            if vim_enum.variants.is_empty() {
+                self.printer.println("Ok(Self::Other_(u.arbitrary()?))")?;
+            } else {
+                self.printer.println(&format!("Ok(match u.choose_index({})? {{", vim_enum.variants.len() + 1))?;
+                self.printer.indent();
+                for (index, variant) in vim_enum.variants.iter().enumerate() {
+                    self.printer.println(&format!("{index} => Self::{},", to_enum_variant(variant)))?;
+                }
+                self.printer.println("_ => Self::Other_(u.arbitrary()?),")?;
+                self.printer.dedent();
+                self.printer.println("})")?;
+            }
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.dedent();
+            self.printer.println("}")?;
+            self.printer.newline()?;
+        }
+        Ok(())
+    }
+
+    fn emit_helpers(&mut self) -> Result<()> {
+        for helper in &self.helpers {
+            let code = match *helper {
+                "option" => OPTION_HELPER,
+                "array" => ARRAY_HELPER,
+                "float" => FLOAT_HELPER,
+                "double" => DOUBLE_HELPER,
+                "date_time" => DATE_TIME_HELPER,
+                _ => return Err(Error::InternalError(format!("Unknown arbitrary helper {helper}"))),
+            };
+            self.printer.println(code.trim())?;
+            self.printer.newline()?;
+        }
+        Ok(())
+    }
+
+    /// Expression evaluating to the value of a field. Values the `Arbitrary` implementations of the
+    /// field type cannot produce use the helpers.
+    fn field_value(&mut self, vim_type: &DataType, optional: bool, boxed: bool) -> Result<String> {
+        if self.is_nested(vim_type) {
+            // Ensure the type is known to the bindings
+            self.tdf.to_rust_field_type(vim_type)?;
+            let generator = if matches!(vim_type, DataType::Array(_)) {
+                self.helpers.insert("array");
+                "|u| array(u, |u| u.arbitrary())"
+            } else {
+                "|u| u.arbitrary()"
+            };
+            if optional {
+                self.helpers.insert("option");
+                return Ok(format!("option(u, {generator})?"));
+            }
+            return Ok(match vim_type {
+                DataType::Array(_) => "array(u, |u| u.arbitrary())?".to_string(),
+                _ => "u.arbitrary()?".to_string(),
+            });
+        }
+        let Some(generator) = self.generator(vim_type) else {
+            // Ensure the type is known to the bindings
+            self.tdf.to_rust_field_type(vim_type)?;
+            return Ok("u.arbitrary()?".to_string());
+        };
+        if boxed {
+            return Err(Error::InternalError(format!("Boxed field of type {vim_type:?}")));
+        }
+        if optional {
+            self.helpers.insert("option");
+            return Ok(format!("option(u, {generator})?"));
+        }
+        Ok(match vim_type {
+            DataType::Array(item_type) => {
+                let item_generator = self.generator(item_type).unwrap_or_default();
+                format!("array(u, {item_generator})?")
+            }
+            _ => format!("{generator}(u)?"),
+        })
+    }
+
+    /// Whether values of the type contain data objects and thus count against the depth budget.
+    fn is_nested(&self, vim_type: &DataType) -> bool {
+        match vim_type {
+            DataType::Array(item_type) => self.is_nested(item_type),
+            DataType::Reference(name) => self.vim_model.structs.contains_key(name),
+            _ => false,
+        }
+    }
+
+    /// Function producing values of `vim_type` if it needs a helper.
+    fn generator(&mut self, vim_type: &DataType) -> Option<String> {
+        let helper = match vim_type {
+            DataType::Float => "float",
+            DataType::Double => "double",
+            DataType::DateTime => "date_time",
+            DataType::Array(item_type) => {
+                let item_generator = self.generator(item_type)?;
+                self.helpers.insert("array");
+                return Some(format!("|u| array(u, {item_generator})"));
+            }
+            _ => return None,
+        };
+        self.helpers.insert(helper);
+        Some(helper.to_string())
+    }
+}
+
+const DEPTH_BUDGET: &str = r#"
+/// Maximum nesting of generated data objects.
+const MAX_DEPTH: usize = 8;
+
+thread_local! {
+    /// Nesting of the data object being generated.
+    static DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Whether the depth budget is used up and no further data objects may be nested.
+fn exhausted() -> bool {
+    DEPTH.with(|depth| depth.get() >= MAX_DEPTH)
+}
+
+/// Generate the fields of a data object one level deeper.
+fn nested<'a, T>(u: &mut Unstructured<'a>, fields: impl FnOnce(&mut Unstructured<'a>) -> Result<T>) -> Result<T> {
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let value = fields(u);
+    DEPTH.with(|depth| depth.set(depth.get() - 1));
+    value
+}
+
+/// Choose the type of a polymorphic value. The first type i.e. the declared one is taken once the
+/// depth budget is used up.
+fn choose<T>(u: &mut Unstructured<'_>, types: &[Constructor<T>]) -> Result<T> {
+    if exhausted() {
+        return types[0](u);
+    }
+    u.choose(types)?(u)
+}
+"#;
+
+const OPTION_HELPER: &str = r#"
+fn option<'a, T>(u: &mut Unstructured<'a>, value: impl FnOnce(&mut Unstructured<'a>) -> Result<T>) -> Result<Option<T>> {
+    Ok(if !exhausted() && u.arbitrary()? { Some(value(u)?) } else { None })
+}
+"#;
+
+const ARRAY_HELPER: &str = r#"
+fn array<'a, T>(u: &mut Unstructured<'a>, mut element: impl FnMut(&mut Unstructured<'a>) -> Result<T>) -> Result<Vec<T>> {
+    let mut elements = Vec::new();
+    while !exhausted() && u.arbitrary()? {
+        elements.push(element(u)?);
+    }
+    Ok(elements)
+}
+"#;
+
+const FLOAT_HELPER: &str = r#"
+/// Finite value as NaN and infinity have no JSON representation.
+fn float(u: &mut Unstructured<'_>) -> Result<f32> {
+    let value: f32 = u.arbitrary()?;
+    Ok(if value.is_finite() { value } else { 0.0 })
+}
+"#;
+
+const DOUBLE_HELPER: &str = r#"
+/// Finite value as NaN and infinity have no JSON representation.
+fn double(u: &mut Unstructured<'_>) -> Result<f64> {
+    let value: f64 = u.arbitrary()?;
+    Ok(if value.is_finite() { value } else { 0.0 })
+}
+"#;
+
+const DATE_TIME_HELPER: &str = r#"
+/// Whole second UTC timestamp that is valid and formatted alike by all `DateTime` representations.
+fn date_time(u: &mut Unstructured<'_>) -> Result<super::date_time::DateTime> {
+    let text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        u.int_in_range(1970..=2099)?,
+        u.int_in_range(1..=12)?,
+        u.int_in_range(1..=28)?,
+        u.int_in_range(0..=23)?,
+        u.int_in_range(0..=59)?,
+        u.int_in_range(0..=59)?,
+    );
+    super::date_time::parse(&text).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::printer::StringPrinter;
+    use std::cell::RefCell;
+
+    fn field_value(vim_type: DataType, optional: bool) -> (String, Vec<&'static str>) {
+        let device = Struct {
+            name: "VirtualDevice".to_string(),
+            description: None,
+            fields: Default::default(),
+            parent: None,
+            discriminator_value: None,
+            children: vec![],
+            last_child: "VirtualDevice".to_string(),
+            emit_mode: EmitMode::Emit,
+        };
+        let model = Model {
+            enums: Default::default(),
+            structs: [("VirtualDevice".to_string(), RefCell::new(device))].into_iter().collect(),
+            request_types: Default::default(),
+            any_value_types: Default::default(),
+            managed_objects: Default::default(),
+            feature_groups: Default::default(),
+        };
+        let mut printer = StringPrinter::new(None, None);
+        let mut emitter = ArbitraryEmitter { vim_model: &model, printer: &mut printer, tdf: TypeDefResolver::new(&model), helpers: BTreeSet::new() };
+        let value = emitter.field_value(&vim_type, optional, false).unwrap();
+        (value, emitter.helpers.into_iter().collect())
+    }
+
+    #[test]
+    fn test_field_value() {
+        assert_eq!(field_value(DataType::Int32, true), ("u.arbitrary()?".to_string(), vec![]));
+        assert_eq!(field_value(DataType::Double, false), ("double(u)?".to_string(), vec!["double"]));
+        assert_eq!(field_value(DataType::DateTime, true), ("option(u, date_time)?".to_string(), vec!["date_time", "option"]));
+        let floats = DataType::Array(Box::new(DataType::Float));
+        assert_eq!(field_value(floats.clone(), false), ("array(u, float)?".to_string(), vec!["array", "float"]));
+        assert_eq!(field_value(floats, true), ("option(u, |u| array(u, float))?".to_string(), vec!["array", "float", "option"]));
+    }
+
+    #[test]
+    fn test_nested_field_value() {
+        let device = DataType::Reference("VirtualDevice".to_string());
+        assert_eq!(field_value(device.clone(), false), ("u.arbitrary()?".to_string(), vec![]));
+        assert_eq!(field_value(device.clone(), true), ("option(u, |u| u.arbitrary())?".to_string(), vec!["option"]));
+        let devices = DataType::Array(Box::new(device));
+        assert_eq!(field_value(devices.clone(), false), ("array(u, |u| u.arbitrary())?".to_string(), vec!["array"]));
+        assert_eq!(field_value(devices, true), ("option(u, |u| array(u, |u| u.arbitrary()))?".to_string(), vec!["array", "option"]));
+    }
+}
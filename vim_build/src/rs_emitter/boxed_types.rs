@@ -1,8 +1,8 @@
 use crate::printer::Printer;
-use crate::rs_emitter::common::emit_description;
+use crate::rs_emitter::common::{emit_cfg, emit_description};
 use crate::rs_emitter::Result;
-use crate::rs_emitter::{to_type_name, TypeDefResolver};
-use crate::vim_model::{DataType, Model};
+use crate::rs_emitter::{serialize_adapter, to_type_name, TypeDefResolver};
+use crate::vim_model::Model;
 
 pub struct BoxedTypesEmitter<'a> {
     vim_model: &'a Model,
@@ -29,6 +29,8 @@ impl<'a> BoxedTypesEmitter<'a> {
     }
     fn emit_enum(&mut self) -> Result<()> {
         self.printer.println("#[derive(Debug)]")?;
+        self.printer.println(r#"#[cfg_attr(feature = "clone", derive(Clone))]"#)?;
+        self.printer.println(r#"#[cfg_attr(feature = "partial-eq", derive(PartialEq))]"#)?;
         self.printer.println("pub enum ValueElements {")?;
         self.printer.indent();
         for (_, box_type) in &self.vim_model.any_value_types {
@@ -39,6 +41,7 @@ impl<'a> BoxedTypesEmitter<'a> {
             }?;
             let type_name = to_type_name(&box_type.name);
             let rust_type = self.tdf.to_rust_field_type(&box_type.property_type)?;
+            emit_cfg(self.printer, self.vim_model.data_type_cfg_attribute(&box_type.property_type))?;
             self.printer
                 .println(&format!("{type_name}({rust_type}),"))?;
         }
@@ -51,7 +54,7 @@ impl<'a> BoxedTypesEmitter<'a> {
         self.printer.println("use serde::de;")?;
         self.printer.println("use serde::ser::SerializeStruct;")?;
         self.printer
-            .println("use super::deserialize::get_value_deserializer;")?;
+            .println("use super::deserialize::ValueSeed;")?;
         self.printer.println("use super::vim_any::VimAny;")?;
         self.printer.println("use super::structs::*;")?;
         self.printer.newline()?;
@@ -78,16 +81,14 @@ impl<'a> BoxedTypesEmitter<'a> {
                 .discriminator_value
                 .as_ref()
                 .unwrap_or(&box_type.name);
+            emit_cfg(self.printer, self.vim_model.data_type_cfg_attribute(&box_type.property_type))?;
             self.printer
                 .println(&format!("ValueElements::{type_name}(value) => {{"))?;
             self.printer.indent();
             self.printer.println(&format!(
                 "state.serialize_field(\"_typeName\", \"{ser_name}\")?;"
             ))?;
-            let value = match box_type.property_type {
-                DataType::Binary => "&crate::core::helpers::SerializeBinary { value }",
-                _ => "value",
-            };
+            let value = serialize_adapter(&box_type.property_type, "value", self.tdf.root_package()).unwrap_or_else(|| "value".to_string());
             self.printer
                 .println(&format!("state.serialize_field(\"_value\", {value})?;"))?;
             self.printer.dedent();
@@ -135,13 +136,14 @@ impl<'a> BoxedTypesEmitter<'a> {
         self.printer.println("fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {")?;
         self.printer.indent();
         self.printer
-            .println("let mut type_name: Option<String> = None;")?;
+            .println("let mut type_name: Option<super::map_access::FieldKey> = None;")?;
+        self.printer.println("let mut value: Option<ValueElements> = None;")?;
+        self.printer.println("// A value preceding the discriminator is buffered")?;
+        self.printer.println("let mut buffered: Option<serde_json::Value> = None;")?;
         self.printer
-            .println("let mut value: Option<&serde_json::value::RawValue> = None;")?;
-        self.printer
-            .println("while let Some(key) = map.next_key::<String>()? {")?;
+            .println("while let Some(key) = map.next_key::<super::map_access::FieldKey>()? {")?;
         self.printer.indent();
-        self.printer.println("match key.as_str() {")?;
+        self.printer.println("match &*key {")?;
         self.printer.indent();
         self.printer.println("\"_typeName\" => {")?;
         self.printer.indent();
@@ -157,19 +159,24 @@ impl<'a> BoxedTypesEmitter<'a> {
         self.printer.println("}")?;
         self.printer.println("\"_value\" => {")?;
         self.printer.indent();
-        self.printer.println("if value.is_some() {")?;
+        self.printer.println("if value.is_some() || buffered.is_some() {")?;
         self.printer.indent();
         self.printer
             .println("return Err(de::Error::duplicate_field(\"_value\"));")?;
         self.printer.dedent();
         self.printer.println("}")?;
-        self.printer.println("value = Some(map.next_value()?);")?;
+        self.printer.println("match &type_name {")?;
+        self.printer.indent();
+        self.printer.println("Some(type_name) => value = Some(map.next_value_seed(ValueSeed(type_name))?),")?;
+        self.printer.println("None => buffered = Some(map.next_value()?),")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
         self.printer.dedent();
         self.printer.println("}")?;
         self.printer.println("_ => {")?;
         self.printer.indent();
         self.printer
-            .println("let _: serde_json::Value = map.next_value()?;")?;
+            .println("map.next_value::<de::IgnoredAny>()?;")?;
         self.printer.dedent();
         self.printer.println("}")?;
         self.printer.dedent();
@@ -179,17 +186,13 @@ impl<'a> BoxedTypesEmitter<'a> {
         self.printer.println(
             "let type_name = type_name.ok_or(de::Error::missing_field(\"_typeName\"))?;",
         )?;
-        self.printer
-            .println("let value = value.ok_or(de::Error::missing_field(\"_value\"))?;")?;
-        self.printer
-            .println("let Some(dsfunc) = get_value_deserializer(type_name.as_str()) else {")?;
+        self.printer.println("match (value, buffered) {")?;
         self.printer.indent();
-        self.printer
-            .println("return Err(de::Error::custom(format!(\"Unknown type: {}\", type_name)));")?;
+        self.printer.println("(Some(value), _) => Ok(value),")?;
+        self.printer.println("(None, Some(buffered)) => de::DeserializeSeed::deserialize(ValueSeed(&type_name), buffered).map_err(de::Error::custom),")?;
+        self.printer.println("(None, None) => Err(de::Error::missing_field(\"_value\")),")?;
         self.printer.dedent();
-        self.printer.println("};")?;
-        self.printer
-            .println("dsfunc(value).map_err(de::Error::custom)")?;
+        self.printer.println("}")?;
         self.printer.dedent();
         self.printer.println("}")?;
         self.printer.dedent();
@@ -9,3 +9,51 @@ pub fn emit_description(printer: &mut dyn Printer, doc_string: &Option<String>)
     }
     Ok(())
 }
+
+/// Print the conditional compilation attribute of an item unless the item is always compiled.
+pub fn emit_cfg(printer: &mut dyn Printer, cfg_attribute: Option<String>) -> Result<()> {
+    if let Some(cfg_attribute) = cfg_attribute {
+        printer.println(&cfg_attribute)?;
+    }
+    Ok(())
+}
+
+/// Print the derive attribute of the configured extra derives. Derives the item already has are
+/// skipped.
+pub fn emit_extra_derives(printer: &mut dyn Printer, derives: &[String], present: &[&str]) -> Result<()> {
+    let derives: Vec<&str> = derives
+        .iter()
+        .map(String::as_str)
+        .filter(|derive| !present.contains(derive))
+        .collect();
+    if !derives.is_empty() {
+        printer.println(&format!("#[derive({})]", derives.join(", ")))?;
+    }
+    Ok(())
+}
+
+/// First sentence of the first paragraph of a description on a single line.
+pub fn doc_summary(doc_string: &Option<String>) -> String {
+    let Some(doc) = doc_string else {
+        return String::new();
+    };
+    let paragraph = doc.trim().split("\n\n").next().unwrap_or_default();
+    let summary = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+    match summary.find(". ") {
+        Some(end) => summary[..=end].to_string(),
+        None => summary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_summary() {
+        let doc = Some("The VirtualDisk data object type contains information\nabout a disk. It is a device.\n\nSee also VirtualDevice.".to_string());
+        assert_eq!(doc_summary(&doc), "The VirtualDisk data object type contains information about a disk.");
+        assert_eq!(doc_summary(&Some("Key of the device".to_string())), "Key of the device");
+        assert_eq!(doc_summary(&None), "");
+    }
+}
@@ -5,7 +5,8 @@ use indexmap::IndexMap;
 use crate::vim_model::{BoxType, EmitMode};
 use crate::{printer::Printer, vim_model::Model};
 
-use super::{to_type_name, TypeDefResolver};
+use super::common::emit_cfg;
+use super::{deserialize_adapter, to_type_name, TypeDefResolver};
 
 use super::errors::{Error, Result};
 
@@ -117,65 +118,56 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
     }
 
     fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
-        let mut type_name = None;
-        let mut map_data: Vec<(String, &serde_json::value::RawValue)> = Vec::new();
-        while let Some(key) = map.next_key::<String>()? {
-            if key == "_typeName" {
-                if type_name.is_none() {
-                    let value: String = map.next_value()?;
-                    type_name = Some(value);
-                } // ignore subsequent _typeName fields
-                if map_data.is_empty() {
-                    break
-                };
-            } else {
-                let value: &serde_json::value::RawValue = map.next_value()?;
-                map_data.push((key, value));
-            }
-        }
-        let Some(type_name) = type_name else {
+        // vCenter sends the discriminator first. Entries preceding it are buffered as JSON values
+        // and replayed to the type specific deserializer.
+        let mut prefix = MapPrefix::new();
+        let type_name = loop {
+            let Some(key) = map.next_key::<FieldKey>()? else {
                 return Err(de::Error::missing_field("_typeName"));
-        };
-        if map_data.is_empty() { // Optimize the case when the first element is the discriminator
-            // Attempt to deserialize object from type_name and map
-            if let Some(dsfunc) = get_object_deserializer(&type_name) {
-                let ds = de::value::MapAccessDeserializer::new(map);
-                return dsfunc(ds).map_err(de::Error::custom);
-            } else {
-                let Some(dsfunc) = get_value_deserializer(&type_name) else {
-                    return Err(de::Error::custom(format!("Unknown variant: {}", type_name)));
-                };
-                let Some(key) = map.next_key::<String>()? else {
-                    return Err(de::Error::custom("Missing key"));
-                };
-                if key == "_value" {
-                    let v: &serde_json::value::RawValue = map.next_value()?;
-                    return dsfunc(v).map_err(de::Error::custom).map(VimAny::Value);
-                }
-                return Err(de::Error::custom(format!("Expected key '_value' and found {}", key)));
+            };
+            if &*key == "_typeName" {
+                break map.next_value::<FieldKey>()?;
             }
+            prefix.push((key.0, map.next_value()?));
         };
-
-        // We have buffered all keys try to make sense of it
-        // Process value elements
         if let Some(dsfunc) = get_object_deserializer(&type_name) {
-            let map = de::value::MapDeserializer::new(map_data.into_iter());
-            let ds = de::value::MapAccessDeserializer::new(map);
-            return dsfunc(ds).map_err(de::Error::custom);
+            let ds = de::value::MapAccessDeserializer::new(PrefixedMapAccess::new(prefix, map));
+            return dsfunc(ds);
         }
 
         // Process value elements
-        let Some(dsfunc) = get_value_deserializer(&type_name) else {
-            return Err(de::Error::custom(format!("Unknown variant: {}", type_name)));
+        let Some(dsfunc) = get_value_deserializer::<serde_json::Value>(&type_name) else {
+            return Err(de::Error::custom(format!("Unknown variant: {}", &*type_name)));
         };
-        if map_data.len() == 1 && map_data[0].0 == "_value" {
-            let v: &serde_json::value::RawValue = map_data
-                .get(0)
-                .ok_or_else(|| de::Error::missing_field("_value"))?
-                .1;
-            return dsfunc(v).map_err(de::Error::custom).map(VimAny::Value);
+        let mut value = match prefix.into_iter().find(|(key, _)| key == "_value") {
+            Some((_, value)) => Some(dsfunc(value).map_err(de::Error::custom)?),
+            None => None,
+        };
+        while let Some(key) = map.next_key::<FieldKey>()? {
+            if &*key == "_value" && value.is_none() {
+                value = Some(map.next_value_seed(ValueSeed(&type_name))?);
+            } else {
+                map.next_value::<de::IgnoredAny>()?;
+            }
         }
-        Err(de::Error::custom("Invalid format for boxed value element."))
+        let Some(value) = value else {
+            return Err(de::Error::missing_field("_value"));
+        };
+        Ok(VimAny::Value(value))
+    }
+}
+
+/// Decodes the `_value` of a boxed value of the given `_typeName` directly from the input.
+pub(crate) struct ValueSeed<'a>(pub &'a str);
+
+impl<'de> de::DeserializeSeed<'de> for ValueSeed<'_> {
+    type Value = ValueElements;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let Some(dsfunc) = get_value_deserializer::<D>(self.0) else {
+            return Err(de::Error::custom(format!("Unknown type: {}", self.0)));
+        };
+        dsfunc(deserializer)
     }
 }"#,
         )?;
@@ -186,7 +178,7 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
     fn render_match_tree(&mut self, group_data: Vec<GroupInfo>) -> Result<()> {
         match self.deserialize_renderer {
             ItemRenderer::Object => self.printer.println("fn get_object_deserializer<'de, A: de::MapAccess<'de>>(type_name: &str) -> Option<fn(de::value::MapAccessDeserializer<A>) -> Result<VimAny, A::Error>> {")?,
-            ItemRenderer::Value => self.printer.println("pub(crate) fn get_value_deserializer(type_name: &str) -> Option<fn(&serde_json::value::RawValue) -> Result<ValueElements, serde_json::Error>> {")?,
+            ItemRenderer::Value => self.printer.println("pub(crate) fn get_value_deserializer<'de, D: Deserializer<'de>>(type_name: &str) -> Option<fn(D) -> Result<ValueElements, D::Error>> {")?,
         }
         self.printer.indent();
 
@@ -225,7 +217,7 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
             };
             match self.deserialize_renderer {
                 ItemRenderer::Object => self.printer.println(&format!("fn get_object_deserializer_{}<'de, A: de::MapAccess<'de>>(type_name: &str) -> Option<fn(de::value::MapAccessDeserializer<A>) -> Result<VimAny, A::Error>> {{", group.length))?,
-                ItemRenderer::Value => self.printer.println(&format!("fn get_value_deserializer_{}(type_name: &str) -> Option<fn(&serde_json::value::RawValue) -> Result<ValueElements, serde_json::Error>> {{", group.length))?,
+                ItemRenderer::Value => self.printer.println(&format!("fn get_value_deserializer_{}<'de, D: Deserializer<'de>>(type_name: &str) -> Option<fn(D) -> Result<ValueElements, D::Error>> {{", group.length))?,
             }
             self.printer.indent();
             if group.filter_len > 0 {
@@ -250,12 +242,19 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
         let enum_name = to_type_name(&box_type.name);
         let value_type = self.tdf.to_rust_field_type(&box_type.property_type)?;
 
-        self.printer.println("Some(|raw| {")?;
+        self.printer.println("Some(|ds| {")?;
         self.printer.indent();
-        self.printer.println(&format!(
-            "let value: {} = serde_json::from_str(raw.get())?;",
-            value_type
-        ))?;
+        if let Some((adapter, value)) = deserialize_adapter(&box_type.property_type, self.tdf.root_package()) {
+            self.printer.println(&format!(
+                "let value: {} = <{} as de::Deserialize>::deserialize(ds)?.{};",
+                value_type, adapter, value
+            ))?;
+        } else {
+            self.printer.println(&format!(
+                "let value: {} = de::Deserialize::deserialize(ds)?;",
+                value_type
+            ))?;
+        }
         self.printer
             .println(&format!("Ok(ValueElements::{}(value))", enum_name))?;
         self.printer.dedent();
@@ -290,13 +289,25 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
         Ok(())
     }
 
+    /// Conditional compilation attribute of the deserializer of a type or value type.
+    fn cfg_attribute(&self, name: &str) -> Option<String> {
+        match self.deserialize_renderer {
+            ItemRenderer::Object => self.vim_model.cfg_attribute(name),
+            ItemRenderer::Value => self
+                .any_value_types
+                .get(name)
+                .and_then(|box_type| self.vim_model.data_type_cfg_attribute(&box_type.property_type)),
+        }
+    }
+
     fn process_simple_group(&mut self, names: &[String]) -> Result<()> {
         if names.is_empty() {
             return Err(Error::InternalError(
                 "No names provided to process_simple_group".into(),
             ));
         }
-        if names.len() == 1 {
+        // Conditionally compiled types need a match arm to carry the attribute
+        if names.len() == 1 && self.cfg_attribute(&names[0]).is_none() {
             self.printer
                 .println(&format!("if type_name == \"{}\" {{", names[0]))?;
             self.printer.indent();
@@ -312,6 +323,8 @@ impl<'de> de::Visitor<'de> for VimAnyVisitor {
         self.printer.println("match type_name {")?;
         self.printer.indent();
         for name in names {
+            let cfg = self.cfg_attribute(name);
+            emit_cfg(self.printer, cfg)?;
             self.printer.println(&format!("\"{}\" => {{", name))?;
             self.printer.indent();
             match self.deserialize_renderer {
@@ -1,10 +1,11 @@
 use crate::{printer::Printer, vim_model::Model};
 
-use super::common::emit_description;
+use super::common::{emit_description, emit_extra_derives};
 use super::errors::Result;
 use super::{to_enum_variant, to_type_name};
 
-pub fn emit_enums(vim_model: &Model, printer: &mut dyn Printer) -> Result<()> {
+/// Emit the enum types with the `derives` in addition to the standard ones.
+pub fn emit_enums(vim_model: &Model, derives: &[String], printer: &mut dyn Printer) -> Result<()> {
     for (_, vim_enum) in &vim_model.enums {
         {
             let doc_string: &Option<String> = &vim_enum.description;
@@ -15,8 +16,11 @@ pub fn emit_enums(vim_model: &Model, printer: &mut dyn Printer) -> Result<()> {
         if vim_enum.name == "MoTypes_enum" {
             // Add clone and partial eq for MoTypes_enum
             printer.println("#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize, strum_macros::IntoStaticStr)]")?;
+            emit_extra_derives(printer, derives, &["Clone", "Debug", "PartialEq", "Eq", "Hash"])?;
         } else {
             printer.println("#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, strum_macros::IntoStaticStr)]")?;
+            printer.println(r#"#[cfg_attr(feature = "partial-eq", derive(PartialEq))]"#)?;
+            emit_extra_derives(printer, derives, &["Clone", "Debug", "PartialEq"])?;
         }
         printer.println(&format!("pub enum {} {{", enum_name))?;
         printer.indent();
@@ -0,0 +1,313 @@
+use serde_json::{json, Map, Value};
+
+use crate::rs_emitter::errors::{Error, Result};
+use crate::rs_emitter::structs::ANY;
+use crate::vim_model::{DataType, EmitMode, Model, Struct};
+
+use super::common::doc_summary;
+
+/// The JSON Schema dialect of the generated document.
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Suffix of the definitions accepting a struct type or any of its descendants.
+pub const TRAIT_SUFFIX: &str = "Trait";
+
+/// Generates a JSON Schema (draft 2020-12) document describing the VI/JSON wire format of the
+/// data types backing `types::json_schema`.
+///
+/// Each struct `S` has a definition `#/$defs/S` with its own and inherited properties. Structs
+/// with descendants also have `#/$defs/STrait` requiring the `_typeName` discriminator and
+/// selecting the definition of the named type. Fields typed as such a struct reference the trait
+/// definition as they accept any descendant. Enums are string definitions listing their values
+/// and `#/$defs/Any` dispatches to every struct and boxed value type. Pruned types accept any
+/// `_typeName` of their subtree and properties not known to the model.
+pub fn generate_json_schema(vim_model: &Model) -> Result<Value> {
+    let mut defs = Map::new();
+    defs.insert(ANY.to_string(), any_schema(vim_model)?);
+    for struct_type in vim_model.structs.values() {
+        let struct_type = struct_type.borrow();
+        if struct_type.name == ANY || struct_type.emit_mode.is_skip() {
+            continue;
+        }
+        defs.insert(struct_type.name.clone(), struct_schema(vim_model, &struct_type)?);
+        if is_trait(&struct_type) {
+            let subtree = subtree(vim_model, &struct_type.name)?;
+            defs.insert(format!("{}{TRAIT_SUFFIX}", struct_type.name), dispatch_schema(&struct_type.name, &subtree));
+        }
+    }
+    for box_type in vim_model.any_value_types.values() {
+        let mut schema = object_schema(&box_type.description);
+        let discriminator = box_type.discriminator_value.as_ref().unwrap_or(&box_type.name);
+        schema["properties"] = json!({
+            "_typeName": {"const": discriminator},
+            "_value": data_type_schema(vim_model, &box_type.property_type)?,
+        });
+        schema["required"] = json!(["_typeName", "_value"]);
+        schema["additionalProperties"] = json!(false);
+        defs.insert(box_type.name.clone(), schema);
+    }
+    for vim_enum in vim_model.enums.values() {
+        let mut schema = Map::new();
+        let summary = doc_summary(&vim_enum.description);
+        if !summary.is_empty() {
+            schema.insert("description".to_string(), json!(summary));
+        }
+        schema.insert("type".to_string(), json!("string"));
+        schema.insert("enum".to_string(), json!(vim_enum.variants));
+        defs.insert(vim_enum.name.clone(), Value::Object(schema));
+    }
+    Ok(json!({
+        "$schema": SCHEMA_DIALECT,
+        "$comment": "Generated by vim_build. VI/JSON data types of the vSphere Web Services API.",
+        "$defs": defs,
+    }))
+}
+
+/// Only emitted structs with descendants are represented as trait objects. Pruned types keep the
+/// members of their subtree in a single struct.
+fn is_trait(struct_type: &Struct) -> bool {
+    struct_type.emit_mode == EmitMode::Emit && struct_type.has_children()
+}
+
+/// The `_typeName` values of a struct and its descendants with the definition validating each.
+fn subtree(vim_model: &Model, name: &String) -> Result<Vec<(String, String)>> {
+    Ok(vim_model
+        .children(name)?
+        .into_iter()
+        .map(|struct_type| {
+            let struct_type = struct_type.borrow();
+            let definition = match &struct_type.emit_mode {
+                EmitMode::Skip(pruned) => pruned.clone(),
+                _ => struct_type.name.clone(),
+            };
+            (struct_type.discriminator(), definition)
+        })
+        .collect())
+}
+
+/// Require one of the `_typeName` values in `subtree` and validate the object with the definition
+/// of the type it names.
+fn dispatch_schema(title: &str, subtree: &[(String, String)]) -> Value {
+    let names: Vec<&String> = subtree.iter().map(|(name, _)| name).collect();
+    let cases: Vec<Value> = subtree
+        .iter()
+        .map(|(name, definition)| {
+            json!({
+                "if": {"properties": {"_typeName": {"const": name}}, "required": ["_typeName"]},
+                "then": {"$ref": format!("#/$defs/{definition}")},
+            })
+        })
+        .collect();
+    json!({
+        "title": title,
+        "type": "object",
+        "properties": {"_typeName": {"enum": names}},
+        "required": ["_typeName"],
+        "allOf": cases,
+    })
+}
+
+fn any_schema(vim_model: &Model) -> Result<Value> {
+    let mut subtree = vec![];
+    for struct_type in vim_model.structs.values() {
+        let struct_type = struct_type.borrow();
+        if struct_type.name != ANY && struct_type.parent.as_deref() == Some(ANY) {
+            subtree.extend(self::subtree(vim_model, &struct_type.name)?);
+        }
+    }
+    for box_type in vim_model.any_value_types.values() {
+        let discriminator = box_type.discriminator_value.as_ref().unwrap_or(&box_type.name);
+        subtree.push((discriminator.clone(), box_type.name.clone()));
+    }
+    Ok(dispatch_schema(ANY, &subtree))
+}
+
+fn struct_schema(vim_model: &Model, struct_type: &Struct) -> Result<Value> {
+    let mut schema = object_schema(&struct_type.description);
+    let pruned = struct_type.emit_mode == EmitMode::Prune;
+    let mut properties = Map::new();
+    let type_name = if pruned {
+        // Pruned types are deserialized from any of their descendants
+        json!({"type": "string"})
+    } else {
+        json!({"const": struct_type.discriminator()})
+    };
+    properties.insert("_typeName".to_string(), type_name);
+    let mut required = vec![];
+    for ancestor in vim_model.inheritance_chain(&struct_type.name)? {
+        for field in ancestor.borrow().fields.values() {
+            let mut field_schema = data_type_schema(vim_model, &field.vim_type)?;
+            let summary = doc_summary(&field.description);
+            if !summary.is_empty() {
+                field_schema["description"] = json!(summary);
+            }
+            properties.insert(field.name.clone(), field_schema);
+            if !field.optional {
+                required.push(field.name.clone());
+            }
+        }
+    }
+    schema["title"] = json!(struct_type.name);
+    schema["properties"] = Value::Object(properties);
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    // Pruned types keep the properties of their descendants in their extra fields
+    schema["additionalProperties"] = json!(pruned);
+    Ok(schema)
+}
+
+fn object_schema(description: &Option<String>) -> Value {
+    let mut schema = Map::new();
+    let summary = doc_summary(description);
+    if !summary.is_empty() {
+        schema.insert("description".to_string(), json!(summary));
+    }
+    schema.insert("type".to_string(), json!("object"));
+    Value::Object(schema)
+}
+
+fn data_type_schema(vim_model: &Model, vim_type: &DataType) -> Result<Value> {
+    Ok(match vim_type {
+        DataType::Boolean => json!({"type": "boolean"}),
+        DataType::String => json!({"type": "string"}),
+        DataType::Int8 => json!({"type": "integer", "minimum": i8::MIN, "maximum": i8::MAX}),
+        DataType::Int16 => json!({"type": "integer", "minimum": i16::MIN, "maximum": i16::MAX}),
+        DataType::Int32 => json!({"type": "integer", "minimum": i32::MIN, "maximum": i32::MAX}),
+        DataType::Int64 => json!({"type": "integer"}),
+        DataType::Float | DataType::Double => json!({"type": "number"}),
+        DataType::DateTime => json!({"type": "string", "format": "date-time"}),
+        DataType::Binary => json!({"type": "string", "contentEncoding": "base64"}),
+        DataType::Array(item_type) => json!({"type": "array", "items": data_type_schema(vim_model, item_type)?}),
+        DataType::Reference(name) if name == ANY || vim_model.enums.contains_key(name) => reference(name),
+        DataType::Reference(name) => {
+            let struct_type = vim_model
+                .structs
+                .get(name)
+                .ok_or_else(|| Error::TypeNotFound(name.to_string()))?
+                .borrow();
+            match &struct_type.emit_mode {
+                EmitMode::Skip(pruned) => reference(pruned),
+                _ if is_trait(&struct_type) => reference(&format!("{name}{TRAIT_SUFFIX}")),
+                _ => reference(name),
+            }
+        }
+    })
+}
+
+fn reference(definition: &str) -> Value {
+    json!({"$ref": format!("#/$defs/{definition}")})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vim_model::{BoxType, Enum, Field};
+    use indexmap::IndexMap;
+    use std::cell::RefCell;
+
+    fn data_type(name: &str, parent: &str, fields: &[(&str, DataType, bool)], children: &[&str], last_child: &str) -> (String, RefCell<Struct>) {
+        let fields: IndexMap<String, Field> = fields
+            .iter()
+            .map(|(field_name, vim_type, optional)| {
+                (field_name.to_string(), Field {
+                    name: field_name.to_string(),
+                    description: None,
+                    optional: *optional,
+                    vim_type: vim_type.clone(),
+                    require_box: false,
+                })
+            })
+            .collect();
+        (name.to_string(), RefCell::new(Struct {
+            name: name.to_string(),
+            description: Some(format!("The {name} type. More text.")),
+            fields,
+            parent: (!parent.is_empty()).then(|| parent.to_string()),
+            discriminator_value: None,
+            children: children.iter().map(|child| child.to_string()).collect(),
+            last_child: last_child.to_string(),
+            emit_mode: EmitMode::Emit,
+        }))
+    }
+
+    fn test_model() -> Model {
+        let reference = |name: &str| DataType::Reference(name.to_string());
+        let structs = [
+            data_type("Any", "", &[], &["DataObject"], "VirtualHardware"),
+            data_type("DataObject", "Any", &[], &["VirtualDevice", "VirtualHardware"], "VirtualHardware"),
+            data_type("VirtualDevice", "DataObject", &[("key", DataType::Int32, false)], &["VirtualDisk"], "VirtualDisk"),
+            data_type("VirtualDisk", "VirtualDevice", &[("mode", reference("DiskMode_enum"), true)], &[], "VirtualDisk"),
+            data_type("VirtualHardware", "DataObject", &[("device", DataType::Array(Box::new(reference("VirtualDevice"))), true)], &[], "VirtualHardware"),
+        ];
+        Model {
+            enums: [("DiskMode_enum".to_string(), Enum {
+                name: "DiskMode_enum".to_string(),
+                description: None,
+                variants: vec!["persistent".to_string(), "independent".to_string()],
+                discriminator_value: None,
+            })]
+            .into_iter()
+            .collect(),
+            structs: structs.into_iter().collect(),
+            request_types: IndexMap::new(),
+            any_value_types: [("ArrayOfInt".to_string(), BoxType {
+                name: "ArrayOfInt".to_string(),
+                description: None,
+                property_type: DataType::Array(Box::new(DataType::Int32)),
+                discriminator_value: None,
+            })]
+            .into_iter()
+            .collect(),
+            managed_objects: IndexMap::new(),
+            feature_groups: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_generate_json_schema() {
+        let schema = generate_json_schema(&test_model()).unwrap();
+        assert_eq!(schema["$schema"], SCHEMA_DIALECT);
+        let defs = &schema["$defs"];
+        assert_eq!(defs["VirtualDisk"], json!({
+            "description": "The VirtualDisk type.",
+            "type": "object",
+            "title": "VirtualDisk",
+            "properties": {
+                "_typeName": {"const": "VirtualDisk"},
+                "key": {"type": "integer", "minimum": i32::MIN, "maximum": i32::MAX},
+                "mode": {"$ref": "#/$defs/DiskMode_enum"},
+            },
+            "required": ["key"],
+            "additionalProperties": false,
+        }));
+        assert_eq!(defs["VirtualHardware"]["properties"]["device"], json!({
+            "type": "array",
+            "items": {"$ref": "#/$defs/VirtualDeviceTrait"},
+        }));
+        assert_eq!(defs["VirtualDeviceTrait"]["properties"]["_typeName"], json!({"enum": ["VirtualDevice", "VirtualDisk"]}));
+        assert_eq!(defs["VirtualDeviceTrait"]["allOf"][1], json!({
+            "if": {"properties": {"_typeName": {"const": "VirtualDisk"}}, "required": ["_typeName"]},
+            "then": {"$ref": "#/$defs/VirtualDisk"},
+        }));
+        assert!(defs.get("VirtualDiskTrait").is_none());
+        assert_eq!(defs["Any"]["properties"]["_typeName"]["enum"].as_array().unwrap().len(), 5);
+        assert_eq!(defs["ArrayOfInt"]["properties"]["_value"]["items"]["maximum"], i32::MAX);
+        assert_eq!(defs["DiskMode_enum"], json!({"type": "string", "enum": ["persistent", "independent"]}));
+    }
+
+    #[test]
+    fn test_pruned_type_schema() {
+        let model = test_model();
+        model.structs["VirtualDevice"].borrow_mut().emit_mode = EmitMode::Prune;
+        model.structs["VirtualDisk"].borrow_mut().emit_mode = EmitMode::Skip("VirtualDevice".to_string());
+        let schema = generate_json_schema(&model).unwrap();
+        let defs = &schema["$defs"];
+        assert!(defs.get("VirtualDisk").is_none());
+        assert!(defs.get("VirtualDeviceTrait").is_none());
+        assert_eq!(defs["VirtualDevice"]["additionalProperties"], true);
+        assert_eq!(defs["VirtualDevice"]["properties"]["_typeName"], json!({"type": "string"}));
+        assert_eq!(defs["VirtualHardware"]["properties"]["device"]["items"], json!({"$ref": "#/$defs/VirtualDevice"}));
+        assert_eq!(defs["DataObjectTrait"]["allOf"][2]["then"], json!({"$ref": "#/$defs/VirtualDevice"}));
+    }
+}
@@ -1,14 +1,36 @@
 use super::errors::Result;
 use crate::printer::Printer;
 
-pub fn emit_library(modules: &Vec<String>, printer: &mut dyn Printer) -> Result<()> {
-    for module in modules {
+/// Emit the module declarations and re-exports of the managed objects. Modules with a feature name
+/// are compiled only with that feature enabled. The synchronous stubs are re-exported from the
+/// `blocking` module.
+pub fn emit_library(modules: &[(String, Option<String>)], printer: &mut dyn Printer) -> Result<()> {
+    for (module, feature) in modules {
+        emit_feature_cfg(feature, printer)?;
         printer.println(&format!("pub mod {};", module))?;
     }
     printer.println("")?;
     // Add public re-exports
-    for module in modules {
+    for (module, feature) in modules {
+        emit_feature_cfg(feature, printer)?;
         printer.println(&format!("pub use {}::*;", module))?;
     }
+    printer.println("")?;
+    printer.println(r#"#[cfg(feature = "blocking")]"#)?;
+    printer.println("pub mod blocking {")?;
+    printer.indent();
+    for (module, feature) in modules {
+        emit_feature_cfg(feature, printer)?;
+        printer.println(&format!("pub use super::{}::blocking::*;", module))?;
+    }
+    printer.dedent();
+    printer.println("}")?;
+    Ok(())
+}
+
+fn emit_feature_cfg(feature: &Option<String>, printer: &mut dyn Printer) -> Result<()> {
+    if let Some(feature) = feature {
+        printer.println(&format!(r#"#[cfg(feature = "{feature}")]"#))?;
+    }
     Ok(())
 }
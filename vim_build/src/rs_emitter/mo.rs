@@ -4,7 +4,7 @@ use std::collections::HashSet;
 
 use super::common::emit_description;
 use super::errors::{Error, Result};
-use super::TypeDefResolver;
+use super::{deserialize_adapter, TypeDefResolver};
 use super::{to_fn_name, to_type_name};
 use crate::printer::Printer;
 use crate::vim_model::{DataType, EmitMode};
@@ -26,12 +26,13 @@ impl<'a> ManagedObjectEmitter<'a> {
         mo: &'a ManagedObject,
         printer: &'a mut dyn Printer,
         vim_model: &'a Model,
+        types_path: String,
     ) -> ManagedObjectEmitter<'a> {
         ManagedObjectEmitter {
             mo,
             vim_model,
             printer,
-            tdf: TypeDefResolver::new_with_root_package(vim_model, "crate::types".to_string()),
+            tdf: TypeDefResolver::new_with_root_package(vim_model, types_path),
         }
     }
 
@@ -40,6 +41,7 @@ impl<'a> ManagedObjectEmitter<'a> {
         self.emit_mo_struct()?;
         self.emit_impl()?;
         self.emit_request_types()?;
+        self.emit_blocking()?;
         // self.emit_footer()?;
         Ok(())
     }
@@ -48,10 +50,14 @@ impl<'a> ManagedObjectEmitter<'a> {
         self.printer.println("use std::sync::Arc;")?;
         self.printer
             .println("use crate::core::client::{Client, Result};")?;
+        self.emit_type_imports()
+    }
+
+    fn emit_type_imports(&mut self) -> Result<()> {
         let imported_types = self.get_imported_types()?;
         for type_name in &imported_types {
             self.printer
-                .println(&format!("use crate::types::{type_name};"))?;
+                .println(&format!("use {}::{type_name};", self.tdf.root_package()))?;
         }
         Ok(())
     }
@@ -171,37 +177,7 @@ impl<'a> ManagedObjectEmitter<'a> {
         self.emit_docstring(method)?;
 
         let request_type = get_request_type(method, self.vim_model)?;
-        let method_name = to_fn_name(&method.name);
-        self.printer.print_indent()?;
-        self.printer
-            .print(&format!("pub async fn {}(&self", method_name))?;
-
-        if let Some(request_type) = request_type {
-            for (_, field) in &request_type.borrow().fields {
-                self.printer.print(&format!(
-                    ", {}: {}",
-                    field.rust_name(),
-                    self.tdf.to_rust_param_type(field, None)?
-                ))?;
-            }
-        }
-        match &method.output {
-            Some(output) => {
-                let res_type = self.tdf.to_rust_field_type(output)?;
-                if method.optional_response {
-                    self.printer
-                        .print(&format!(") -> Result<Option<{}>> {{", res_type))?;
-                } else {
-                    self.printer
-                        .print(&format!(") -> Result<{}> {{", res_type))?;
-                }
-            }
-            None => {
-                self.printer.print(") -> Result<()> {")?;
-            }
-        }
-        self.printer.indent();
-        self.printer.newline()?;
+        self.emit_signature(method, "pub async fn")?;
         if let Some(request_type) = request_type {
             self.printer.print_indent()?;
             self.printer.print(&format!(
@@ -236,8 +212,20 @@ impl<'a> ManagedObjectEmitter<'a> {
             }
         }
 
-        match &method.output {
-            Some(_) => {
+        // Values like binary and date time are decoded from their wire format through adapters
+        let adapter = method.output.as_ref().and_then(|output| deserialize_adapter(output, self.tdf.root_package()));
+        match (&method.output, adapter) {
+            (Some(_), Some((adapter, value))) => {
+                if method.optional_response {
+                    self.printer.println(&format!(
+                        "Ok(self.client.execute_option::<{adapter}>(req).await?.map(|res| res.{value}))"
+                    ))?;
+                } else {
+                    self.printer
+                        .println(&format!("Ok(self.client.execute::<{adapter}>(req).await?.{value})"))?;
+                }
+            }
+            (Some(_), None) => {
                 if method.optional_response {
                     self.printer
                         .println("self.client.execute_option(req).await")?;
@@ -246,7 +234,7 @@ impl<'a> ManagedObjectEmitter<'a> {
                         .println("self.client.execute(req).await")?;
                 }
             }
-            None => {
+            (None, _) => {
                 self.printer
                     .println("self.client.execute_void(req).await")?;
             }
@@ -256,6 +244,106 @@ impl<'a> ManagedObjectEmitter<'a> {
         Ok(())
     }
 
+    /// Print the method signature up to and including the opening brace of the body.
+    fn emit_signature(&mut self, method: &Method, fn_keyword: &str) -> Result<()> {
+        let request_type = get_request_type(method, self.vim_model)?;
+        let method_name = to_fn_name(&method.name);
+        self.printer.print_indent()?;
+        self.printer
+            .print(&format!("{} {}(&self", fn_keyword, method_name))?;
+
+        if let Some(request_type) = request_type {
+            for (_, field) in &request_type.borrow().fields {
+                self.printer.print(&format!(
+                    ", {}: {}",
+                    field.rust_name(),
+                    self.tdf.to_rust_param_type(field, None)?
+                ))?;
+            }
+        }
+        match &method.output {
+            Some(output) => {
+                let res_type = self.tdf.to_rust_field_type(output)?;
+                if method.optional_response {
+                    self.printer
+                        .print(&format!(") -> Result<Option<{}>> {{", res_type))?;
+                } else {
+                    self.printer
+                        .print(&format!(") -> Result<{}> {{", res_type))?;
+                }
+            }
+            None => {
+                self.printer.print(") -> Result<()> {")?;
+            }
+        }
+        self.printer.indent();
+        self.printer.newline()?;
+        Ok(())
+    }
+
+    /// Emit the synchronous stub of the `blocking` feature. It wraps the async stub and runs its
+    /// calls on the runtime of the blocking client.
+    fn emit_blocking(&mut self) -> Result<()> {
+        let struct_name = to_type_name(&self.mo.name);
+        self.printer.println(r#"#[cfg(feature = "blocking")]"#)?;
+        self.printer.println("pub mod blocking {")?;
+        self.printer.indent();
+        self.printer.println("use std::sync::Arc;")?;
+        self.printer.println("use crate::core::blocking::Client;")?;
+        self.printer.println("use crate::core::client::Result;")?;
+        self.emit_type_imports()?;
+        // The docs stay with the async stub to keep the modules small
+        self.printer.println(&format!("/// Blocking version of [`super::{}`].", struct_name))?;
+        self.printer.println(&format!("pub struct {} {{", struct_name))?;
+        self.printer.indent();
+        self.printer.println(&format!("inner: super::{},", struct_name))?;
+        self.printer.println("client: Arc<Client>,")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.println(&format!("impl {} {{", struct_name))?;
+        self.printer.indent();
+        self.printer
+            .println("pub fn new(client: Arc<Client>, mo_id: &str) -> Self {")?;
+        self.printer.indent();
+        self.printer.println("Self {")?;
+        self.printer.indent();
+        self.printer.println(&format!(
+            "inner: super::{}::new(client.inner().clone(), mo_id),",
+            struct_name
+        ))?;
+        self.printer.println("client,")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        for method in self.mo.methods.iter() {
+            self.printer.println(&format!(
+                "/// Blocking version of [`super::{}::{}`].",
+                struct_name,
+                to_fn_name(&method.name)
+            ))?;
+            self.emit_signature(method, "pub fn")?;
+            let mut args = Vec::new();
+            if let Some(request_type) = get_request_type(method, self.vim_model)? {
+                for (_, field) in &request_type.borrow().fields {
+                    args.push(field.rust_name());
+                }
+            }
+            self.printer.println(&format!(
+                "self.client.block_on(self.inner.{}({}))",
+                to_fn_name(&method.name),
+                args.join(", ")
+            ))?;
+            self.printer.dedent();
+            self.printer.println("}")?;
+        }
+        self.printer.dedent();
+        self.printer.println("}")?;
+        self.printer.dedent();
+        self.printer.println("}")?;
+        Ok(())
+    }
+
     fn emit_docstring(&mut self, method: &Method) -> Result<()> {
         emit_description(self.printer, &method.description)?;
         self.emit_param_docs(method)?;
@@ -363,6 +451,12 @@ impl<'a> ManagedObjectEmitter<'a> {
                 self.printer
                     .println(&format!(r#"#[serde(rename = "{}")]"#, field.name))?;
             }
+            if let Some(serializer) = param_serializer(&field.vim_type, field.optional) {
+                self.printer.println(&format!(
+                    r#"#[serde(serialize_with = "{}::date_time::{serializer}")]"#,
+                    self.tdf.root_package()
+                ))?;
+            }
             self.printer.println(&format!(
                 "{}: {},",
                 field_name,
@@ -392,6 +486,18 @@ impl<'a> ManagedObjectEmitter<'a> {
     }
 }
 
+/// Serializer of `date_time` for parameters whose wire format differs from the serde format of their
+/// borrowed Rust type.
+fn param_serializer(vim_type: &DataType, optional: bool) -> Option<&'static str> {
+    match (vim_type, optional) {
+        (DataType::DateTime, false) => Some("serialize_param"),
+        (DataType::DateTime, true) => Some("serialize_option_param"),
+        (DataType::Array(item_type), false) if **item_type == DataType::DateTime => Some("serialize_array_param"),
+        (DataType::Array(item_type), true) if **item_type == DataType::DateTime => Some("serialize_option_array_param"),
+        _ => None,
+    }
+}
+
 fn get_request_type<'a>(
     method: &Method,
     vim_model: &'a Model,
@@ -1,11 +1,14 @@
+pub mod arbitrary;
 pub(crate) mod boxed_types;
-mod common;
+pub mod common;
 pub mod deser;
 pub mod enums;
 pub mod errors;
+pub mod json_schema;
 pub mod library;
 mod mo;
 mod names;
+pub mod reflection;
 pub mod ser;
 pub mod struct_enum;
 pub(crate) mod structs;
@@ -28,6 +28,14 @@ pub fn getter_name(name: &str) -> String {
     format!("get_{}", name.to_case(Case::Snake))
 }
 
+pub fn getter_mut_name(name: &str) -> String {
+    format!("get_{}_mut", name.to_case(Case::Snake))
+}
+
+pub fn setter_name(name: &str) -> String {
+    format!("set_{}", name.to_case(Case::Snake))
+}
+
 pub fn any_into_name(name: &str) -> String {
     format!("any_into_{}", name.to_case(Case::Snake))
 }
@@ -64,6 +72,11 @@ impl TypeDefResolver<'_> {
         }
     }
 
+    /// Path of the data types module in the generated code.
+    pub fn root_package(&self) -> &str {
+        &self.root_package
+    }
+
     pub fn field_type(&self, field: &Field) -> Result<String> {
         let mut field_type = self.to_rust_field_type(&field.vim_type)?;
         if field.require_box {
@@ -86,7 +99,7 @@ impl TypeDefResolver<'_> {
     pub fn to_rust_param_type(&self, field: &Field, lifecycle: Option<String>) -> Result<String> {
         let mut decl = match &field.vim_type {
             DataType::String => ref_type_declaration("str", lifecycle.clone()),
-            DataType::DateTime => ref_type_declaration("str", lifecycle.clone()),
+            DataType::DateTime => ref_type_declaration(&self.date_time_ref_type(), lifecycle.clone()),
             DataType::Array(nested_type) => ref_type_declaration(
                 &format!("[{}]", self.to_rust_field_type(nested_type)?),
                 lifecycle.clone(),
@@ -102,6 +115,12 @@ impl TypeDefResolver<'_> {
         Ok(decl)
     }
 
+    /// Borrowed date and time type of getters and parameters. It is `str` with the default `String`
+    /// representation so that the signatures do not change with the `chrono` and `time` features.
+    pub fn date_time_ref_type(&self) -> String {
+        format!("{}::date_time::DateTimeRef", self.root_package)
+    }
+
     /// Convert a VimType to a Rust type declaration. Allowing to customize the wrapper of the top
     /// level reference types. By using `box_type_declaration` the top level structs are boxed. In
     /// case of method calls we want to use borrow semantics instead to avoid boxing. This change of
@@ -122,7 +141,7 @@ impl TypeDefResolver<'_> {
             DataType::Int64 => Ok("i64".to_string()),
             DataType::Float => Ok("f32".to_string()),
             DataType::Double => Ok("f64".to_string()),
-            DataType::DateTime => Ok("String".to_string()),
+            DataType::DateTime => Ok(format!("{}::date_time::DateTime", self.root_package)),
             DataType::Array(nested_type) => {
                 Ok(format!("Vec<{}>", self.to_rust_field_type(nested_type)?))
             }
@@ -0,0 +1,133 @@
+use crate::printer::Printer;
+use crate::rs_emitter::errors::{Error, Result};
+use crate::rs_emitter::structs::ANY;
+use crate::vim_model::{DataType, Field, Model, Struct};
+
+use super::common::{doc_summary, emit_cfg};
+use super::struct_enum::ordinal;
+
+/// Generates the static reflection tables backing `types::reflection`.
+///
+/// `TYPES` holds one `TypeInfo` per `StructType` value in the same order. With all managed object
+/// features enabled it can be indexed by the `StructType` discriminant. `TYPE_NAMES` maps the wire
+/// type names to `StructType` and is sorted by name for binary search.
+pub fn generate_type_tables(vim_model: &Model, printer: &mut dyn Printer) -> Result<()> {
+    printer.println("use super::reflection::{DataType, FieldInfo, TypeInfo};")?;
+    printer.println("use super::struct_enum::StructType;")?;
+    printer.newline()?;
+
+    let structs: Vec<_> = vim_model
+        .structs
+        .values()
+        .filter(|struct_type| struct_type.borrow().name != ANY)
+        .collect();
+
+    printer.println("pub(super) static TYPES: &[TypeInfo] = &[")?;
+    printer.indent();
+    for struct_type in &structs {
+        emit_type_info(vim_model, &struct_type.borrow(), printer)?;
+    }
+    printer.dedent();
+    printer.println("];")?;
+    printer.newline()?;
+
+    let mut names: Vec<(String, String)> = structs
+        .iter()
+        .map(|struct_type| {
+            let struct_type = struct_type.borrow();
+            (struct_type.name.clone(), struct_type.rust_name())
+        })
+        .collect();
+    names.sort();
+    printer.println("pub(super) static TYPE_NAMES: &[(&str, StructType)] = &[")?;
+    printer.indent();
+    for (name, rust_name) in &names {
+        emit_cfg(printer, vim_model.cfg_attribute(name))?;
+        printer.println(&format!("(\"{name}\", StructType::{rust_name}),"))?;
+    }
+    printer.dedent();
+    printer.println("];")?;
+    Ok(())
+}
+
+fn emit_type_info(vim_model: &Model, struct_type: &Struct, printer: &mut dyn Printer) -> Result<()> {
+    emit_cfg(printer, vim_model.cfg_attribute(&struct_type.name))?;
+    printer.println("TypeInfo {")?;
+    printer.indent();
+    printer.println(&format!("name: \"{}\",", struct_type.name))?;
+    printer.println(&format!("struct_type: StructType::{},", struct_type.rust_name()))?;
+    let parent = match struct_type.parent.as_deref() {
+        Some(parent) if parent != ANY => format!("Some(StructType::{})", struct_rust_name(vim_model, parent)?),
+        _ => "None".to_string(),
+    };
+    printer.println(&format!("parent: {parent},"))?;
+    let children = struct_type
+        .children
+        .iter()
+        .map(|child| {
+            let cfg = vim_model.cfg_attribute(child).map(|cfg| format!("{cfg} ")).unwrap_or_default();
+            Ok(format!("{cfg}StructType::{}", struct_rust_name(vim_model, child)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    printer.println(&format!("children: &[{}],", children.join(", ")))?;
+    printer.println(&format!("subtree_end: {},", ordinal(vim_model, &struct_type.last_child)?))?;
+    if struct_type.fields.is_empty() {
+        printer.println("fields: &[],")?;
+    } else {
+        printer.println("fields: &[")?;
+        printer.indent();
+        for field in struct_type.fields.values() {
+            emit_field_info(vim_model, field, printer)?;
+        }
+        printer.dedent();
+        printer.println("],")?;
+    }
+    printer.println(&format!("summary: {:?},", doc_summary(&struct_type.description)))?;
+    printer.dedent();
+    printer.println("},")?;
+    Ok(())
+}
+
+fn emit_field_info(vim_model: &Model, field: &Field, printer: &mut dyn Printer) -> Result<()> {
+    let (vim_type, array) = match &field.vim_type {
+        DataType::Array(item_type) => (item_type.as_ref(), true),
+        vim_type => (vim_type, false),
+    };
+    printer.println(&format!(
+        "FieldInfo {{ name: \"{}\", rust_name: \"{}\", data_type: {}, optional: {}, array: {}, summary: {:?} }},",
+        field.name,
+        field.rust_name(),
+        data_type_expr(vim_model, vim_type)?,
+        field.optional,
+        array,
+        doc_summary(&field.description),
+    ))?;
+    Ok(())
+}
+
+fn data_type_expr(vim_model: &Model, vim_type: &DataType) -> Result<String> {
+    Ok(match vim_type {
+        DataType::Boolean => "DataType::Boolean".to_string(),
+        DataType::String => "DataType::String".to_string(),
+        DataType::Int8 => "DataType::Int8".to_string(),
+        DataType::Int16 => "DataType::Int16".to_string(),
+        DataType::Int32 => "DataType::Int32".to_string(),
+        DataType::Int64 => "DataType::Int64".to_string(),
+        DataType::Float => "DataType::Float".to_string(),
+        DataType::Double => "DataType::Double".to_string(),
+        DataType::DateTime => "DataType::DateTime".to_string(),
+        DataType::Binary => "DataType::Binary".to_string(),
+        DataType::Array(_) => return Err(Error::InternalError(format!("Nested arrays are not supported: {vim_type:?}"))),
+        DataType::Reference(name) if name == ANY => "DataType::Any".to_string(),
+        DataType::Reference(name) if vim_model.enums.contains_key(name) => format!("DataType::Enum(\"{name}\")"),
+        DataType::Reference(name) => format!("DataType::Struct(StructType::{})", struct_rust_name(vim_model, name)?),
+    })
+}
+
+fn struct_rust_name(vim_model: &Model, name: &str) -> Result<String> {
+    vim_model
+        .structs
+        .get(name)
+        .map(|struct_type| struct_type.borrow().rust_name())
+        .ok_or_else(|| Error::TypeNotFound(name.to_string()))
+}
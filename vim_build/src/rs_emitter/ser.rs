@@ -1,5 +1,6 @@
 use crate::printer::Printer;
 use crate::rs_emitter;
+use crate::rs_emitter::common::emit_cfg;
 use crate::vim_model::{EmitMode, Model};
 
 pub fn generate_serialize_polymorphic_enum(
@@ -31,6 +32,7 @@ pub fn generate_serialize_polymorphic_enum(
             continue;
         }
 
+        emit_cfg(printer, vim_model.cfg_attribute(&data_type.borrow().name))?;
         printer.println(&format!(
             "StructType::{struct_name} => {struct_name}::serialize("
         ))?;
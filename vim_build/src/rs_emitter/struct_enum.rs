@@ -1,6 +1,7 @@
 use convert_case::{Case, Casing};
 use crate::printer::Printer;
 use crate::rs_emitter;
+use crate::rs_emitter::common::emit_cfg;
 use crate::vim_model::{Model};
 
 /// Generates enum with all the struct type names. The names are converted to RUST enum naming convention.
@@ -20,23 +21,26 @@ pub fn generate_struct_enum(
     printer.println("/// implemented using jump tables i.e. O(1) complexity.")?;
     printer.println("/// 1. Parent child relationship can be checked with range check.")?;
     printer.println("/// 1. Values are 32-bit integers that can be efficiently compared.")?;
+    printer.println("///")?;
+    printer.println("/// The values are the positions of the types in the full API. They do not change when the")?;
+    printer.println("/// managed object features leave some types out.")?;
     printer.println(
         "#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, serde::Serialize, serde::Deserialize, strum_macros::IntoStaticStr)]",
     )?;
     printer.println("#[repr(u32)]")?;
     printer.println("pub enum StructType {")?;
     printer.indent();
-    for (_, data_type) in &vim_model.structs {
+    // The ordinals count all types, including the ones left out by features. The type tables of
+    // vim_rs/src/types/reflection.rs are indexed by them when all features are enabled.
+    for (ordinal, data_type) in vim_model.structs.values().filter(|s| s.borrow().name != rs_emitter::structs::ANY).enumerate() {
         let struct_ref = data_type.borrow();
         let rust_type_name = struct_ref.rust_name();
-        if rust_type_name == rs_emitter::structs::ANY {
-            continue;
-        }
+        emit_cfg(printer, vim_model.cfg_attribute(&struct_ref.name))?;
         if rust_type_name != struct_ref.name {
             printer.println(&format!("#[serde(rename = \"{}\")]", struct_ref.name))?;
             printer.println(&format!("#[strum(serialize = \"{}\")]", struct_ref.name))?;
         }
-        printer.println(&format!("{},", rust_type_name))?;
+        printer.println(&format!("{rust_type_name} = {ordinal},"))?;
     }
     printer.dedent();
     printer.println("}")?;
@@ -77,8 +81,10 @@ pub fn generate_child_of_impl(
             continue;
         }
         let parent = &data_type.name.to_case(Case::Pascal);
-        let last_child = &data_type.last_child.to_case(Case::Pascal);
-        prn.println(&format!("StructType::{parent} => self <= StructType::{last_child},"))?
+        // The last descendant may be left out by the managed object features so compare ordinals
+        let last_child = ordinal(vim_model, &data_type.last_child)?;
+        emit_cfg(prn, vim_model.cfg_attribute(&data_type.name))?;
+        prn.println(&format!("StructType::{parent} => self as u32 <= {last_child},"))?
     }
     prn.println("_ => false // Others")?;
     prn.dedent();
@@ -91,3 +97,15 @@ pub fn generate_child_of_impl(
     prn.println("}")?;
     Ok(())
 }
+
+/// Position of a struct type in the `StructType` enum.
+pub fn ordinal(vim_model: &Model, name: &str) -> rs_emitter::errors::Result<usize> {
+    let index = vim_model
+        .structs
+        .get_index_of(name)
+        .ok_or_else(|| rs_emitter::errors::Error::TypeNotFound(name.to_string()))?;
+    // Any is the root of the hierarchy and thus the first type
+    index
+        .checked_sub(1)
+        .ok_or_else(|| rs_emitter::errors::Error::InternalError(format!("{name} has no position in StructType")))
+}
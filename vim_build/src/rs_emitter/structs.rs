@@ -6,24 +6,31 @@ use crate::vim_model::Model;
 use super::super::printer::Printer;
 
 use super::super::vim_model::*;
-use super::common::emit_description;
+use super::common::{emit_cfg, emit_description, emit_extra_derives};
 use super::errors::{Error, Result};
 use super::names::*;
 
 pub(crate) const ANY: &str = "Any";
 
+/// Methods of the generated builders that setters must not shadow. `default` comes from the
+/// derived `Default` that `builder()` calls.
+const BUILDER_METHODS: &[&str] = &["build", "default"];
+
 pub struct TypesEmitter<'a> {
     vim_model: &'a Model,
     printer: &'a mut dyn Printer,
     tdf: TypeDefResolver<'a>,
+    derives: &'a [String],
 }
 
 impl<'a> TypesEmitter<'a> {
-    pub fn new(vim_model: &'a Model, printer: &'a mut dyn Printer) -> Self {
+    /// Create an emitter of the structs deriving `derives` in addition to the standard traits.
+    pub fn new(vim_model: &'a Model, derives: &'a [String], printer: &'a mut dyn Printer) -> Self {
         TypesEmitter {
             vim_model,
             printer,
             tdf: TypeDefResolver::new(vim_model),
+            derives,
         }
     }
 
@@ -53,6 +60,7 @@ impl<'a> TypesEmitter<'a> {
                 continue;
             }
             self.emit_struct_type(name, &struct_type)?;
+            self.emit_builder(&struct_type)?;
             self.emit_debug(&struct_type)?;
             self.emit_serialize(&struct_type)?;
             self.emit_deserialize(&struct_type)?;
@@ -67,9 +75,23 @@ impl<'a> TypesEmitter<'a> {
             emit_description(this.printer, doc_string)
         }?;
         let struct_name = to_type_name(name);
+        emit_cfg(self.printer, self.vim_model.cfg_attribute(name))?;
         if name == "ManagedObjectReference" {
             // Add Clone, PartialEq in addition for ManagedObjectReference
             self.printer.println("#[derive(Clone, PartialEq, Eq, Hash)]")?;
+            emit_extra_derives(self.printer, self.derives, &["Clone", "PartialEq", "Eq", "Hash"])?;
+        } else {
+            self.printer.println(r#"#[cfg_attr(feature = "clone", derive(Clone))]"#)?;
+            self.printer.println(r#"#[cfg_attr(feature = "partial-eq", derive(PartialEq))]"#)?;
+            emit_extra_derives(self.printer, self.derives, &["Clone", "PartialEq"])?;
+        }
+        if self.has_natural_default(vim_type)? {
+            if self.has_required_date_time(vim_type)? {
+                // time::OffsetDateTime has no default value
+                self.printer.println(r#"#[cfg_attr(any(feature = "chrono", not(feature = "time")), derive(Default))]"#)?;
+            } else {
+                self.printer.println("#[derive(Default)]")?;
+            }
         }
         self.printer
             .println(&format!("pub struct {struct_name} {{"))?;
@@ -86,9 +108,127 @@ impl<'a> TypesEmitter<'a> {
         Ok(())
     }
 
+    /// Check if all required fields in the inheritance chain have a natural default value i.e.
+    /// are primitives, strings or arrays. Optional fields default to `None`.
+    fn has_natural_default(&self, vim_type: &Struct) -> Result<bool> {
+        for struct_type in self.vim_model.inheritance_chain(&vim_type.name)? {
+            for (_, field) in &struct_type.borrow().fields {
+                if !field.optional && matches!(field.vim_type, DataType::Reference(_)) {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn has_required_date_time(&self, vim_type: &Struct) -> Result<bool> {
+        for struct_type in self.vim_model.inheritance_chain(&vim_type.name)? {
+            for (_, field) in &struct_type.borrow().fields {
+                if !field.optional && field.vim_type == DataType::DateTime {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Emit a typed builder for the struct under the `builders` feature. Setters are named after
+    /// the fields and `build` checks that the required fields are set.
+    fn emit_builder(&mut self, vim_type: &Struct) -> Result<()> {
+        let struct_name = to_type_name(&vim_type.name);
+        let builder_name = format!("{struct_name}Builder");
+        let inheritance_chain = self.vim_model.inheritance_chain(&vim_type.name)?;
+        // Field name, VIM property name, optional flag, stored type, setter parameter type and value
+        let mut fields = Vec::new();
+        for struct_type in &inheritance_chain {
+            for (_, field) in &(*struct_type).borrow().fields {
+                let field_name = to_field_name(&field.name);
+                let rust_type = self.tdf.to_rust_field_type(&field.vim_type)?;
+                let (param_type, mut value) = if rust_type == "String" || field.vim_type == DataType::DateTime {
+                    (format!("impl Into<{rust_type}>"), format!("{field_name}.into()"))
+                } else {
+                    (rust_type.clone(), field_name.clone())
+                };
+                let mut stored_type = rust_type;
+                if field.require_box {
+                    stored_type = format!("Box<{stored_type}>");
+                    value = format!("Box::new({value})");
+                }
+                fields.push((field_name, field.name.clone(), field.optional, stored_type, param_type, value));
+            }
+        }
+        let cfg = self.vim_model.cfg_attribute(&vim_type.name);
+        let prn = &mut *self.printer;
+        prn.println(r#"#[cfg(feature = "builders")]"#)?;
+        emit_cfg(prn, cfg.clone())?;
+        prn.println(&format!("impl {struct_name} {{"))?;
+        prn.indent();
+        prn.println(&format!("/// Create a builder for [`{struct_name}`]."))?;
+        prn.println(&format!("pub fn builder() -> {builder_name} {{"))?;
+        prn.indent();
+        prn.println(&format!("{builder_name}::default()"))?;
+        prn.dedent();
+        prn.println("}")?;
+        prn.dedent();
+        prn.println("}")?;
+        prn.newline()?;
+        prn.println(&format!("/// Builder for [`{struct_name}`]. `build` fails if a required field is not set."))?;
+        prn.println(r#"#[cfg(feature = "builders")]"#)?;
+        emit_cfg(prn, cfg.clone())?;
+        prn.println("#[derive(Default)]")?;
+        prn.println(&format!("pub struct {builder_name} {{"))?;
+        prn.indent();
+        for (field_name, _, _, stored_type, _, _) in &fields {
+            prn.println(&format!("{field_name}: Option<{stored_type}>,"))?;
+        }
+        prn.dedent();
+        prn.println("}")?;
+        prn.newline()?;
+        prn.println(r#"#[cfg(feature = "builders")]"#)?;
+        emit_cfg(prn, cfg)?;
+        prn.println(&format!("impl {builder_name} {{"))?;
+        prn.indent();
+        for (field_name, _, _, _, param_type, value) in &fields {
+            // Avoid a clash of fields such as AboutInfo::build with the builder methods
+            let setter_name = builder_setter_name(field_name);
+            prn.println(&format!("pub fn {setter_name}(mut self, {field_name}: {param_type}) -> Self {{"))?;
+            prn.indent();
+            prn.println(&format!("self.{field_name} = Some({value});"))?;
+            prn.println("self")?;
+            prn.dedent();
+            prn.println("}")?;
+        }
+        prn.println(&format!("pub fn build(self) -> Result<{struct_name}, super::builder::BuilderError> {{"))?;
+        prn.indent();
+        prn.println(&format!("Ok({struct_name} {{"))?;
+        prn.indent();
+        for (field_name, vim_name, optional, _, _, _) in &fields {
+            if *optional {
+                prn.println(&format!("{field_name}: self.{field_name},"))?;
+            } else {
+                prn.println(&format!(
+                    r#"{field_name}: self.{field_name}.ok_or(super::builder::BuilderError {{ type_name: "{struct_name}", field: "{vim_name}" }})?,"#
+                ))?;
+            }
+        }
+        if vim_type.emit_mode == EmitMode::Prune {
+            prn.println("type_: None,")?;
+            prn.println("extra_fields_: Default::default(),")?;
+        }
+        prn.dedent();
+        prn.println("})")?;
+        prn.dedent();
+        prn.println("}")?;
+        prn.dedent();
+        prn.println("}")?;
+        prn.newline()?;
+        Ok(())
+    }
+
     fn emit_debug(&mut self, vim_type: &Struct) -> Result<()> {
         let prn = &mut *self.printer;
         let struct_name = to_type_name(&vim_type.name);
+        emit_cfg(prn, self.vim_model.cfg_attribute(&vim_type.name))?;
         prn.println(&format!("impl std::fmt::Debug for {struct_name} {{"))?;
         prn.indent();
         prn.println("fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {")?;
@@ -148,6 +288,7 @@ impl<'a> TypesEmitter<'a> {
         let struct_name = to_type_name(&vim_type.name);
         let discriminant = vim_type.discriminator();
         let inheritance_chain = self.vim_model.inheritance_chain(&vim_type.name)?;
+        emit_cfg(self.printer, self.vim_model.cfg_attribute(&vim_type.name))?;
         self.printer
             .println(&format!("impl serde::Serialize for {struct_name} {{"))?;
         self.printer.indent();
@@ -173,20 +314,14 @@ impl<'a> TypesEmitter<'a> {
                 let field_name = to_field_name(&field.name);
                 let serialization_name = &field.name;
                 if !field.optional {
-                    let field_value = if field.vim_type == DataType::Binary {
-                        format!("&crate::core::helpers::SerializeBinary {{ value: &self.{field_name} }}")
-                    } else {
-                        format!("&self.{field_name}")
-                    };
+                    let field_value = serialize_adapter(&field.vim_type, &format!("&self.{field_name}"), self.tdf.root_package())
+                        .unwrap_or_else(|| format!("&self.{field_name}"));
                     self.printer.println(&format!(
                         "state.serialize_entry(\"{serialization_name}\", {field_value})?;"
                     ))?;
                 } else {
-                    let field_value = if field.vim_type == DataType::Binary {
-                        "&crate::core::helpers::SerializeBinary { value: field_value }"
-                    } else {
-                        "field_value"
-                    };
+                    let field_value = serialize_adapter(&field.vim_type, "field_value", self.tdf.root_package())
+                        .unwrap_or_else(|| "field_value".to_string());
                     self.printer
                         .println(&format!("if let Some(field_value) = &self.{field_name} {{"))?;
                     self.printer.indent();
@@ -217,7 +352,9 @@ impl<'a> TypesEmitter<'a> {
         let struct_name = to_type_name(&vim_type.name);
         let type_name = vim_type.discriminator();
         let inheritance_chain = self.vim_model.inheritance_chain(&vim_type.name)?;
+        let cfg = self.vim_model.cfg_attribute(&vim_type.name);
 
+        emit_cfg(self.printer, cfg.clone())?;
         self.printer.println(&format!(
             "impl<'de> de::Deserialize<'de> for {struct_name} {{"
         ))?;
@@ -240,6 +377,7 @@ impl<'a> TypesEmitter<'a> {
         self.printer.dedent();
         self.printer.println("}")?;
         self.printer.newline()?;
+        emit_cfg(self.printer, cfg.clone())?;
         if vim_type.emit_mode == EmitMode::Prune {
             self.printer
                 .println(&format!("pub struct __{struct_name}Visitor(pub Option<struct_enum::StructType>);"))?;
@@ -248,6 +386,7 @@ impl<'a> TypesEmitter<'a> {
                 .println(&format!("struct __{struct_name}Visitor;"))?;
         }
         self.printer.newline()?;
+        emit_cfg(self.printer, cfg)?;
         self.printer.println(&format!(
             "impl<'de> de::Visitor<'de> for __{struct_name}Visitor {{"
         ))?;
@@ -290,14 +429,14 @@ impl<'a> TypesEmitter<'a> {
             }
         }
         if vim_type.emit_mode == EmitMode::Prune {
-            self.printer.println(&format!("let mut type_: Option<struct_enum::StructType> = Some(struct_enum::StructType::{struct_name});"))?;
+            self.printer.println(&format!("let mut type_: Option<struct_enum::StructType> = Some(self.0.unwrap_or(struct_enum::StructType::{struct_name}));"))?;
             self.printer.println("let mut extra_fields_: std::collections::HashMap<String, serde_json::Value> = std::collections::HashMap::new();")?;
         }
         self.printer.newline()?;
         self.printer
-            .println("while let Some(key) = map.next_key::<String>()? {")?;
+            .println("while let Some(key) = map.next_key::<super::map_access::FieldKey>()? {")?;
         self.printer.indent();
-        self.printer.println("match key.as_str() {")?;
+        self.printer.println("match &*key {")?;
         self.printer.indent();
         self.printer.println(r#""_typeName" => {"#)?;
         self.printer.indent();
@@ -321,8 +460,8 @@ impl<'a> TypesEmitter<'a> {
                 let ser_name = &field.name;
                 self.printer.println(&format!(r#""{ser_name}" => {{"#))?;
                 self.printer.indent();
-                if field.vim_type == DataType::Binary {
-                    self.printer.println(&format!("field{field_count} = Some(map.next_value::<crate::core::helpers::DeserializeBinary>()?.value);"))?;
+                if let Some((adapter, value)) = deserialize_adapter(&field.vim_type, self.tdf.root_package()) {
+                    self.printer.println(&format!("field{field_count} = Some(map.next_value::<{adapter}>()?.{value});"))?;
                 } else {
                     self.printer
                         .println(&format!("field{field_count} = Some(map.next_value()?);"))?;
@@ -336,12 +475,12 @@ impl<'a> TypesEmitter<'a> {
             self.printer.println(r#"_ => {"#)?;
             self.printer.indent();
             self.printer.println("let value: serde_json::Value = map.next_value()?;")?;
-            self.printer.println("extra_fields_.insert(key, value);")?;
+            self.printer.println("extra_fields_.insert(key.0.into_owned(), value);")?;
             self.printer.dedent();
             self.printer.println("},")?;
         } else {
             self.printer
-                .println(r#"_ => { let _: serde_json::Value = map.next_value()?; }"#)?;
+                .println(r#"_ => { map.next_value::<de::IgnoredAny>()?; }"#)?;
         }
         self.printer.dedent();
         self.printer.println("}")?;
@@ -380,6 +519,45 @@ impl<'a> TypesEmitter<'a> {
     }
 }
 
+/// Name of the builder setter of a field. Fields named after a builder method get a trailing
+/// underscore.
+fn builder_setter_name(field_name: &str) -> String {
+    if BUILDER_METHODS.contains(&field_name) {
+        format!("{field_name}_")
+    } else {
+        field_name.to_string()
+    }
+}
+
+/// Serialization adapter for values whose wire format differs from the serde format of their Rust
+/// type. `value` is an expression referencing the value and `root_package` the path of the data
+/// types module.
+pub fn serialize_adapter(vim_type: &DataType, value: &str, root_package: &str) -> Option<String> {
+    let init = |field: &str| if field == value { field.to_string() } else { format!("{field}: {value}") };
+    match vim_type {
+        DataType::Binary => Some(format!("&crate::core::helpers::SerializeBinary {{ {} }}", init("value"))),
+        DataType::DateTime => Some(format!("&{root_package}::date_time::SerializeDateTime {{ {} }}", init("value"))),
+        DataType::Array(item_type) if **item_type == DataType::DateTime => {
+            Some(format!("&{root_package}::date_time::SerializeDateTimes {{ {} }}", init("values")))
+        }
+        _ => None,
+    }
+}
+
+/// Deserialization adapter type for values whose wire format differs from the serde format of their
+/// Rust type and the adapter field holding the value. `root_package` is the path of the data types
+/// module.
+pub fn deserialize_adapter(vim_type: &DataType, root_package: &str) -> Option<(String, &'static str)> {
+    match vim_type {
+        DataType::Binary => Some(("crate::core::helpers::DeserializeBinary".to_string(), "value")),
+        DataType::DateTime => Some((format!("{root_package}::date_time::DeserializeDateTime"), "value")),
+        DataType::Array(item_type) if **item_type == DataType::DateTime => {
+            Some((format!("{root_package}::date_time::DeserializeDateTimes"), "values"))
+        }
+        _ => None,
+    }
+}
+
 /// Checks if type is to be returned as value copy or reference. Integer and float types are good to
 /// copy. Structures, strings and arrays go by immutable reference
 pub fn get_by_ref(vim_type: &DataType) -> bool {
@@ -388,7 +566,92 @@ pub fn get_by_ref(vim_type: &DataType) -> bool {
         DataType::Array(_) => true,
         DataType::Binary => true,
         DataType::String => true,
-        DataType::DateTime => true, // Uses string
+        DataType::DateTime => true, // String or typed value
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::printer::StringPrinter;
+    use indexmap::IndexMap;
+    use std::cell::RefCell;
+
+    fn data_type(name: &str, fields: &[(&str, DataType, bool)]) -> (String, RefCell<Struct>) {
+        let fields: IndexMap<String, Field> = fields
+            .iter()
+            .map(|(field_name, vim_type, optional)| {
+                (field_name.to_string(), Field {
+                    name: field_name.to_string(),
+                    description: None,
+                    optional: *optional,
+                    vim_type: vim_type.clone(),
+                    require_box: false,
+                })
+            })
+            .collect();
+        (name.to_string(), RefCell::new(Struct {
+            name: name.to_string(),
+            description: None,
+            fields,
+            parent: None,
+            discriminator_value: None,
+            children: vec![],
+            last_child: name.to_string(),
+            emit_mode: EmitMode::Emit,
+        }))
+    }
+
+    fn test_model() -> Model {
+        Model {
+            enums: IndexMap::new(),
+            structs: [
+                data_type("AboutInfo", &[("name", DataType::String, false), ("build", DataType::String, true), ("default", DataType::Boolean, true)]),
+                data_type("Description", &[("label", DataType::String, false)]),
+                data_type("VirtualDevice", &[("key", DataType::Int32, false), ("deviceInfo", DataType::Reference("Description".to_string()), false)]),
+                data_type("Event", &[("createdTime", DataType::DateTime, false)]),
+            ]
+            .into_iter()
+            .collect(),
+            request_types: IndexMap::new(),
+            any_value_types: IndexMap::new(),
+            managed_objects: IndexMap::new(),
+            feature_groups: Default::default(),
+        }
+    }
+
+    fn emit(name: &str, emit: impl Fn(&mut TypesEmitter, &Struct) -> Result<()>) -> String {
+        let model = test_model();
+        let mut printer = StringPrinter::new(None, None);
+        let mut emitter = TypesEmitter::new(&model, &[], &mut printer);
+        emit(&mut emitter, &model.structs[name].borrow()).unwrap();
+        printer.to_string()
+    }
+
+    #[test]
+    fn test_default_derive() {
+        let struct_type = |name: &str| emit(name, |emitter, vim_type| emitter.emit_struct_type(&vim_type.name, vim_type));
+        assert!(struct_type("AboutInfo").contains("#[derive(Default)]\npub struct AboutInfo {"));
+        // A required data object has no default value
+        assert!(!struct_type("VirtualDevice").contains("Default"));
+        // A required date and time has no default value with the time feature
+        assert!(struct_type("Event").contains(
+            "#[cfg_attr(any(feature = \"chrono\", not(feature = \"time\")), derive(Default))]\npub struct Event {"
+        ));
+    }
+
+    #[test]
+    fn test_builder() {
+        let builder = emit("AboutInfo", |emitter, vim_type| emitter.emit_builder(vim_type));
+        assert!(builder.contains(
+            r#"name: self.name.ok_or(super::builder::BuilderError { type_name: "AboutInfo", field: "name" })?,"#
+        ));
+        assert!(builder.contains("build: self.build,"));
+        // Setters must not shadow the build method or Default::default used by builder()
+        assert!(builder.contains("pub fn name(mut self, name: impl Into<String>) -> Self {"));
+        assert!(builder.contains("pub fn build_(mut self, build: impl Into<String>) -> Self {"));
+        assert!(builder.contains("pub fn default_(mut self, default: bool) -> Self {"));
+        assert_eq!(builder.matches("pub fn build(").count(), 1);
+    }
+}
@@ -1,8 +1,10 @@
 use crate::printer::Printer;
-use crate::rs_emitter::common::emit_description;
+use crate::rs_emitter::common::{emit_cfg, emit_description};
 use crate::rs_emitter::errors::{Error, Result};
-use crate::rs_emitter::{get_by_ref, getter_name, to_field_name, to_type_name, TypeDefResolver};
-use crate::vim_model::{EmitMode, Field, Model, Struct};
+use crate::rs_emitter::vim_object::VIM_OBJECT_METHODS;
+use crate::rs_emitter::{get_by_ref, getter_mut_name, getter_name, setter_name, to_field_name, to_type_name, TypeDefResolver};
+use crate::vim_model::{DataType, EmitMode, Field, Model, Struct};
+use std::collections::HashSet;
 use std::ops::Deref;
 pub struct TraitEmitter<'a> {
     type_name: String,
@@ -39,6 +41,8 @@ impl<'a> TraitEmitter<'a> {
 
         self.emit_trait_type(type_ref)?;
         self.emit_serialize()?;
+        self.emit_clone()?;
+        self.emit_partial_eq()?;
         self.emit_trait_deserialization()?;
         self.generate_implementations(type_ref)?;
         self.generate_cast_from_trait()?;
@@ -81,6 +85,8 @@ impl<'a> TraitEmitter<'a> {
             let doc_string: &Option<String> = &vim_type.description;
             emit_description(this.printer, doc_string)
         }?;
+        self.check_accessor_names()?;
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer.println(&format!(
             "pub trait {}Trait : super::traits::{}Trait {{",
             struct_name, base_trait
@@ -94,8 +100,29 @@ impl<'a> TraitEmitter<'a> {
         Ok(())
     }
 
+    /// Check that the accessors of the trait and its ancestors are distinct and do not reuse a
+    /// `VimObjectTrait` method e.g. `get_x_mut` of a field `x` and the getter of a field `xMut`.
+    /// Such methods would make calls through the trait ambiguous.
+    fn check_accessor_names(&self) -> Result<()> {
+        let mut method_names: HashSet<String> = VIM_OBJECT_METHODS.iter().map(|name| name.to_string()).collect();
+        for struct_type in self.model.inheritance_chain(&self.type_name)? {
+            for prop_name in struct_type.borrow().fields.keys() {
+                for method_name in [getter_name(prop_name), getter_mut_name(prop_name), setter_name(prop_name)] {
+                    if !method_names.insert(method_name.clone()) {
+                        return Err(Error::InternalError(format!(
+                            "Accessor {method_name} of {}.{prop_name} clashes with another trait method",
+                            struct_type.borrow().name
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn emit_serialize(&mut self) -> Result<()> {
         let struct_name = to_type_name(&self.type_name);
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer.println(&format!(
             r#"impl<'s> serde::Serialize for dyn {struct_name}Trait + 's {{
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -109,17 +136,53 @@ impl<'a> TraitEmitter<'a> {
         Ok(())
     }
 
+    /// Clone boxed trait objects through `VimObjectTrait::clone_box` and cast the copy back to
+    /// the trait. Emitted under the `clone` feature.
+    fn emit_clone(&mut self) -> Result<()> {
+        let struct_name = to_type_name(&self.type_name);
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
+        self.printer.println(&format!(
+            r#"#[cfg(feature = "clone")]
+impl Clone for Box<dyn {struct_name}Trait> {{
+    fn clone(&self) -> Self {{
+        CastFrom::from_box(self.as_vim_object_ref().clone_box())
+            .unwrap_or_else(|_| unreachable!("clone_box preserves the data type"))
+    }}
+}}"#
+        ))?;
+        Ok(())
+    }
+
+    /// Compare trait objects by data type and fields through `VimObjectTrait::eq_dyn`. Emitted
+    /// under the `partial-eq` feature.
+    fn emit_partial_eq(&mut self) -> Result<()> {
+        let struct_name = to_type_name(&self.type_name);
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
+        self.printer.println(&format!(
+            r#"#[cfg(feature = "partial-eq")]
+impl PartialEq for dyn {struct_name}Trait {{
+    fn eq(&self, other: &Self) -> bool {{
+        self.data_type() == other.data_type() && self.eq_dyn(other.as_vim_object_ref())
+    }}
+}}"#
+        ))?;
+        Ok(())
+    }
+
     fn emit_trait_deserialization(&mut self) -> Result<()> {
         let struct_name = to_type_name(&self.type_name);
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer.println(&format!(r#"impl<'de> serde::Deserialize<'de> for Box<dyn {struct_name}Trait> {{
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
                 deserializer.deserialize_map({struct_name}Visitor)
             }}
         }}"#))?;
         self.printer.newline()?;
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer
             .println(&format!("struct {struct_name}Visitor;"))?;
         self.printer.newline()?;
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer.println(&format!(
             "impl<'de> de::Visitor<'de> for {struct_name}Visitor {{"
         ))?;
@@ -189,6 +252,7 @@ impl<'a> TraitEmitter<'a> {
     fn emit_trait_implementation(&mut self, trait_type: &Struct, type_name: &str) -> Result<()> {
         let base_name = to_type_name(&trait_type.name);
         let struct_name = &to_type_name(type_name);
+        emit_cfg(self.printer, self.model.cfg_attribute(type_name))?;
         self.printer
             .println(&format!("impl {}Trait for {} {{", base_name, struct_name))?;
         self.printer.indent();
@@ -210,6 +274,16 @@ impl<'a> TraitEmitter<'a> {
         self.printer.println(&format!(
             "fn {getter_name}(&self) -> {field_type} {{ {field_access} }}"
         ))?;
+        let field_name = to_field_name(prop_name);
+        let field_type = self.tdf.field_type(property)?;
+        self.printer.println(&format!(
+            "fn {}(&mut self) -> &mut {field_type} {{ &mut self.{field_name} }}",
+            getter_mut_name(prop_name)
+        ))?;
+        self.printer.println(&format!(
+            "fn {}(&mut self, value: {field_type}) {{ self.{field_name} = value; }}",
+            setter_name(prop_name)
+        ))?;
         Ok(())
     }
 
@@ -223,6 +297,13 @@ impl<'a> TraitEmitter<'a> {
         let field_type = self.getter_return_type(property)?;
         self.printer
             .println(&format!("fn {field_name}(&self) -> {field_type};"))?;
+        let field_type = self.tdf.field_type(property)?;
+        self.printer.println(&format!("/// Mutable access to the value returned by `{field_name}`."))?;
+        self.printer
+            .println(&format!("fn {}(&mut self) -> &mut {field_type};", getter_mut_name(prop_name)))?;
+        self.printer.println(&format!("/// Replace the value returned by `{field_name}`."))?;
+        self.printer
+            .println(&format!("fn {}(&mut self, value: {field_type});", setter_name(prop_name)))?;
         Ok(())
     }
 
@@ -230,6 +311,7 @@ impl<'a> TraitEmitter<'a> {
         if self.model.structs.get(&self.type_name).is_none() {
             return Err(Error::TypeNotFound(self.type_name.clone()));
         }
+        emit_cfg(self.printer, self.model.cfg_attribute(&self.type_name))?;
         self.printer.println(&format!(
             "impl<From: VimObjectTrait + ?Sized + 'static> CastFrom<From> for dyn {}Trait {{",
             to_type_name(&self.type_name)
@@ -247,6 +329,7 @@ impl<'a> TraitEmitter<'a> {
                 continue;
             }
             let type_name = child_struct.borrow().rust_name();
+            emit_cfg(self.printer, self.model.cfg_attribute(&child_struct.borrow().name))?;
             self.printer.println(&format!("StructType::{type_name} => Some(from.as_any_ref().downcast_ref::<{type_name}>()?),"))?;
         }
         self.printer.println("_ => None,")?;
@@ -267,6 +350,7 @@ impl<'a> TraitEmitter<'a> {
                 continue;
             }
             let type_name = child_struct.borrow().rust_name();
+            emit_cfg(self.printer, self.model.cfg_attribute(&child_struct.borrow().name))?;
             self.printer.println(&format!(
                 "StructType::{type_name} => Ok(from.as_any_box().downcast::<{type_name}>()?),"
             ))?;
@@ -283,6 +367,9 @@ impl<'a> TraitEmitter<'a> {
     }
 
     fn getter_return_type(&mut self, property: &Field) -> Result<String> {
+        if property.vim_type == DataType::DateTime && !property.optional {
+            return Ok(format!("&{}", self.tdf.date_time_ref_type()));
+        }
         let mut field_type = self.tdf.field_type(property)?;
         if crate::rs_emitter::structs::get_by_ref(&property.vim_type) {
             field_type = format!("&{field_type}");
@@ -293,3 +380,75 @@ impl<'a> TraitEmitter<'a> {
         Ok(field_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::printer::StringPrinter;
+    use indexmap::IndexMap;
+    use std::cell::RefCell;
+
+    fn data_type(name: &str, parent: &str, fields: &[(&str, DataType)], children: &[&str]) -> (String, RefCell<Struct>) {
+        let fields = fields
+            .iter()
+            .map(|(field_name, vim_type)| {
+                (field_name.to_string(), Field {
+                    name: field_name.to_string(),
+                    description: None,
+                    optional: true,
+                    vim_type: vim_type.clone(),
+                    require_box: false,
+                })
+            })
+            .collect();
+        (name.to_string(), RefCell::new(Struct {
+            name: name.to_string(),
+            description: None,
+            fields,
+            parent: Some(parent.to_string()),
+            discriminator_value: None,
+            children: children.iter().map(|child| child.to_string()).collect(),
+            last_child: children.last().unwrap_or(&name).to_string(),
+            emit_mode: EmitMode::Emit,
+        }))
+    }
+
+    fn emit_trait_type(device_fields: &[(&str, DataType)]) -> Result<String> {
+        let model = Model {
+            enums: IndexMap::new(),
+            structs: [
+                data_type("DataObject", "Any", &[("dynamicType", DataType::String)], &["VirtualDevice", "VirtualDisk"]),
+                data_type("VirtualDevice", "DataObject", device_fields, &["VirtualDisk"]),
+                data_type("VirtualDisk", "VirtualDevice", &[], &[]),
+            ]
+            .into_iter()
+            .collect(),
+            request_types: IndexMap::new(),
+            any_value_types: IndexMap::new(),
+            managed_objects: IndexMap::new(),
+            feature_groups: Default::default(),
+        };
+        let mut printer = StringPrinter::new(None, None);
+        let mut emitter = TraitEmitter::new("VirtualDevice".to_string(), &model, &mut printer);
+        emitter.emit_trait_type(&model.structs["VirtualDevice"].borrow())?;
+        Ok(printer.to_string())
+    }
+
+    #[test]
+    fn test_mutable_accessors() {
+        let trait_type = emit_trait_type(&[("unitNumber", DataType::Int32)]).unwrap();
+        assert!(trait_type.contains("fn get_unit_number(&self) -> Option<i32>;"));
+        assert!(trait_type.contains("fn get_unit_number_mut(&mut self) -> &mut Option<i32>;"));
+        assert!(trait_type.contains("fn set_unit_number(&mut self, value: Option<i32>);"));
+    }
+
+    #[test]
+    fn test_accessor_name_clash() {
+        let clash = |fields: &[(&str, DataType)]| matches!(emit_trait_type(fields), Err(Error::InternalError(_)));
+        assert!(clash(&[("key", DataType::Int32), ("keyMut", DataType::Int32)]));
+        assert!(clash(&[("byPath", DataType::String)]));
+        // The accessors of inherited fields are methods of the trait as well
+        assert!(clash(&[("dynamicTypeMut", DataType::String)]));
+        assert!(!clash(&[("path", DataType::String)]));
+    }
+}
@@ -1,15 +1,33 @@
 use crate::printer::Printer;
 use crate::rs_emitter;
+use crate::rs_emitter::common::emit_cfg;
 use crate::vim_model::{EmitMode, Model};
 
+/// Methods of `VimObjectTrait` and its supertrait `AsAny` that the accessors of the generated
+/// traits must not reuse.
+pub(crate) const VIM_OBJECT_METHODS: &[&str] = &[
+    "as_any_ref",
+    "as_any_box",
+    "type_id",
+    "as_vim_object_ref",
+    "data_type",
+    "clone_box",
+    "eq_dyn",
+    "get_by_path",
+    "apply_change",
+    "apply_changes",
+];
+
 pub fn generate_vim_object_trait(
     vim_model: &Model,
     printer: &mut dyn Printer,
 ) -> rs_emitter::errors::Result<()> {
     printer.println("use super::as_any::AsAny;")?;
     printer.println("use super::dyn_serialize;")?;
+    printer.println("use super::path::PathError;")?;
     printer.println("use super::struct_enum::StructType;")?;
     printer.println("use super::structs::*;")?;
+    printer.println("use super::vim_any::VimAny;")?;
     printer.println("")?;
     printer.println("/// Base trait of all VIM (Virtual Infrastructure Management) objects.")?;
     printer.println("/// This trait is used to obtain the actual data type of object even")?;
@@ -20,6 +38,43 @@ pub fn generate_vim_object_trait(
     printer.indent();
     printer.println("fn as_vim_object_ref<'a>(self: &'a Self) -> &'a dyn VimObjectTrait;")?;
     printer.println("fn data_type(&self) -> StructType;")?;
+    printer.println("/// Clone the object behind a trait reference. Requires the `clone` feature.")?;
+    printer.println(r#"#[cfg(feature = "clone")]"#)?;
+    printer.println("fn clone_box(&self) -> Box<dyn VimObjectTrait>;")?;
+    printer.println("/// Compare with an object behind a trait reference. Objects of different data types are")?;
+    printer.println("/// never equal. Requires the `partial-eq` feature.")?;
+    printer.println(r#"#[cfg(feature = "partial-eq")]"#)?;
+    printer.println("fn eq_dyn(&self, other: &dyn VimObjectTrait) -> bool;")?;
+    printer.println("/// Evaluate a property path such as `hardware.device[4000].backing` against the object. The")?;
+    printer.println("/// value is returned in its JSON wire format. `None` is returned if the property is not set.")?;
+    printer.println("fn get_by_path(&self, path: &str) -> Option<serde_json::Value>;")?;
+    printer.println("/// Apply a property collector change e.g. from a `WaitForUpdatesEx` delta to the object.")?;
+    printer.println("fn apply_change(&mut self, change: &PropertyChange) -> Result<(), PathError>;")?;
+    printer.println("/// Apply all the changes of an `ObjectUpdate` in order. The object is converted to its wire")?;
+    printer.println("/// format once for all the changes and left unchanged if any of them cannot be applied.")?;
+    printer.println("fn apply_changes(&mut self, changes: &[PropertyChange]) -> Result<(), PathError>;")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.println("")?;
+    printer.println(r#"#[cfg(feature = "clone")]"#)?;
+    printer.println("impl Clone for Box<dyn VimObjectTrait> {")?;
+    printer.indent();
+    printer.println("fn clone(&self) -> Self {")?;
+    printer.indent();
+    printer.println("self.clone_box()")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.println("")?;
+    printer.println(r#"#[cfg(feature = "partial-eq")]"#)?;
+    printer.println("impl PartialEq for dyn VimObjectTrait {")?;
+    printer.indent();
+    printer.println("fn eq(&self, other: &Self) -> bool {")?;
+    printer.indent();
+    printer.println("self.data_type() == other.data_type() && self.eq_dyn(other)")?;
+    printer.dedent();
+    printer.println("}")?;
     printer.dedent();
     printer.println("}")?;
     printer.println("")?;
@@ -38,6 +93,21 @@ pub fn generate_vim_object_trait(
     printer.dedent();
     printer.println("}")?;
     printer.println("")?;
+    printer.println("impl<'de> serde::Deserialize<'de> for Box<dyn VimObjectTrait> {")?;
+    printer.indent();
+    printer.println("fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {")?;
+    printer.indent();
+    printer.println("match <VimAny as serde::Deserialize>::deserialize(deserializer)? {")?;
+    printer.indent();
+    printer.println("VimAny::Object(object) => Ok(object),")?;
+    printer.println(r#"VimAny::Value(_) => Err(serde::de::Error::custom("Expected data object and found boxed value")),"#)?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.dedent();
+    printer.println("}")?;
+    printer.println("")?;
     for (_, data_type) in &vim_model.structs {
         let struct_name = data_type.borrow().rust_name();
         if struct_name == "Any" {
@@ -46,6 +116,7 @@ pub fn generate_vim_object_trait(
         if matches!(data_type.borrow().emit_mode, EmitMode::Skip(_)) {
             continue;
         }
+        emit_cfg(printer, vim_model.cfg_attribute(&data_type.borrow().name))?;
         printer.println(&format!("impl VimObjectTrait for {struct_name} {{"))?;
         printer.indent();
         printer.println("fn as_vim_object_ref<'a>(self: &'a Self) -> &'a dyn VimObjectTrait {")?;
@@ -59,6 +130,38 @@ pub fn generate_vim_object_trait(
         printer.println(&format!("StructType::{struct_name}"))?;
         printer.dedent();
         printer.println("}")?;
+        printer.println("")?;
+        printer.println(r#"#[cfg(feature = "clone")]"#)?;
+        printer.println("fn clone_box(&self) -> Box<dyn VimObjectTrait> {")?;
+        printer.indent();
+        printer.println("Box::new(self.clone())")?;
+        printer.dedent();
+        printer.println("}")?;
+        printer.println("")?;
+        printer.println(r#"#[cfg(feature = "partial-eq")]"#)?;
+        printer.println("fn eq_dyn(&self, other: &dyn VimObjectTrait) -> bool {")?;
+        printer.indent();
+        printer.println("other.as_any_ref().downcast_ref::<Self>().is_some_and(|other| self == other)")?;
+        printer.dedent();
+        printer.println("}")?;
+        printer.println("")?;
+        printer.println("fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {")?;
+        printer.indent();
+        printer.println("super::path::get_path(self, path)")?;
+        printer.dedent();
+        printer.println("}")?;
+        printer.println("")?;
+        printer.println("fn apply_change(&mut self, change: &PropertyChange) -> Result<(), PathError> {")?;
+        printer.indent();
+        printer.println("super::path::apply_changes(self, std::slice::from_ref(change))")?;
+        printer.dedent();
+        printer.println("}")?;
+        printer.println("")?;
+        printer.println("fn apply_changes(&mut self, changes: &[PropertyChange]) -> Result<(), PathError> {")?;
+        printer.indent();
+        printer.println("super::path::apply_changes(self, changes)")?;
+        printer.dedent();
+        printer.println("}")?;
         printer.dedent();
         printer.println("}")?;
         printer.println("")?;
@@ -0,0 +1,129 @@
+/// Logic in this module types string fields and method parameters whose values come from an enum.
+/// The OpenAPI specification declares such properties as plain strings and names the enum in the
+/// description only e.g. "See *VirtualMachineTicketType_enum*". The generated enums are open i.e.
+/// have an `Other_(String)` variant so values unknown at compile time are still accepted.
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use super::{DataType, Field, Model, Result, Struct};
+
+/// Properties holding a managed object type name that do not reference `MoTypes_enum` in their
+/// description. The entries are struct or request type name, property name and enum name.
+const KNOWN_ENUM_PROPERTIES: &[(&str, &str, &str)] = &[
+    ("PropertySpec", "type", "MoTypes_enum"),
+    ("TraversalSpec", "type", "MoTypes_enum"),
+    ("CreateContainerViewRequestType", "type", "MoTypes_enum"),
+];
+
+/// Change the type of string and string array fields to the enum referenced in their description.
+/// Fields referencing more than one enum are left as strings as the set of values is ambiguous.
+pub fn resolve_enum_references(vim_model: &mut Model) -> Result<()> {
+    let enum_names: HashMap<String, String> = vim_model
+        .enums
+        .keys()
+        .map(|name| (name.to_lowercase(), name.clone()))
+        .collect();
+    resolve_fields(&vim_model.structs, &enum_names);
+    resolve_fields(&vim_model.request_types, &enum_names);
+    Ok(())
+}
+
+fn resolve_fields(structs: &IndexMap<String, RefCell<Struct>>, enum_names: &HashMap<String, String>) {
+    for (struct_name, struct_cell) in structs {
+        let mut struct_ref = struct_cell.borrow_mut();
+        for (field_name, field) in struct_ref.fields.iter_mut() {
+            let known = KNOWN_ENUM_PROPERTIES
+                .iter()
+                .find(|(name, property, _)| name == struct_name && property == field_name)
+                .map(|(_, _, enum_name)| enum_name.to_string());
+            let Some(enum_name) = known.or_else(|| referenced_enum(field, enum_names)) else {
+                continue;
+            };
+            if !enum_names.contains_key(&enum_name.to_lowercase()) {
+                continue;
+            }
+            field.vim_type = match &field.vim_type {
+                DataType::String => DataType::Reference(enum_name),
+                DataType::Array(item_type) if **item_type == DataType::String => {
+                    DataType::Array(Box::new(DataType::Reference(enum_name)))
+                }
+                _ => continue,
+            };
+        }
+    }
+}
+
+/// Find the single enum referenced by the description of a string field. Enum names are matched
+/// case insensitively as some descriptions spell them in camel case e.g. `vslmDiskInfoFlag_enum`.
+fn referenced_enum(field: &Field, enum_names: &HashMap<String, String>) -> Option<String> {
+    if !matches!(&field.vim_type, DataType::String)
+        && !matches!(&field.vim_type, DataType::Array(item_type) if **item_type == DataType::String)
+    {
+        return None;
+    }
+    let mut found: Option<&String> = None;
+    for name in enum_mentions(field.description.as_deref()?) {
+        let Some(enum_name) = enum_names.get(&name.to_lowercase()) else {
+            continue;
+        };
+        match found {
+            Some(previous) if previous != enum_name => return None,
+            _ => found = Some(enum_name),
+        }
+    }
+    found.cloned()
+}
+
+/// Emphasized enum names in a description e.g. `VirtualMachineTicketType_enum` in
+/// "See *VirtualMachineTicketType_enum*". Text between emphasis markers contains spaces or
+/// punctuation so it is not mistaken for an enum name.
+fn enum_mentions(description: &str) -> Vec<&str> {
+    description
+        .split('*')
+        .filter(|name| {
+            name.ends_with("_enum") && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string_field(description: &str) -> Field {
+        Field {
+            name: "ticketType".to_string(),
+            description: Some(description.to_string()),
+            optional: false,
+            vim_type: DataType::String,
+            require_box: false,
+        }
+    }
+
+    #[test]
+    fn test_enum_mentions() {
+        assert_eq!(
+            enum_mentions("The type. See *VirtualMachineTicketType_enum*. Also see *VirtualMachine*."),
+            vec!["VirtualMachineTicketType_enum"]
+        );
+        assert_eq!(enum_mentions("***Since:*** vSphere API Release 8.0"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_referenced_enum() {
+        let enum_names: HashMap<String, String> = ["VirtualMachineTicketType_enum", "VslmDiskInfoFlag_enum"]
+            .iter()
+            .map(|name| (name.to_lowercase(), name.to_string()))
+            .collect();
+        let field = string_field("Values are described in *VirtualMachineTicketType_enum*.");
+        assert_eq!(referenced_enum(&field, &enum_names), Some("VirtualMachineTicketType_enum".to_string()));
+        let field = string_field("See *vslmDiskInfoFlag_enum* for the list of supported values.");
+        assert_eq!(referenced_enum(&field, &enum_names), Some("VslmDiskInfoFlag_enum".to_string()));
+        let field = string_field("See *VirtualMachineTicketType_enum* and *VslmDiskInfoFlag_enum*.");
+        assert_eq!(referenced_enum(&field, &enum_names), None);
+        let field = string_field("See *UnknownType_enum*.");
+        assert_eq!(referenced_enum(&field, &enum_names), None);
+    }
+}
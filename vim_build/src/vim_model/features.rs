@@ -0,0 +1,294 @@
+/// Logic in this module partitions the data types by the managed objects that use them. Every
+/// managed object gets a cargo feature e.g. `mo-virtual-machine` that compiles its module and the
+/// data types reachable from its methods. Types reached by the same set of managed objects form a
+/// group. The generated items are marked with `#[cfg(vim_group = "N")]` and the build script of
+/// vim_rs enables the groups of the selected features. This keeps the attributes short as a type
+/// can be reachable from more than a hundred managed objects.
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use convert_case::{Case, Casing};
+use indexmap::IndexMap;
+
+use super::{DataType, ManagedObject, Model, Result};
+
+/// Managed objects used by the client to log in. They and the data types they use are always
+/// compiled.
+pub const CORE_MANAGED_OBJECTS: &[&str] = &["ServiceInstance", "SessionManager"];
+
+/// Data types used by the hand written library code e.g. to report faults and apply property
+/// collector updates. They are always compiled.
+const CORE_DATA_TYPES: &[&str] = &["MethodFault", "PropertyChange"];
+
+/// Assignment of data types to feature groups.
+#[derive(Debug, Default, PartialEq)]
+pub struct FeatureGroups {
+    /// Managed objects enabling each group. Types not reachable from any managed object e.g. only
+    /// through `Any` properties form a group without managed objects that is enabled by the
+    /// `full` feature.
+    pub groups: Vec<Vec<String>>,
+    /// Group index of the data types. Types used by the core are always compiled and not listed.
+    pub type_groups: HashMap<String, usize>,
+}
+
+/// Name of the cargo feature of a managed object e.g. `mo-virtual-machine`.
+pub fn mo_feature_name(mo_name: &str) -> String {
+    format!("mo-{}", mo_name.to_case(Case::Kebab))
+}
+
+/// Check if the module of a managed object is compiled without a feature.
+pub fn is_core_managed_object(mo_name: &str) -> bool {
+    CORE_MANAGED_OBJECTS.contains(&mo_name)
+}
+
+/// Compute the feature groups of the data types. Must run after the structs are ordered as the
+/// descendants of a type are taken from the range following it.
+pub fn partition_features(vim_model: &mut Model) -> Result<()> {
+    let core = core_types(vim_model)?;
+    let mut closures = Vec::new();
+    for (mo_name, mo) in &vim_model.managed_objects {
+        if mo.methods.is_empty() || is_core_managed_object(mo_name) {
+            continue; // No module is emitted or its types are in the core
+        }
+        closures.push((mo_name.clone(), type_closure(vim_model, &method_types(vim_model, mo))?));
+    }
+
+    let mut groups: IndexMap<Vec<String>, usize> = IndexMap::new();
+    let mut type_groups = HashMap::new();
+    for name in vim_model.structs.keys() {
+        if name == "Any" || core.contains(name) {
+            continue;
+        }
+        let mos: Vec<String> = closures
+            .iter()
+            .filter(|(_, closure)| closure.contains(name))
+            .map(|(mo_name, _)| mo_name.clone())
+            .collect();
+        let next_index = groups.len();
+        let index = *groups.entry(mos).or_insert(next_index);
+        type_groups.insert(name.clone(), index);
+    }
+    vim_model.feature_groups = FeatureGroups {
+        groups: groups.into_keys().collect(),
+        type_groups,
+    };
+    Ok(())
+}
+
+/// Names of the data types used by the core managed objects and the hand written library code.
+pub(super) fn core_types(vim_model: &Model) -> Result<HashSet<String>> {
+    let mut core = HashSet::new();
+    for name in CORE_DATA_TYPES {
+        if vim_model.structs.contains_key(*name) {
+            core.extend(type_closure(vim_model, &[DataType::Reference(name.to_string())])?);
+        }
+    }
+    for (mo_name, mo) in &vim_model.managed_objects {
+        if is_core_managed_object(mo_name) {
+            core.extend(type_closure(vim_model, &method_types(vim_model, mo))?);
+        }
+    }
+    Ok(core)
+}
+
+/// Parameter and return types of the methods of a managed object.
+pub(super) fn method_types(vim_model: &Model, mo: &ManagedObject) -> Vec<DataType> {
+    let mut types = Vec::new();
+    for method in &mo.methods {
+        match &method.input {
+            Some(DataType::Reference(input)) if vim_model.request_types.contains_key(input) => {
+                let request_type = vim_model.request_types[input].borrow();
+                types.extend(request_type.fields.values().map(|field| field.vim_type.clone()));
+            }
+            Some(input) => types.push(input.clone()),
+            None => {}
+        }
+        types.extend(method.output.clone());
+    }
+    types
+}
+
+/// Names of the data types needed to use values of `roots`. A referenced type comes with all of
+/// its descendants as any of them can be received in its place. Every type needs its ancestors
+/// and the types of its fields. `Any` properties do not pull in any types.
+pub(super) fn type_closure(vim_model: &Model, roots: &[DataType]) -> Result<BTreeSet<String>> {
+    let mut closure = BTreeSet::new();
+    let mut referenced = HashSet::new();
+    let mut pending = Vec::new();
+    for root in roots {
+        referenced_types(vim_model, root, &mut referenced, &mut pending)?;
+    }
+    while let Some(name) = pending.pop() {
+        if !closure.insert(name.clone()) {
+            continue;
+        }
+        let struct_type = vim_model.structs[&name].borrow();
+        if let Some(parent) = struct_type.parent.as_ref().filter(|parent| *parent != "Any") {
+            pending.push(parent.clone());
+        }
+        for field in struct_type.fields.values() {
+            referenced_types(vim_model, &field.vim_type, &mut referenced, &mut pending)?;
+        }
+    }
+    Ok(closure)
+}
+
+/// Queue the data type referenced by `vim_type` and its descendants unless already referenced.
+fn referenced_types(
+    vim_model: &Model,
+    vim_type: &DataType,
+    referenced: &mut HashSet<String>,
+    pending: &mut Vec<String>,
+) -> Result<()> {
+    match vim_type {
+        DataType::Array(item_type) => referenced_types(vim_model, item_type, referenced, pending),
+        DataType::Reference(name) if name != "Any" && vim_model.structs.contains_key(name) => {
+            if referenced.insert(name.clone()) {
+                for struct_type in vim_model.children(name)? {
+                    pending.push(struct_type.borrow().name.clone());
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+impl Model {
+    /// Conditional compilation attribute for the items of a data type. `None` if the type is
+    /// always compiled.
+    pub fn cfg_attribute(&self, type_name: &str) -> Option<String> {
+        let group = self.feature_groups.type_groups.get(type_name)?;
+        Some(format!(r#"#[cfg(vim_group = "{group}")]"#))
+    }
+
+    /// Conditional compilation attribute for a value of the given type e.g. a boxed array.
+    pub fn data_type_cfg_attribute(&self, vim_type: &DataType) -> Option<String> {
+        match vim_type {
+            DataType::Array(item_type) => self.data_type_cfg_attribute(item_type),
+            DataType::Reference(name) => self.cfg_attribute(name),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::cell::RefCell;
+
+    use super::super::struct_order::reorder_structs;
+    use super::super::{EmitMode, Field, HttpMethod, Method, Struct};
+    use super::*;
+
+    fn data_type(name: &str, parent: &str, fields: &[(&str, DataType)]) -> (String, RefCell<Struct>) {
+        let fields = fields
+            .iter()
+            .map(|(field_name, vim_type)| {
+                let field = Field {
+                    name: field_name.to_string(),
+                    description: None,
+                    optional: true,
+                    vim_type: vim_type.clone(),
+                    require_box: false,
+                };
+                (field_name.to_string(), field)
+            })
+            .collect();
+        let struct_type = Struct {
+            name: name.to_string(),
+            description: None,
+            fields,
+            parent: if name == "Any" { None } else { Some(parent.to_string()) },
+            discriminator_value: None,
+            children: vec![],
+            last_child: String::new(),
+            emit_mode: EmitMode::Emit,
+        };
+        (name.to_string(), RefCell::new(struct_type))
+    }
+
+    fn managed_object(name: &str, output: &str) -> (String, ManagedObject) {
+        let method = Method {
+            name: format!("get{output}"),
+            description: None,
+            path: format!("/{name}/{{moId}}/get{output}"),
+            http_method: HttpMethod::Get,
+            input: None,
+            output: Some(DataType::Reference(output.to_string())),
+            output_description: None,
+            error_description: None,
+            optional_response: false,
+        };
+        let mo = ManagedObject {
+            name: name.to_string(),
+            description: None,
+            methods: vec![method],
+        };
+        (name.to_string(), mo)
+    }
+
+    pub(in super::super) fn test_model() -> Model {
+        let reference = |name: &str| DataType::Reference(name.to_string());
+        let mut structs: IndexMap<String, RefCell<Struct>> = [
+            data_type("Any", "", &[]),
+            data_type("DataObject", "Any", &[]),
+            data_type("MethodFault", "DataObject", &[]),
+            data_type("AboutInfo", "DataObject", &[]),
+            data_type("Description", "DataObject", &[]),
+            data_type("VirtualDevice", "DataObject", &[("deviceInfo", reference("Description"))]),
+            data_type("VirtualDisk", "VirtualDevice", &[("value", reference("Any"))]),
+            data_type("VirtualHardware", "DataObject", &[("device", DataType::Array(Box::new(reference("VirtualDevice"))))]),
+            data_type("HostHardwareInfo", "DataObject", &[("pciDevice", reference("Description"))]),
+            data_type("Event", "DataObject", &[]),
+        ]
+        .into_iter()
+        .collect();
+        for name in structs.keys().cloned().collect::<Vec<_>>() {
+            let parent = structs[&name].borrow().parent.clone();
+            if let Some(parent) = parent {
+                structs[&parent].borrow_mut().children.push(name);
+            }
+        }
+        Model {
+            enums: IndexMap::new(),
+            structs: reorder_structs(&mut structs).unwrap(),
+            request_types: IndexMap::new(),
+            any_value_types: IndexMap::new(),
+            managed_objects: [
+                managed_object("ServiceInstance", "AboutInfo"),
+                managed_object("VirtualMachine", "VirtualHardware"),
+                managed_object("HostSystem", "HostHardwareInfo"),
+            ]
+            .into_iter()
+            .collect(),
+            feature_groups: FeatureGroups::default(),
+        }
+    }
+
+    #[test]
+    fn test_mo_feature_name() {
+        assert_eq!(mo_feature_name("VirtualMachine"), "mo-virtual-machine");
+        assert_eq!(mo_feature_name("HostVStorageObjectManager"), "mo-host-v-storage-object-manager");
+    }
+
+    #[test]
+    fn test_partition_features() {
+        let mut model = test_model();
+        partition_features(&mut model).unwrap();
+        let mos = |type_name: &str| {
+            let group = model.feature_groups.type_groups.get(type_name)?;
+            Some(model.feature_groups.groups[*group].clone())
+        };
+        // Core types are always compiled
+        assert_eq!(mos("AboutInfo"), None);
+        assert_eq!(mos("MethodFault"), None);
+        assert_eq!(mos("DataObject"), None);
+        // Descendants of referenced types are included
+        assert_eq!(mos("VirtualDisk"), Some(vec!["VirtualMachine".to_string()]));
+        assert_eq!(mos("Description"), Some(vec!["VirtualMachine".to_string(), "HostSystem".to_string()]));
+        // Types reachable only through `Any` are left to the `full` feature
+        assert_eq!(mos("Event"), Some(vec![]));
+        let group = model.feature_groups.type_groups["VirtualDisk"];
+        assert_eq!(model.cfg_attribute("VirtualDisk"), Some(format!(r#"#[cfg(vim_group = "{group}")]"#)));
+        assert_eq!(model.cfg_attribute("AboutInfo"), None);
+    }
+}
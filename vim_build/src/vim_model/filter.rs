@@ -0,0 +1,275 @@
+/// Logic in this module selects the managed objects, methods and data types to generate so that
+/// slimmed bindings can be built from the full specification. Managed objects and methods are
+/// selected with allow- and deny-lists of name patterns where `*` matches any text. Data types are
+/// left out with a deny-list or because no selected method can reach them. The types and methods
+/// used by the client core are always generated.
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use super::features::{core_types, method_types, type_closure};
+use super::struct_order::reorder_structs;
+use super::{is_core_managed_object, DataType, Error, Method, Model, Result};
+
+/// Allow- and deny-list of name patterns.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NameFilter {
+    /// Patterns of the names to select. All names are selected if empty.
+    pub include: Vec<String>,
+    /// Patterns of the names to leave out. Takes precedence over `include`.
+    pub exclude: Vec<String>,
+}
+
+impl NameFilter {
+    /// Check if the filter selects all names.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Check if `name` matches an include pattern, if any, and no exclude pattern.
+    pub fn selects(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| glob_match(pattern, name)))
+            && !self.excludes(name)
+    }
+
+    /// Check if `name` matches an exclude pattern.
+    pub fn excludes(&self, name: &str) -> bool {
+        self.exclude.iter().any(|pattern| glob_match(pattern, name))
+    }
+}
+
+/// Selection of the parts of the model to generate.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModelFilter {
+    /// `include` selects managed object types. `exclude` leaves out managed object types and data
+    /// types. Excluded data types take their descendants, the data types requiring them and the
+    /// methods using them along. Optional properties of excluded types are left out.
+    pub types: NameFilter,
+    /// Methods named `<ManagedObject>.<name>` e.g. `VirtualMachine.PowerOnVM_Task`. Properties
+    /// are named as on the wire e.g. `VirtualMachine.config`.
+    pub methods: NameFilter,
+    /// Leave out data types that no selected method can reach. Types received only in `Any`
+    /// properties e.g. events are left out as well.
+    pub drop_unreachable_types: bool,
+}
+
+impl ModelFilter {
+    /// Check if the filter selects the complete model.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.methods.is_empty() && !self.drop_unreachable_types
+    }
+}
+
+/// Name of a method as matched by `ModelFilter::methods` e.g. `VirtualMachine.config`.
+pub fn method_filter_name(mo_name: &str, method: &Method) -> String {
+    let name = method.path.rsplit('/').next().unwrap_or(&method.name);
+    format!("{mo_name}.{name}")
+}
+
+/// Remove the managed objects, methods and data types not selected by the filter from the model.
+/// Must run after the structs are ordered.
+pub fn filter_model(vim_model: &mut Model, filter: &ModelFilter) -> Result<()> {
+    if filter.is_empty() {
+        return Ok(());
+    }
+    let core = core_types(vim_model)?;
+    let removed = excluded_types(vim_model, filter)?;
+    if let Some(name) = core.iter().find(|name| removed.contains(*name)) {
+        return Err(Error::InvalidFilter(format!("{name} is used by the client core and cannot be excluded")));
+    }
+
+    let Model { managed_objects, request_types, .. } = vim_model;
+    for (mo_name, mo) in managed_objects.iter_mut() {
+        if is_core_managed_object(mo_name) {
+            if filter.types.excludes(mo_name) {
+                return Err(Error::InvalidFilter(format!("{mo_name} is used by the client core and cannot be excluded")));
+            }
+            continue;
+        }
+        if !filter.types.selects(mo_name) {
+            mo.methods.clear();
+            continue;
+        }
+        mo.methods.retain(|method| {
+            if !filter.methods.selects(&method_filter_name(mo_name, method)) {
+                return false;
+            }
+            if method.output.as_ref().is_some_and(|output| references_any(output, &removed)) {
+                return false;
+            }
+            match &method.input {
+                Some(DataType::Reference(input)) if request_types.contains_key(input) => {
+                    let request_type = request_types[input].borrow();
+                    !request_type.fields.values().any(|field| !field.optional && references_any(&field.vim_type, &removed))
+                }
+                Some(input) => !references_any(input, &removed),
+                None => true,
+            }
+        });
+    }
+    remove_types(vim_model, &removed)?;
+
+    if filter.drop_unreachable_types {
+        let mut reachable = core;
+        for mo in vim_model.managed_objects.values() {
+            reachable.extend(type_closure(vim_model, &method_types(vim_model, mo))?);
+        }
+        let unreachable = vim_model
+            .structs
+            .keys()
+            .filter(|name| *name != "Any" && !reachable.contains(*name))
+            .cloned()
+            .collect();
+        remove_types(vim_model, &unreachable)?;
+    }
+    Ok(())
+}
+
+/// Names of the excluded data types, their descendants and the data types requiring them.
+fn excluded_types(vim_model: &Model, filter: &ModelFilter) -> Result<HashSet<String>> {
+    let mut removed = HashSet::new();
+    let mut pending: Vec<String> = vim_model
+        .structs
+        .keys()
+        .filter(|name| *name != "Any" && filter.types.excludes(name))
+        .cloned()
+        .collect();
+    while !pending.is_empty() {
+        for name in pending.drain(..) {
+            if removed.contains(&name) {
+                continue;
+            }
+            for struct_type in vim_model.children(&name)? {
+                removed.insert(struct_type.borrow().name.clone());
+            }
+        }
+        for (name, struct_type) in &vim_model.structs {
+            let struct_type = struct_type.borrow();
+            if !removed.contains(name)
+                && struct_type.fields.values().any(|field| !field.optional && references_any(&field.vim_type, &removed))
+            {
+                pending.push(name.clone());
+            }
+        }
+    }
+    Ok(removed)
+}
+
+/// Remove the data types from the model together with the optional properties and boxed arrays
+/// referring to them.
+fn remove_types(vim_model: &mut Model, removed: &HashSet<String>) -> Result<()> {
+    if removed.is_empty() {
+        return Ok(());
+    }
+    vim_model.structs.retain(|name, _| !removed.contains(name));
+    for struct_type in vim_model.structs.values().chain(vim_model.request_types.values()) {
+        let mut struct_type = struct_type.borrow_mut();
+        struct_type.children.retain(|child| !removed.contains(child));
+        struct_type.fields.retain(|_, field| !references_any(&field.vim_type, removed));
+    }
+    vim_model.any_value_types.retain(|_, box_type| !references_any(&box_type.property_type, removed));
+    vim_model.structs = reorder_structs(&mut vim_model.structs)?;
+    Ok(())
+}
+
+/// Check if the data type refers to one of the named data types.
+fn references_any(vim_type: &DataType, names: &HashSet<String>) -> bool {
+    match vim_type {
+        DataType::Array(item_type) => references_any(item_type, names),
+        DataType::Reference(name) => names.contains(name),
+        _ => false,
+    }
+}
+
+/// Match `name` against a pattern where `*` matches any text.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => name.strip_prefix(prefix).is_some_and(|name| {
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain([name.len()])
+                .any(|index| glob_match(rest, &name[index..]))
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::features::tests::test_model;
+    use super::*;
+
+    fn names(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|pattern| pattern.to_string()).collect()
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("VirtualMachine.*", "VirtualMachine.config"));
+        assert!(glob_match("*.Destroy_Task", "Folder.Destroy_Task"));
+        assert!(glob_match("Host*Info", "HostHardwareInfo"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("Host*Info", "HostHardware"));
+        assert!(!glob_match("VirtualMachine", "VirtualMachineConfigInfo"));
+    }
+
+    #[test]
+    fn test_filter_managed_objects() {
+        let mut model = test_model();
+        let filter = ModelFilter {
+            types: NameFilter { include: names(&["Virtual*"]), exclude: vec![] },
+            drop_unreachable_types: true,
+            ..Default::default()
+        };
+        filter_model(&mut model, &filter).unwrap();
+        // The core managed objects are always generated
+        assert_eq!(model.managed_objects["ServiceInstance"].methods.len(), 1);
+        assert_eq!(model.managed_objects["VirtualMachine"].methods.len(), 1);
+        assert!(model.managed_objects["HostSystem"].methods.is_empty());
+        assert!(model.structs.contains_key("VirtualDisk"));
+        assert!(model.structs.contains_key("Description"));
+        assert!(!model.structs.contains_key("HostHardwareInfo"));
+        assert!(!model.structs.contains_key("Event"));
+        assert!(model.children(&"DataObject".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_filter_methods() {
+        let mut model = test_model();
+        let filter = ModelFilter {
+            methods: NameFilter { include: vec![], exclude: names(&["HostSystem.*"]) },
+            ..Default::default()
+        };
+        filter_model(&mut model, &filter).unwrap();
+        assert!(model.managed_objects["HostSystem"].methods.is_empty());
+        // Data types are kept unless unreachable types are dropped
+        assert!(model.structs.contains_key("HostHardwareInfo"));
+    }
+
+    #[test]
+    fn test_exclude_data_types() {
+        let mut model = test_model();
+        let filter = ModelFilter {
+            types: NameFilter { include: vec![], exclude: names(&["VirtualDevice", "Description"]) },
+            ..Default::default()
+        };
+        filter_model(&mut model, &filter).unwrap();
+        // Descendants are excluded and optional properties left out
+        assert!(!model.structs.contains_key("VirtualDisk"));
+        assert!(model.structs["VirtualHardware"].borrow().fields.is_empty());
+        assert!(model.structs["HostHardwareInfo"].borrow().fields.is_empty());
+        assert_eq!(model.managed_objects["VirtualMachine"].methods.len(), 1);
+    }
+
+    #[test]
+    fn test_exclude_core_type() {
+        let mut model = test_model();
+        let filter = ModelFilter {
+            types: NameFilter { include: vec![], exclude: names(&["AboutInfo"]) },
+            ..Default::default()
+        };
+        assert!(matches!(filter_model(&mut model, &filter), Err(Error::InvalidFilter(_))));
+    }
+}
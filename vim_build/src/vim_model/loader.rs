@@ -33,6 +33,8 @@ pub enum Error {
     FieldDecoding(String, String, Box<Error>),
     #[error("Invalid operation: {0} {1}")]
     InvalidOperation(String, String),
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
     #[error("Internal processing error: {0}")]
     InternalProcessing(String),
 }
@@ -40,13 +42,14 @@ pub enum Error {
 // Result is a type alias for handling errors.
 pub type Result<T> = std::result::Result<T, Error>;
 
-pub fn load_vim_model(model: &OpenAPI, pruned_types: Option<&[&str]>) -> Result<Model> {
+pub fn load_vim_model(model: &OpenAPI, pruned_types: Option<&[&str]>, filter: &ModelFilter) -> Result<Model> {
     let mut vim_model = Model {
         enums: IndexMap::new(),
         structs: IndexMap::new(),
         request_types: IndexMap::new(),
         any_value_types: IndexMap::new(),
         managed_objects: IndexMap::new(),
+        feature_groups: FeatureGroups::default(),
     };
     let Some(components) = model.components.as_ref() else {
         return Err(Error::MissingField("#/components".to_string()));
@@ -62,7 +65,10 @@ pub fn load_vim_model(model: &OpenAPI, pruned_types: Option<&[&str]>) -> Result<
     mark_cycles(&mut vim_model)?;
     load_managed_objects(model, &mut vim_model)?;
     transform_paths(model, &mut vim_model)?;
+    resolve_enum_references(&mut vim_model)?;
     vim_model.structs = reorder_structs(&mut vim_model.structs)?;
+    filter_model(&mut vim_model, filter)?;
+    partition_features(&mut vim_model)?;
     
     Ok(vim_model)
 }
@@ -148,7 +154,7 @@ fn process_discriminator_mappings(
         ));
     };
     for (alias, type_ref) in mapping {
-        let type_name = reference_to_schema_name(type_ref)?.to_string();
+        let type_name = reference_to_schema_name(type_ref)?.into_owned();
         if let Some(vim_type) = vim_model.any_value_types.get_mut(&type_name) {
             vim_type.discriminator_value = Some(alias.to_string());
             continue;
@@ -311,23 +317,14 @@ fn as_string(value: &serde_json::Value) -> Result<String> {
 fn get_parent_schema(schema: &Schema) -> Option<String> {
     let all_of = schema.all_of.as_ref()?;
     if all_of.len() == 1 {
-        if let RefOr::Ref { ref reference, .. } = &all_of[0] {
-            return Some(trim_schema_prefix(reference).to_string());
-        }
+        let reference = schema.parent_reference()?;
+        return ComponentRef::name_of(reference, ComponentKind::Schemas).ok().map(|name| name.into_owned());
     }
     None
 }
 
-pub fn reference_to_schema_name(reference: &String) -> Result<&str> {
-    if !reference.starts_with("#/components/schemas/") {
-        return Err(Error::InvalidReference(reference.to_string()));
-    }
-    let schema_name = trim_schema_prefix(reference);
-    Ok(schema_name)
-}
-
-fn trim_schema_prefix(reference: &str) -> &str {
-    reference.trim_start_matches("#/components/schemas/")
+pub fn reference_to_schema_name(reference: &str) -> Result<std::borrow::Cow<'_, str>> {
+    ComponentRef::name_of(reference, ComponentKind::Schemas).map_err(|_| Error::InvalidReference(reference.to_string()))
 }
 
 fn load_managed_objects(model: &OpenAPI, vim_model: &mut Model) -> Result<()> {
@@ -611,7 +608,7 @@ mod tests {
             }
         });
         let open_api = serde_json::from_value::<OpenAPI>(value).unwrap();
-        let vim_model = load_vim_model(&open_api, None).unwrap();
+        let vim_model = load_vim_model(&open_api, None, &ModelFilter::default()).unwrap();
         assert_eq!(vim_model.enums.len(), 1);
         let test_enum = vim_model.enums.get("test").unwrap();
         assert_eq!(test_enum.description, Some("test".to_string()));
@@ -665,7 +662,7 @@ mod tests {
             }
         });
         let open_api = serde_json::from_value::<OpenAPI>(value).unwrap();
-        let vim_model = load_vim_model(&open_api, None).unwrap();
+        let vim_model = load_vim_model(&open_api, None, &ModelFilter::default()).unwrap();
         assert_eq!(vim_model.structs.len(), 2);
         let test_request_type = vim_model.structs.get("TestRequestType").unwrap().borrow();
         assert_eq!(test_request_type.name, "TestRequestType");
@@ -745,7 +742,7 @@ mod tests {
             }
         });
         let open_api = serde_json::from_value::<OpenAPI>(value).unwrap();
-        let vim_model = load_vim_model(&open_api, None).unwrap();
+        let vim_model = load_vim_model(&open_api, None, &ModelFilter::default()).unwrap();
         assert_eq!(vim_model.structs.len(), 2);
         let test_struct = vim_model.structs.get("MethodFault").unwrap().borrow();
         assert_eq!(test_struct.name, "MethodFault");
@@ -812,7 +809,7 @@ mod tests {
             }
         });
         let open_api = serde_json::from_value::<OpenAPI>(value).unwrap();
-        let vim_model = load_vim_model(&open_api, None).unwrap();
+        let vim_model = load_vim_model(&open_api, None, &ModelFilter::default()).unwrap();
         assert_eq!(vim_model.any_value_types.len(), 1);
         let boxed_type = vim_model
             .any_value_types
@@ -839,7 +836,7 @@ mod tests {
     #[test]
     fn test_load_vim_model() {
         let model = load_openapi();
-        let vim_model = load_vim_model(&model, None).unwrap();
+        let vim_model = load_vim_model(&model, None, &ModelFilter::default()).unwrap();
         assert_eq!(vim_model.any_value_types.len(), 3071);
         assert_eq!(vim_model.enums.len(), 414);
         assert_eq!(
@@ -1,8 +1,14 @@
 mod cycles;
+mod enum_refs;
+mod features;
+mod filter;
 mod loader;
 mod struct_order;
 mod types;
 
 pub use cycles::*;
+pub use enum_refs::*;
+pub use features::*;
+pub use filter::*;
 pub use loader::*;
 pub use types::*;
@@ -168,7 +168,7 @@ impl TryFrom<&RefOr<Schema>> for DataType {
     fn try_from(schema: &RefOr<Schema>) -> Result<Self> {
         match schema {
             RefOr::Ref { reference, .. } => Ok(DataType::Reference(
-                reference_to_schema_name(reference)?.to_string(),
+                reference_to_schema_name(reference)?.into_owned(),
             )),
             RefOr::Val(inline_schema) => match inline_schema.as_ref() {
                 Schema {
@@ -321,6 +321,7 @@ pub struct Model {
     pub request_types: IndexMap<String, RefCell<Struct>>,
     pub any_value_types: IndexMap<String, BoxType>,
     pub managed_objects: IndexMap<String, ManagedObject>,
+    pub feature_groups: FeatureGroups,
 }
 
 impl Model {
@@ -0,0 +1,23 @@
+//! Bindings for the vSphere Automation REST API generated by vim_build from the OpenAPI
+//! specification of the API. Only the tagging services ship. They are generated from
+//! `vim_build/data/automation_cis_tagging.json`, a hand-written excerpt of the tagging paths and
+//! schemas rather than the published specification.
+//!
+//! The services in `services` call the API with the `vim_rs` client. The session created by
+//! `ClientBuilder::basic_authn` for the VI/JSON API authenticates the Automation API calls as
+//! well:
+//!
+//! ```rust
+//! let client = ClientBuilder::new(&vc_server)
+//!     .basic_authn(&username, &pwd)
+//!     .build()
+//!     .await?;
+//! let tags = services::TaggingTag::new(client.clone()).list_tags().await?;
+//! ```
+//!
+//! Errors reported by the Automation API are returned as `Error::Api`.
+pub mod models;
+pub mod services;
+
+pub use vim_rs::core::automation::{ApiError, ApiMessage};
+pub use vim_rs::core::client::{Client, ClientBuilder, Error, Result};
@@ -0,0 +1,123 @@
+/// The CategoryCreateSpec structure is used to create a category.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingCategoryCreateSpec {
+    /// The display name of the category.
+    pub name: String,
+    /// The description of the category.
+    pub description: String,
+    /// It indicates how many tags from this category can be assigned to an object.
+    pub cardinality: CisTaggingCategoryModelCardinality,
+    /// Object types to which this category's tags can be attached.
+    pub associable_types: Vec<String>,
+    /// This attribute was added in vSphere API 7.0.0.0. If unset an identifier will be generated by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<String>,
+}
+/// The CategoryModel structure holds category metadata.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingCategoryModel {
+    /// The unique identifier of the category.
+    pub id: String,
+    /// The display name of the category.
+    pub name: String,
+    /// The description of the category.
+    pub description: String,
+    /// The associated cardinality (SINGLE, MULTIPLE) of the category.
+    pub cardinality: CisTaggingCategoryModelCardinality,
+    /// The types of objects that the tags in this category can be attached to. If the set is empty, then tags can be attached to all types of objects.
+    pub associable_types: Vec<String>,
+    /// The set of users that can use this category.
+    pub used_by: Vec<String>,
+}
+/// The CategoryModel.Cardinality enumerated type defines the number of tags in a category that can be assigned to an object.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum CisTaggingCategoryModelCardinality {
+    #[serde(rename = "SINGLE")]
+    Single,
+    #[serde(rename = "MULTIPLE")]
+    Multiple,
+    /// This variant handles values not known at compile time.
+    #[serde(untagged)]
+    Other_(String),
+}
+/// The CategoryUpdateSpec structure describes the updates to be made to an existing category.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingCategoryUpdateSpec {
+    /// The display name of the category. If unset the name will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the category. If unset the description will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// It indicates how many tags from this category can be assigned to an object. If unset the cardinality will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<CisTaggingCategoryModelCardinality>,
+    /// Object types to which this category's tags can be attached. If unset the associable types will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associable_types: Option<Vec<String>>,
+}
+/// The TagCreateSpec structure describes a tag.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingTagCreateSpec {
+    /// The display name of the tag. The name must be unique within its category.
+    pub name: String,
+    /// The description of the tag.
+    pub description: String,
+    /// The unique identifier of the parent category in which this tag will be created.
+    pub category_id: String,
+    /// This attribute was added in vSphere API 7.0.0.0. If unset an identifier will be generated by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<String>,
+}
+/// The TagModel structure holds tag metadata.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingTagModel {
+    /// The unique identifier of the tag.
+    pub id: String,
+    /// The identifier of the parent category in which this tag will be created.
+    pub category_id: String,
+    /// The display name of the tag.
+    pub name: String,
+    /// The description of the tag.
+    pub description: String,
+    /// The set of users that can use this tag.
+    pub used_by: Vec<String>,
+}
+/// The TagUpdateSpec structure describes the updates to be made to an existing tag.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CisTaggingTagUpdateSpec {
+    /// The display name of the tag. If unset the name will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the tag. If unset the description will not be modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TaggingTagAssociationAttachBody {
+    /// The identifier of the input object.
+    pub object_id: VapiStdDynamicId,
+}
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TaggingTagAssociationDetachBody {
+    /// The identifier of the input object.
+    pub object_id: VapiStdDynamicId,
+}
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TaggingTagAssociationListAttachedTagsBody {
+    /// The identifier of the input object.
+    pub object_id: VapiStdDynamicId,
+}
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TaggingTagListTagsForCategoryBody {
+    /// Identifier of the category.
+    pub category_id: String,
+}
+/// The DynamicID structure represents an identifier for a resource of an arbitrary type.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct VapiStdDynamicId {
+    /// The type of resource being identified (for example VirtualMachine).
+    pub r#type: String,
+    /// The identifier for a resource whose type is specified by DynamicID.type.
+    pub id: String,
+}
@@ -0,0 +1,7 @@
+pub mod tagging_category;
+pub mod tagging_tag;
+pub mod tagging_tag_association;
+
+pub use tagging_category::*;
+pub use tagging_tag::*;
+pub use tagging_tag_association::*;
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use vim_rs::core::client::{Client, Result};
+use vim_rs::core::automation::encode_component;
+use crate::models::CisTaggingCategoryCreateSpec;
+use crate::models::CisTaggingCategoryModel;
+use crate::models::CisTaggingCategoryUpdateSpec;
+/// The Category service provides operations to create, read, update and delete categories.
+pub struct TaggingCategory {
+    client: Arc<Client>,
+}
+impl TaggingCategory {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+    /// Enumerates the categories in the system.
+    pub async fn list_categories(&self) -> Result<Vec<String>> {
+        let req = self.client.api_request(http::Method::GET, "/api/cis/tagging/category");
+        self.client.execute(req).await
+    }
+    /// Creates a category.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### spec
+    /// Specification for the new category to be created.
+    pub async fn create_category(&self, spec: &CisTaggingCategoryCreateSpec) -> Result<String> {
+        let req = self.client.api_request(http::Method::POST, "/api/cis/tagging/category").json(spec);
+        self.client.execute(req).await
+    }
+    /// Fetches the category information for the given category identifier.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### category_id
+    /// Identifier of the category.
+    pub async fn get_category(&self, category_id: &str) -> Result<CisTaggingCategoryModel> {
+        let path = format!("/api/cis/tagging/category/{}", encode_component(category_id));
+        let req = self.client.api_request(http::Method::GET, &path).path_template("/api/cis/tagging/category/{category_id}");
+        self.client.execute(req).await
+    }
+    /// Deletes an existing category.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### category_id
+    /// Identifier of the category.
+    pub async fn delete_category(&self, category_id: &str) -> Result<()> {
+        let path = format!("/api/cis/tagging/category/{}", encode_component(category_id));
+        let req = self.client.api_request(http::Method::DELETE, &path).path_template("/api/cis/tagging/category/{category_id}");
+        self.client.execute_void(req).await
+    }
+    /// Updates an existing category.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### category_id
+    /// Identifier of the category.
+    ///
+    /// ### spec
+    /// Specification to update the category.
+    pub async fn update_category(&self, category_id: &str, spec: &CisTaggingCategoryUpdateSpec) -> Result<()> {
+        let path = format!("/api/cis/tagging/category/{}", encode_component(category_id));
+        let req = self.client.api_request(http::Method::PATCH, &path).path_template("/api/cis/tagging/category/{category_id}").json(spec);
+        self.client.execute_void(req).await
+    }
+}
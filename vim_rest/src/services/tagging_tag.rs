@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use vim_rs::core::client::{Client, Result};
+use vim_rs::core::automation::encode_component;
+use crate::models::CisTaggingTagCreateSpec;
+use crate::models::CisTaggingTagModel;
+use crate::models::CisTaggingTagUpdateSpec;
+use crate::models::TaggingTagListTagsForCategoryBody;
+/// The Tag service provides operations to create, read, update and delete tags.
+pub struct TaggingTag {
+    client: Arc<Client>,
+}
+impl TaggingTag {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+    /// Enumerates the tags in the system.
+    pub async fn list_tags(&self) -> Result<Vec<String>> {
+        let req = self.client.api_request(http::Method::GET, "/api/cis/tagging/tag");
+        self.client.execute(req).await
+    }
+    /// Creates a tag.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### spec
+    /// Specification for the new tag to be created.
+    pub async fn create_tag(&self, spec: &CisTaggingTagCreateSpec) -> Result<String> {
+        let req = self.client.api_request(http::Method::POST, "/api/cis/tagging/tag").json(spec);
+        self.client.execute(req).await
+    }
+    /// Enumerates all tags for the given category.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### request_body
+    /// Category whose tags are listed.
+    pub async fn list_tags_for_category(&self, request_body: &TaggingTagListTagsForCategoryBody) -> Result<Vec<String>> {
+        let req = self.client.api_request(http::Method::POST, "/api/cis/tagging/tag?action=list-tags-for-category").json(request_body);
+        self.client.execute(req).await
+    }
+    /// Fetches the tag information for the given tag identifier.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    pub async fn get_tag(&self, tag_id: &str) -> Result<CisTaggingTagModel> {
+        let path = format!("/api/cis/tagging/tag/{}", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::GET, &path).path_template("/api/cis/tagging/tag/{tag_id}");
+        self.client.execute(req).await
+    }
+    /// Deletes an existing tag.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    pub async fn delete_tag(&self, tag_id: &str) -> Result<()> {
+        let path = format!("/api/cis/tagging/tag/{}", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::DELETE, &path).path_template("/api/cis/tagging/tag/{tag_id}");
+        self.client.execute_void(req).await
+    }
+    /// Updates an existing tag.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    ///
+    /// ### spec
+    /// Specification to update the tag.
+    pub async fn update_tag(&self, tag_id: &str, spec: &CisTaggingTagUpdateSpec) -> Result<()> {
+        let path = format!("/api/cis/tagging/tag/{}", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::PATCH, &path).path_template("/api/cis/tagging/tag/{tag_id}").json(spec);
+        self.client.execute_void(req).await
+    }
+}
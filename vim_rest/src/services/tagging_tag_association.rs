@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use vim_rs::core::client::{Client, Result};
+use vim_rs::core::automation::encode_component;
+use crate::models::TaggingTagAssociationAttachBody;
+use crate::models::TaggingTagAssociationDetachBody;
+use crate::models::TaggingTagAssociationListAttachedTagsBody;
+use crate::models::VapiStdDynamicId;
+/// The TagAssociation service provides operations to attach, detach and query tags.
+pub struct TaggingTagAssociation {
+    client: Arc<Client>,
+}
+impl TaggingTagAssociation {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+    /// Attaches the given tag to the input object.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    ///
+    /// ### request_body
+    /// Object the tag is attached to.
+    pub async fn attach(&self, tag_id: &str, request_body: &TaggingTagAssociationAttachBody) -> Result<()> {
+        let path = format!("/api/cis/tagging/tag-association/{}?action=attach", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::POST, &path).path_template("/api/cis/tagging/tag-association/{tag_id}?action=attach").json(request_body);
+        self.client.execute_void(req).await
+    }
+    /// Detaches the tag from the given object.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    ///
+    /// ### request_body
+    /// Object the tag is detached from.
+    pub async fn detach(&self, tag_id: &str, request_body: &TaggingTagAssociationDetachBody) -> Result<()> {
+        let path = format!("/api/cis/tagging/tag-association/{}?action=detach", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::POST, &path).path_template("/api/cis/tagging/tag-association/{tag_id}?action=detach").json(request_body);
+        self.client.execute_void(req).await
+    }
+    /// Fetches the list of attached objects for the given tag.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### tag_id
+    /// Identifier of the tag.
+    pub async fn list_attached_objects(&self, tag_id: &str) -> Result<Vec<VapiStdDynamicId>> {
+        let path = format!("/api/cis/tagging/tag-association/{}?action=list-attached-objects", encode_component(tag_id));
+        let req = self.client.api_request(http::Method::POST, &path).path_template("/api/cis/tagging/tag-association/{tag_id}?action=list-attached-objects");
+        self.client.execute(req).await
+    }
+    /// Fetches the list of tags attached to the given object.
+    ///
+    /// ## Parameters:
+    ///
+    /// ### request_body
+    /// Object whose tags are listed.
+    pub async fn list_attached_tags(&self, request_body: &TaggingTagAssociationListAttachedTagsBody) -> Result<Vec<String>> {
+        let req = self.client.api_request(http::Method::POST, "/api/cis/tagging/tag-association?action=list-attached-tags").json(request_body);
+        self.client.execute(req).await
+    }
+}
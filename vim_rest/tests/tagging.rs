@@ -0,0 +1,236 @@
+//! Calls the tagging services against a local stand-in of vCenter. The stand-in serves the
+//! VI/JSON calls made by `ClientBuilder::build` and keeps tags in memory. The Automation API calls
+//! are accepted only with the session created by the VI/JSON login.
+use std::sync::{Arc, Mutex};
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use vim_rest::models::{CisTaggingTagCreateSpec, TaggingTagAssociationAttachBody, TaggingTagAssociationListAttachedTagsBody, VapiStdDynamicId};
+use vim_rest::services::{TaggingTag, TaggingTagAssociation};
+use vim_rest::{ClientBuilder, Error};
+
+const SESSION: &str = "session-1";
+
+const SERVICE_CONTENT: &str = r#"{"_typeName":"ServiceContent",
+    "rootFolder":{"_typeName":"ManagedObjectReference","type":"Folder","value":"group-d1"},
+    "propertyCollector":{"_typeName":"ManagedObjectReference","type":"PropertyCollector","value":"propertyCollector"},
+    "sessionManager":{"_typeName":"ManagedObjectReference","type":"SessionManager","value":"SessionManager"},
+    "about":{"_typeName":"AboutInfo","name":"VMware vCenter Server","fullName":"VMware vCenter Server 8.0.2",
+        "vendor":"VMware, Inc.","version":"8.0.2","build":"1","osType":"linux-x64","productLineId":"vpx",
+        "apiType":"VirtualCenter","apiVersion":"8.0.2.0"}}"#;
+
+const USER_SESSION: &str = r#"{"_typeName":"UserSession","key":"52a1","userName":"admin","fullName":"Admin",
+    "loginTime":"2024-05-01T10:00:00Z","lastActiveTime":"2024-05-01T10:00:00Z","locale":"en",
+    "messageLocale":"en","extensionSession":false}"#;
+
+#[derive(Default)]
+struct Inventory {
+    tags: Vec<Value>,
+    /// Tag and object identifier pairs
+    attachments: Vec<(String, String)>,
+}
+
+struct Response {
+    status: u16,
+    session: bool,
+    body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: Value) -> Self {
+        Response { status, session: false, body: body.to_string() }
+    }
+
+    fn empty(status: u16) -> Self {
+        Response { status, session: false, body: String::new() }
+    }
+
+    fn error(status: u16, error_type: &str, message: &str) -> Self {
+        Response::json(
+            status,
+            json!({"error_type": error_type, "messages": [{"id": "test", "default_message": message, "args": []}]}),
+        )
+    }
+}
+
+/// Start the stand-in on a free port and return the port.
+async fn start_server(inventory: Arc<Mutex<Inventory>>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(serve(stream, inventory.clone()));
+        }
+    });
+    port
+}
+
+/// Serve a single request and close the connection.
+async fn serve(mut stream: TcpStream, inventory: Arc<Mutex<Inventory>>) {
+    let mut data = Vec::new();
+    let mut buffer = [0u8; 4096];
+    let header_end = loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+        if read == 0 {
+            return;
+        }
+        data.extend_from_slice(&buffer[..read]);
+        if let Some(position) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+    let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_string();
+    let target = request_line.next().unwrap().to_string();
+    let mut content_length = 0;
+    let mut session = None;
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap(),
+                "vmware-api-session-id" => session = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+    while data.len() < header_end + content_length {
+        let read = stream.read(&mut buffer).await.unwrap();
+        data.extend_from_slice(&buffer[..read]);
+    }
+    let body: Value = serde_json::from_slice(&data[header_end..]).unwrap_or(Value::Null);
+
+    let response = route(&method, &target, session.as_deref(), &body, &mut inventory.lock().unwrap());
+    let mut head = format!("HTTP/1.1 {} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n", response.status, response.body.len());
+    if response.session {
+        head.push_str(&format!("vmware-api-session-id: {SESSION}\r\n"));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(response.body.as_bytes()).await.unwrap();
+    stream.shutdown().await.ok();
+}
+
+fn route(method: &str, target: &str, session: Option<&str>, body: &Value, inventory: &mut Inventory) -> Response {
+    let target = percent_decode(target);
+    if let Some(vim_path) = target.strip_prefix("/sdk/vim25/8.0.2.0") {
+        return match vim_path {
+            "/ServiceInstance/ServiceInstance/content" => Response { status: 200, session: false, body: SERVICE_CONTENT.to_string() },
+            "/SessionManager/SessionManager/Login" => Response { status: 200, session: true, body: USER_SESSION.to_string() },
+            _ => Response::empty(204),
+        };
+    }
+    if session != Some(SESSION) {
+        return Response::error(401, "UNAUTHENTICATED", "Session is not authenticated");
+    }
+    let find_tag = |inventory: &Inventory, id: &str| inventory.tags.iter().find(|tag| tag["id"] == id).cloned();
+    match (method, target.as_str()) {
+        ("GET", "/api/cis/tagging/tag") => {
+            Response::json(200, inventory.tags.iter().map(|tag| tag["id"].clone()).collect())
+        }
+        ("POST", "/api/cis/tagging/tag") => {
+            let id = format!("urn:vmomi:InventoryServiceTag:{}:GLOBAL", inventory.tags.len() + 1);
+            let mut tag = body.clone();
+            tag["id"] = json!(id);
+            tag["used_by"] = json!([]);
+            inventory.tags.push(tag);
+            Response::json(201, json!(id))
+        }
+        ("POST", "/api/cis/tagging/tag-association?action=list-attached-tags") => {
+            let object_id = body["object_id"]["id"].as_str().unwrap_or_default();
+            let tags: Vec<&str> =
+                inventory.attachments.iter().filter(|(_, object)| object == object_id).map(|(tag, _)| tag.as_str()).collect();
+            Response::json(200, json!(tags))
+        }
+        ("GET", path) if path.starts_with("/api/cis/tagging/tag/") => {
+            match find_tag(inventory, &path["/api/cis/tagging/tag/".len()..]) {
+                Some(tag) => Response::json(200, tag),
+                None => Response::error(404, "NOT_FOUND", "Tag not found"),
+            }
+        }
+        ("POST", path) if path.starts_with("/api/cis/tagging/tag-association/") && path.ends_with("?action=attach") => {
+            let tag_id = &path["/api/cis/tagging/tag-association/".len()..path.len() - "?action=attach".len()];
+            if find_tag(inventory, tag_id).is_none() {
+                return Response::error(404, "NOT_FOUND", "Tag not found");
+            }
+            let object_id = body["object_id"]["id"].as_str().unwrap_or_default();
+            inventory.attachments.push((tag_id.to_string(), object_id.to_string()));
+            Response::empty(204)
+        }
+        _ => Response::error(404, "NOT_FOUND", "Unknown path"),
+    }
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' && index + 2 < bytes.len() {
+            if let Ok(byte) = u8::from_str_radix(&value[index + 1..index + 3], 16) {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    String::from_utf8(decoded).unwrap()
+}
+
+fn builder(port: u16) -> ClientBuilder {
+    ClientBuilder::new("127.0.0.1").scheme("http").port(port).api_release("8.0.2.0")
+}
+
+#[tokio::test]
+async fn test_tagging() {
+    let port = start_server(Arc::new(Mutex::new(Inventory::default()))).await;
+    let client = builder(port).basic_authn("admin", "secret").build().await.unwrap();
+    let tags = TaggingTag::new(client.clone());
+    let associations = TaggingTagAssociation::new(client.clone());
+
+    let spec = CisTaggingTagCreateSpec {
+        name: "production".to_string(),
+        description: "Production workloads".to_string(),
+        category_id: "urn:vmomi:InventoryServiceCategory:1:GLOBAL".to_string(),
+        tag_id: None,
+    };
+    let tag_id = tags.create_tag(&spec).await.unwrap();
+    assert_eq!(tag_id, "urn:vmomi:InventoryServiceTag:1:GLOBAL");
+    assert_eq!(tags.list_tags().await.unwrap(), vec![tag_id.clone()]);
+
+    let tag = tags.get_tag(&tag_id).await.unwrap();
+    assert_eq!(tag.name, "production");
+    assert_eq!(tag.category_id, spec.category_id);
+    assert!(tag.used_by.is_empty());
+
+    let vm = VapiStdDynamicId { r#type: "VirtualMachine".to_string(), id: "vm-42".to_string() };
+    associations.attach(&tag_id, &TaggingTagAssociationAttachBody { object_id: vm.clone() }).await.unwrap();
+    let attached = associations
+        .list_attached_tags(&TaggingTagAssociationListAttachedTagsBody { object_id: vm })
+        .await
+        .unwrap();
+    assert_eq!(attached, vec![tag_id]);
+
+    match tags.get_tag("missing").await {
+        Err(Error::Api(404, error)) => {
+            assert_eq!(error.error_type.as_deref(), Some("NOT_FOUND"));
+            assert_eq!(error.messages[0].default_message, "Tag not found");
+        }
+        other => panic!("Expected NOT_FOUND error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_unauthenticated() {
+    let port = start_server(Arc::new(Mutex::new(Inventory::default()))).await;
+    let client = builder(port).build().await.unwrap();
+    match TaggingTag::new(client).list_tags().await {
+        Err(Error::Api(401, error)) => assert_eq!(error.error_type.as_deref(), Some("UNAUTHENTICATED")),
+        other => panic!("Expected UNAUTHENTICATED error: {other:?}"),
+    }
+}
@@ -0,0 +1,287 @@
+//! Deserialization benchmarks over property collector results of the size returned for large
+//! inventories.
+//!
+//! Run with `cargo bench --bench deserialize`. To compare two versions of the decoder save a
+//! baseline first e.g. `cargo bench --bench deserialize -- --save-baseline before` and then run
+//! `cargo bench --bench deserialize -- --baseline before` on the changed code.
+//!
+//! Before the timings the peak heap usage of `Client::execute` is printed for a response body
+//! that is received completely and for one that is streamed in chunks. The `client_execute`
+//! group times the same two decoders.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use criterion::{criterion_group, BenchmarkId, Criterion, Throughput};
+use vim_rs::core::client::{ClientBuilder, Result, API_RELEASE};
+use vim_rs::core::runtime::{Body, BodyStream, BoxFuture, HttpRequest, HttpResponse, HttpTransport};
+use vim_rs::types::structs::ObjectContent;
+use vim_rs::types::traits::VirtualDeviceTrait;
+use vim_rs::types::vim_any::VimAny;
+
+/// Number of virtual machines in the simulated inventory.
+const INVENTORY_SIZES: [usize; 2] = [100, 2000];
+/// Number of disks per virtual machine.
+const DISKS: usize = 4;
+/// Size of the chunks of streamed response bodies.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Global allocator tracking the heap in use and its peak.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Render a JSON object with the `_typeName` discriminator first as vCenter does or last as
+/// generic JSON tooling may.
+fn object(type_name: &str, fields: &[(&str, String)], type_first: bool) -> String {
+    let mut members: Vec<String> = fields
+        .iter()
+        .map(|(name, value)| format!("\"{name}\":{value}"))
+        .collect();
+    let discriminator = format!("\"_typeName\":\"{type_name}\"");
+    if type_first {
+        members.insert(0, discriminator);
+    } else {
+        members.push(discriminator);
+    }
+    format!("{{{}}}", members.join(","))
+}
+
+fn string(value: &str) -> String {
+    format!("\"{value}\"")
+}
+
+fn moref(mo_type: &str, value: &str, type_first: bool) -> String {
+    object("ManagedObjectReference", &[("type", string(mo_type)), ("value", string(value))], type_first)
+}
+
+fn disk(vm: usize, index: usize, type_first: bool) -> String {
+    let device_info = object(
+        "Description",
+        &[("label", string(&format!("Hard disk {}", index + 1))), ("summary", string("16,777,216 KB"))],
+        type_first,
+    );
+    let backing = object(
+        "VirtualDiskFlatVer2BackingInfo",
+        &[
+            ("fileName", string(&format!("[datastore1] vm-{vm}/vm-{vm}_{index}.vmdk"))),
+            ("datastore", moref("Datastore", "datastore-11", type_first)),
+            ("diskMode", string("persistent")),
+            ("thinProvisioned", "true".to_string()),
+        ],
+        type_first,
+    );
+    object(
+        "VirtualDisk",
+        &[
+            ("key", (2000 + index).to_string()),
+            ("deviceInfo", device_info),
+            ("backing", backing),
+            ("controllerKey", "1000".to_string()),
+            ("unitNumber", index.to_string()),
+            ("capacityInKB", "16777216".to_string()),
+        ],
+        type_first,
+    )
+}
+
+/// Result of `RetrievePropertiesEx` for the `name` and `config.hardware.device` properties.
+fn retrieve_result(vms: usize, type_first: bool) -> String {
+    let objects: Vec<String> = (0..vms)
+        .map(|vm| {
+            let devices: Vec<String> = (0..DISKS).map(|index| disk(vm, index, type_first)).collect();
+            let name = object(
+                "DynamicProperty",
+                &[
+                    ("name", string("name")),
+                    ("val", object("string", &[("_value", string(&format!("vm-{vm}")))], type_first)),
+                ],
+                type_first,
+            );
+            let hardware = object(
+                "DynamicProperty",
+                &[
+                    ("name", string("config.hardware.device")),
+                    (
+                        "val",
+                        object("ArrayOfVirtualDevice", &[("_value", format!("[{}]", devices.join(",")))], type_first),
+                    ),
+                ],
+                type_first,
+            );
+            object(
+                "ObjectContent",
+                &[
+                    ("obj", moref("VirtualMachine", &format!("vm-{vm}"), type_first)),
+                    ("propSet", format!("[{name},{hardware}]")),
+                ],
+                type_first,
+            )
+        })
+        .collect();
+    format!("[{}]", objects.join(","))
+}
+
+fn bench_retrieve_result(c: &mut Criterion) {
+    let mut group = c.benchmark_group("retrieve_result");
+    for vms in INVENTORY_SIZES {
+        for (order, type_first) in [("type_name_first", true), ("type_name_last", false)] {
+            let payload = retrieve_result(vms, type_first);
+            group.throughput(Throughput::Bytes(payload.len() as u64));
+            group.bench_with_input(BenchmarkId::new(order, vms), payload.as_bytes(), |b, payload| {
+                b.iter(|| serde_json::from_slice::<Vec<ObjectContent>>(payload).unwrap())
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_polymorphic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polymorphic");
+    for (order, type_first) in [("type_name_first", true), ("type_name_last", false)] {
+        let disks: Vec<String> = (0..1000).map(|index| disk(0, index, type_first)).collect();
+        let payload = format!("[{}]", disks.join(","));
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        group.bench_with_input(BenchmarkId::new("vim_any", order), payload.as_bytes(), |b, payload| {
+            b.iter(|| serde_json::from_slice::<Vec<VimAny>>(payload).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("trait_object", order), payload.as_bytes(), |b, payload| {
+            b.iter(|| serde_json::from_slice::<Vec<Box<dyn VirtualDeviceTrait>>>(payload).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// Transport answering the service content request and serving `payload` otherwise, either
+/// completely or in chunks of `CHUNK_SIZE` bytes.
+struct PayloadTransport {
+    payload: Arc<Vec<u8>>,
+    streamed: bool,
+}
+
+/// Chunks of the payload copied as they are "received".
+struct PayloadStream {
+    payload: Arc<Vec<u8>>,
+    offset: usize,
+}
+
+impl BodyStream for PayloadStream {
+    fn next_chunk(&mut self) -> BoxFuture<'_, Result<Option<bytes::Bytes>>> {
+        let end = (self.offset + CHUNK_SIZE).min(self.payload.len());
+        let chunk = (self.offset < end).then(|| bytes::Bytes::copy_from_slice(&self.payload[self.offset..end]));
+        self.offset = end;
+        Box::pin(async move { Ok(chunk) })
+    }
+}
+
+impl HttpTransport for PayloadTransport {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let body = if request.uri().path().ends_with("/content") {
+            Body::from(object(
+                "ServiceContent",
+                &[
+                    ("rootFolder", moref("Folder", "group-d1", true)),
+                    ("propertyCollector", moref("PropertyCollector", "propertyCollector", true)),
+                    ("about", object("AboutInfo", &[
+                        ("name", string("VMware vCenter Server")),
+                        ("fullName", string("VMware vCenter Server 8.0.2")),
+                        ("vendor", string("VMware, Inc.")),
+                        ("version", string("8.0.2")),
+                        ("build", string("1")),
+                        ("osType", string("linux-x64")),
+                        ("productLineId", string("vpx")),
+                        ("apiType", string("VirtualCenter")),
+                        ("apiVersion", string("8.0.2.0")),
+                    ], true)),
+                ],
+                true,
+            ))
+        } else if self.streamed {
+            Body::Stream(Box::new(PayloadStream { payload: self.payload.clone(), offset: 0 }))
+        } else {
+            Body::from(self.payload.to_vec())
+        };
+        Box::pin(async move { Ok(http::Response::builder().status(200).body(body)?) })
+    }
+}
+
+/// Print the peak heap usage of deserializing the property collector result through the client
+/// with a buffered and a streamed response body.
+fn report_peak_memory() {
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+    for vms in INVENTORY_SIZES {
+        let payload = Arc::new(retrieve_result(vms, true).into_bytes());
+        let mut peaks = Vec::new();
+        for streamed in [false, true] {
+            let transport = Arc::new(PayloadTransport { payload: payload.clone(), streamed });
+            let client = runtime.block_on(
+                ClientBuilder::new("vc.local").api_release(API_RELEASE).http_transport(transport).build()
+            ).unwrap();
+            let base = ALLOCATED.load(Ordering::Relaxed);
+            PEAK.store(base, Ordering::Relaxed);
+            let req = client.post_bare("/PropertyCollector/propertyCollector/RetrievePropertiesEx");
+            let content: Vec<ObjectContent> = runtime.block_on(client.execute(req)).unwrap();
+            peaks.push(PEAK.load(Ordering::Relaxed).saturating_sub(base));
+            drop(content);
+        }
+        println!(
+            "peak memory retrieve_result/{vms} ({} KiB body): buffered {} KiB, streamed {} KiB",
+            payload.len() / 1024,
+            peaks[0] / 1024,
+            peaks[1] / 1024,
+        );
+    }
+}
+
+/// Compare the response body decoders of `Client::execute`. `buffered` collects the body and
+/// parses it with `serde_json::from_slice` as the client did before bodies were streamed.
+/// `streamed` feeds the chunks to `serde_json::from_reader` in a blocking task.
+fn bench_client_execute(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+    let mut group = c.benchmark_group("client_execute");
+    for vms in INVENTORY_SIZES {
+        let payload = Arc::new(retrieve_result(vms, true).into_bytes());
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        for (decoder, streamed) in [("buffered", false), ("streamed", true)] {
+            let transport = Arc::new(PayloadTransport { payload: payload.clone(), streamed });
+            let client = runtime.block_on(
+                ClientBuilder::new("vc.local").api_release(API_RELEASE).http_transport(transport).build()
+            ).unwrap();
+            group.bench_function(BenchmarkId::new(decoder, vms), |b| {
+                b.iter(|| {
+                    let req = client.post_bare("/PropertyCollector/propertyCollector/RetrievePropertiesEx");
+                    runtime.block_on(client.execute::<Vec<ObjectContent>>(req)).unwrap()
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_retrieve_result, bench_polymorphic, bench_client_execute);
+
+fn main() {
+    report_peak_memory();
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+}
@@ -1,5 +1,50 @@
+/// Managed object features enabling each group of data types, one line per group. Generated by
+/// vim_build together with the `#[cfg(vim_group = "N")]` attributes of the data types.
+const TYPE_GROUPS: &str = "type_groups.txt";
+
+/// Files generated by vim_build from the specification that are only needed by a feature e.g. the
+/// JSON Schema embedded by `json-schema`.
+const FEATURE_FILES: &[(&str, &str)] = &[
+    ("json-schema", "schema/vim.schema.json"),
+    ("arbitrary", "src/types/arbitrary.rs"),
+    ("reflection", "src/types/type_tables.rs"),
+];
+
 fn main() {
     // Capture Rustc version at compile time. Used in core::client to set the User-Agent header.
     let version = rustc_version::version().unwrap();
     println!("cargo:rustc-env=RUSTC_VERSION={}", version);
-}
\ No newline at end of file
+
+    for (feature, file) in FEATURE_FILES {
+        if std::env::var_os(feature_env(feature)).is_some() && !std::path::Path::new(file).exists() {
+            panic!("{file} not found. The {feature} feature needs it generated by vim_build, see \"Generating Bindings\" in the README");
+        }
+    }
+
+    // Compile the data types reachable from the enabled managed object features. See
+    // vim_build/src/vim_model/features.rs for how the types are grouped.
+    let Ok(type_groups) = std::fs::read_to_string(TYPE_GROUPS) else {
+        // Sources generated without the table carry no group attributes i.e. all data types are
+        // compiled whatever the features.
+        println!("cargo:warning={TYPE_GROUPS} not found, compiling all data types");
+        return;
+    };
+    let groups: Vec<Vec<&str>> = type_groups
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    let values: Vec<String> = (0..groups.len()).map(|group| format!("\"{group}\"")).collect();
+    println!("cargo:rustc-check-cfg=cfg(vim_group, values({}))", values.join(", "));
+    let full = std::env::var_os("CARGO_FEATURE_FULL").is_some();
+    for (group, features) in groups.iter().enumerate() {
+        if full || features.iter().any(|feature| std::env::var_os(feature_env(feature)).is_some()) {
+            println!("cargo:rustc-cfg=vim_group=\"{group}\"");
+        }
+    }
+}
+
+/// Environment variable cargo sets for an enabled feature e.g. `CARGO_FEATURE_MO_VIRTUAL_MACHINE`.
+fn feature_env(feature: &str) -> String {
+    format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"))
+}
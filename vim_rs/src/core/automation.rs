@@ -0,0 +1,115 @@
+//! Support for calling the vSphere Automation REST API under `/api` with the VI/JSON client. The
+//! Automation API accepts the `vmware-api-session-id` of the VI/JSON session so a logged in
+//! `Client` can call both APIs. Requests are prepared with `Client::api_request`.
+
+use std::fmt::Display;
+
+/// Error reported by the Automation API e.g.
+/// `{"error_type": "NOT_FOUND", "messages": [{"id": "...", "default_message": "..."}]}`.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize)]
+pub struct ApiError {
+    /// Category of the error e.g. `NOT_FOUND` or `UNAUTHENTICATED`.
+    #[serde(default)]
+    pub error_type: Option<String>,
+    /// Localizable messages describing the error.
+    #[serde(default)]
+    pub messages: Vec<ApiMessage>,
+    /// Additional data specific to the error.
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+/// Localizable message of an Automation API error.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize)]
+pub struct ApiMessage {
+    /// Identifier of the localizable string.
+    pub id: String,
+    /// The message in English.
+    pub default_message: String,
+    /// Arguments of the message.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error_type.as_deref().unwrap_or("ERROR"))?;
+        for message in &self.messages {
+            write!(f, ": {}", message.default_message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent encode a value for use as path segment or query component. Unreserved characters as
+/// defined by RFC 3986 are kept.
+pub fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Append a query parameter to the URL. Strings, numbers and booleans are added as is, arrays as
+/// repeated parameters and objects as `name.field` parameters. Null values are skipped.
+pub(crate) fn append_query(url: &mut String, name: &str, value: &serde_json::Value) {
+    use serde_json::Value;
+    let scalar = match value {
+        Value::Null => return,
+        Value::String(value) => value.clone(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Array(values) => {
+            for value in values {
+                append_query(url, name, value);
+            }
+            return;
+        }
+        Value::Object(fields) => {
+            for (field, value) in fields {
+                append_query(url, &format!("{name}.{field}"), value);
+            }
+            return;
+        }
+    };
+    url.push(if url.contains('?') { '&' } else { '?' });
+    url.push_str(&encode_component(name));
+    url.push('=');
+    url.push_str(&encode_component(&scalar));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_append_query() {
+        let mut url = "/api/cis/tagging/tag-association?action=list-attached-tags".to_string();
+        append_query(&mut url, "ids", &json!(["urn:a", "b c"]));
+        append_query(&mut url, "limit", &json!(10));
+        append_query(&mut url, "missing", &json!(null));
+        append_query(&mut url, "filter", &json!({"enabled": true}));
+        assert_eq!(
+            url,
+            "/api/cis/tagging/tag-association?action=list-attached-tags&ids=urn%3Aa&ids=b%20c&limit=10&filter.enabled=true"
+        );
+        let mut url = "/api/vcenter/vm".to_string();
+        append_query(&mut url, "names", &json!(["web"]));
+        assert_eq!(url, "/api/vcenter/vm?names=web");
+    }
+
+    #[test]
+    fn test_api_error() {
+        let error: ApiError = serde_json::from_str(
+            r#"{"error_type": "NOT_FOUND", "messages": [{"id": "tag.not.found", "default_message": "Tag not found", "args": ["t1"]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(error.messages[0].args, vec!["t1"]);
+        assert_eq!(error.to_string(), "NOT_FOUND: Tag not found");
+    }
+}
@@ -0,0 +1,109 @@
+//! Synchronous client for programs that do not run an async runtime e.g. CLI tools and build
+//! scripts. Enabled with the `blocking` feature.
+//!
+//! The `blocking::Client` is configured with the same `ClientBuilder` and drives the async client
+//! on a private single threaded runtime, similar to `reqwest::blocking`. The stubs in
+//! `mo::blocking` mirror the async stubs in `mo` with synchronous methods:
+//!
+//! ```rust
+//! let client = ClientBuilder::new(&vc_server)
+//!     .basic_authn(&username, &pwd)
+//!     .build_blocking()?;
+//! let service_instance = mo::blocking::ServiceInstance::new(client.clone(), "ServiceInstance");
+//! let server_time = service_instance.current_time()?;
+//! ```
+//!
+//! The blocking client must not be created, used or dropped from within an async runtime.
+use std::future::Future;
+use std::sync::Arc;
+
+use log::debug;
+use tokio::runtime::Runtime;
+
+use super::client::{self, ClientBuilder, Error, Result};
+use super::throttle::ThrottleStats;
+use crate::types::structs::ServiceContent;
+
+impl ClientBuilder {
+    /// Build a synchronous client instance. Blocks until the API release is negotiated and the
+    /// session is created.
+    pub fn build_blocking(self) -> Result<Arc<Client>> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(Error::BlockingRuntime)?;
+        let inner = runtime.block_on(self.build())?;
+        Ok(Arc::new(Client { inner, runtime }))
+    }
+}
+
+/// Synchronous client for the VI JSON API.
+///
+/// The session is logged out when the client is dropped.
+pub struct Client {
+    inner: Arc<client::Client>,
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Get the service instance content
+    pub fn service_content(&self) -> &ServiceContent {
+        self.inner.service_content()
+    }
+
+    /// Get the currently used API release. See `client::Client::api_release`.
+    pub fn api_release(&self) -> String {
+        self.inner.api_release()
+    }
+
+    /// Get the queueing statistics of the client side throttling.
+    pub fn throttle_stats(&self) -> ThrottleStats {
+        self.inner.throttle_stats()
+    }
+
+    /// The async client used by the async stubs the blocking stubs delegate to.
+    pub(crate) fn inner(&self) -> &Arc<client::Client> {
+        &self.inner
+    }
+
+    /// Run a call of an async stub to completion on the runtime of the client.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Logout the session on the runtime of the client. The async client finds the session key
+/// cleared when it is dropped afterwards and has nothing to do.
+impl Drop for Client {
+    fn drop(&mut self) {
+        debug!("Disposing blocking VIM client.");
+        self.runtime.block_on(self.inner.logout());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cassette::CassetteMode;
+    use crate::mo;
+
+    const CASSETTE: &str = r#"{"method": "GET", "path": "/ServiceInstance/ServiceInstance/content", "status": 200, "response_headers": [["content-type", "application/json"]], "response_body": "{\"_typeName\":\"ServiceContent\",\"rootFolder\":{\"_typeName\":\"ManagedObjectReference\",\"type\":\"Folder\",\"value\":\"group-d1\"},\"propertyCollector\":{\"_typeName\":\"ManagedObjectReference\",\"type\":\"PropertyCollector\",\"value\":\"propertyCollector\"},\"about\":{\"_typeName\":\"AboutInfo\",\"name\":\"VMware vCenter Server\",\"fullName\":\"VMware vCenter Server 8.0.2\",\"vendor\":\"VMware, Inc.\",\"version\":\"8.0.2\",\"build\":\"1\",\"osType\":\"linux-x64\",\"productLineId\":\"vpx\",\"apiType\":\"VirtualCenter\",\"apiVersion\":\"8.0.2.0\"}}"}
+{"method": "POST", "path": "/ServiceInstance/ServiceInstance/CurrentTime", "status": 200, "response_headers": [["content-type", "application/json"]], "response_body": "\"2024-05-01T10:00:00Z\""}
+"#;
+
+    #[test]
+    fn test_blocking_replay() {
+        let file = std::env::temp_dir().join(format!("vim_rs_blocking_{}.json", std::process::id()));
+        std::fs::write(&file, CASSETTE).unwrap();
+        let client = ClientBuilder::new("vc.local")
+            .api_release("8.0.2.0")
+            .cassette(&file, CassetteMode::Replay)
+            .build_blocking()
+            .unwrap();
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(client.service_content().root_folder.value, "group-d1");
+        let service_instance = mo::blocking::ServiceInstance::new(client.clone(), "ServiceInstance");
+        let current_time = service_instance.current_time().unwrap();
+        assert!(format!("{:?}", current_time).contains("2024-05-01"));
+    }
+}
@@ -0,0 +1,361 @@
+//! HTTP record and replay of client traffic. In `CassetteMode::Record` every exchange of the
+//! `Client` with the server is appended to a cassette file as a line of JSON. In `CassetteMode::Replay` the
+//! responses are served from the cassette without contacting a server which allows deterministic
+//! regression tests of `mo` calls and of the deserialization of real vCenter payloads.
+//!
+//! Requests are matched by HTTP method, managed object path i.e. the request path without the
+//! base path and the `/sdk/vim25/{release}` prefix, and JSON body. Bodies are compared as JSON
+//! values so key order and whitespace do not matter. Identical requests are replayed in the order they were recorded.
+//! Passwords, session keys and similar secrets are redacted before anything is written.
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use super::client::{Error, Result};
+use super::runtime::{Body, HttpRequest, HttpResponse, HttpTransport};
+use super::telemetry::{redact_json, REDACTED};
+
+/// Response headers whose values are redacted in the cassette.
+const SECRET_HEADERS: [&str; 3] = ["vmware-api-session-id", "set-cookie", "authorization"];
+
+const VIM_PATH_PREFIX: &str = "/sdk/vim25/";
+
+/// Whether the client records traffic into a cassette or replays it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Execute requests against the server and append the exchanges to the cassette file. An
+    /// existing file is truncated.
+    Record,
+    /// Serve responses from the cassette file without contacting a server.
+    Replay,
+}
+
+/// A recorded request and the response to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// HTTP method e.g. `POST`.
+    pub method: String,
+    /// Request path without the base path and the `/sdk/vim25/{release}` prefix including the
+    /// query if any e.g. `/Folder/group-d1/CreateFolder`.
+    pub path: String,
+    /// Redacted JSON request body. Non-JSON bodies are kept as a string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// Redacted response headers in the order received. Repeated headers such as `set-cookie`
+    /// are kept as separate entries.
+    #[serde(default)]
+    pub response_headers: Vec<(String, String)>,
+    /// Redacted response body.
+    #[serde(default)]
+    pub response_body: String,
+}
+
+/// Cassette shared by the clients created by one `ClientBuilder`.
+pub(crate) struct Cassette {
+    mode: CassetteMode,
+    /// File the interactions are appended to in record mode.
+    recording: Option<Mutex<File>>,
+    state: Mutex<CassetteState>,
+}
+
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// Open the cassette file. In replay mode the file must exist.
+    pub(crate) fn open(file: &Path, mode: CassetteMode) -> Result<Self> {
+        match mode {
+            CassetteMode::Record => {
+                let mut cassette = Self::new(mode, Vec::new());
+                cassette.recording = Some(Mutex::new(File::create(file)?));
+                Ok(cassette)
+            }
+            CassetteMode::Replay => {
+                let mut interactions = Vec::new();
+                for line in BufReader::new(File::open(file)?).lines() {
+                    let line = line?;
+                    if !line.trim().is_empty() {
+                        interactions.push(serde_json::from_str(&line)?);
+                    }
+                }
+                debug!("Loaded {} interactions from cassette {}", interactions.len(), file.display());
+                Ok(Self::new(mode, interactions))
+            }
+        }
+    }
+
+    fn new(mode: CassetteMode, interactions: Vec<Interaction>) -> Self {
+        let used = vec![false; interactions.len()];
+        Self {
+            mode,
+            recording: None,
+            state: Mutex::new(CassetteState { interactions, used }),
+        }
+    }
+
+    pub(crate) fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    async fn exchange(&self, transport: &dyn HttpTransport, req: HttpRequest) -> Result<HttpResponse> {
+        let method = req.method().to_string();
+        let path = normalize_path(req.uri());
+        let request_body = normalize_body(req.body());
+        match self.mode {
+            CassetteMode::Replay => self.replay(&method, &path, &request_body),
+            CassetteMode::Record => {
+                // The body is recorded so streamed bodies are read completely
+                let (parts, body) = transport.execute(req).await?.into_parts();
+                let body = body.collect().await?;
+                let interaction = Interaction {
+                    method,
+                    path,
+                    request_body,
+                    status: parts.status.as_u16(),
+                    response_headers: redact_headers(&parts.headers),
+                    response_body: redact_body(&body),
+                };
+                self.record(interaction)?;
+                Ok(HttpResponse::from_parts(parts, Body::Full(body)))
+            }
+        }
+    }
+
+    fn replay(&self, method: &str, path: &str, request_body: &Option<serde_json::Value>) -> Result<HttpResponse> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let CassetteState { interactions, used } = &mut *state;
+        let index = interactions.iter().zip(used.iter()).position(|(interaction, used)| {
+            !used && interaction.method == method && interaction.path == path && &interaction.request_body == request_body
+        });
+        let Some(index) = index else {
+            return Err(Error::NoRecordedInteraction(format!("{} {}", method, path)));
+        };
+        used[index] = true;
+        let interaction = &interactions[index];
+        debug!("Replaying cassette interaction {} for {} {}", index, method, path);
+        let mut builder = http::Response::builder().status(interaction.status);
+        for (name, value) in &interaction.response_headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(Body::from(interaction.response_body.clone()))
+            .map_err(|e| Error::NoRecordedInteraction(format!("{} {}: {}", method, path, e)))?;
+        Ok(response)
+    }
+
+    /// Append the interaction to the cassette file. Each interaction is written as one line in a
+    /// single write so the file is complete even if the process does not terminate gracefully.
+    fn record(&self, interaction: Interaction) -> Result<()> {
+        let Some(recording) = &self.recording else {
+            return Ok(());
+        };
+        let mut line = serde_json::to_vec(&interaction)?;
+        line.push(b'\n');
+        recording.lock().unwrap_or_else(|e| e.into_inner()).write_all(&line)?;
+        Ok(())
+    }
+}
+
+/// Execute the request through the cassette if one is configured or directly otherwise.
+pub(crate) async fn execute(transport: &dyn HttpTransport, cassette: Option<&Cassette>, req: HttpRequest) -> Result<HttpResponse> {
+    match cassette {
+        Some(cassette) => cassette.exchange(transport, req).await,
+        None => transport.execute(req).await,
+    }
+}
+
+/// Strip the base path and the `/sdk/vim25/{release}` prefix so that cassettes can be replayed
+/// with a different negotiated release or endpoint.
+fn normalize_path(url: &http::Uri) -> String {
+    let path = url.path();
+    let path = match path.find(VIM_PATH_PREFIX) {
+        Some(start) => {
+            let rest = &path[start + VIM_PATH_PREFIX.len()..];
+            rest.find('/').map_or(path, |i| &rest[i..])
+        }
+        None => path,
+    };
+    match url.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path.to_string(),
+    }
+}
+
+fn normalize_body(body: &[u8]) -> Option<serde_json::Value> {
+    if body.is_empty() {
+        return None;
+    }
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            Some(value)
+        }
+        Err(_) => Some(serde_json::Value::String(String::from_utf8_lossy(body).into_owned())),
+    }
+}
+
+fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+fn redact_headers(headers: &http::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+// The tests run on tokio which is not available on wasm32
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::core::runtime::BoxFuture;
+
+    /// Transport of a client that must not contact a server.
+    struct OfflineTransport;
+
+    impl HttpTransport for OfflineTransport {
+        fn execute(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            panic!("unexpected request in replay mode")
+        }
+    }
+
+    fn interaction(path: &str, request_body: Option<serde_json::Value>, response_body: &str) -> Interaction {
+        Interaction {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            request_body,
+            status: 200,
+            response_headers: vec![("content-type".to_string(), "application/json".to_string())],
+            response_body: response_body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        let url: http::Uri = "https://vc.local/sdk/vim25/8.0.2.0/Folder/group-d1/CreateFolder".parse().unwrap();
+        assert_eq!(normalize_path(&url), "/Folder/group-d1/CreateFolder");
+        let url: http::Uri = "http://localhost:8989/vcenter-a/sdk/vim25/8.0.2.0/Folder/group-d1/CreateFolder".parse().unwrap();
+        assert_eq!(normalize_path(&url), "/Folder/group-d1/CreateFolder");
+        let url: http::Uri = "https://vc.local/api/vcenter/system?action=hello".parse().unwrap();
+        assert_eq!(normalize_path(&url), "/api/vcenter/system?action=hello");
+    }
+
+    #[test]
+    fn test_normalize_body() {
+        let body = normalize_body(br#"{"userName":"admin","password":"s3cr3t"}"#).unwrap();
+        assert_eq!(body, serde_json::json!({"password": REDACTED, "userName": "admin"}));
+        assert_eq!(normalize_body(b""), None);
+    }
+
+    /// Transport answering every request with the same body.
+    struct CannedTransport(&'static str);
+
+    impl HttpTransport for CannedTransport {
+        fn execute(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let body = self.0;
+            Box::pin(async move { Ok(http::Response::builder().status(200).body(Body::from(body))?) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_replay_secret_object() {
+        let file = std::env::temp_dir().join(format!("vim_rs_cassette_{}.json", std::process::id()));
+        let url = "https://vc.local/sdk/vim25/8.0.2.0/CustomizationSpecManager/CustomizationSpecManager/CheckCustomizationResources";
+        let request_body = br#"{"guiUnattended":{"_typeName":"CustomizationGuiUnattended","password":{"_typeName":"CustomizationPassword","value":"s3cr3t","plainText":true},"timeZone":85,"autoLogon":false,"autoLogonCount":1}}"#;
+        let response_body = r#"{"_typeName":"CustomizationGuiUnattended","password":{"_typeName":"CustomizationPassword","value":"s3cr3t","plainText":true},"timeZone":85,"autoLogon":false,"autoLogonCount":1}"#;
+
+        let recorder = Cassette::open(&file, CassetteMode::Record).unwrap();
+        let req = http::Request::post(url).body(request_body.to_vec()).unwrap();
+        execute(&CannedTransport(response_body), Some(&recorder), req).await.unwrap();
+        assert!(!std::fs::read_to_string(&file).unwrap().contains("s3cr3t"));
+
+        let player = Cassette::open(&file, CassetteMode::Replay).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        let req = http::Request::post(url).body(request_body.to_vec()).unwrap();
+        let res = execute(&OfflineTransport, Some(&player), req).await.unwrap();
+        let gui: crate::types::structs::CustomizationGuiUnattended = serde_json::from_slice(&res.into_body().collect().await.unwrap()).unwrap();
+        let password = gui.password.unwrap();
+        assert_eq!(password.value, REDACTED);
+        assert!(password.plain_text);
+        assert_eq!(gui.time_zone, 85);
+    }
+
+    /// Transport answering with repeated response headers.
+    struct MultiHeaderTransport;
+
+    impl HttpTransport for MultiHeaderTransport {
+        fn execute(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            Box::pin(async move {
+                Ok(http::Response::builder()
+                    .status(200)
+                    .header("set-cookie", "a=1")
+                    .header("set-cookie", "b=2")
+                    .header("link", "</a>")
+                    .header("link", "</b>")
+                    .body(Body::from("{}"))?)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_appends_repeated_headers() {
+        let file = std::env::temp_dir().join(format!("vim_rs_cassette_headers_{}.json", std::process::id()));
+        let url = "https://vc.local/sdk/vim25/8.0.2.0/SessionManager/SessionManager/currentSession";
+        let recorder = Cassette::open(&file, CassetteMode::Record).unwrap();
+        for _ in 0..2 {
+            let req = http::Request::get(url).body(Vec::new()).unwrap();
+            execute(&MultiHeaderTransport, Some(&recorder), req).await.unwrap();
+        }
+        assert_eq!(std::fs::read_to_string(&file).unwrap().lines().count(), 2);
+
+        let player = Cassette::open(&file, CassetteMode::Replay).unwrap();
+        std::fs::remove_file(&file).unwrap();
+        for _ in 0..2 {
+            let req = http::Request::get(url).body(Vec::new()).unwrap();
+            let res = execute(&OfflineTransport, Some(&player), req).await.unwrap();
+            let links: Vec<_> = res.headers().get_all("link").iter().collect();
+            assert_eq!(links, ["</a>", "</b>"]);
+            let cookies: Vec<_> = res.headers().get_all("set-cookie").iter().collect();
+            assert_eq!(cookies, [REDACTED, REDACTED]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_in_order() {
+        let cassette = Cassette::new(CassetteMode::Replay, vec![
+            interaction("/PropertyCollector/pc/WaitForUpdatesEx", Some(serde_json::json!({"version": ""})), r#"{"version":"1"}"#),
+            interaction("/PropertyCollector/pc/WaitForUpdatesEx", Some(serde_json::json!({"version": ""})), r#"{"version":"2"}"#),
+        ]);
+        let url = "https://vc.local/sdk/vim25/8.0.1.0/PropertyCollector/pc/WaitForUpdatesEx";
+        for expected in ["1", "2"] {
+            let req = http::Request::post(url).body(br#"{ "version" : "" }"#.to_vec()).unwrap();
+            let res = execute(&OfflineTransport, Some(&cassette), req).await.unwrap();
+            let value: serde_json::Value = serde_json::from_slice(&res.into_body().collect().await.unwrap()).unwrap();
+            assert_eq!(value["version"], expected);
+        }
+        let req = http::Request::post(url).body(Vec::new()).unwrap();
+        assert!(matches!(execute(&OfflineTransport, Some(&cassette), req).await, Err(Error::NoRecordedInteraction(_))));
+    }
+}
@@ -1,13 +1,28 @@
-use std::sync::Arc;
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc, PoisonError, RwLock};
+use async_lock::{Semaphore, SemaphoreGuardArc};
 
-use tokio::sync::RwLock;
 use super::super::types::structs;
 use log::{warn, debug, trace, log_enabled};
 use log::Level::Trace;
 
 use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use web_time::Instant;
 use crate::mo;
 use crate::types::structs::ServiceContent;
+use super::automation::{self, ApiError};
+use super::cassette::{self, Cassette, CassetteMode};
+use super::runtime::{Body, Executor, HttpRequest, HttpResponse, HttpTransport};
+#[cfg(any(feature = "tokio", feature = "wasm"))]
+use super::runtime::ReqwestTransport;
+#[cfg(feature = "tokio")]
+use super::runtime::TokioExecutor;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+use super::runtime::WasmExecutor;
+use super::metrics::{CallMetrics, MetricsSink};
+use super::telemetry::{self, redact_secrets, ApiCall};
+use super::throttle::{Limits, Throttle, ThrottleStats};
 
 const LIB_NAME: &str = env!("CARGO_PKG_NAME");
 const LIB_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -23,14 +38,29 @@ pub const API_RELEASE: &str = "8.0.2.0";
 /// The header key for the session key
 const AUTHN_HEADER: &str = "vmware-api-session-id";
 
+/// Maximum number of received body chunks queued for the decoding task. The body is not read
+/// further until the decoder catches up.
+const DECODE_QUEUE_CHUNKS: usize = 16;
+
 const SERVICE_INSTANCE_MOID: &str = "ServiceInstance";
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("MethodFault: {0:?}")]
     MethodFault(structs::MethodFault),
+    #[cfg(any(feature = "tokio", feature = "wasm"))]
     #[error("Reqwest error: {0}")]
     ReqwestError(#[from] reqwest::Error),
+    #[error("HTTP transport error: {0}")]
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    #[error("HTTP error: {0}")]
+    Http(#[from] http::Error),
+    #[error("HTTP status {0} returned by: {1}")]
+    HttpStatus(u16, String),
+    #[error("Automation API error (HTTP status {0}): {1}")]
+    Api(u16, ApiError),
+    #[error("No {0} configured. Enable the tokio feature or set one in the ClientBuilder")]
+    MissingRuntime(&'static str),
     #[error("Serde error: {0}")]
     SerdeError(#[from] serde_json::Error),
     #[error("Missing or Invalid session key")]
@@ -39,21 +69,43 @@ pub enum Error {
     InvalidObjectType(String, String),
     #[error("Cannot negotiate compatible API release. Attempted with: {0:?}")]
     CannotNegotiateAPIRelease(Vec<String>),
+    #[error("Cassette I/O error: {0}")]
+    CassetteIoError(#[from] std::io::Error),
+    #[error("No recorded interaction in cassette for: {0}")]
+    NoRecordedInteraction(String),
+    #[error("Invalid rate limit of {0} requests per second. The rate must be positive and finite")]
+    InvalidRateLimit(f64),
+    #[cfg(feature = "blocking")]
+    #[error("Cannot start the runtime of the blocking client: {0}")]
+    BlockingRuntime(std::io::Error),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub struct ClientBuilder {
     server_address: String,
+    scheme: Option<String>,
+    port: Option<u16>,
+    base_path: Option<String>,
+    #[cfg(feature = "tokio")]
+    proxy: Option<reqwest::Proxy>,
     compatible_api_releases: Option<Vec<String>>,
     api_release: Option<String>,
+    #[cfg(any(feature = "tokio", feature = "wasm"))]
     http_client: Option<reqwest::Client>,
+    #[cfg(feature = "tokio")]
     insecure: Option<bool>,
+    http_transport: Option<Arc<dyn HttpTransport>>,
+    executor: Option<Arc<dyn Executor>>,
     app_name: Option<String>,
     app_version: Option<String>,
     user_name: Option<String>,
     password: Option<String>,
     locale: Option<String>,
+    limits: Limits,
+    long_poll_limits: Limits,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
+    cassette: Option<(PathBuf, CassetteMode)>,
 }
 
 impl ClientBuilder {
@@ -63,18 +115,63 @@ impl ClientBuilder {
     pub fn new(server_address: &str) -> Self {
         Self {
             server_address: server_address.to_string(),
+            scheme: None,
+            port: None,
+            base_path: None,
+            #[cfg(feature = "tokio")]
+            proxy: None,
             compatible_api_releases: None,
             api_release: None,
+            #[cfg(any(feature = "tokio", feature = "wasm"))]
             http_client: None,
+            #[cfg(feature = "tokio")]
             insecure: None,
+            http_transport: None,
+            executor: None,
             app_name: None,
             app_version: None,
             user_name: None,
             password: None,
             locale: None,
+            limits: Limits::default(),
+            long_poll_limits: Limits::default(),
+            metrics_sink: None,
+            cassette: None,
         }
     }
 
+    /// Set the URL scheme. The default is `https`. Use `http` for local simulators.
+    /// * `scheme` - URL scheme i.e. `https` or `http`
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = Some(scheme.to_string());
+        self
+    }
+
+    /// Set the server port. The default is the port implied by the scheme.
+    /// * `port` - TCP port
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Set a path prefix under which the APIs are mounted e.g. by a reverse proxy. The hello
+    /// negotiation, the VI/JSON calls and the logout are all sent under this prefix.
+    /// * `base_path` - Path prefix e.g. `/vcenter-a`
+    pub fn base_path(mut self, base_path: &str) -> Self {
+        self.base_path = Some(base_path.to_string());
+        self
+    }
+
+    /// Send requests through an outbound HTTP(S) proxy or, with the `socks` feature, a SOCKS
+    /// proxy. This resets the http_client. A new reqwest::Client instance will be created instead.
+    /// * `proxy` - reqwest proxy configuration e.g. `reqwest::Proxy::all("http://proxy:3128")`
+    #[cfg(feature = "tokio")]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self.http_client = None;
+        self
+    }
+
     /// Set the compatible API releases. The default is set from the openapi spec. If `api_release`
     /// is not explicitly set then this value or `COMPATIBLE_API_RELEASES` will be used to call the
     /// vCenter [Hello System](https://developer.broadcom.com/xapis/vsphere-automation-api/latest/vcenter/api/vcenter/system__action=hello/post/index)
@@ -95,18 +192,24 @@ impl ClientBuilder {
     }
 
     /// Set the reqwest::Client instance to use for HTTP requests.
-    /// This resets the insecure flag. Use the http_client methods to set the certificate and
-    /// hostname verification behavior.
+    /// This resets the insecure flag and the proxy. Use the http_client methods to set the
+    /// certificate and hostname verification and the proxy behavior.
     /// * `http_client` - preconfigured reqwest::Client instance
+    #[cfg(any(feature = "tokio", feature = "wasm"))]
     pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
         self.http_client = Some(http_client);
-        self.insecure = None;
+        #[cfg(feature = "tokio")]
+        {
+            self.insecure = None;
+            self.proxy = None;
+        }
         self
     }
 
     /// Set the insecure flag to allow invalid certificates and hostnames.
     /// This resets the http_client. A new reqwest::Client instance will be created instead.
     /// * `insecure` - Allow invalid certificates and hostnames
+    #[cfg(feature = "tokio")]
     pub fn insecure(mut self, insecure: bool) -> Self {
         warn!("!!! WARNING !!! Insecure mode enabled. TLS certificate and hostname verification is disabled. !!! WARNING !!!");
         self.insecure = Some(insecure);
@@ -145,20 +248,159 @@ impl ClientBuilder {
         self
     }
 
+    /// Limit the number of API calls executing concurrently. Calls over the limit wait in a queue
+    /// until a slot is released. Long-poll calls have a separate budget set with
+    /// `long_poll_max_in_flight`.
+    /// * `max_in_flight` - Maximum number of concurrent requests
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.limits.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    /// Limit the rate of API calls with a token bucket. Long-poll calls have a separate budget set
+    /// with `long_poll_rate_limit`.
+    /// * `requests_per_second` - Sustained request rate. Must be positive and finite, `build`
+    ///   fails with `Error::InvalidRateLimit` otherwise
+    /// * `burst` - Number of requests that can be issued at once after a quiet period
+    pub fn rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.limits.rate_limit = Some((requests_per_second, burst));
+        self
+    }
+
+    /// Limit the number of long-poll calls (see `throttle::LONG_POLL_METHODS`) executing
+    /// concurrently. Long-poll calls do not consume the regular `max_in_flight` budget.
+    /// * `max_in_flight` - Maximum number of concurrent long-poll requests
+    pub fn long_poll_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.long_poll_limits.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    /// Limit the rate of long-poll calls (see `throttle::LONG_POLL_METHODS`) with a token bucket.
+    /// * `requests_per_second` - Sustained request rate. Must be positive and finite, `build`
+    ///   fails with `Error::InvalidRateLimit` otherwise
+    /// * `burst` - Number of requests that can be issued at once after a quiet period
+    pub fn long_poll_rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.long_poll_limits.rate_limit = Some((requests_per_second, burst));
+        self
+    }
+
+    /// Report per-method call metrics and session logins to the given sink. See
+    /// `metrics::MetricsFacadeSink` for an adapter to the `metrics` crate.
+    pub fn metrics_sink(mut self, metrics_sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics_sink = Some(metrics_sink);
+        self
+    }
+
+    /// Set the HTTP stack used to send requests e.g. to run under an executor other than tokio.
+    /// It takes precedence over `http_client`, `insecure` and `proxy`.
+    /// * `http_transport` - HTTP client implementation
+    pub fn http_transport(mut self, http_transport: Arc<dyn HttpTransport>) -> Self {
+        self.http_transport = Some(http_transport);
+        self
+    }
+
+    /// Set the async runtime used to log out when the client is dropped and to wait for the rate
+    /// limits. The default with the `tokio` feature is `TokioExecutor`.
+    /// * `executor` - Executor of the async runtime the client is used from
+    pub fn executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
+    /// Record the HTTP traffic of the client into a cassette file or replay it from one without
+    /// contacting the server. See the `cassette` module for how requests are matched.
+    /// * `file` - Path of the cassette file
+    /// * `mode` - Record or replay
+    pub fn cassette<P: AsRef<Path>>(mut self, file: P, mode: CassetteMode) -> Self {
+        self.cassette = Some((file.as_ref().to_path_buf(), mode));
+        self
+    }
+
+    /// Compose the endpoint URL i.e. scheme, authority and base path without a trailing slash
+    fn endpoint(&self) -> String {
+        let scheme = self.scheme.as_deref().unwrap_or("https");
+        let port = self.port.map(|port| format!(":{}", port)).unwrap_or_default();
+        let base_path = self.base_path.as_deref().unwrap_or_default().trim_matches('/');
+        let base_path = if base_path.is_empty() { String::new() } else { format!("/{}", base_path) };
+        format!("{}://{}{}{}", scheme, self.server_address, port, base_path)
+    }
+
+    /// HTTP transport on top of the configured or a new `reqwest::Client`.
+    #[cfg(any(feature = "tokio", feature = "wasm"))]
+    fn default_transport(&self) -> Result<Arc<dyn HttpTransport>> {
+        let http_client = match &self.http_client {
+            Some(client) => client.clone(),
+            None => self.new_http_client()?,
+        };
+        Ok(Arc::new(ReqwestTransport::new(http_client)))
+    }
+
+    #[cfg(not(any(feature = "tokio", feature = "wasm")))]
+    fn default_transport(&self) -> Result<Arc<dyn HttpTransport>> {
+        Err(Error::MissingRuntime("HTTP transport"))
+    }
+
+    /// Create a `reqwest::Client` with the insecure and proxy settings.
+    #[cfg(feature = "tokio")]
+    fn new_http_client(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::ClientBuilder::new();
+        if let Some(insecure) = self.insecure {
+            builder = builder.danger_accept_invalid_certs(insecure)
+                             .danger_accept_invalid_hostnames(insecure);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Create a `reqwest::Client` on top of the fetch API. Certificates and proxies are handled by
+    /// the browser or node.
+    #[cfg(all(feature = "wasm", not(feature = "tokio")))]
+    fn new_http_client(&self) -> Result<reqwest::Client> {
+        Ok(reqwest::Client::new())
+    }
+
+    #[cfg(feature = "tokio")]
+    fn default_executor(&self) -> Result<Arc<dyn Executor>> {
+        Ok(Arc::new(TokioExecutor))
+    }
+
+    #[cfg(all(feature = "wasm", target_arch = "wasm32", not(feature = "tokio")))]
+    fn default_executor(&self) -> Result<Arc<dyn Executor>> {
+        Ok(Arc::new(WasmExecutor))
+    }
+
+    #[cfg(not(any(feature = "tokio", all(feature = "wasm", target_arch = "wasm32"))))]
+    fn default_executor(&self) -> Result<Arc<dyn Executor>> {
+        Err(Error::MissingRuntime("executor"))
+    }
+
     /// Build the client instance
     pub async fn build(self) -> Result<Arc<Client>> {
-        let http_client = match self.http_client {
-            Some(client) => client,
-            None => {
-                let mut builder = reqwest::ClientBuilder::new();
-                if let Some(insecure) = self.insecure {
-                    builder = builder.danger_accept_invalid_certs(insecure)
-                                     .danger_accept_invalid_hostnames(insecure);
+        // The token bucket computes its waits from the rate
+        for limits in [&self.limits, &self.long_poll_limits] {
+            if let Some((rate, _)) = limits.rate_limit {
+                if !(rate > 0.0 && rate.is_finite()) {
+                    return Err(Error::InvalidRateLimit(rate));
                 }
-                builder.build()?
-            },
+            }
+        }
+        let endpoint = self.endpoint();
+        let transport = match &self.http_transport {
+            Some(transport) => transport.clone(),
+            None => self.default_transport()?,
+        };
+        let executor = match &self.executor {
+            Some(executor) => executor.clone(),
+            None => self.default_executor()?,
         };
         let session_key = Arc::new(RwLock::new(None));
+        let throttle = Arc::new(Throttle::new(&self.limits, &self.long_poll_limits, executor.clone()));
+        let cassette = match &self.cassette {
+            Some((file, mode)) => Some(Arc::new(Cassette::open(file, *mode)?)),
+            None => None,
+        };
 
         let user_agent = user_agent(self.app_name.as_deref(), self.app_version.as_deref());
 
@@ -171,14 +413,16 @@ impl ClientBuilder {
                 let spec = HelloSpec {
                     api_releases: &releases,
                 };
-                let path = format!("https://{}/api/vcenter/system?action=hello", self.server_address);
-                let req = http_client.post(&path)
+                let path = format!("{}/api/vcenter/system?action=hello", endpoint);
+                let req = http::Request::post(&path)
                     .header("Content-Type", "application/json")
                     .header("User-Agent", &user_agent)
-                    .json(&spec);
-                let res = req.send().await?;
-                let res = res.error_for_status()?;
-                let result: HelloResult = res.json().await?;
+                    .body(serde_json::to_vec(&spec)?)?;
+                let res = cassette::execute(transport.as_ref(), cassette.as_deref(), req).await?;
+                if !res.status().is_success() {
+                    return Err(Error::HttpStatus(res.status().as_u16(), path));
+                }
+                let result: HelloResult = serde_json::from_slice(&res.into_body().collect().await?)?;
                 let api_release = result.api_release;
                 // Throw error if api_release is empty string indicating no compatible API release
                 // was found.
@@ -190,14 +434,19 @@ impl ClientBuilder {
             },
         };
 
-        let base_url = format!("https://{}/sdk/vim25/{}", self.server_address, api_release);
+        let base_url = format!("{}/sdk/vim25/{}", endpoint, api_release);
 
         let bootstrap = Arc::new(Client {
-            http_client: http_client.clone(),
+            transport: transport.clone(),
+            executor: executor.clone(),
             session_key: session_key.clone(),
             api_release: api_release.clone(),
+            endpoint: endpoint.clone(),
             base_url: base_url.clone(),
             user_agent: user_agent.clone(),
+            throttle: throttle.clone(),
+            metrics_sink: self.metrics_sink.clone(),
+            cassette: cassette.clone(),
             service_content: None,
         });
 
@@ -208,11 +457,16 @@ impl ClientBuilder {
 
         let sm_id = content.session_manager.as_ref().map(|moid| moid.value.clone());
         let client = Arc::new(Client {
-            http_client: http_client.clone(),
+            transport: transport.clone(),
+            executor: executor.clone(),
             session_key: session_key.clone(),
             api_release: api_release.clone(),
+            endpoint: endpoint.clone(),
             base_url: base_url.clone(),
             user_agent: user_agent.clone(),
+            throttle: throttle.clone(),
+            metrics_sink: self.metrics_sink.clone(),
+            cassette: cassette.clone(),
             service_content: Some(content),
         });
 
@@ -226,19 +480,28 @@ impl ClientBuilder {
     }
 }
 
+/// Client for the VI JSON API that handles basic HTTP requests and authentication headers.
+/// 
+/// The client is responsible for managing the session key header and logging out the session when
+/// the client is dropped. The logout on drop runs on the `Executor` of the client. With
+/// `TokioExecutor` it completes before the drop returns on the multi-threaded runtime only. On the
+/// current thread runtime it is spawned and is lost if the runtime shuts down first, and outside
+/// of a runtime it is skipped. Call `logout().await` before dropping the client to end the
+/// session in these cases.
 pub struct Client {
-    http_client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
+    executor: Arc<dyn Executor>,
     session_key: Arc<RwLock<Option<String>>>,
     api_release: String,
+    endpoint: String,
     base_url: String,
     user_agent: String,
+    throttle: Arc<Throttle>,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
+    cassette: Option<Arc<Cassette>>,
     service_content: Option<ServiceContent>,
 }
 
-/// Client for the VI JSON API that handles basic HTTP requests and authentication headers.
-/// 
-/// The client is responsible for managing the session key header and logging out the session when
-/// the client is dropped.
 impl Client {
 
     /// Get the service instance content
@@ -255,56 +518,65 @@ impl Client {
         self.api_release.clone()
     }
 
+    /// Get the queueing statistics of the client side throttling configured with
+    /// `ClientBuilder::max_in_flight`, `ClientBuilder::rate_limit` and their long-poll variants.
+    pub fn throttle_stats(&self) -> ThrottleStats {
+        self.throttle.stats()
+    }
+
+    /// Compose the URL of a VI/JSON API path under the configured endpoint
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
     /// Prepare GET request
-    pub fn get_request(&self, path: &str) -> reqwest::RequestBuilder
+    pub fn get_request(&self, path: &str) -> Request
     {
         debug!("GET request: {}", path);
-        let url = format!("{}{}", self.base_url, path);
-        self.http_client.get(&url)
+        Request::new(http::Method::GET, self.url(path), Ok(None))
     }
 
     /// Prepare POST request with a body
-    pub fn post_request<B>(&self, path: &str, payload: &B) -> reqwest::RequestBuilder
+    pub fn post_request<B>(&self, path: &str, payload: &B) -> Request
     where
         B: serde::Serialize,
     {
         debug!("POST request: {}", path);
-        let url = format!("{}{}", self.base_url, path);
-        let req = self.http_client.post(&url);
-        req.header("Content-Type", "application/json").json(payload)
+        Request::new(http::Method::POST, self.url(path), serde_json::to_vec(payload).map(Some))
     }
 
     /// Prepare POST request without a body
-    pub fn post_bare(&self, path: &str) -> reqwest::RequestBuilder
+    pub fn post_bare(&self, path: &str) -> Request
     {
         debug!("POST request (void): {}", path);
-        let url = format!("{}{}", self.base_url, path);
-        self.http_client.post(&url)
+        Request::new(http::Method::POST, self.url(path), Ok(None))
+    }
+
+    /// Prepare a request to a vSphere Automation REST API path e.g. `/api/cis/tagging/tag`. The
+    /// request carries the session of the client so the REST API can be called once logged in.
+    /// Errors of the Automation API are returned as `Error::Api`. Paths with identifiers should
+    /// set `Request::path_template` to keep the identifiers out of metric labels.
+    pub fn api_request(&self, method: http::Method, path: &str) -> Request {
+        debug!("{} API request: {}", method, path);
+        let mut req = Request::new(method, format!("{}{}", self.endpoint, path), Ok(None));
+        req.automation = true;
+        req.template = Some(path.to_string());
+        req
     }
 
     /// Execute a request that returns a response body
-    pub async fn execute<T>(&self, mut req: reqwest::RequestBuilder) -> Result<T> 
-    where T: serde::de::DeserializeOwned 
+    pub async fn execute<T>(&self, req: Request) -> Result<T> 
+    where T: serde::de::DeserializeOwned + Send + 'static
     {
-        req = self.prepare(req).await;
-        let res = req.send().await?;
-        let res = self.process_response(res).await?;
-        let content: T = res.json().await?;
+        let content: T = self.round_trip(req).await??;
         Ok(content)
     }
 
     /// Execute a request that optionally returns a response body
-    pub async fn execute_option<T>(&self, mut req: reqwest::RequestBuilder) -> Result<Option<T>> 
-    where T: serde::de::DeserializeOwned 
+    pub async fn execute_option<T>(&self, req: Request) -> Result<Option<T>> 
+    where T: serde::de::DeserializeOwned + Send + 'static
     {
-        req = self.prepare(req).await;
-        let res = req.send().await?;
-        let res = self.process_response(res).await?;
-        let bytes = res.bytes().await?;
-        if log_enabled!(Trace) {
-            trace!("Response body: {}", std::str::from_utf8(&bytes).unwrap());
-        }
-        let r: serde_json::Result<T> = serde_json::from_slice(&bytes);
+        let r: serde_json::Result<T> = self.round_trip(req).await?;
         let content = match r {
             Ok(c) => Some(c),
             Err(e) => {
@@ -319,95 +591,415 @@ impl Client {
     }
 
     /// Execute a request that does not return a response body
-    pub async fn execute_void(&self, mut req: reqwest::RequestBuilder) -> Result<()> 
+    pub async fn execute_void(&self, req: Request) -> Result<()> 
     {
-        req = self.prepare(req).await;
-        let res = req.send().await?;
-        let _ = self.process_response(res).await?;
+        // Drain the body so the call completes with the response
+        let _: serde_json::Result<serde::de::IgnoredAny> = self.round_trip(req).await?;
         Ok(())
     }
 
+    /// Execute a request and deserialize the response body. With the `tracing` feature the call
+    /// is executed within a `vim.call` span.
+    async fn round_trip<T>(&self, req: Request) -> Result<serde_json::Result<T>>
+    where T: serde::de::DeserializeOwned + Send + 'static
+    {
+        let automation = req.automation;
+        // Automation API calls are identified by their path template to keep identifiers out of
+        // span names and metric labels
+        let route = req.template.as_ref().map(|template| format!("{} {template}", req.method));
+        let req = self.prepare(req)?;
+        let path = req.uri().path().to_string();
+        let call = match &route {
+            Some(route) => ApiCall::automation(route),
+            None => ApiCall::parse(&path),
+        };
+        #[cfg(feature = "tracing")]
+        let transfer = {
+            use tracing::Instrument;
+            let span = telemetry::call_span(&call, &self.api_release);
+            self.transfer(req, &call, automation).instrument(span)
+        };
+        #[cfg(not(feature = "tracing"))]
+        let transfer = self.transfer(req, &call, automation);
+        transfer.await
+    }
+
+    /// Wait for the throttling budget, send the request and deserialize the response body. The
+    /// throttling permit is held until the body is consumed.
+    async fn transfer<T>(&self, req: HttpRequest, call: &ApiCall<'_>, automation: bool) -> Result<serde_json::Result<T>>
+    where T: serde::de::DeserializeOwned + Send + 'static
+    {
+        #[cfg(feature = "opentelemetry")]
+        let req = telemetry::with_trace_context(req);
+        if log_enabled!(Trace) && !req.body().is_empty() {
+            trace!("Request body: {}", redact_secrets(req.body()));
+        }
+        let request_bytes = req.body().len() as u64;
+        let path = req.uri().path().to_string();
+        let _permit = self.throttle.acquire(&path).await;
+        // Time spent waiting for the throttling budget is not part of the call latency
+        let start = Instant::now();
+        let res = match cassette::execute(self.transport.as_ref(), self.cassette.as_deref(), req).await {
+            Ok(res) => res,
+            Err(e) => {
+                self.report(call, None, None, start, request_bytes, 0);
+                return Err(e);
+            }
+        };
+        let status = res.status().as_u16();
+        let (content, response_bytes) = self.read_response(res, automation).await;
+        let fault = content.as_ref().err().and_then(telemetry::fault_type);
+        self.report(call, Some(status), fault, start, request_bytes, response_bytes);
+        content
+    }
+
+    /// Process the response and deserialize its body. Successful responses are deserialized while
+    /// the body is received unless it is traced. Returns the outcome and the number of body bytes
+    /// read.
+    async fn read_response<T>(&self, res: HttpResponse, automation: bool) -> (Result<serde_json::Result<T>>, u64)
+    where T: serde::de::DeserializeOwned + Send + 'static
+    {
+        if let Err(e) = self.update_session_key(&res) {
+            return (Err(e), 0);
+        }
+        let status = res.status();
+        if status.is_success() && !log_enabled!(Trace) {
+            return self.decode(res.into_body()).await;
+        }
+        let bytes = match res.into_body().collect().await {
+            Ok(bytes) => bytes,
+            Err(e) => return (Err(e), 0),
+        };
+        let content_length = bytes.len() as u64;
+        if !status.is_success() {
+            return (Err(response_error(status, &bytes, automation)), content_length);
+        }
+        trace!("Response body: {}", redact_secrets(&bytes));
+        (Ok(serde_json::from_slice(&bytes)), content_length)
+    }
+
+    /// Deserialize the body while it is received. The body chunks are fed to a blocking task of
+    /// the executor so the complete body is not held in memory next to the deserialized value. At
+    /// most `DECODE_QUEUE_CHUNKS` chunks wait for the task; reading the body pauses until a permit
+    /// is released. Bodies of a single chunk and executors without blocking tasks are deserialized
+    /// once the body is received. Returns the outcome and the number of body bytes read.
+    ///
+    /// `serde_json::from_reader` is about half as fast as `from_slice` on the same bytes as it
+    /// copies every string out of the input. The `client_execute` group of the `deserialize`
+    /// benchmark compares both.
+    async fn decode<T>(&self, body: Body) -> (Result<serde_json::Result<T>>, u64)
+    where T: serde::de::DeserializeOwned + Send + 'static
+    {
+        let mut body = match body {
+            Body::Full(bytes) => return (Ok(serde_json::from_slice(&bytes)), bytes.len() as u64),
+            body => body,
+        };
+        let mut received = 0;
+        let mut chunks = VecDeque::new();
+        // Small responses arrive in one chunk
+        while chunks.len() < 2 {
+            match body.next_chunk().await {
+                Ok(Some(chunk)) => {
+                    received += chunk.len() as u64;
+                    chunks.push_back(chunk);
+                }
+                Ok(None) => break,
+                Err(e) => return (Err(e), received),
+            }
+        }
+        if chunks.len() < 2 {
+            let chunk = chunks.pop_front().unwrap_or_default();
+            return (Ok(serde_json::from_slice(&chunk)), received);
+        }
+
+        // The permits bound the queue so the channel never blocks the sending task. A permit is
+        // released when the decoder takes the chunk or drops the queue on failure.
+        let capacity = Arc::new(Semaphore::new(DECODE_QUEUE_CHUNKS));
+        let (chunk_sender, chunk_receiver) = mpsc::sync_channel::<(bytes::Bytes, SemaphoreGuardArc)>(DECODE_QUEUE_CHUNKS);
+        let (result_sender, result_receiver) = mpsc::channel();
+        let task = Box::new(move || {
+            let chunks = chunk_receiver.into_iter().map(|(chunk, _permit)| chunk);
+            let content = serde_json::from_reader(std::io::BufReader::new(ChunkReader::new(chunks)));
+            let _ = result_sender.send(content);
+        });
+        let Some(decoded) = self.executor.spawn_blocking(task) else {
+            loop {
+                match body.next_chunk().await {
+                    Ok(Some(chunk)) => {
+                        received += chunk.len() as u64;
+                        chunks.push_back(chunk);
+                    }
+                    Ok(None) => break,
+                    Err(e) => return (Err(e), received),
+                }
+            }
+            return (Ok(serde_json::from_reader(std::io::BufReader::new(ChunkReader::new(chunks.into_iter())))), received);
+        };
+        let mut next = chunks.pop_front();
+        let mut pumped = Ok(());
+        while let Some(chunk) = next {
+            let permit = capacity.acquire_arc().await;
+            // The task stops receiving once it fails
+            if chunk_sender.send((chunk, permit)).is_err() {
+                break;
+            }
+            next = match chunks.pop_front() {
+                Some(chunk) => Some(chunk),
+                None => match body.next_chunk().await {
+                    Ok(chunk) => {
+                        received += chunk.as_ref().map_or(0, |chunk| chunk.len() as u64);
+                        chunk
+                    }
+                    Err(e) => {
+                        pumped = Err(e);
+                        None
+                    }
+                },
+            };
+        }
+        drop(chunk_sender);
+        decoded.await;
+        if let Err(e) = pumped {
+            return (Err(e), received);
+        }
+        let content = result_receiver.try_recv()
+            .unwrap_or_else(|_| Err(serde::de::Error::custom("The deserialization of the response body was aborted")));
+        (Ok(content), received)
+    }
+
+    /// Record the outcome of a call in the current span and the metrics sink.
+    fn report(&self, call: &ApiCall, status: Option<u16>, fault: Option<&str>, start: Instant, request_bytes: u64, response_bytes: u64) {
+        let latency = start.elapsed();
+        telemetry::record_outcome(status, fault, latency);
+        if let Some(sink) = &self.metrics_sink {
+            sink.record_call(&CallMetrics {
+                method_path: &call.method_path(),
+                mo_type: call.mo_type,
+                method: call.method,
+                status,
+                fault,
+                latency,
+                request_bytes,
+                response_bytes,
+            });
+        }
+    }
+
     /// Add authn header to request
-    async fn prepare(&self, mut req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        let session_key = self.session_key.read().await;
-        if let Some(value) = session_key.as_ref() {
-            req = req.header(AUTHN_HEADER, value);
+    fn prepare(&self, req: Request) -> Result<HttpRequest> {
+        let body = req.body?;
+        let mut builder = http::Request::builder()
+            .method(req.method)
+            .uri(req.url)
+            .header("User-Agent", &self.user_agent);
+        if let Some(value) = self.session_key.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+            builder = builder.header(AUTHN_HEADER, value);
         }
-        req = req.header("User-Agent", &self.user_agent);
-        req
+        if body.is_some() {
+            builder = builder.header("Content-Type", "application/json");
+        }
+        Ok(builder.body(body.unwrap_or_default())?)
     }
 
-    /// Handle authn header update and error unmarsalling
-    async fn process_response(&self, res: reqwest::Response) -> Result<reqwest::Response> {
+    /// Handle authn header update
+    fn update_session_key(&self, res: &HttpResponse) -> Result<()> {
         if res.status().is_success() && res.headers().contains_key(AUTHN_HEADER) {
             let session_key = res.headers().get(AUTHN_HEADER).unwrap().to_str().map_err(|_| Error::MissingOrInvalidSessionKey)?.to_string();
-            let mut key_holder = self.session_key.write().await;
+            let mut key_holder = self.session_key.write().unwrap_or_else(PoisonError::into_inner);
+            if key_holder.as_deref() != Some(session_key.as_str()) {
+                if let Some(sink) = &self.metrics_sink {
+                    sink.record_session_login(key_holder.is_some());
+                }
+            }
             *key_holder = Some(session_key);
         }
-        if !res.status().is_success() {
-            warn!("HTTP error: {}", res.status());
-            let fault: structs::MethodFault = res.json().await?;
-            return Err(Error::MethodFault(fault));
+        Ok(())
+    }
+}
+
+/// Unmarshal the error of a failed call from the response body.
+fn response_error(status: http::StatusCode, body: &[u8], automation: bool) -> Error {
+    warn!("HTTP error: {}", status);
+    if automation {
+        // Errors such as 401 may come without a body
+        let error = serde_json::from_slice(body).unwrap_or_default();
+        return Error::Api(status.as_u16(), error);
+    }
+    match serde_json::from_slice::<structs::MethodFault>(body) {
+        Ok(fault) => Error::MethodFault(fault),
+        Err(e) => Error::SerdeError(e),
+    }
+}
+
+/// `Read` over body chunks that releases each chunk once it is consumed.
+struct ChunkReader<I> {
+    chunks: I,
+    current: bytes::Bytes,
+    /// Read position in `current`.
+    offset: usize,
+}
+
+impl<I: Iterator<Item = bytes::Bytes>> ChunkReader<I> {
+    fn new(chunks: I) -> Self {
+        Self { chunks, current: bytes::Bytes::new(), offset: 0 }
+    }
+}
+
+impl<I: Iterator<Item = bytes::Bytes>> std::io::Read for ChunkReader<I> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.offset == self.current.len() {
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.current = chunk;
+                    self.offset = 0;
+                }
+                None => return Ok(0),
+            }
         }
-        Ok(res)
+        let len = buf.len().min(self.current.len() - self.offset);
+        buf[..len].copy_from_slice(&self.current[self.offset..self.offset + len]);
+        self.offset += len;
+        Ok(len)
     }
 }
 
 
-/// Task called asynchronously during drop of the VimClient instance to logout the session if one
-/// was created.
+/// Logout the session if one was created when the VimClient instance is dropped. The logout is
+/// run by the executor of the client.
 impl Drop for Client {
     fn drop(&mut self) {
         debug!("Disposing VIM client.");
+        let Some(path) = self.logout_path() else {
+            return;
+        };
+        let Some(key) = self.take_session_key() else {
+            return;
+        };
+        self.executor.spawn(Box::pin(logout(self.transport.clone(), path, key)));
+    }
+}
 
-        let session_key = Arc::clone(&self.session_key);
-        let http_client = &self.http_client.clone();
-        let base_url = self.base_url.clone();
-
+impl Client {
+    /// URL of the logout method of the session manager. `None` if there is no session to log out.
+    fn logout_path(&self) -> Option<String> {
         let sm_id = self.service_content.as_ref().and_then(|content| content.session_manager.as_ref().map(|moid| moid.value.clone()));
-        let sm_id = match sm_id {
-            Some(id) => id,
-            None => {
-                debug!("No session manager found. Skipping logout.");
-                return;
-            },
+        let Some(sm_id) = sm_id else {
+            debug!("No session manager found. Skipping logout.");
+            return None;
         };
+        if self.cassette.as_ref().is_some_and(|cassette| cassette.mode() == CassetteMode::Replay) {
+            debug!("Replaying from cassette. Skipping logout.");
+            return None;
+        }
+        Some(self.url(&format!("/SessionManager/{moId}/Logout", moId = sm_id)))
+    }
 
-        tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async move {
-                debug!("Terminating VIM session as needed.");
-                let key = {
-                    let session_key = session_key.read().await;
-                    session_key.clone()
-                };
-                let Some(key) = key else {
-                    debug!("No session key present. Skipping logout.");
-                    return;
+    /// Clear the session key so the session is logged out only once.
+    fn take_session_key(&self) -> Option<String> {
+        let key = self.session_key.write().unwrap_or_else(PoisonError::into_inner).take();
+        if key.is_none() {
+            debug!("No session key present. Skipping logout.");
+        }
+        key
+    }
+
+    /// Logout the session if one was created and wait for the logout to complete. Dropping the
+    /// client afterwards has nothing left to log out.
+    pub async fn logout(&self) {
+        let Some(path) = self.logout_path() else {
+            return;
+        };
+        let Some(key) = self.take_session_key() else {
+            return;
+        };
+        logout(self.transport.clone(), path, key).await;
+    }
+}
+
+/// Send the logout request of the session.
+async fn logout(transport: Arc<dyn HttpTransport>, path: String, key: String) {
+    debug!("Session is present. Sending logout request...");
+    let req = match http::Request::post(&path).header(AUTHN_HEADER, key).body(Vec::new()) {
+        Ok(req) => req,
+        Err(e) => {
+            warn!("Failed to logout session. Cannot create logout request: {}", e);
+            return;
+        },
+    };
+    match transport.execute(req).await {
+        Ok(resp) => {
+            let status = resp.status();
+            if status.is_success() {
+                debug!("Session logged out successfully");
+            } else {
+                let body = match resp.into_body().collect().await {
+                    Ok(body) => body,
+                    Err(e) => {
+                        warn!("Failed to logout session(HTTP code: {}). Cannot read response: {}", status, e);
+                        return;
+                    }
                 };
-                debug!("Session is present. Sending logout request...");
-
-                let path = format!("{base_url}/SessionManager/{moId}/Logout",
-                                    base_url = base_url,
-                                    moId = sm_id);
-                let req = http_client.post(&path)
-                                        .header(AUTHN_HEADER, key);
-                match req.send().await {
-                    Ok(resp) => {
-                        let status = resp.status();
-                        if status.is_success() {
-                            debug!("Session logged out successfully");
-                        } else {
-                            resp.json::<structs::MethodFault>().await.map(|fault| {
-                                warn!("Failed to logout session(HTTP code: {}). MethodFault: {:?}", status, fault);
-                            }).unwrap_or_else(|e| {
-                                warn!("Failed to logout session(HTTP code: {}). Cannot parse MethodFault: {}", status, e);
-                            });
-                        }
-                    },
-                    Err(e) => warn!("Failed to logout session. Cannot execute logout request: {}", e),
-                }
-            });
-        });
+                serde_json::from_slice::<structs::MethodFault>(&body).map(|fault| {
+                    warn!("Failed to logout session(HTTP code: {}). MethodFault: {:?}", status, fault);
+                }).unwrap_or_else(|e| {
+                    warn!("Failed to logout session(HTTP code: {}). Cannot parse MethodFault: {}", status, e);
+                });
+            }
+        },
+        Err(e) => warn!("Failed to logout session. Cannot execute logout request: {}", e),
+    }
+}
+
+/// Request prepared by the managed object stubs and executed with `Client::execute` and its
+/// variants.
+pub struct Request {
+    method: http::Method,
+    url: String,
+    /// JSON body. Serialization errors are reported when the request is executed.
+    body: serde_json::Result<Option<Vec<u8>>>,
+    /// Request to the Automation API that reports errors as `ApiError`
+    automation: bool,
+    /// Path template identifying an Automation API call in spans and metrics.
+    template: Option<String>,
+}
+
+impl Request {
+    fn new(method: http::Method, url: String, body: serde_json::Result<Option<Vec<u8>>>) -> Self {
+        Self { method, url, body, automation: false, template: None }
+    }
+
+    /// Set the path template of an Automation API request e.g. `/api/cis/tagging/tag/{tag_id}`.
+    /// Spans and metrics identify the call by the HTTP method and the template instead of the
+    /// path given to `Client::api_request`, which may contain identifiers.
+    pub fn path_template(mut self, template: &str) -> Self {
+        if self.automation {
+            self.template = Some(template.to_string());
+        }
+        self
+    }
+
+    /// Append a query parameter. Strings, numbers and booleans are added as is, arrays as repeated
+    /// parameters and objects as `name.field` parameters. `None` values are skipped.
+    pub fn query<V>(mut self, name: &str, value: &V) -> Self
+    where
+        V: serde::Serialize + ?Sized,
+    {
+        match serde_json::to_value(value) {
+            Ok(value) => automation::append_query(&mut self.url, name, &value),
+            Err(e) => self.body = Err(e),
+        }
+        self
+    }
+
+    /// Set the JSON body of the request.
+    pub fn json<B>(mut self, payload: &B) -> Self
+    where
+        B: serde::Serialize + ?Sized,
+    {
+        if self.body.is_ok() {
+            self.body = serde_json::to_vec(payload).map(Some);
+        }
+        self
     }
 }
 
@@ -468,4 +1060,317 @@ struct HelloResult {
     /// be an empty string, e.g. "". Typically, this is a case where one of the parties is much
     /// older than the other party.
     api_release: String,
-}
\ No newline at end of file
+}
+
+// The tests run on tokio which is not available on wasm32
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::core::runtime::{BodyStream, BoxFuture};
+
+    #[test]
+    fn test_default_endpoint() {
+        assert_eq!(ClientBuilder::new("vc.local").endpoint(), "https://vc.local");
+    }
+
+    #[test]
+    fn test_custom_endpoint() {
+        let builder = ClientBuilder::new("localhost")
+            .scheme("http")
+            .port(8989)
+            .base_path("vcenter-a/");
+        assert_eq!(builder.endpoint(), "http://localhost:8989/vcenter-a");
+    }
+
+    const SERVICE_CONTENT: &str = r#"{"_typeName":"ServiceContent",
+        "rootFolder":{"_typeName":"ManagedObjectReference","type":"Folder","value":"group-d1"},
+        "propertyCollector":{"_typeName":"ManagedObjectReference","type":"PropertyCollector","value":"propertyCollector"},
+        "sessionManager":{"_typeName":"ManagedObjectReference","type":"SessionManager","value":"SessionManager"},
+        "about":{"_typeName":"AboutInfo","name":"VMware vCenter Server","fullName":"VMware vCenter Server 8.0.2",
+            "vendor":"VMware, Inc.","version":"8.0.2","build":"1","osType":"linux-x64","productLineId":"vpx",
+            "apiType":"VirtualCenter","apiVersion":"8.0.2.0"}}"#;
+
+    const NOT_FOUND: &str = r#"{"_typeName":"NotFound","faultMessage":[]}"#;
+
+    const USER_SESSION: &str = r#"{"_typeName":"UserSession","key":"52a1","userName":"admin","fullName":"Admin",
+        "loginTime":"2024-05-01T10:00:00Z","lastActiveTime":"2024-05-01T10:00:00Z","locale":"en",
+        "messageLocale":"en","extensionSession":false}"#;
+
+    /// Transport serving canned responses that records the requested paths.
+    #[derive(Default)]
+    struct FakeTransport {
+        paths: std::sync::Mutex<Vec<String>>,
+        /// Stream the bodies in small chunks
+        chunked: bool,
+    }
+
+    impl HttpTransport for FakeTransport {
+        fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let path = request.uri().path().to_string();
+            self.paths.lock().unwrap().push(path.clone());
+            let mut builder = http::Response::builder().status(200);
+            if path.starts_with("/api/") {
+                return Box::pin(async move { Ok(api_response(&request)?) });
+            }
+            let body = if path.ends_with("/content") {
+                SERVICE_CONTENT
+            } else if path.ends_with("/Login") {
+                builder = builder.header(AUTHN_HEADER, "session-1");
+                USER_SESSION
+            } else if path.ends_with("/CreateFolder") {
+                builder = builder.status(500);
+                NOT_FOUND
+            } else {
+                builder = builder.status(204);
+                ""
+            };
+            let body = if self.chunked { chunked(body) } else { Body::from(body) };
+            Box::pin(async move { Ok(builder.body(body)?) })
+        }
+    }
+
+    /// Body streamed from the given chunks.
+    struct ChunkedBody(std::vec::IntoIter<bytes::Bytes>);
+
+    impl BodyStream for ChunkedBody {
+        fn next_chunk(&mut self) -> BoxFuture<'_, Result<Option<bytes::Bytes>>> {
+            let chunk = self.0.next();
+            Box::pin(async move { Ok(chunk) })
+        }
+    }
+
+    fn chunked(body: &'static str) -> Body {
+        let chunks: Vec<_> = body.as_bytes().chunks(7).map(bytes::Bytes::copy_from_slice).collect();
+        Body::Stream(Box::new(ChunkedBody(chunks.into_iter())))
+    }
+
+    /// Response of the Automation API stand-in: the tags for the VI/JSON session and errors
+    /// otherwise.
+    fn api_response(request: &HttpRequest) -> http::Result<HttpResponse> {
+        let builder = http::Response::builder();
+        if request.headers().get(AUTHN_HEADER).is_none_or(|key| key != "session-1") {
+            return builder.status(401).body(Body::default());
+        }
+        if request.uri().path() != "/api/cis/tagging/tag" {
+            return builder.status(404).body(Body::from(r#"{"error_type":"NOT_FOUND","messages":[]}"#));
+        }
+        let body = format!(r#"["{}"]"#, request.uri().query().unwrap_or_default());
+        builder.status(200).body(Body::from(body))
+    }
+
+    /// Outcome of a call as reported to the metrics sink.
+    #[derive(Debug, PartialEq)]
+    struct RecordedCall {
+        method_path: String,
+        mo_type: String,
+        status: Option<u16>,
+        fault: Option<String>,
+        request_bytes: u64,
+        response_bytes: u64,
+    }
+
+    /// Metrics sink that keeps the reported calls.
+    #[derive(Default)]
+    struct RecordingSink {
+        calls: std::sync::Mutex<Vec<RecordedCall>>,
+    }
+
+    impl MetricsSink for RecordingSink {
+        fn record_call(&self, call: &CallMetrics) {
+            self.calls.lock().unwrap().push(RecordedCall {
+                method_path: call.method_path.to_string(),
+                mo_type: call.mo_type.to_string(),
+                status: call.status,
+                fault: call.fault.map(str::to_string),
+                request_bytes: call.request_bytes,
+                response_bytes: call.response_bytes,
+            });
+        }
+    }
+
+    /// Executor that keeps the spawned futures for the test to run.
+    #[derive(Default)]
+    struct FakeExecutor {
+        spawned: std::sync::Mutex<Vec<BoxFuture<'static, ()>>>,
+    }
+
+    impl Executor for FakeExecutor {
+        fn spawn(&self, future: BoxFuture<'static, ()>) {
+            self.spawned.lock().unwrap().push(future);
+        }
+
+        fn sleep(&self, _duration: std::time::Duration) -> BoxFuture<'static, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_runtime() {
+        let transport = Arc::new(FakeTransport::default());
+        let executor = Arc::new(FakeExecutor::default());
+        let client = ClientBuilder::new("vc.local")
+            .api_release(API_RELEASE)
+            .basic_authn("admin", "secret")
+            .http_transport(transport.clone())
+            .executor(executor.clone())
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.service_content().root_folder.value, "group-d1");
+        drop(client);
+        let spawned: Vec<_> = executor.spawned.lock().unwrap().drain(..).collect();
+        assert_eq!(spawned.len(), 1);
+        for future in spawned {
+            future.await;
+        }
+        assert_eq!(*transport.paths.lock().unwrap(), vec![
+            "/sdk/vim25/8.0.2.0/ServiceInstance/ServiceInstance/content",
+            "/sdk/vim25/8.0.2.0/SessionManager/SessionManager/Login",
+            "/sdk/vim25/8.0.2.0/SessionManager/SessionManager/Logout",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_explicit_logout() {
+        let transport = Arc::new(FakeTransport::default());
+        let executor = Arc::new(FakeExecutor::default());
+        let client = ClientBuilder::new("vc.local")
+            .api_release(API_RELEASE)
+            .basic_authn("admin", "secret")
+            .http_transport(transport.clone())
+            .executor(executor.clone())
+            .build()
+            .await
+            .unwrap();
+        client.logout().await;
+        drop(client);
+        assert!(executor.spawned.lock().unwrap().is_empty());
+        assert_eq!(transport.paths.lock().unwrap().last().unwrap(), "/sdk/vim25/8.0.2.0/SessionManager/SessionManager/Logout");
+        assert_eq!(transport.paths.lock().unwrap().len(), 3);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_streamed_response() {
+        // Deserialized on a blocking task and once received respectively
+        let executors: [Arc<dyn Executor>; 2] = [Arc::new(TokioExecutor), Arc::new(FakeExecutor::default())];
+        for executor in executors {
+            let transport = Arc::new(FakeTransport { chunked: true, ..Default::default() });
+            let client = ClientBuilder::new("vc.local")
+                .api_release(API_RELEASE)
+                .basic_authn("admin", "secret")
+                .http_transport(transport.clone())
+                .executor(executor)
+                .build()
+                .await
+                .unwrap();
+            assert_eq!(client.service_content().about.full_name, "VMware vCenter Server 8.0.2");
+            assert_eq!(transport.paths.lock().unwrap().len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_chunk_reader() {
+        use std::io::Read;
+        let chunks = ["{\"a\":", "", "[1,2]", "}"].map(|chunk| bytes::Bytes::from_static(chunk.as_bytes()));
+        let mut reader = ChunkReader::new(chunks.into_iter());
+        let mut buf = [0u8; 3];
+        let mut read = Vec::new();
+        loop {
+            match reader.read(&mut buf).unwrap() {
+                0 => break,
+                len => read.extend_from_slice(&buf[..len]),
+            }
+        }
+        assert_eq!(read, b"{\"a\":[1,2]}");
+    }
+
+    #[tokio::test]
+    async fn test_call_metrics() {
+        let sink = Arc::new(RecordingSink::default());
+        let client = ClientBuilder::new("vc.local")
+            .api_release(API_RELEASE)
+            .basic_authn("admin", "secret")
+            .http_transport(Arc::new(FakeTransport::default()))
+            .executor(Arc::new(FakeExecutor::default()))
+            .metrics_sink(sink.clone())
+            .build()
+            .await
+            .unwrap();
+        let req = client.post_request("/Folder/group-d1/CreateFolder", &serde_json::json!({"name": "vms"}));
+        let result: Result<structs::ManagedObjectReference> = client.execute(req).await;
+        assert!(matches!(result, Err(Error::MethodFault(_))));
+        let calls = sink.calls.lock().unwrap();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0], RecordedCall {
+            method_path: "/ServiceInstance/{moId}/content".to_string(),
+            mo_type: "ServiceInstance".to_string(),
+            status: Some(200),
+            fault: None,
+            request_bytes: 0,
+            response_bytes: SERVICE_CONTENT.len() as u64,
+        });
+        assert_eq!(calls[1].method_path, "/SessionManager/{moId}/Login");
+        assert_eq!(calls[1].status, Some(200));
+        assert!(calls[1].request_bytes > 0);
+        assert_eq!(calls[1].response_bytes, USER_SESSION.len() as u64);
+        assert_eq!(calls[2], RecordedCall {
+            method_path: "/Folder/{moId}/CreateFolder".to_string(),
+            mo_type: "Folder".to_string(),
+            status: Some(500),
+            fault: Some("NotFound".to_string()),
+            request_bytes: r#"{"name":"vms"}"#.len() as u64,
+            response_bytes: NOT_FOUND.len() as u64,
+        });
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rate_limit() {
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let transport = Arc::new(FakeTransport::default());
+            let result = ClientBuilder::new("vc.local")
+                .api_release(API_RELEASE)
+                .http_transport(transport.clone())
+                .executor(Arc::new(FakeExecutor::default()))
+                .long_poll_rate_limit(rate, 1)
+                .build()
+                .await;
+            assert!(matches!(result, Err(Error::InvalidRateLimit(r)) if r.to_bits() == rate.to_bits()));
+            assert!(transport.paths.lock().unwrap().is_empty());
+        }
+        let result = ClientBuilder::new("vc.local").rate_limit(0.0, 1).build().await;
+        assert!(matches!(result, Err(Error::InvalidRateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn test_api_request() {
+        let sink = Arc::new(RecordingSink::default());
+        let client = ClientBuilder::new("vc.local")
+            .api_release(API_RELEASE)
+            .basic_authn("admin", "secret")
+            .http_transport(Arc::new(FakeTransport::default()))
+            .executor(Arc::new(FakeExecutor::default()))
+            .metrics_sink(sink.clone())
+            .build()
+            .await
+            .unwrap();
+        let req = client.api_request(http::Method::GET, "/api/cis/tagging/tag").query("ids", &["a b", "c"]);
+        let tags: Vec<String> = client.execute(req).await.unwrap();
+        assert_eq!(tags, vec!["ids=a%20b&ids=c"]);
+        let req = client.api_request(http::Method::GET, "/api/cis/tagging/category");
+        let result: Result<Vec<String>> = client.execute(req).await;
+        assert!(matches!(result, Err(Error::Api(404, ApiError { error_type: Some(t), .. })) if t == "NOT_FOUND"));
+        let req = client
+            .api_request(http::Method::DELETE, "/api/cis/tagging/tag/urn%3Atag%3A1")
+            .path_template("/api/cis/tagging/tag/{tag_id}");
+        assert!(client.execute_void(req).await.is_err());
+        let calls = sink.calls.lock().unwrap();
+        let labels: Vec<_> = calls.iter().map(|call| (call.method_path.as_str(), call.fault.as_deref())).collect();
+        assert_eq!(labels[labels.len() - 3..], [
+            ("GET /api/cis/tagging/tag", None),
+            ("GET /api/cis/tagging/category", Some("NOT_FOUND")),
+            ("DELETE /api/cis/tagging/tag/{tag_id}", Some("NOT_FOUND")),
+        ]);
+        assert!(calls[calls.len() - 3..].iter().all(|call| call.mo_type.is_empty()));
+    }
+}
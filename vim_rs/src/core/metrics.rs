@@ -0,0 +1,93 @@
+//! Per-method client metrics. The `Client` reports every API call to the `MetricsSink` configured
+//! with `ClientBuilder::metrics_sink`. With the `metrics` feature `MetricsFacadeSink` forwards the
+//! measurements to the `metrics` crate facade from where they can be exported to Prometheus with
+//! `metrics-exporter-prometheus` or any other compatible recorder.
+use std::time::Duration;
+
+/// Measurements of a single API call.
+#[derive(Debug, Clone)]
+pub struct CallMetrics<'a> {
+    /// Method path in the format used by the `mo` stubs e.g. `/Folder/{moId}/CreateFolder`. The
+    /// managed object identifier is not included to keep the metric cardinality low. Automation
+    /// API calls use the HTTP method and path template e.g. `GET /api/cis/tagging/tag/{tag_id}`.
+    pub method_path: &'a str,
+    /// Managed object type e.g. `Folder`. Empty for Automation API calls.
+    pub mo_type: &'a str,
+    /// Method name e.g. `CreateFolder`. Same as `method_path` for Automation API calls.
+    pub method: &'a str,
+    /// HTTP status if a response was received.
+    pub status: Option<u16>,
+    /// Fault type name if the call failed with a `MethodFault` or the `error_type` of an
+    /// Automation API error.
+    pub fault: Option<&'a str>,
+    /// Time from sending the request until the response body was read. Throttling delays are
+    /// not included; they are reported by `Client::throttle_stats`.
+    pub latency: Duration,
+    /// Size of the request body.
+    pub request_bytes: u64,
+    /// Size of the response body.
+    pub response_bytes: u64,
+}
+
+/// Receiver of client metrics. Implementations must be cheap as they are invoked on the request
+/// path.
+pub trait MetricsSink: Send + Sync {
+    /// Record the outcome of an API call.
+    fn record_call(&self, call: &CallMetrics);
+
+    /// Record that the server issued a session key. `relogin` is true if a different session
+    /// key was already in use i.e. the session was re-established.
+    fn record_session_login(&self, relogin: bool) {
+        let _ = relogin;
+    }
+}
+
+/// Forward client metrics to the `metrics` crate facade.
+///
+/// The following metrics are emitted, all labelled with `method` (the method path):
+/// * `vim_client_requests_total` - counter of API calls
+/// * `vim_client_request_duration_seconds` - histogram of call latency
+/// * `vim_client_faults_total` - counter of failed calls additionally labelled with `fault`
+/// * `vim_client_request_bytes` and `vim_client_response_bytes` - histograms of payload sizes
+///
+/// Session logins are counted in `vim_client_session_logins_total` labelled with `relogin`.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Default)]
+pub struct MetricsFacadeSink;
+
+#[cfg(feature = "metrics")]
+impl MetricsSink for MetricsFacadeSink {
+    fn record_call(&self, call: &CallMetrics) {
+        let method = call.method_path.to_string();
+        metrics::counter!("vim_client_requests_total", "method" => method.clone()).increment(1);
+        metrics::histogram!("vim_client_request_duration_seconds", "method" => method.clone())
+            .record(call.latency.as_secs_f64());
+        metrics::histogram!("vim_client_request_bytes", "method" => method.clone())
+            .record(call.request_bytes as f64);
+        metrics::histogram!("vim_client_response_bytes", "method" => method.clone())
+            .record(call.response_bytes as f64);
+        if let Some(fault) = call.fault {
+            metrics::counter!("vim_client_faults_total", "method" => method, "fault" => fault.to_string())
+                .increment(1);
+        }
+    }
+
+    fn record_session_login(&self, relogin: bool) {
+        metrics::counter!("vim_client_session_logins_total", "relogin" => relogin.to_string()).increment(1);
+    }
+}
+
+/// Build the method path of a call in the format used by the `mo` stubs.
+pub(crate) fn method_path(mo_type: &str, method: &str) -> String {
+    format!("/{}/{{moId}}/{}", mo_type, method)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_path() {
+        assert_eq!(method_path("PropertyCollector", "RetrievePropertiesEx"), "/PropertyCollector/{moId}/RetrievePropertiesEx");
+    }
+}
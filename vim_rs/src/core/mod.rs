@@ -1,3 +1,10 @@
+pub mod automation;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cassette;
 pub mod client;
 pub(crate) mod helpers;
-
+pub mod metrics;
+pub mod runtime;
+pub mod telemetry;
+pub mod throttle;
@@ -0,0 +1,259 @@
+//! Abstractions of the async runtime and the HTTP stack the `Client` runs on. The client itself
+//! only uses executor agnostic locks and futures so it works under any executor given an
+//! `HttpTransport` and an `Executor`.
+//!
+//! With the default `tokio` feature `ReqwestTransport` and `TokioExecutor` are used unless others
+//! are set with `ClientBuilder::http_transport` and `ClientBuilder::executor`. On
+//! `wasm32-unknown-unknown` the `wasm` feature provides `ReqwestTransport` on top of the fetch API
+//! and `WasmExecutor` for the JavaScript event loop. Services built on async-std, smol or an
+//! embedded executor disable the default features and provide their own:
+//!
+//! ```rust
+//! struct SmolExecutor;
+//!
+//! impl Executor for SmolExecutor {
+//!     fn spawn(&self, future: BoxFuture<'static, ()>) {
+//!         smol::spawn(future).detach();
+//!     }
+//!
+//!     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+//!         Box::pin(async move {
+//!             smol::Timer::after(duration).await;
+//!         })
+//!     }
+//! }
+//! ```
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use bytes::Bytes;
+
+use super::client::Result;
+
+/// Boxed future as returned by the runtime abstractions.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Boxed future as returned by the runtime abstractions. JavaScript futures are not `Send`.
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// HTTP request with a complete body as sent by the client.
+pub type HttpRequest = http::Request<Vec<u8>>;
+
+/// HTTP response as processed by the client.
+pub type HttpResponse = http::Response<Body>;
+
+/// HTTP client used to exchange requests with vCenter. Implementations report connection and
+/// protocol failures as `Error::Transport`. HTTP error statuses are returned as responses.
+pub trait HttpTransport: Send + Sync {
+    /// Send the request and return the response once its headers are received. The body may be
+    /// read afterwards through a `Body::Stream`.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// Body of an `HttpResponse`. Streamed bodies let the client deserialize large responses such as
+/// property collector results while they are received instead of buffering them completely.
+pub enum Body {
+    /// The complete body.
+    Full(Bytes),
+    /// Body read chunk by chunk from the connection.
+    Stream(Box<dyn BodyStream>),
+}
+
+/// Source of the chunks of a streamed response body.
+pub trait BodyStream: Send {
+    /// The next chunk of the body or `None` at its end.
+    fn next_chunk(&mut self) -> BoxFuture<'_, Result<Option<Bytes>>>;
+}
+
+impl Body {
+    /// The next chunk of the body or `None` at its end.
+    pub async fn next_chunk(&mut self) -> Result<Option<Bytes>> {
+        match self {
+            Body::Full(bytes) if bytes.is_empty() => Ok(None),
+            Body::Full(bytes) => Ok(Some(std::mem::take(bytes))),
+            Body::Stream(stream) => stream.next_chunk().await,
+        }
+    }
+
+    /// Read the complete body.
+    pub async fn collect(self) -> Result<Bytes> {
+        let mut stream = match self {
+            Body::Full(bytes) => return Ok(bytes),
+            Body::Stream(stream) => stream,
+        };
+        let mut body = Vec::new();
+        while let Some(chunk) = stream.next_chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(body))
+    }
+}
+
+impl Default for Body {
+    fn default() -> Self {
+        Body::Full(Bytes::new())
+    }
+}
+
+impl From<Bytes> for Body {
+    fn from(bytes: Bytes) -> Self {
+        Body::Full(bytes)
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(bytes: Vec<u8>) -> Self {
+        Body::Full(Bytes::from(bytes))
+    }
+}
+
+impl From<String> for Body {
+    fn from(body: String) -> Self {
+        Body::Full(Bytes::from(body))
+    }
+}
+
+impl From<&'static str> for Body {
+    fn from(body: &'static str) -> Self {
+        Body::Full(Bytes::from_static(body.as_bytes()))
+    }
+}
+
+impl std::fmt::Debug for Body {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Body::Full(bytes) => f.debug_tuple("Full").field(bytes).finish(),
+            Body::Stream(_) => f.write_str("Stream"),
+        }
+    }
+}
+
+/// Task execution and timers of the async runtime.
+pub trait Executor: Send + Sync {
+    /// Run a future that the client does not wait for e.g. the logout of the session when the
+    /// client is dropped. The future may also be run to completion before returning.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Future completing after the given duration. Used by the client side rate limiting.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Run a task that blocks on a thread where blocking is allowed and return a future that
+    /// completes with the task. The client deserializes streamed response bodies in such tasks
+    /// while they are received. Runtimes without such threads return `None` without running the
+    /// task and the client deserializes the body once it is received.
+    fn spawn_blocking(&self, _task: Box<dyn FnOnce() + Send>) -> Option<BoxFuture<'static, ()>> {
+        None
+    }
+}
+
+/// `HttpTransport` on top of a `reqwest::Client`.
+#[cfg(any(feature = "tokio", feature = "wasm"))]
+pub struct ReqwestTransport {
+    http_client: reqwest::Client,
+}
+
+#[cfg(any(feature = "tokio", feature = "wasm"))]
+impl ReqwestTransport {
+    pub fn new(http_client: reqwest::Client) -> Self {
+        Self { http_client }
+    }
+}
+
+#[cfg(any(feature = "tokio", feature = "wasm"))]
+impl HttpTransport for ReqwestTransport {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let request = reqwest::Request::try_from(request)?;
+            let response = self.http_client.execute(request).await?;
+            let mut builder = http::Response::builder().status(response.status());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            let body = Body::Stream(Box::new(ReqwestBody(response)));
+            #[cfg(target_arch = "wasm32")]
+            let body = Body::Full(response.bytes().await?);
+            Ok(builder.body(body)?)
+        })
+    }
+}
+
+/// Streamed body of a reqwest response.
+#[cfg(all(any(feature = "tokio", feature = "wasm"), not(target_arch = "wasm32")))]
+struct ReqwestBody(reqwest::Response);
+
+#[cfg(all(any(feature = "tokio", feature = "wasm"), not(target_arch = "wasm32")))]
+impl BodyStream for ReqwestBody {
+    fn next_chunk(&mut self) -> BoxFuture<'_, Result<Option<Bytes>>> {
+        Box::pin(async move { Ok(self.0.chunk().await?) })
+    }
+}
+
+/// `Executor` for the tokio runtime the client is used from.
+#[cfg(feature = "tokio")]
+pub struct TokioExecutor;
+
+#[cfg(feature = "tokio")]
+impl Executor for TokioExecutor {
+    /// On the multi-threaded runtime the future is completed before returning so that the session
+    /// is logged out even if the program exits right after dropping the client. On the current
+    /// thread runtime it is spawned. Outside of a runtime it is not run.
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        use tokio::runtime::{Handle, RuntimeFlavor};
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| handle.block_on(future));
+            }
+            Ok(handle) => {
+                handle.spawn(future);
+            }
+            Err(_) => log::warn!("No tokio runtime to run the task on. Skipping it."),
+        }
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) -> Option<BoxFuture<'static, ()>> {
+        let handle = tokio::runtime::Handle::try_current().ok()?;
+        let join = handle.spawn_blocking(task);
+        Some(Box::pin(async move {
+            let _ = join.await;
+        }))
+    }
+}
+
+/// `Executor` for the JavaScript event loop of the browser or node.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub struct WasmExecutor;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl Executor for WasmExecutor {
+    /// The future is queued on the event loop. The logout of a dropped client is sent when the
+    /// current task yields.
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        let millis = duration.as_millis().min(i32::MAX as u128) as i32;
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            set_timeout(&resolve, millis);
+        });
+        Box::pin(async move {
+            let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+        })
+    }
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    /// The global `setTimeout` available in browsers, workers and node.
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> wasm_bindgen::JsValue;
+}
@@ -0,0 +1,233 @@
+//! Request telemetry helpers. With the `tracing` feature every API call is executed within a
+//! `vim.call` span. With the `opentelemetry` feature the W3C trace context of the span is
+//! propagated to vCenter in the `traceparent` and `tracestate` headers.
+use std::time::Duration;
+
+use super::client::Error;
+use super::metrics;
+
+/// JSON keys whose values are replaced with `***` before request and response payloads are
+/// logged. Keys are matched case-insensitively as substrings.
+const SECRET_KEYS: [&str; 4] = ["password", "secret", "sessionkey", "privatekey"];
+
+pub(crate) const REDACTED: &str = "***";
+
+/// Managed object type, identifier and method parsed from a VI/JSON request path i.e.
+/// `{base_path}/{mo_type}/{moid}/{method}`. Automation API calls have no managed object type or
+/// identifier; the method is the HTTP method and path template.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ApiCall<'a> {
+    pub mo_type: &'a str,
+    pub moid: &'a str,
+    pub method: &'a str,
+}
+
+impl<'a> ApiCall<'a> {
+    pub(crate) fn parse(path: &'a str) -> Self {
+        let mut segments = path.rsplitn(4, '/');
+        let method = segments.next().unwrap_or_default();
+        let moid = segments.next().unwrap_or_default();
+        let mo_type = segments.next().unwrap_or_default();
+        Self { mo_type, moid, method }
+    }
+
+    /// Automation API call identified by its route e.g. `GET /api/cis/tagging/tag/{tag_id}`.
+    pub(crate) fn automation(route: &'a str) -> Self {
+        Self { mo_type: "", moid: "", method: route }
+    }
+
+    /// Method path in the format used by the `mo` stubs or the route of an Automation API call.
+    pub(crate) fn method_path(&self) -> String {
+        if self.mo_type.is_empty() {
+            self.method.to_string()
+        } else {
+            metrics::method_path(self.mo_type, self.method)
+        }
+    }
+
+    /// Span name e.g. `Folder.CreateFolder` or `GET /api/cis/tagging/tag/{tag_id}`.
+    #[cfg(feature = "tracing")]
+    fn name(&self) -> String {
+        if self.mo_type.is_empty() {
+            self.method.to_string()
+        } else {
+            format!("{}.{}", self.mo_type, self.method)
+        }
+    }
+}
+
+/// Name of the fault type carried by the error if any. Automation API errors are named by their
+/// `error_type` e.g. `NOT_FOUND`.
+pub(crate) fn fault_type(error: &Error) -> Option<&str> {
+    match error {
+        Error::MethodFault(fault) => Some(
+            fault
+                .type_
+                .map(|t| t.into())
+                .unwrap_or("MethodFault"),
+        ),
+        Error::Api(_, error) => Some(error.error_type.as_deref().unwrap_or("ERROR")),
+        _ => None,
+    }
+}
+
+/// Render a JSON payload for logging with secrets such as passwords and session keys redacted.
+/// Payloads that are not valid JSON are summarized by size as they may contain anything.
+pub fn redact_secrets(payload: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(payload) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes of non-JSON content>", payload.len()),
+    }
+}
+
+/// Replace the values of secret keys in the JSON value in place.
+pub(crate) fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if SECRET_KEYS.iter().any(|secret| key.contains(secret)) {
+                    redact_strings(value);
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Replace the string leaves of a secret. Secrets that are objects e.g. `CustomizationPassword`
+/// keep their shape and `_typeName` so that redacted payloads still deserialize.
+fn redact_strings(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(text) => *text = REDACTED.to_string(),
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key != "_typeName" {
+                    redact_strings(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_strings),
+        _ => {}
+    }
+}
+
+/// Create the span for an API call. The outcome fields are filled in by `record_outcome`.
+#[cfg(feature = "tracing")]
+pub(crate) fn call_span(call: &ApiCall, api_release: &str) -> tracing::Span {
+    tracing::info_span!(
+        "vim.call",
+        otel.name = %call.name(),
+        otel.kind = "client",
+        otel.status_code = tracing::field::Empty,
+        vim.mo_type = call.mo_type,
+        vim.moid = call.moid,
+        vim.method = call.method,
+        vim.api_release = api_release,
+        vim.fault = tracing::field::Empty,
+        http.status_code = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+/// Record the HTTP status, fault type and latency of the call in the current span.
+#[allow(unused_variables)]
+pub(crate) fn record_outcome(status: Option<u16>, fault: Option<&str>, latency: Duration) {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        if let Some(status) = status {
+            span.record("http.status_code", status);
+        }
+        if let Some(fault) = fault {
+            span.record("vim.fault", fault);
+            span.record("otel.status_code", "ERROR");
+        }
+        span.record("latency_ms", latency.as_secs_f64() * 1000.0);
+    }
+}
+
+/// Add the W3C trace context of the current span to the request headers using the globally
+/// configured OpenTelemetry propagator.
+#[cfg(feature = "opentelemetry")]
+pub(crate) fn with_trace_context(mut req: super::runtime::HttpRequest) -> super::runtime::HttpRequest {
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    struct HeaderInjector<'a>(&'a mut http::HeaderMap);
+
+    impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            let Ok(name) = http::HeaderName::from_bytes(key.as_bytes()) else {
+                return;
+            };
+            let Ok(value) = http::HeaderValue::from_str(&value) else {
+                return;
+            };
+            self.0.insert(name, value);
+        }
+    }
+
+    let context = tracing::Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(req.headers_mut()))
+    });
+    req
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::automation::ApiError;
+
+    #[test]
+    fn test_parse_api_call() {
+        let call = ApiCall::parse("/sdk/vim25/8.0.2.0/PropertyCollector/propertyCollector/WaitForUpdatesEx");
+        assert_eq!(call, ApiCall { mo_type: "PropertyCollector", moid: "propertyCollector", method: "WaitForUpdatesEx" });
+        assert_eq!(call.method_path(), "/PropertyCollector/{moId}/WaitForUpdatesEx");
+        let call = ApiCall::automation("GET /api/cis/tagging/tag/{tag_id}");
+        assert_eq!(call.method_path(), "GET /api/cis/tagging/tag/{tag_id}");
+    }
+
+    #[test]
+    fn test_fault_type() {
+        let error = Error::Api(404, ApiError { error_type: Some("NOT_FOUND".to_string()), ..Default::default() });
+        assert_eq!(fault_type(&error), Some("NOT_FOUND"));
+        assert_eq!(fault_type(&Error::Api(401, ApiError::default())), Some("ERROR"));
+    }
+
+    #[test]
+    fn test_redact_login() {
+        let body = br#"{"_typeName":"LoginRequestType","userName":"admin","password":"s3cr3t"}"#;
+        let redacted = redact_secrets(body);
+        assert!(!redacted.contains("s3cr3t"));
+        assert!(redacted.contains("admin"));
+    }
+
+    #[test]
+    fn test_redact_nested() {
+        let body = br#"{"spec":[{"auth":{"sessionKey":"52b8","newPassword":"x1"}}],"key":1000}"#;
+        let value: serde_json::Value = serde_json::from_str(&redact_secrets(body)).unwrap();
+        assert_eq!(value["spec"][0]["auth"]["sessionKey"], REDACTED);
+        assert_eq!(value["spec"][0]["auth"]["newPassword"], REDACTED);
+        assert_eq!(value["key"], 1000);
+    }
+
+    #[test]
+    fn test_redact_secret_object() {
+        let body = br#"{"password":{"_typeName":"CustomizationPassword","value":"s3cr3t","plainText":true},"keys":["k1"]}"#;
+        let value: serde_json::Value = serde_json::from_str(&redact_secrets(body)).unwrap();
+        assert_eq!(value["password"], serde_json::json!({"_typeName": "CustomizationPassword", "value": REDACTED, "plainText": true}));
+        assert_eq!(value["keys"][0], "k1");
+    }
+
+    #[test]
+    fn test_redact_non_json() {
+        assert_eq!(redact_secrets(b"password=x"), "<10 bytes of non-JSON content>");
+    }
+}
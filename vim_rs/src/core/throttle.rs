@@ -0,0 +1,254 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use async_lock::{Semaphore, SemaphoreGuardArc};
+use log::debug;
+use web_time::Instant;
+
+use super::runtime::Executor;
+
+/// VIM methods that park on the server until data is available. These are throttled with a
+/// separate budget so that they do not starve regular API calls.
+pub const LONG_POLL_METHODS: [&str; 3] = ["WaitForUpdatesEx", "WaitForUpdates", "ReadNextEvents"];
+
+/// Limits applied to a class of API calls. The default is unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct Limits {
+    /// Maximum number of requests executing concurrently. Additional requests wait for a slot.
+    pub max_in_flight: Option<usize>,
+    /// Token bucket rate limit i.e. requests per second and burst size.
+    pub rate_limit: Option<(f64, u32)>,
+}
+
+/// Snapshot of the queueing statistics of a throttling budget.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueStats {
+    /// Number of requests that passed through the budget.
+    pub requests: u64,
+    /// Number of requests that had to wait for a concurrency slot or a rate limit token.
+    pub queued: u64,
+    /// Accumulated time spent waiting in the queue.
+    pub total_delay: Duration,
+    /// Longest time a single request spent waiting in the queue.
+    pub max_delay: Duration,
+}
+
+/// Queueing statistics of the client split by budget.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThrottleStats {
+    /// Regular API calls.
+    pub regular: QueueStats,
+    /// Long-poll calls as listed in `LONG_POLL_METHODS`.
+    pub long_poll: QueueStats,
+}
+
+/// Client side throttling of API calls. Holds separate budgets for regular and long-poll calls.
+pub(crate) struct Throttle {
+    regular: Budget,
+    long_poll: Budget,
+    executor: Arc<dyn Executor>,
+}
+
+/// Keeps a concurrency slot occupied until dropped. The permit is to be held until the response
+/// body is consumed.
+pub(crate) struct Permit {
+    _permit: Option<SemaphoreGuardArc>,
+}
+
+impl Throttle {
+    pub(crate) fn new(regular: &Limits, long_poll: &Limits, executor: Arc<dyn Executor>) -> Self {
+        Self {
+            regular: Budget::new(regular),
+            long_poll: Budget::new(long_poll),
+            executor,
+        }
+    }
+
+    /// Wait until the budget for the given request path allows the request to proceed.
+    pub(crate) async fn acquire(&self, path: &str) -> Permit {
+        if is_long_poll(path) {
+            self.long_poll.acquire(self.executor.as_ref()).await
+        } else {
+            self.regular.acquire(self.executor.as_ref()).await
+        }
+    }
+
+    pub(crate) fn stats(&self) -> ThrottleStats {
+        ThrottleStats {
+            regular: self.regular.stats(),
+            long_poll: self.long_poll.stats(),
+        }
+    }
+}
+
+/// Check if the request path ends with one of the `LONG_POLL_METHODS`.
+pub(crate) fn is_long_poll(path: &str) -> bool {
+    let method = path.rsplit('/').next().unwrap_or_default();
+    LONG_POLL_METHODS.contains(&method)
+}
+
+struct Budget {
+    semaphore: Option<Arc<Semaphore>>,
+    bucket: Option<TokenBucket>,
+    requests: AtomicU64,
+    queued: AtomicU64,
+    total_delay_us: AtomicU64,
+    max_delay_us: AtomicU64,
+}
+
+impl Budget {
+    fn new(limits: &Limits) -> Self {
+        Self {
+            semaphore: limits.max_in_flight.map(|max| Arc::new(Semaphore::new(max.max(1)))),
+            bucket: limits.rate_limit.map(|(rate, burst)| TokenBucket::new(rate, burst)),
+            requests: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+            total_delay_us: AtomicU64::new(0),
+            max_delay_us: AtomicU64::new(0),
+        }
+    }
+
+    async fn acquire(&self, executor: &dyn Executor) -> Permit {
+        let start = Instant::now();
+        let mut waited = false;
+        if let Some(bucket) = &self.bucket {
+            waited |= bucket.take(executor).await;
+        }
+        let permit = match &self.semaphore {
+            Some(semaphore) => {
+                let permit = match semaphore.try_acquire_arc() {
+                    Some(permit) => permit,
+                    None => {
+                        waited = true;
+                        semaphore.acquire_arc().await
+                    }
+                };
+                Some(permit)
+            }
+            None => None,
+        };
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if waited {
+            let delay = start.elapsed();
+            let delay_us = delay.as_micros() as u64;
+            self.queued.fetch_add(1, Ordering::Relaxed);
+            self.total_delay_us.fetch_add(delay_us, Ordering::Relaxed);
+            self.max_delay_us.fetch_max(delay_us, Ordering::Relaxed);
+            debug!("Request queued by client throttling for {:?}", delay);
+        }
+        Permit { _permit: permit }
+    }
+
+    fn stats(&self) -> QueueStats {
+        QueueStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            total_delay: Duration::from_micros(self.total_delay_us.load(Ordering::Relaxed)),
+            max_delay: Duration::from_micros(self.max_delay_us.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Classic token bucket. Tokens are refilled continuously at `rate` per second up to `burst`.
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            rate,
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: burst,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take a token waiting for one to become available. Returns true if the caller had to wait.
+    async fn take(&self, executor: &dyn Executor) -> bool {
+        let mut waited = false;
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+                state.tokens = (state.tokens + elapsed * self.rate).min(self.burst);
+                state.last_refill = now;
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return waited;
+                }
+                Duration::from_secs_f64((1.0 - state.tokens) / self.rate)
+            };
+            waited = true;
+            executor.sleep(wait).await;
+        }
+    }
+}
+
+// The tests run on tokio which is not available on wasm32
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::core::runtime::BoxFuture;
+
+    struct TestExecutor;
+
+    impl Executor for TestExecutor {
+        fn spawn(&self, future: BoxFuture<'static, ()>) {
+            tokio::spawn(future);
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            Box::pin(tokio::time::sleep(duration))
+        }
+    }
+
+    #[test]
+    fn test_is_long_poll() {
+        assert!(is_long_poll("/PropertyCollector/propertyCollector/WaitForUpdatesEx"));
+        assert!(is_long_poll("/EventHistoryCollector/session[1]/ReadNextEvents"));
+        assert!(!is_long_poll("/PropertyCollector/propertyCollector/RetrievePropertiesEx"));
+        assert!(!is_long_poll("/sdk/vim25/8.0.2.0/PropertyCollector/propertyCollector/WaitForUpdatesExtra"));
+    }
+
+    #[tokio::test]
+    async fn test_max_in_flight() {
+        let throttle = Throttle::new(&Limits { max_in_flight: Some(1), rate_limit: None }, &Limits::default(), Arc::new(TestExecutor));
+        let first = throttle.acquire("/Folder/group-d1/CreateFolder").await;
+        let long_poll = throttle.acquire("/PropertyCollector/pc/WaitForUpdatesEx").await;
+        let pending = tokio::time::timeout(Duration::from_millis(20), throttle.acquire("/Folder/group-d1/CreateFolder")).await;
+        assert!(pending.is_err());
+        drop(first);
+        let _second = throttle.acquire("/Folder/group-d1/CreateFolder").await;
+        drop(long_poll);
+        let stats = throttle.stats();
+        assert_eq!(stats.regular.requests, 2);
+        assert_eq!(stats.long_poll.requests, 1);
+        assert_eq!(stats.long_poll.queued, 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit() {
+        let throttle = Throttle::new(&Limits { max_in_flight: None, rate_limit: Some((50.0, 1)) }, &Limits::default(), Arc::new(TestExecutor));
+        let start = Instant::now();
+        for _ in 0..3 {
+            throttle.acquire("/Folder/group-d1/CreateFolder").await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(35));
+        let stats = throttle.stats();
+        assert_eq!(stats.regular.requests, 3);
+        assert_eq!(stats.regular.queued, 2);
+    }
+}
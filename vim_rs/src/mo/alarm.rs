@@ -115,3 +115,48 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmInfo;
+    use crate::types::structs::CustomFieldDef;
+    /// Blocking version of [`super::Alarm`].
+    pub struct Alarm {
+        inner: super::Alarm,
+        client: Arc<Client>,
+    }
+    impl Alarm {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::Alarm::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::Alarm::reconfigure_alarm`].
+        pub fn reconfigure_alarm(&self, spec: &dyn crate::types::traits::AlarmSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_alarm(spec))
+        }
+        /// Blocking version of [`super::Alarm::remove_alarm`].
+        pub fn remove_alarm(&self) -> Result<()> {
+            self.client.block_on(self.inner.remove_alarm())
+        }
+        /// Blocking version of [`super::Alarm::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::Alarm::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::Alarm::info`].
+        pub fn info(&self) -> Result<AlarmInfo> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::Alarm::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
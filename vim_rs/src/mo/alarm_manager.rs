@@ -297,3 +297,70 @@ struct EnableAlarmActionsRequestType<'a> {
     entity: &'a ManagedObjectReference,
     enabled: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmDescription;
+    use crate::types::structs::AlarmFilterSpec;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::AlarmManager`].
+    pub struct AlarmManager {
+        inner: super::AlarmManager,
+        client: Arc<Client>,
+    }
+    impl AlarmManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::AlarmManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::AlarmManager::acknowledge_alarm`].
+        pub fn acknowledge_alarm(&self, alarm: &ManagedObjectReference, entity: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.acknowledge_alarm(alarm, entity))
+        }
+        /// Blocking version of [`super::AlarmManager::clear_triggered_alarms`].
+        pub fn clear_triggered_alarms(&self, filter: &AlarmFilterSpec) -> Result<()> {
+            self.client.block_on(self.inner.clear_triggered_alarms(filter))
+        }
+        /// Blocking version of [`super::AlarmManager::create_alarm`].
+        pub fn create_alarm(&self, entity: &ManagedObjectReference, spec: &dyn crate::types::traits::AlarmSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_alarm(entity, spec))
+        }
+        /// Blocking version of [`super::AlarmManager::disable_alarm`].
+        pub fn disable_alarm(&self, alarm: &ManagedObjectReference, entity: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.disable_alarm(alarm, entity))
+        }
+        /// Blocking version of [`super::AlarmManager::enable_alarm`].
+        pub fn enable_alarm(&self, alarm: &ManagedObjectReference, entity: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.enable_alarm(alarm, entity))
+        }
+        /// Blocking version of [`super::AlarmManager::get_alarm`].
+        pub fn get_alarm(&self, entity: Option<&ManagedObjectReference>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.get_alarm(entity))
+        }
+        /// Blocking version of [`super::AlarmManager::are_alarm_actions_enabled`].
+        pub fn are_alarm_actions_enabled(&self, entity: &ManagedObjectReference) -> Result<bool> {
+            self.client.block_on(self.inner.are_alarm_actions_enabled(entity))
+        }
+        /// Blocking version of [`super::AlarmManager::get_alarm_state`].
+        pub fn get_alarm_state(&self, entity: &ManagedObjectReference) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.get_alarm_state(entity))
+        }
+        /// Blocking version of [`super::AlarmManager::enable_alarm_actions`].
+        pub fn enable_alarm_actions(&self, entity: &ManagedObjectReference, enabled: bool) -> Result<()> {
+            self.client.block_on(self.inner.enable_alarm_actions(entity, enabled))
+        }
+        /// Blocking version of [`super::AlarmManager::default_expression`].
+        pub fn default_expression(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::AlarmExpressionTrait>>>> {
+            self.client.block_on(self.inner.default_expression())
+        }
+        /// Blocking version of [`super::AlarmManager::description`].
+        pub fn description(&self) -> Result<AlarmDescription> {
+            self.client.block_on(self.inner.description())
+        }
+    }
+}
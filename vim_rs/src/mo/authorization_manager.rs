@@ -719,3 +719,97 @@ struct UpdateAuthorizationRoleRequestType<'a> {
     #[serde(rename = "privIds")]
     priv_ids: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AuthorizationDescription;
+    use crate::types::structs::AuthorizationPrivilege;
+    use crate::types::structs::AuthorizationRole;
+    use crate::types::structs::EntityPrivilege;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::UserPrivilegeResult;
+    /// Blocking version of [`super::AuthorizationManager`].
+    pub struct AuthorizationManager {
+        inner: super::AuthorizationManager,
+        client: Arc<Client>,
+    }
+    impl AuthorizationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::AuthorizationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::AuthorizationManager::add_authorization_role`].
+        pub fn add_authorization_role(&self, name: &str, priv_ids: Option<&[String]>) -> Result<i32> {
+            self.client.block_on(self.inner.add_authorization_role(name, priv_ids))
+        }
+        /// Blocking version of [`super::AuthorizationManager::fetch_user_privilege_on_entities`].
+        pub fn fetch_user_privilege_on_entities(&self, entities: &[ManagedObjectReference], user_name: &str) -> Result<Option<Vec<UserPrivilegeResult>>> {
+            self.client.block_on(self.inner.fetch_user_privilege_on_entities(entities, user_name))
+        }
+        /// Blocking version of [`super::AuthorizationManager::has_privilege_on_entities`].
+        pub fn has_privilege_on_entities(&self, entity: &[ManagedObjectReference], session_id: &str, priv_id: Option<&[String]>) -> Result<Option<Vec<EntityPrivilege>>> {
+            self.client.block_on(self.inner.has_privilege_on_entities(entity, session_id, priv_id))
+        }
+        /// Blocking version of [`super::AuthorizationManager::has_privilege_on_entity`].
+        pub fn has_privilege_on_entity(&self, entity: &ManagedObjectReference, session_id: &str, priv_id: Option<&[String]>) -> Result<Option<Vec<bool>>> {
+            self.client.block_on(self.inner.has_privilege_on_entity(entity, session_id, priv_id))
+        }
+        /// Blocking version of [`super::AuthorizationManager::has_user_privilege_on_entities`].
+        pub fn has_user_privilege_on_entities(&self, entities: &[ManagedObjectReference], user_name: &str, priv_id: Option<&[String]>) -> Result<Option<Vec<EntityPrivilege>>> {
+            self.client.block_on(self.inner.has_user_privilege_on_entities(entities, user_name, priv_id))
+        }
+        /// Blocking version of [`super::AuthorizationManager::merge_permissions`].
+        pub fn merge_permissions(&self, src_role_id: i32, dst_role_id: i32) -> Result<()> {
+            self.client.block_on(self.inner.merge_permissions(src_role_id, dst_role_id))
+        }
+        /// Blocking version of [`super::AuthorizationManager::remove_entity_permission`].
+        pub fn remove_entity_permission(&self, entity: &ManagedObjectReference, user: &str, is_group: bool) -> Result<()> {
+            self.client.block_on(self.inner.remove_entity_permission(entity, user, is_group))
+        }
+        /// Blocking version of [`super::AuthorizationManager::remove_authorization_role`].
+        pub fn remove_authorization_role(&self, role_id: i32, fail_if_used: bool) -> Result<()> {
+            self.client.block_on(self.inner.remove_authorization_role(role_id, fail_if_used))
+        }
+        /// Blocking version of [`super::AuthorizationManager::reset_entity_permissions`].
+        pub fn reset_entity_permissions(&self, entity: &ManagedObjectReference, permission: Option<&[Permission]>) -> Result<()> {
+            self.client.block_on(self.inner.reset_entity_permissions(entity, permission))
+        }
+        /// Blocking version of [`super::AuthorizationManager::retrieve_all_permissions`].
+        pub fn retrieve_all_permissions(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.retrieve_all_permissions())
+        }
+        /// Blocking version of [`super::AuthorizationManager::retrieve_entity_permissions`].
+        pub fn retrieve_entity_permissions(&self, entity: &ManagedObjectReference, inherited: bool) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.retrieve_entity_permissions(entity, inherited))
+        }
+        /// Blocking version of [`super::AuthorizationManager::retrieve_role_permissions`].
+        pub fn retrieve_role_permissions(&self, role_id: i32) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.retrieve_role_permissions(role_id))
+        }
+        /// Blocking version of [`super::AuthorizationManager::set_entity_permissions`].
+        pub fn set_entity_permissions(&self, entity: &ManagedObjectReference, permission: Option<&[Permission]>) -> Result<()> {
+            self.client.block_on(self.inner.set_entity_permissions(entity, permission))
+        }
+        /// Blocking version of [`super::AuthorizationManager::update_authorization_role`].
+        pub fn update_authorization_role(&self, role_id: i32, new_name: &str, priv_ids: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.update_authorization_role(role_id, new_name, priv_ids))
+        }
+        /// Blocking version of [`super::AuthorizationManager::description`].
+        pub fn description(&self) -> Result<AuthorizationDescription> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::AuthorizationManager::privilege_list`].
+        pub fn privilege_list(&self) -> Result<Option<Vec<AuthorizationPrivilege>>> {
+            self.client.block_on(self.inner.privilege_list())
+        }
+        /// Blocking version of [`super::AuthorizationManager::role_list`].
+        pub fn role_list(&self) -> Result<Option<Vec<AuthorizationRole>>> {
+            self.client.block_on(self.inner.role_list())
+        }
+    }
+}
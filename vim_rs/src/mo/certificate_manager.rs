@@ -93,3 +93,35 @@ struct CertMgrRefreshCertificatesRequestType<'a> {
 struct CertMgrRevokeCertificatesRequestType<'a> {
     host: &'a [ManagedObjectReference],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::CertificateManager`].
+    pub struct CertificateManager {
+        inner: super::CertificateManager,
+        client: Arc<Client>,
+    }
+    impl CertificateManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CertificateManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CertificateManager::cert_mgr_refresh_ca_certificates_and_cr_ls_task`].
+        pub fn cert_mgr_refresh_ca_certificates_and_cr_ls_task(&self, host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.cert_mgr_refresh_ca_certificates_and_cr_ls_task(host))
+        }
+        /// Blocking version of [`super::CertificateManager::cert_mgr_refresh_certificates_task`].
+        pub fn cert_mgr_refresh_certificates_task(&self, host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.cert_mgr_refresh_certificates_task(host))
+        }
+        /// Blocking version of [`super::CertificateManager::cert_mgr_revoke_certificates_task`].
+        pub fn cert_mgr_revoke_certificates_task(&self, host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.cert_mgr_revoke_certificates_task(host))
+        }
+    }
+}
@@ -843,7 +843,7 @@ impl ClusterComputeResource {
     /// ***InvalidRequest***: if the interface is not implemented.
     /// 
     /// ***InvalidArgument***: if one of the parameters is invalid.
-    pub async fn set_crypto_mode(&self, crypto_mode: &str) -> Result<()> {
+    pub async fn set_crypto_mode(&self, crypto_mode: crate::types::enums::ClusterCryptoConfigInfoCryptoModeEnum) -> Result<()> {
         let input = SetCryptoModeRequestType {crypto_mode, };
         let path = format!("/ClusterComputeResource/{moId}/SetCryptoMode", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1552,9 +1552,9 @@ struct RenameRequestType<'a> {
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
-struct SetCryptoModeRequestType<'a> {
+struct SetCryptoModeRequestType {
     #[serde(rename = "cryptoMode")]
-    crypto_mode: &'a str,
+    crypto_mode: crate::types::enums::ClusterCryptoConfigInfoCryptoModeEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(rename = "setCustomValueRequestType", tag = "_typeName")]
@@ -1571,3 +1571,282 @@ struct ValidateHciConfigurationRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     hosts: Option<&'a [ManagedObjectReference]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::ClusterActionHistory;
+    use crate::types::structs::ClusterComputeResourceHciConfigInfo;
+    use crate::types::structs::ClusterComputeResourceHciConfigSpec;
+    use crate::types::structs::ClusterComputeResourceHostConfigurationInput;
+    use crate::types::structs::ClusterComputeResourceSummary;
+    use crate::types::structs::ClusterConfigInfo;
+    use crate::types::structs::ClusterConfigSpec;
+    use crate::types::structs::ClusterDrsFaults;
+    use crate::types::structs::ClusterDrsMigration;
+    use crate::types::structs::ClusterDrsRecommendation;
+    use crate::types::structs::ClusterEnterMaintenanceResult;
+    use crate::types::structs::ClusterHostRecommendation;
+    use crate::types::structs::ClusterRecommendation;
+    use crate::types::structs::ClusterResourceUsageSummary;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::Event;
+    use crate::types::structs::HostConnectSpec;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::PlacementResult;
+    use crate::types::structs::PlacementSpec;
+    use crate::types::structs::SddcBase;
+    use crate::types::structs::Tag;
+    /// Blocking version of [`super::ClusterComputeResource`].
+    pub struct ClusterComputeResource {
+        inner: super::ClusterComputeResource,
+        client: Arc<Client>,
+    }
+    impl ClusterComputeResource {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ClusterComputeResource::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ClusterComputeResource::abandon_hci_workflow`].
+        pub fn abandon_hci_workflow(&self) -> Result<()> {
+            self.client.block_on(self.inner.abandon_hci_workflow())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::add_host_task`].
+        pub fn add_host_task(&self, spec: &HostConnectSpec, as_connected: bool, resource_pool: Option<&ManagedObjectReference>, license: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.add_host_task(spec, as_connected, resource_pool, license))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::apply_recommendation`].
+        pub fn apply_recommendation(&self, key: &str) -> Result<()> {
+            self.client.block_on(self.inner.apply_recommendation(key))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::cancel_recommendation`].
+        pub fn cancel_recommendation(&self, key: &str) -> Result<()> {
+            self.client.block_on(self.inner.cancel_recommendation(key))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::configure_hci_task`].
+        pub fn configure_hci_task(&self, cluster_spec: &ClusterComputeResourceHciConfigSpec, host_inputs: Option<&[ClusterComputeResourceHostConfigurationInput]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_hci_task(cluster_spec, host_inputs))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::cluster_enter_maintenance_mode`].
+        pub fn cluster_enter_maintenance_mode(&self, host: &[ManagedObjectReference], option: Option<&[Box<dyn crate::types::traits::OptionValueTrait>]>) -> Result<ClusterEnterMaintenanceResult> {
+            self.client.block_on(self.inner.cluster_enter_maintenance_mode(host, option))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::evc_manager`].
+        pub fn evc_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.evc_manager())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::extend_hci_task`].
+        pub fn extend_hci_task(&self, host_inputs: Option<&[ClusterComputeResourceHostConfigurationInput]>, v_san_config_spec: Option<&SddcBase>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.extend_hci_task(host_inputs, v_san_config_spec))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::find_rules_for_vm`].
+        pub fn find_rules_for_vm(&self, vm: &ManagedObjectReference) -> Result<Option<Vec<Box<dyn crate::types::traits::ClusterRuleInfoTrait>>>> {
+            self.client.block_on(self.inner.find_rules_for_vm(vm))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::get_resource_usage`].
+        pub fn get_resource_usage(&self) -> Result<ClusterResourceUsageSummary> {
+            self.client.block_on(self.inner.get_resource_usage())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::get_system_v_ms_restricted_datastores`].
+        pub fn get_system_v_ms_restricted_datastores(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.get_system_v_ms_restricted_datastores())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::move_host_into_task`].
+        pub fn move_host_into_task(&self, host: &ManagedObjectReference, resource_pool: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_host_into_task(host, resource_pool))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::move_into_task`].
+        pub fn move_into_task(&self, host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_into_task(host))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::place_vm`].
+        pub fn place_vm(&self, placement_spec: &PlacementSpec) -> Result<PlacementResult> {
+            self.client.block_on(self.inner.place_vm(placement_spec))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::recommend_hosts_for_vm`].
+        pub fn recommend_hosts_for_vm(&self, vm: &ManagedObjectReference, pool: Option<&ManagedObjectReference>) -> Result<Option<Vec<ClusterHostRecommendation>>> {
+            self.client.block_on(self.inner.recommend_hosts_for_vm(vm, pool))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::reconfigure_cluster_task`].
+        pub fn reconfigure_cluster_task(&self, spec: &ClusterConfigSpec, modify: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_cluster_task(spec, modify))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::reconfigure_compute_resource_task`].
+        pub fn reconfigure_compute_resource_task(&self, spec: &dyn crate::types::traits::ComputeResourceConfigSpecTrait, modify: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_compute_resource_task(spec, modify))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::refresh_recommendation`].
+        pub fn refresh_recommendation(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_recommendation())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::retrieve_das_advanced_runtime_info`].
+        pub fn retrieve_das_advanced_runtime_info(&self) -> Result<Option<Box<dyn crate::types::traits::ClusterDasAdvancedRuntimeInfoTrait>>> {
+            self.client.block_on(self.inner.retrieve_das_advanced_runtime_info())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::set_crypto_mode`].
+        pub fn set_crypto_mode(&self, crypto_mode: crate::types::enums::ClusterCryptoConfigInfoCryptoModeEnum) -> Result<()> {
+            self.client.block_on(self.inner.set_crypto_mode(crypto_mode))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::stamp_all_rules_with_uuid_task`].
+        pub fn stamp_all_rules_with_uuid_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.stamp_all_rules_with_uuid_task())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::validate_hci_configuration`].
+        pub fn validate_hci_configuration(&self, hci_config_spec: Option<&ClusterComputeResourceHciConfigSpec>, hosts: Option<&[ManagedObjectReference]>) -> Result<Option<Vec<Box<dyn crate::types::traits::ClusterComputeResourceValidationResultBaseTrait>>>> {
+            self.client.block_on(self.inner.validate_hci_configuration(hci_config_spec, hosts))
+        }
+        /// Blocking version of [`super::ClusterComputeResource::action_history`].
+        pub fn action_history(&self) -> Result<Option<Vec<ClusterActionHistory>>> {
+            self.client.block_on(self.inner.action_history())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::config_manager_enabled`].
+        pub fn config_manager_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.config_manager_enabled())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::configuration`].
+        pub fn configuration(&self) -> Result<ClusterConfigInfo> {
+            self.client.block_on(self.inner.configuration())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::configuration_ex`].
+        pub fn configuration_ex(&self) -> Result<Box<dyn crate::types::traits::ComputeResourceConfigInfoTrait>> {
+            self.client.block_on(self.inner.configuration_ex())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::drs_fault`].
+        pub fn drs_fault(&self) -> Result<Option<Vec<ClusterDrsFaults>>> {
+            self.client.block_on(self.inner.drs_fault())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::drs_recommendation`].
+        pub fn drs_recommendation(&self) -> Result<Option<Vec<ClusterDrsRecommendation>>> {
+            self.client.block_on(self.inner.drs_recommendation())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::environment_browser`].
+        pub fn environment_browser(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.environment_browser())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::hci_config`].
+        pub fn hci_config(&self) -> Result<Option<ClusterComputeResourceHciConfigInfo>> {
+            self.client.block_on(self.inner.hci_config())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::host`].
+        pub fn host(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.host())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::lifecycle_managed`].
+        pub fn lifecycle_managed(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.lifecycle_managed())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::migration_history`].
+        pub fn migration_history(&self) -> Result<Option<Vec<ClusterDrsMigration>>> {
+            self.client.block_on(self.inner.migration_history())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::recommendation`].
+        pub fn recommendation(&self) -> Result<Option<Vec<ClusterRecommendation>>> {
+            self.client.block_on(self.inner.recommendation())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::resource_pool`].
+        pub fn resource_pool(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.resource_pool())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::summary`].
+        pub fn summary(&self) -> Result<Box<dyn crate::types::traits::ComputeResourceSummaryTrait>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::summary_ex`].
+        pub fn summary_ex(&self) -> Result<ClusterComputeResourceSummary> {
+            self.client.block_on(self.inner.summary_ex())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::ClusterComputeResource::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -223,3 +223,62 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ClusterEvcManagerEvcState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostConnectSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ClusterEvcManager`].
+    pub struct ClusterEvcManager {
+        inner: super::ClusterEvcManager,
+        client: Arc<Client>,
+    }
+    impl ClusterEvcManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ClusterEvcManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ClusterEvcManager::check_add_host_evc_task`].
+        pub fn check_add_host_evc_task(&self, cnx_spec: &HostConnectSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_add_host_evc_task(cnx_spec))
+        }
+        /// Blocking version of [`super::ClusterEvcManager::check_configure_evc_mode_task`].
+        pub fn check_configure_evc_mode_task(&self, evc_mode_key: &str, evc_graphics_mode_key: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_configure_evc_mode_task(evc_mode_key, evc_graphics_mode_key))
+        }
+        /// Blocking version of [`super::ClusterEvcManager::configure_evc_mode_task`].
+        pub fn configure_evc_mode_task(&self, evc_mode_key: &str, evc_graphics_mode_key: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_evc_mode_task(evc_mode_key, evc_graphics_mode_key))
+        }
+        /// Blocking version of [`super::ClusterEvcManager::disable_evc_mode_task`].
+        pub fn disable_evc_mode_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.disable_evc_mode_task())
+        }
+        /// Blocking version of [`super::ClusterEvcManager::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::ClusterEvcManager::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::ClusterEvcManager::evc_state`].
+        pub fn evc_state(&self) -> Result<ClusterEvcManagerEvcState> {
+            self.client.block_on(self.inner.evc_state())
+        }
+        /// Blocking version of [`super::ClusterEvcManager::managed_cluster`].
+        pub fn managed_cluster(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.managed_cluster())
+        }
+        /// Blocking version of [`super::ClusterEvcManager::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -216,3 +216,80 @@ struct DissociateProfileRequestType<'a> {
 struct UpdateClusterProfileRequestType<'a> {
     config: &'a dyn crate::types::traits::ClusterProfileConfigSpecTrait,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfileDescription;
+    /// Blocking version of [`super::ClusterProfile`].
+    pub struct ClusterProfile {
+        inner: super::ClusterProfile,
+        client: Arc<Client>,
+    }
+    impl ClusterProfile {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ClusterProfile::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ClusterProfile::associate_profile`].
+        pub fn associate_profile(&self, entity: &[ManagedObjectReference]) -> Result<()> {
+            self.client.block_on(self.inner.associate_profile(entity))
+        }
+        /// Blocking version of [`super::ClusterProfile::check_profile_compliance_task`].
+        pub fn check_profile_compliance_task(&self, entity: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_profile_compliance_task(entity))
+        }
+        /// Blocking version of [`super::ClusterProfile::destroy_profile`].
+        pub fn destroy_profile(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_profile())
+        }
+        /// Blocking version of [`super::ClusterProfile::dissociate_profile`].
+        pub fn dissociate_profile(&self, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.dissociate_profile(entity))
+        }
+        /// Blocking version of [`super::ClusterProfile::export_profile`].
+        pub fn export_profile(&self) -> Result<String> {
+            self.client.block_on(self.inner.export_profile())
+        }
+        /// Blocking version of [`super::ClusterProfile::retrieve_description`].
+        pub fn retrieve_description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.retrieve_description())
+        }
+        /// Blocking version of [`super::ClusterProfile::update_cluster_profile`].
+        pub fn update_cluster_profile(&self, config: &dyn crate::types::traits::ClusterProfileConfigSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_cluster_profile(config))
+        }
+        /// Blocking version of [`super::ClusterProfile::compliance_status`].
+        pub fn compliance_status(&self) -> Result<String> {
+            self.client.block_on(self.inner.compliance_status())
+        }
+        /// Blocking version of [`super::ClusterProfile::config`].
+        pub fn config(&self) -> Result<Box<dyn crate::types::traits::ProfileConfigInfoTrait>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::ClusterProfile::created_time`].
+        pub fn created_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.created_time())
+        }
+        /// Blocking version of [`super::ClusterProfile::description`].
+        pub fn description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::ClusterProfile::entity`].
+        pub fn entity(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.entity())
+        }
+        /// Blocking version of [`super::ClusterProfile::modified_time`].
+        pub fn modified_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.modified_time())
+        }
+        /// Blocking version of [`super::ClusterProfile::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+    }
+}
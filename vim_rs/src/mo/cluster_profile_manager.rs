@@ -125,3 +125,40 @@ struct QueryPolicyMetadataRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     profile: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfilePolicyMetadata;
+    /// Blocking version of [`super::ClusterProfileManager`].
+    pub struct ClusterProfileManager {
+        inner: super::ClusterProfileManager,
+        client: Arc<Client>,
+    }
+    impl ClusterProfileManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ClusterProfileManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ClusterProfileManager::create_profile`].
+        pub fn create_profile(&self, create_spec: &dyn crate::types::traits::ProfileCreateSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_profile(create_spec))
+        }
+        /// Blocking version of [`super::ClusterProfileManager::find_associated_profile`].
+        pub fn find_associated_profile(&self, entity: &ManagedObjectReference) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.find_associated_profile(entity))
+        }
+        /// Blocking version of [`super::ClusterProfileManager::query_policy_metadata`].
+        pub fn query_policy_metadata(&self, policy_name: Option<&[String]>, profile: Option<&ManagedObjectReference>) -> Result<Option<Vec<ProfilePolicyMetadata>>> {
+            self.client.block_on(self.inner.query_policy_metadata(policy_name, profile))
+        }
+        /// Blocking version of [`super::ClusterProfileManager::profile`].
+        pub fn profile(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.profile())
+        }
+    }
+}
@@ -592,3 +592,148 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::Tag;
+    /// Blocking version of [`super::ComputeResource`].
+    pub struct ComputeResource {
+        inner: super::ComputeResource,
+        client: Arc<Client>,
+    }
+    impl ComputeResource {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ComputeResource::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ComputeResource::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::ComputeResource::reconfigure_compute_resource_task`].
+        pub fn reconfigure_compute_resource_task(&self, spec: &dyn crate::types::traits::ComputeResourceConfigSpecTrait, modify: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_compute_resource_task(spec, modify))
+        }
+        /// Blocking version of [`super::ComputeResource::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::ComputeResource::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::ComputeResource::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::ComputeResource::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::ComputeResource::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::ComputeResource::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::ComputeResource::config_manager_enabled`].
+        pub fn config_manager_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.config_manager_enabled())
+        }
+        /// Blocking version of [`super::ComputeResource::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::ComputeResource::configuration_ex`].
+        pub fn configuration_ex(&self) -> Result<Box<dyn crate::types::traits::ComputeResourceConfigInfoTrait>> {
+            self.client.block_on(self.inner.configuration_ex())
+        }
+        /// Blocking version of [`super::ComputeResource::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::ComputeResource::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::ComputeResource::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::ComputeResource::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::ComputeResource::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::ComputeResource::environment_browser`].
+        pub fn environment_browser(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.environment_browser())
+        }
+        /// Blocking version of [`super::ComputeResource::host`].
+        pub fn host(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.host())
+        }
+        /// Blocking version of [`super::ComputeResource::lifecycle_managed`].
+        pub fn lifecycle_managed(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.lifecycle_managed())
+        }
+        /// Blocking version of [`super::ComputeResource::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::ComputeResource::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::ComputeResource::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::ComputeResource::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::ComputeResource::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::ComputeResource::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::ComputeResource::resource_pool`].
+        pub fn resource_pool(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.resource_pool())
+        }
+        /// Blocking version of [`super::ComputeResource::summary`].
+        pub fn summary(&self) -> Result<Box<dyn crate::types::traits::ComputeResourceSummaryTrait>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::ComputeResource::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::ComputeResource::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::ComputeResource::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -77,3 +77,39 @@ impl ContainerView {
         self.client.execute_option(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ContainerView`].
+    pub struct ContainerView {
+        inner: super::ContainerView,
+        client: Arc<Client>,
+    }
+    impl ContainerView {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ContainerView::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ContainerView::destroy_view`].
+        pub fn destroy_view(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_view())
+        }
+        /// Blocking version of [`super::ContainerView::container`].
+        pub fn container(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.container())
+        }
+        /// Blocking version of [`super::ContainerView::recursive`].
+        pub fn recursive(&self) -> Result<bool> {
+            self.client.block_on(self.inner.recursive())
+        }
+        /// Blocking version of [`super::ContainerView::view`].
+        pub fn view(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.view())
+        }
+    }
+}
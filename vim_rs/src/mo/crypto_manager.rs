@@ -166,3 +166,49 @@ struct RemoveKeysRequestType<'a> {
     keys: Option<&'a [CryptoKeyId]>,
     force: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CryptoKeyId;
+    use crate::types::structs::CryptoKeyPlain;
+    use crate::types::structs::CryptoKeyResult;
+    /// Blocking version of [`super::CryptoManager`].
+    pub struct CryptoManager {
+        inner: super::CryptoManager,
+        client: Arc<Client>,
+    }
+    impl CryptoManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CryptoManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CryptoManager::add_key`].
+        pub fn add_key(&self, key: &CryptoKeyPlain) -> Result<()> {
+            self.client.block_on(self.inner.add_key(key))
+        }
+        /// Blocking version of [`super::CryptoManager::add_keys`].
+        pub fn add_keys(&self, keys: Option<&[CryptoKeyPlain]>) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.add_keys(keys))
+        }
+        /// Blocking version of [`super::CryptoManager::list_keys`].
+        pub fn list_keys(&self, limit: Option<i32>) -> Result<Option<Vec<CryptoKeyId>>> {
+            self.client.block_on(self.inner.list_keys(limit))
+        }
+        /// Blocking version of [`super::CryptoManager::remove_key`].
+        pub fn remove_key(&self, key: &CryptoKeyId, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.remove_key(key, force))
+        }
+        /// Blocking version of [`super::CryptoManager::remove_keys`].
+        pub fn remove_keys(&self, keys: Option<&[CryptoKeyId]>, force: bool) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.remove_keys(keys, force))
+        }
+        /// Blocking version of [`super::CryptoManager::enabled`].
+        pub fn enabled(&self) -> Result<bool> {
+            self.client.block_on(self.inner.enabled())
+        }
+    }
+}
@@ -286,3 +286,71 @@ struct RemoveKeysRequestType<'a> {
     keys: Option<&'a [CryptoKeyId]>,
     force: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CryptoKeyId;
+    use crate::types::structs::CryptoKeyPlain;
+    use crate::types::structs::CryptoKeyResult;
+    use crate::types::structs::CryptoManagerHostKeyStatus;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::CryptoManagerHost`].
+    pub struct CryptoManagerHost {
+        inner: super::CryptoManagerHost,
+        client: Arc<Client>,
+    }
+    impl CryptoManagerHost {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CryptoManagerHost::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CryptoManagerHost::add_key`].
+        pub fn add_key(&self, key: &CryptoKeyPlain) -> Result<()> {
+            self.client.block_on(self.inner.add_key(key))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::add_keys`].
+        pub fn add_keys(&self, keys: Option<&[CryptoKeyPlain]>) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.add_keys(keys))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::change_key_task`].
+        pub fn change_key_task(&self, new_key: &CryptoKeyPlain) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.change_key_task(new_key))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::crypto_manager_host_disable`].
+        pub fn crypto_manager_host_disable(&self) -> Result<()> {
+            self.client.block_on(self.inner.crypto_manager_host_disable())
+        }
+        /// Blocking version of [`super::CryptoManagerHost::crypto_manager_host_enable`].
+        pub fn crypto_manager_host_enable(&self, initial_key: &CryptoKeyPlain) -> Result<()> {
+            self.client.block_on(self.inner.crypto_manager_host_enable(initial_key))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::get_crypto_key_status`].
+        pub fn get_crypto_key_status(&self, keys: Option<&[CryptoKeyId]>) -> Result<Option<Vec<CryptoManagerHostKeyStatus>>> {
+            self.client.block_on(self.inner.get_crypto_key_status(keys))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::list_keys`].
+        pub fn list_keys(&self, limit: Option<i32>) -> Result<Option<Vec<CryptoKeyId>>> {
+            self.client.block_on(self.inner.list_keys(limit))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::crypto_manager_host_prepare`].
+        pub fn crypto_manager_host_prepare(&self) -> Result<()> {
+            self.client.block_on(self.inner.crypto_manager_host_prepare())
+        }
+        /// Blocking version of [`super::CryptoManagerHost::remove_key`].
+        pub fn remove_key(&self, key: &CryptoKeyId, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.remove_key(key, force))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::remove_keys`].
+        pub fn remove_keys(&self, keys: Option<&[CryptoKeyId]>, force: bool) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.remove_keys(keys, force))
+        }
+        /// Blocking version of [`super::CryptoManagerHost::enabled`].
+        pub fn enabled(&self) -> Result<bool> {
+            self.client.block_on(self.inner.enabled())
+        }
+    }
+}
@@ -363,7 +363,7 @@ impl CryptoManagerKmip {
     /// See *KmipClusterInfoKmsManagementType_enum*
     /// for valid values.
     /// By default trustAuthority.
-    pub async fn register_kms_cluster(&self, cluster_id: &KeyProviderId, management_type: Option<&str>) -> Result<()> {
+    pub async fn register_kms_cluster(&self, cluster_id: &KeyProviderId, management_type: Option<crate::types::enums::KmipClusterInfoKmsManagementTypeEnum>) -> Result<()> {
         let input = RegisterKmsClusterRequestType {cluster_id, management_type, };
         let path = format!("/CryptoManagerKmip/{moId}/RegisterKmsCluster", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -814,7 +814,7 @@ struct RegisterKmsClusterRequestType<'a> {
     cluster_id: &'a KeyProviderId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "managementType")]
-    management_type: Option<&'a str>,
+    management_type: Option<crate::types::enums::KmipClusterInfoKmsManagementTypeEnum>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -918,3 +918,162 @@ struct UploadKmipServerCertRequestType<'a> {
     cluster: &'a KeyProviderId,
     certificate: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CryptoKeyId;
+    use crate::types::structs::CryptoKeyPlain;
+    use crate::types::structs::CryptoKeyResult;
+    use crate::types::structs::CryptoManagerKmipCertSignRequest;
+    use crate::types::structs::CryptoManagerKmipCryptoKeyStatus;
+    use crate::types::structs::CryptoManagerKmipCustomAttributeSpec;
+    use crate::types::structs::CryptoManagerKmipServerCertInfo;
+    use crate::types::structs::KeyProviderId;
+    use crate::types::structs::KmipClusterInfo;
+    use crate::types::structs::KmipServerInfo;
+    use crate::types::structs::KmipServerSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::CryptoManagerKmip`].
+    pub struct CryptoManagerKmip {
+        inner: super::CryptoManagerKmip,
+        client: Arc<Client>,
+    }
+    impl CryptoManagerKmip {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CryptoManagerKmip::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::is_kms_cluster_active`].
+        pub fn is_kms_cluster_active(&self, cluster: Option<&KeyProviderId>) -> Result<bool> {
+            self.client.block_on(self.inner.is_kms_cluster_active(cluster))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::add_key`].
+        pub fn add_key(&self, key: &CryptoKeyPlain) -> Result<()> {
+            self.client.block_on(self.inner.add_key(key))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::add_keys`].
+        pub fn add_keys(&self, keys: Option<&[CryptoKeyPlain]>) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.add_keys(keys))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::generate_client_csr`].
+        pub fn generate_client_csr(&self, cluster: &KeyProviderId, request: Option<&CryptoManagerKmipCertSignRequest>) -> Result<String> {
+            self.client.block_on(self.inner.generate_client_csr(cluster, request))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::generate_key`].
+        pub fn generate_key(&self, key_provider: Option<&KeyProviderId>, spec: Option<&CryptoManagerKmipCustomAttributeSpec>) -> Result<CryptoKeyResult> {
+            self.client.block_on(self.inner.generate_key(key_provider, spec))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::generate_self_signed_client_cert`].
+        pub fn generate_self_signed_client_cert(&self, cluster: &KeyProviderId, request: Option<&CryptoManagerKmipCertSignRequest>) -> Result<String> {
+            self.client.block_on(self.inner.generate_self_signed_client_cert(cluster, request))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::get_default_kms_cluster`].
+        pub fn get_default_kms_cluster(&self, entity: Option<&ManagedObjectReference>, defaults_to_parent: Option<bool>) -> Result<Option<KeyProviderId>> {
+            self.client.block_on(self.inner.get_default_kms_cluster(entity, defaults_to_parent))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::list_keys`].
+        pub fn list_keys(&self, limit: Option<i32>) -> Result<Option<Vec<CryptoKeyId>>> {
+            self.client.block_on(self.inner.list_keys(limit))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::list_kmip_servers`].
+        pub fn list_kmip_servers(&self, limit: Option<i32>) -> Result<Option<Vec<KmipClusterInfo>>> {
+            self.client.block_on(self.inner.list_kmip_servers(limit))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::list_kms_clusters`].
+        pub fn list_kms_clusters(&self, include_kms_servers: Option<bool>, management_type_filter: Option<i32>, status_filter: Option<i32>) -> Result<Option<Vec<KmipClusterInfo>>> {
+            self.client.block_on(self.inner.list_kms_clusters(include_kms_servers, management_type_filter, status_filter))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::mark_default`].
+        pub fn mark_default(&self, cluster_id: &KeyProviderId) -> Result<()> {
+            self.client.block_on(self.inner.mark_default(cluster_id))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::query_crypto_key_status`].
+        pub fn query_crypto_key_status(&self, key_ids: Option<&[CryptoKeyId]>, check_key_bit_map: i32) -> Result<Option<Vec<CryptoManagerKmipCryptoKeyStatus>>> {
+            self.client.block_on(self.inner.query_crypto_key_status(key_ids, check_key_bit_map))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::register_kmip_server`].
+        pub fn register_kmip_server(&self, server: &KmipServerSpec) -> Result<()> {
+            self.client.block_on(self.inner.register_kmip_server(server))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::register_kms_cluster`].
+        pub fn register_kms_cluster(&self, cluster_id: &KeyProviderId, management_type: Option<crate::types::enums::KmipClusterInfoKmsManagementTypeEnum>) -> Result<()> {
+            self.client.block_on(self.inner.register_kms_cluster(cluster_id, management_type))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::remove_key`].
+        pub fn remove_key(&self, key: &CryptoKeyId, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.remove_key(key, force))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::remove_keys`].
+        pub fn remove_keys(&self, keys: Option<&[CryptoKeyId]>, force: bool) -> Result<Option<Vec<CryptoKeyResult>>> {
+            self.client.block_on(self.inner.remove_keys(keys, force))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::remove_kmip_server`].
+        pub fn remove_kmip_server(&self, cluster_id: &KeyProviderId, server_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_kmip_server(cluster_id, server_name))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::retrieve_client_cert`].
+        pub fn retrieve_client_cert(&self, cluster: &KeyProviderId) -> Result<String> {
+            self.client.block_on(self.inner.retrieve_client_cert(cluster))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::retrieve_client_csr`].
+        pub fn retrieve_client_csr(&self, cluster: &KeyProviderId) -> Result<String> {
+            self.client.block_on(self.inner.retrieve_client_csr(cluster))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::retrieve_kmip_server_cert`].
+        pub fn retrieve_kmip_server_cert(&self, key_provider: &KeyProviderId, server: &KmipServerInfo) -> Result<CryptoManagerKmipServerCertInfo> {
+            self.client.block_on(self.inner.retrieve_kmip_server_cert(key_provider, server))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::retrieve_kmip_servers_status_task`].
+        pub fn retrieve_kmip_servers_status_task(&self, clusters: Option<&[KmipClusterInfo]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.retrieve_kmip_servers_status_task(clusters))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::retrieve_self_signed_client_cert`].
+        pub fn retrieve_self_signed_client_cert(&self, cluster: &KeyProviderId) -> Result<String> {
+            self.client.block_on(self.inner.retrieve_self_signed_client_cert(cluster))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::set_default_kms_cluster`].
+        pub fn set_default_kms_cluster(&self, entity: Option<&ManagedObjectReference>, cluster_id: Option<&KeyProviderId>) -> Result<()> {
+            self.client.block_on(self.inner.set_default_kms_cluster(entity, cluster_id))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::set_key_custom_attributes`].
+        pub fn set_key_custom_attributes(&self, key_id: &CryptoKeyId, spec: &CryptoManagerKmipCustomAttributeSpec) -> Result<CryptoKeyResult> {
+            self.client.block_on(self.inner.set_key_custom_attributes(key_id, spec))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::unregister_kms_cluster`].
+        pub fn unregister_kms_cluster(&self, cluster_id: &KeyProviderId) -> Result<()> {
+            self.client.block_on(self.inner.unregister_kms_cluster(cluster_id))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::update_kmip_server`].
+        pub fn update_kmip_server(&self, server: &KmipServerSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_kmip_server(server))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::update_kms_signed_csr_client_cert`].
+        pub fn update_kms_signed_csr_client_cert(&self, cluster: &KeyProviderId, certificate: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_kms_signed_csr_client_cert(cluster, certificate))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::update_self_signed_client_cert`].
+        pub fn update_self_signed_client_cert(&self, cluster: &KeyProviderId, certificate: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_self_signed_client_cert(cluster, certificate))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::upload_client_cert`].
+        pub fn upload_client_cert(&self, cluster: &KeyProviderId, certificate: &str, private_key: &str) -> Result<()> {
+            self.client.block_on(self.inner.upload_client_cert(cluster, certificate, private_key))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::upload_kmip_server_cert`].
+        pub fn upload_kmip_server_cert(&self, cluster: &KeyProviderId, certificate: &str) -> Result<()> {
+            self.client.block_on(self.inner.upload_kmip_server_cert(cluster, certificate))
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::enabled`].
+        pub fn enabled(&self) -> Result<bool> {
+            self.client.block_on(self.inner.enabled())
+        }
+        /// Blocking version of [`super::CryptoManagerKmip::kmip_servers`].
+        pub fn kmip_servers(&self) -> Result<Option<Vec<KmipClusterInfo>>> {
+            self.client.block_on(self.inner.kmip_servers())
+        }
+    }
+}
@@ -161,3 +161,45 @@ struct SetFieldRequestType<'a> {
     key: i32,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::PrivilegePolicyDef;
+    /// Blocking version of [`super::CustomFieldsManager`].
+    pub struct CustomFieldsManager {
+        inner: super::CustomFieldsManager,
+        client: Arc<Client>,
+    }
+    impl CustomFieldsManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CustomFieldsManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CustomFieldsManager::add_custom_field_def`].
+        pub fn add_custom_field_def(&self, name: &str, mo_type: Option<&str>, field_def_policy: Option<&PrivilegePolicyDef>, field_policy: Option<&PrivilegePolicyDef>) -> Result<CustomFieldDef> {
+            self.client.block_on(self.inner.add_custom_field_def(name, mo_type, field_def_policy, field_policy))
+        }
+        /// Blocking version of [`super::CustomFieldsManager::remove_custom_field_def`].
+        pub fn remove_custom_field_def(&self, key: i32) -> Result<()> {
+            self.client.block_on(self.inner.remove_custom_field_def(key))
+        }
+        /// Blocking version of [`super::CustomFieldsManager::rename_custom_field_def`].
+        pub fn rename_custom_field_def(&self, key: i32, name: &str) -> Result<()> {
+            self.client.block_on(self.inner.rename_custom_field_def(key, name))
+        }
+        /// Blocking version of [`super::CustomFieldsManager::set_field`].
+        pub fn set_field(&self, entity: &ManagedObjectReference, key: i32, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_field(entity, key, value))
+        }
+        /// Blocking version of [`super::CustomFieldsManager::field`].
+        pub fn field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.field())
+        }
+    }
+}
@@ -292,3 +292,72 @@ struct XmlToCustomizationSpecItemRequestType<'a> {
     #[serde(rename = "specItemXml")]
     spec_item_xml: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomizationSpecInfo;
+    use crate::types::structs::CustomizationSpecItem;
+    /// Blocking version of [`super::CustomizationSpecManager`].
+    pub struct CustomizationSpecManager {
+        inner: super::CustomizationSpecManager,
+        client: Arc<Client>,
+    }
+    impl CustomizationSpecManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::CustomizationSpecManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::check_customization_resources`].
+        pub fn check_customization_resources(&self, guest_os: &str) -> Result<()> {
+            self.client.block_on(self.inner.check_customization_resources(guest_os))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::create_customization_spec`].
+        pub fn create_customization_spec(&self, item: &CustomizationSpecItem) -> Result<()> {
+            self.client.block_on(self.inner.create_customization_spec(item))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::delete_customization_spec`].
+        pub fn delete_customization_spec(&self, name: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_customization_spec(name))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::duplicate_customization_spec`].
+        pub fn duplicate_customization_spec(&self, name: &str, new_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.duplicate_customization_spec(name, new_name))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::does_customization_spec_exist`].
+        pub fn does_customization_spec_exist(&self, name: &str) -> Result<bool> {
+            self.client.block_on(self.inner.does_customization_spec_exist(name))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::get_customization_spec`].
+        pub fn get_customization_spec(&self, name: &str) -> Result<CustomizationSpecItem> {
+            self.client.block_on(self.inner.get_customization_spec(name))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::overwrite_customization_spec`].
+        pub fn overwrite_customization_spec(&self, item: &CustomizationSpecItem) -> Result<()> {
+            self.client.block_on(self.inner.overwrite_customization_spec(item))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::rename_customization_spec`].
+        pub fn rename_customization_spec(&self, name: &str, new_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.rename_customization_spec(name, new_name))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::customization_spec_item_to_xml`].
+        pub fn customization_spec_item_to_xml(&self, item: &CustomizationSpecItem) -> Result<String> {
+            self.client.block_on(self.inner.customization_spec_item_to_xml(item))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::xml_to_customization_spec_item`].
+        pub fn xml_to_customization_spec_item(&self, spec_item_xml: &str) -> Result<CustomizationSpecItem> {
+            self.client.block_on(self.inner.xml_to_customization_spec_item(spec_item_xml))
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::encryption_key`].
+        pub fn encryption_key(&self) -> Result<Option<Vec<i8>>> {
+            self.client.block_on(self.inner.encryption_key())
+        }
+        /// Blocking version of [`super::CustomizationSpecManager::info`].
+        pub fn info(&self) -> Result<Option<Vec<CustomizationSpecInfo>>> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
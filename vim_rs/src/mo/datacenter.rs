@@ -782,3 +782,166 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::DatacenterBasicConnectInfo;
+    use crate::types::structs::DatacenterConfigInfo;
+    use crate::types::structs::DatacenterConfigSpec;
+    use crate::types::structs::Event;
+    use crate::types::structs::HostConnectInfo;
+    use crate::types::structs::HostConnectSpec;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VirtualMachineConfigOptionDescriptor;
+    /// Blocking version of [`super::Datacenter`].
+    pub struct Datacenter {
+        inner: super::Datacenter,
+        client: Arc<Client>,
+    }
+    impl Datacenter {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::Datacenter::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::Datacenter::batch_query_connect_info`].
+        pub fn batch_query_connect_info(&self, host_specs: Option<&[HostConnectSpec]>) -> Result<Option<Vec<DatacenterBasicConnectInfo>>> {
+            self.client.block_on(self.inner.batch_query_connect_info(host_specs))
+        }
+        /// Blocking version of [`super::Datacenter::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::Datacenter::power_on_multi_vm_task`].
+        pub fn power_on_multi_vm_task(&self, vm: &[ManagedObjectReference], option: Option<&[Box<dyn crate::types::traits::OptionValueTrait>]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_on_multi_vm_task(vm, option))
+        }
+        /// Blocking version of [`super::Datacenter::query_datacenter_config_option_descriptor`].
+        pub fn query_datacenter_config_option_descriptor(&self) -> Result<Option<Vec<VirtualMachineConfigOptionDescriptor>>> {
+            self.client.block_on(self.inner.query_datacenter_config_option_descriptor())
+        }
+        /// Blocking version of [`super::Datacenter::query_connection_info`].
+        pub fn query_connection_info(&self, hostname: &str, port: i32, username: &str, password: &str, ssl_thumbprint: Option<&str>) -> Result<HostConnectInfo> {
+            self.client.block_on(self.inner.query_connection_info(hostname, port, username, password, ssl_thumbprint))
+        }
+        /// Blocking version of [`super::Datacenter::query_connection_info_via_spec`].
+        pub fn query_connection_info_via_spec(&self, spec: &HostConnectSpec) -> Result<HostConnectInfo> {
+            self.client.block_on(self.inner.query_connection_info_via_spec(spec))
+        }
+        /// Blocking version of [`super::Datacenter::reconfigure_datacenter_task`].
+        pub fn reconfigure_datacenter_task(&self, spec: &DatacenterConfigSpec, modify: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_datacenter_task(spec, modify))
+        }
+        /// Blocking version of [`super::Datacenter::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::Datacenter::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::Datacenter::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::Datacenter::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::Datacenter::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::Datacenter::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::Datacenter::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::Datacenter::configuration`].
+        pub fn configuration(&self) -> Result<DatacenterConfigInfo> {
+            self.client.block_on(self.inner.configuration())
+        }
+        /// Blocking version of [`super::Datacenter::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::Datacenter::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::Datacenter::datastore_folder`].
+        pub fn datastore_folder(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.datastore_folder())
+        }
+        /// Blocking version of [`super::Datacenter::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::Datacenter::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::Datacenter::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::Datacenter::host_folder`].
+        pub fn host_folder(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_folder())
+        }
+        /// Blocking version of [`super::Datacenter::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::Datacenter::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::Datacenter::network_folder`].
+        pub fn network_folder(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.network_folder())
+        }
+        /// Blocking version of [`super::Datacenter::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::Datacenter::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::Datacenter::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::Datacenter::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::Datacenter::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::Datacenter::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::Datacenter::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::Datacenter::vm_folder`].
+        pub fn vm_folder(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.vm_folder())
+        }
+    }
+}
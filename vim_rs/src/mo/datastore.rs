@@ -803,3 +803,179 @@ struct UpdateVirtualMachineFilesRequestType<'a> {
     #[serde(rename = "mountPathDatastoreMapping")]
     mount_path_datastore_mapping: &'a [DatastoreMountPathDatastorePair],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::DatastoreCapability;
+    use crate::types::structs::DatastoreHostMount;
+    use crate::types::structs::DatastoreMountPathDatastorePair;
+    use crate::types::structs::DatastoreSummary;
+    use crate::types::structs::DatastoreVVolContainerFailoverPair;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::StorageIormInfo;
+    use crate::types::structs::StoragePlacementResult;
+    use crate::types::structs::Tag;
+    /// Blocking version of [`super::Datastore`].
+    pub struct Datastore {
+        inner: super::Datastore,
+        client: Arc<Client>,
+    }
+    impl Datastore {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::Datastore::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::Datastore::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::Datastore::destroy_datastore`].
+        pub fn destroy_datastore(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_datastore())
+        }
+        /// Blocking version of [`super::Datastore::datastore_enter_maintenance_mode`].
+        pub fn datastore_enter_maintenance_mode(&self) -> Result<StoragePlacementResult> {
+            self.client.block_on(self.inner.datastore_enter_maintenance_mode())
+        }
+        /// Blocking version of [`super::Datastore::datastore_exit_maintenance_mode_task`].
+        pub fn datastore_exit_maintenance_mode_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.datastore_exit_maintenance_mode_task())
+        }
+        /// Blocking version of [`super::Datastore::is_clustered_vmdk_enabled`].
+        pub fn is_clustered_vmdk_enabled(&self) -> Result<bool> {
+            self.client.block_on(self.inner.is_clustered_vmdk_enabled())
+        }
+        /// Blocking version of [`super::Datastore::refresh_datastore`].
+        pub fn refresh_datastore(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_datastore())
+        }
+        /// Blocking version of [`super::Datastore::refresh_datastore_storage_info`].
+        pub fn refresh_datastore_storage_info(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_datastore_storage_info())
+        }
+        /// Blocking version of [`super::Datastore::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::Datastore::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::Datastore::rename_datastore`].
+        pub fn rename_datastore(&self, new_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.rename_datastore(new_name))
+        }
+        /// Blocking version of [`super::Datastore::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::Datastore::update_v_vol_virtual_machine_files_task`].
+        pub fn update_v_vol_virtual_machine_files_task(&self, failover_pair: Option<&[DatastoreVVolContainerFailoverPair]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_v_vol_virtual_machine_files_task(failover_pair))
+        }
+        /// Blocking version of [`super::Datastore::update_virtual_machine_files_task`].
+        pub fn update_virtual_machine_files_task(&self, mount_path_datastore_mapping: &[DatastoreMountPathDatastorePair]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_virtual_machine_files_task(mount_path_datastore_mapping))
+        }
+        /// Blocking version of [`super::Datastore::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::Datastore::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::Datastore::browser`].
+        pub fn browser(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.browser())
+        }
+        /// Blocking version of [`super::Datastore::capability`].
+        pub fn capability(&self) -> Result<DatastoreCapability> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::Datastore::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::Datastore::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::Datastore::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::Datastore::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::Datastore::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::Datastore::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::Datastore::host`].
+        pub fn host(&self) -> Result<Option<Vec<DatastoreHostMount>>> {
+            self.client.block_on(self.inner.host())
+        }
+        /// Blocking version of [`super::Datastore::info`].
+        pub fn info(&self) -> Result<Box<dyn crate::types::traits::DatastoreInfoTrait>> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::Datastore::iorm_configuration`].
+        pub fn iorm_configuration(&self) -> Result<Option<StorageIormInfo>> {
+            self.client.block_on(self.inner.iorm_configuration())
+        }
+        /// Blocking version of [`super::Datastore::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::Datastore::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::Datastore::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::Datastore::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::Datastore::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::Datastore::summary`].
+        pub fn summary(&self) -> Result<DatastoreSummary> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::Datastore::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::Datastore::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::Datastore::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::Datastore::vm`].
+        pub fn vm(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
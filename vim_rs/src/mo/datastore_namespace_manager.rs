@@ -300,3 +300,44 @@ struct QueryDirectoryInfoRequestType<'a> {
     #[serde(rename = "stableName")]
     stable_name: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::DatastoreNamespaceManagerDirectoryInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::DatastoreNamespaceManager`].
+    pub struct DatastoreNamespaceManager {
+        inner: super::DatastoreNamespaceManager,
+        client: Arc<Client>,
+    }
+    impl DatastoreNamespaceManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::DatastoreNamespaceManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::DatastoreNamespaceManager::convert_namespace_path_to_uuid_path`].
+        pub fn convert_namespace_path_to_uuid_path(&self, datacenter: Option<&ManagedObjectReference>, namespace_url: &str) -> Result<String> {
+            self.client.block_on(self.inner.convert_namespace_path_to_uuid_path(datacenter, namespace_url))
+        }
+        /// Blocking version of [`super::DatastoreNamespaceManager::create_directory`].
+        pub fn create_directory(&self, datastore: &ManagedObjectReference, display_name: Option<&str>, policy: Option<&str>, size: Option<i64>) -> Result<String> {
+            self.client.block_on(self.inner.create_directory(datastore, display_name, policy, size))
+        }
+        /// Blocking version of [`super::DatastoreNamespaceManager::delete_directory`].
+        pub fn delete_directory(&self, datacenter: Option<&ManagedObjectReference>, datastore_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_directory(datacenter, datastore_path))
+        }
+        /// Blocking version of [`super::DatastoreNamespaceManager::increase_directory_size`].
+        pub fn increase_directory_size(&self, datacenter: Option<&ManagedObjectReference>, stable_name: &str, size: i64) -> Result<()> {
+            self.client.block_on(self.inner.increase_directory_size(datacenter, stable_name, size))
+        }
+        /// Blocking version of [`super::DatastoreNamespaceManager::query_directory_info`].
+        pub fn query_directory_info(&self, datacenter: Option<&ManagedObjectReference>, stable_name: &str) -> Result<DatastoreNamespaceManagerDirectoryInfo> {
+            self.client.block_on(self.inner.query_directory_info(datacenter, stable_name))
+        }
+    }
+}
@@ -248,3 +248,46 @@ struct QueryDescriptionsRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     host: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::DiagnosticManagerAuditRecordResult;
+    use crate::types::structs::DiagnosticManagerLogDescriptor;
+    use crate::types::structs::DiagnosticManagerLogHeader;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::DiagnosticManager`].
+    pub struct DiagnosticManager {
+        inner: super::DiagnosticManager,
+        client: Arc<Client>,
+    }
+    impl DiagnosticManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::DiagnosticManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::DiagnosticManager::emit_syslog_mark`].
+        pub fn emit_syslog_mark(&self, message: &str) -> Result<()> {
+            self.client.block_on(self.inner.emit_syslog_mark(message))
+        }
+        /// Blocking version of [`super::DiagnosticManager::fetch_audit_records`].
+        pub fn fetch_audit_records(&self, token: Option<&str>) -> Result<DiagnosticManagerAuditRecordResult> {
+            self.client.block_on(self.inner.fetch_audit_records(token))
+        }
+        /// Blocking version of [`super::DiagnosticManager::browse_diagnostic_log`].
+        pub fn browse_diagnostic_log(&self, host: Option<&ManagedObjectReference>, key: &str, start: Option<i32>, lines: Option<i32>) -> Result<DiagnosticManagerLogHeader> {
+            self.client.block_on(self.inner.browse_diagnostic_log(host, key, start, lines))
+        }
+        /// Blocking version of [`super::DiagnosticManager::generate_log_bundles_task`].
+        pub fn generate_log_bundles_task(&self, include_default: bool, host: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.generate_log_bundles_task(include_default, host))
+        }
+        /// Blocking version of [`super::DiagnosticManager::query_descriptions`].
+        pub fn query_descriptions(&self, host: Option<&ManagedObjectReference>) -> Result<Option<Vec<DiagnosticManagerLogDescriptor>>> {
+            self.client.block_on(self.inner.query_descriptions(host))
+        }
+    }
+}
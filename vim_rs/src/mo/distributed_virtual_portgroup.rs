@@ -618,3 +618,147 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::DvPortgroupConfigInfo;
+    use crate::types::structs::DvPortgroupConfigSpec;
+    use crate::types::structs::EntityBackupConfig;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::Tag;
+    /// Blocking version of [`super::DistributedVirtualPortgroup`].
+    pub struct DistributedVirtualPortgroup {
+        inner: super::DistributedVirtualPortgroup,
+        client: Arc<Client>,
+    }
+    impl DistributedVirtualPortgroup {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::DistributedVirtualPortgroup::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::destroy_network`].
+        pub fn destroy_network(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_network())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::reconfigure_dv_portgroup_task`].
+        pub fn reconfigure_dv_portgroup_task(&self, spec: &DvPortgroupConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_dv_portgroup_task(spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::dv_portgroup_rollback_task`].
+        pub fn dv_portgroup_rollback_task(&self, entity_backup: Option<&EntityBackupConfig>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.dv_portgroup_rollback_task(entity_backup))
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::config`].
+        pub fn config(&self) -> Result<DvPortgroupConfigInfo> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::host`].
+        pub fn host(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.host())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::key`].
+        pub fn key(&self) -> Result<String> {
+            self.client.block_on(self.inner.key())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::port_keys`].
+        pub fn port_keys(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.port_keys())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::summary`].
+        pub fn summary(&self) -> Result<Box<dyn crate::types::traits::NetworkSummaryTrait>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::DistributedVirtualPortgroup::vm`].
+        pub fn vm(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
@@ -142,7 +142,7 @@ impl DistributedVirtualSwitchManager {
     /// ***NotFound***: If entity in *EntityBackupConfig.key* doesn't exist.
     /// 
     /// ***DvsFault***: if operation fails on any host.
-    pub async fn dvs_manager_import_entity_task(&self, entity_backup: &[EntityBackupConfig], import_type: &str) -> Result<ManagedObjectReference> {
+    pub async fn dvs_manager_import_entity_task(&self, entity_backup: &[EntityBackupConfig], import_type: crate::types::enums::EntityImportTypeEnum) -> Result<ManagedObjectReference> {
         let input = DvsManagerImportEntityRequestType {entity_backup, import_type, };
         let path = format!("/DistributedVirtualSwitchManager/{moId}/DVSManagerImportEntity_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -443,7 +443,7 @@ struct DvsManagerImportEntityRequestType<'a> {
     #[serde(rename = "entityBackup")]
     entity_backup: &'a [EntityBackupConfig],
     #[serde(rename = "importType")]
-    import_type: &'a str,
+    import_type: crate::types::enums::EntityImportTypeEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(rename = "DVSManagerLookupDvPortGroupRequestType", tag = "_typeName")]
@@ -520,3 +520,88 @@ struct QueryDvsByUuidRequestType<'a> {
 struct RectifyDvsOnHostRequestType<'a> {
     hosts: &'a [ManagedObjectReference],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::DistributedVirtualSwitchHostProductSpec;
+    use crate::types::structs::DistributedVirtualSwitchManagerCompatibilityResult;
+    use crate::types::structs::DistributedVirtualSwitchManagerDvsProductSpec;
+    use crate::types::structs::DistributedVirtualSwitchManagerHostContainer;
+    use crate::types::structs::DistributedVirtualSwitchNetworkOffloadSpec;
+    use crate::types::structs::DistributedVirtualSwitchProductSpec;
+    use crate::types::structs::DvsManagerDvsConfigTarget;
+    use crate::types::structs::DvsManagerPhysicalNicsList;
+    use crate::types::structs::EntityBackupConfig;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::DistributedVirtualSwitchManager`].
+    pub struct DistributedVirtualSwitchManager {
+        inner: super::DistributedVirtualSwitchManager,
+        client: Arc<Client>,
+    }
+    impl DistributedVirtualSwitchManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::DistributedVirtualSwitchManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_dvs_check_compatibility`].
+        pub fn query_dvs_check_compatibility(&self, host_container: &DistributedVirtualSwitchManagerHostContainer, dvs_product_spec: Option<&DistributedVirtualSwitchManagerDvsProductSpec>, host_filter_spec: Option<&[Box<dyn crate::types::traits::DistributedVirtualSwitchManagerHostDvsFilterSpecTrait>]>) -> Result<Option<Vec<DistributedVirtualSwitchManagerCompatibilityResult>>> {
+            self.client.block_on(self.inner.query_dvs_check_compatibility(host_container, dvs_product_spec, host_filter_spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::dvs_manager_export_entity_task`].
+        pub fn dvs_manager_export_entity_task(&self, selection_set: &[Box<dyn crate::types::traits::SelectionSetTrait>]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.dvs_manager_export_entity_task(selection_set))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::dvs_manager_import_entity_task`].
+        pub fn dvs_manager_import_entity_task(&self, entity_backup: &[EntityBackupConfig], import_type: crate::types::enums::EntityImportTypeEnum) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.dvs_manager_import_entity_task(entity_backup, import_type))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::dvs_manager_lookup_dv_port_group`].
+        pub fn dvs_manager_lookup_dv_port_group(&self, switch_uuid: &str, portgroup_key: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.dvs_manager_lookup_dv_port_group(switch_uuid, portgroup_key))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_compatible_host_for_existing_dvs`].
+        pub fn query_compatible_host_for_existing_dvs(&self, container: &ManagedObjectReference, recursive: bool, dvs: &ManagedObjectReference) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_compatible_host_for_existing_dvs(container, recursive, dvs))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_compatible_host_for_new_dvs`].
+        pub fn query_compatible_host_for_new_dvs(&self, container: &ManagedObjectReference, recursive: bool, switch_product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_compatible_host_for_new_dvs(container, recursive, switch_product_spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_dvs_compatible_host_spec`].
+        pub fn query_dvs_compatible_host_spec(&self, switch_product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<Option<Vec<DistributedVirtualSwitchHostProductSpec>>> {
+            self.client.block_on(self.inner.query_dvs_compatible_host_spec(switch_product_spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_compatible_vmnics_from_hosts`].
+        pub fn query_compatible_vmnics_from_hosts(&self, hosts: Option<&[ManagedObjectReference]>, dvs: &ManagedObjectReference) -> Result<Option<Vec<DvsManagerPhysicalNicsList>>> {
+            self.client.block_on(self.inner.query_compatible_vmnics_from_hosts(hosts, dvs))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_dvs_config_target`].
+        pub fn query_dvs_config_target(&self, host: Option<&ManagedObjectReference>, dvs: Option<&ManagedObjectReference>) -> Result<DvsManagerDvsConfigTarget> {
+            self.client.block_on(self.inner.query_dvs_config_target(host, dvs))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_dvs_feature_capability`].
+        pub fn query_dvs_feature_capability(&self, switch_product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<Option<Box<dyn crate::types::traits::DvsFeatureCapabilityTrait>>> {
+            self.client.block_on(self.inner.query_dvs_feature_capability(switch_product_spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_supported_network_offload_spec`].
+        pub fn query_supported_network_offload_spec(&self, switch_product_spec: &DistributedVirtualSwitchProductSpec) -> Result<Option<Vec<DistributedVirtualSwitchNetworkOffloadSpec>>> {
+            self.client.block_on(self.inner.query_supported_network_offload_spec(switch_product_spec))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_available_dvs_spec`].
+        pub fn query_available_dvs_spec(&self, recommended: Option<bool>) -> Result<Option<Vec<DistributedVirtualSwitchProductSpec>>> {
+            self.client.block_on(self.inner.query_available_dvs_spec(recommended))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::query_dvs_by_uuid`].
+        pub fn query_dvs_by_uuid(&self, uuid: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.query_dvs_by_uuid(uuid))
+        }
+        /// Blocking version of [`super::DistributedVirtualSwitchManager::rectify_dvs_on_host_task`].
+        pub fn rectify_dvs_on_host_task(&self, hosts: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rectify_dvs_on_host_task(hosts))
+        }
+    }
+}
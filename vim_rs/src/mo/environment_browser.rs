@@ -239,3 +239,52 @@ struct QueryTargetCapabilitiesRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     host: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ConfigTarget;
+    use crate::types::structs::EnvironmentBrowserConfigOptionQuerySpec;
+    use crate::types::structs::HostCapability;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualMachineConfigOption;
+    use crate::types::structs::VirtualMachineConfigOptionDescriptor;
+    /// Blocking version of [`super::EnvironmentBrowser`].
+    pub struct EnvironmentBrowser {
+        inner: super::EnvironmentBrowser,
+        client: Arc<Client>,
+    }
+    impl EnvironmentBrowser {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::EnvironmentBrowser::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::query_config_option`].
+        pub fn query_config_option(&self, key: Option<&str>, host: Option<&ManagedObjectReference>) -> Result<Option<VirtualMachineConfigOption>> {
+            self.client.block_on(self.inner.query_config_option(key, host))
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::query_config_option_descriptor`].
+        pub fn query_config_option_descriptor(&self) -> Result<Option<Vec<VirtualMachineConfigOptionDescriptor>>> {
+            self.client.block_on(self.inner.query_config_option_descriptor())
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::query_config_option_ex`].
+        pub fn query_config_option_ex(&self, spec: Option<&EnvironmentBrowserConfigOptionQuerySpec>) -> Result<Option<VirtualMachineConfigOption>> {
+            self.client.block_on(self.inner.query_config_option_ex(spec))
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::query_config_target`].
+        pub fn query_config_target(&self, host: Option<&ManagedObjectReference>) -> Result<Option<ConfigTarget>> {
+            self.client.block_on(self.inner.query_config_target(host))
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::query_target_capabilities`].
+        pub fn query_target_capabilities(&self, host: Option<&ManagedObjectReference>) -> Result<Option<HostCapability>> {
+            self.client.block_on(self.inner.query_target_capabilities(host))
+        }
+        /// Blocking version of [`super::EnvironmentBrowser::datastore_browser`].
+        pub fn datastore_browser(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.datastore_browser())
+        }
+    }
+}
@@ -137,3 +137,56 @@ struct SetCollectorPageSizeRequestType {
     #[serde(rename = "maxCount")]
     max_count: i32,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Event;
+    use crate::types::vim_any::VimAny;
+    /// Blocking version of [`super::EventHistoryCollector`].
+    pub struct EventHistoryCollector {
+        inner: super::EventHistoryCollector,
+        client: Arc<Client>,
+    }
+    impl EventHistoryCollector {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::EventHistoryCollector::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::EventHistoryCollector::read_next_events`].
+        pub fn read_next_events(&self, max_count: i32) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.read_next_events(max_count))
+        }
+        /// Blocking version of [`super::EventHistoryCollector::read_previous_events`].
+        pub fn read_previous_events(&self, max_count: i32) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.read_previous_events(max_count))
+        }
+        /// Blocking version of [`super::EventHistoryCollector::destroy_collector`].
+        pub fn destroy_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_collector())
+        }
+        /// Blocking version of [`super::EventHistoryCollector::reset_collector`].
+        pub fn reset_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_collector())
+        }
+        /// Blocking version of [`super::EventHistoryCollector::rewind_collector`].
+        pub fn rewind_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.rewind_collector())
+        }
+        /// Blocking version of [`super::EventHistoryCollector::set_collector_page_size`].
+        pub fn set_collector_page_size(&self, max_count: i32) -> Result<()> {
+            self.client.block_on(self.inner.set_collector_page_size(max_count))
+        }
+        /// Blocking version of [`super::EventHistoryCollector::filter`].
+        pub fn filter(&self) -> Result<VimAny> {
+            self.client.block_on(self.inner.filter())
+        }
+        /// Blocking version of [`super::EventHistoryCollector::latest_page`].
+        pub fn latest_page(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.latest_page())
+        }
+    }
+}
@@ -209,3 +209,60 @@ struct RetrieveArgumentDescriptionRequestType<'a> {
     #[serde(rename = "eventTypeId")]
     event_type_id: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Event;
+    use crate::types::structs::EventArgDesc;
+    use crate::types::structs::EventDescription;
+    use crate::types::structs::EventFilterSpec;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::TaskInfo;
+    /// Blocking version of [`super::EventManager`].
+    pub struct EventManager {
+        inner: super::EventManager,
+        client: Arc<Client>,
+    }
+    impl EventManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::EventManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::EventManager::query_events`].
+        pub fn query_events(&self, filter: &EventFilterSpec) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.query_events(filter))
+        }
+        /// Blocking version of [`super::EventManager::create_collector_for_events`].
+        pub fn create_collector_for_events(&self, filter: &EventFilterSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_collector_for_events(filter))
+        }
+        /// Blocking version of [`super::EventManager::log_user_event`].
+        pub fn log_user_event(&self, entity: &ManagedObjectReference, msg: &str) -> Result<()> {
+            self.client.block_on(self.inner.log_user_event(entity, msg))
+        }
+        /// Blocking version of [`super::EventManager::post_event`].
+        pub fn post_event(&self, event_to_post: &Event, task_info: Option<&TaskInfo>) -> Result<()> {
+            self.client.block_on(self.inner.post_event(event_to_post, task_info))
+        }
+        /// Blocking version of [`super::EventManager::retrieve_argument_description`].
+        pub fn retrieve_argument_description(&self, event_type_id: &str) -> Result<Option<Vec<EventArgDesc>>> {
+            self.client.block_on(self.inner.retrieve_argument_description(event_type_id))
+        }
+        /// Blocking version of [`super::EventManager::description`].
+        pub fn description(&self) -> Result<EventDescription> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::EventManager::latest_event`].
+        pub fn latest_event(&self) -> Result<Option<Event>> {
+            self.client.block_on(self.inner.latest_event())
+        }
+        /// Blocking version of [`super::EventManager::max_collector`].
+        pub fn max_collector(&self) -> Result<i32> {
+            self.client.block_on(self.inner.max_collector())
+        }
+    }
+}
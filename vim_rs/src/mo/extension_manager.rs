@@ -350,3 +350,69 @@ struct UnregisterExtensionRequestType<'a> {
 struct UpdateExtensionRequestType<'a> {
     extension: &'a Extension,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Extension;
+    use crate::types::structs::ExtensionManagerIpAllocationUsage;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ExtensionManager`].
+    pub struct ExtensionManager {
+        inner: super::ExtensionManager,
+        client: Arc<Client>,
+    }
+    impl ExtensionManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ExtensionManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ExtensionManager::find_extension`].
+        pub fn find_extension(&self, extension_key: &str) -> Result<Option<Extension>> {
+            self.client.block_on(self.inner.find_extension(extension_key))
+        }
+        /// Blocking version of [`super::ExtensionManager::get_public_key`].
+        pub fn get_public_key(&self) -> Result<String> {
+            self.client.block_on(self.inner.get_public_key())
+        }
+        /// Blocking version of [`super::ExtensionManager::query_extension_ip_allocation_usage`].
+        pub fn query_extension_ip_allocation_usage(&self, extension_keys: Option<&[String]>) -> Result<Option<Vec<ExtensionManagerIpAllocationUsage>>> {
+            self.client.block_on(self.inner.query_extension_ip_allocation_usage(extension_keys))
+        }
+        /// Blocking version of [`super::ExtensionManager::query_managed_by`].
+        pub fn query_managed_by(&self, extension_key: &str) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_managed_by(extension_key))
+        }
+        /// Blocking version of [`super::ExtensionManager::register_extension`].
+        pub fn register_extension(&self, extension: &Extension) -> Result<()> {
+            self.client.block_on(self.inner.register_extension(extension))
+        }
+        /// Blocking version of [`super::ExtensionManager::set_extension_certificate`].
+        pub fn set_extension_certificate(&self, extension_key: &str, certificate_pem: Option<&str>) -> Result<()> {
+            self.client.block_on(self.inner.set_extension_certificate(extension_key, certificate_pem))
+        }
+        /// Blocking version of [`super::ExtensionManager::set_public_key`].
+        pub fn set_public_key(&self, extension_key: &str, public_key: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_public_key(extension_key, public_key))
+        }
+        /// Blocking version of [`super::ExtensionManager::set_service_account`].
+        pub fn set_service_account(&self, extension_key: &str, service_account: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_service_account(extension_key, service_account))
+        }
+        /// Blocking version of [`super::ExtensionManager::unregister_extension`].
+        pub fn unregister_extension(&self, extension_key: &str) -> Result<()> {
+            self.client.block_on(self.inner.unregister_extension(extension_key))
+        }
+        /// Blocking version of [`super::ExtensionManager::update_extension`].
+        pub fn update_extension(&self, extension: &Extension) -> Result<()> {
+            self.client.block_on(self.inner.update_extension(extension))
+        }
+        /// Blocking version of [`super::ExtensionManager::extension_list`].
+        pub fn extension_list(&self) -> Result<Option<Vec<Extension>>> {
+            self.client.block_on(self.inner.extension_list())
+        }
+    }
+}
@@ -275,3 +275,61 @@ struct PrepareVchaRequestType<'a> {
     #[serde(rename = "networkSpec")]
     network_spec: &'a VchaClusterNetworkSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::PassiveNodeDeploymentSpec;
+    use crate::types::structs::SourceNodeSpec;
+    use crate::types::structs::VchaClusterConfigInfo;
+    use crate::types::structs::VchaClusterConfigSpec;
+    use crate::types::structs::VchaClusterDeploymentSpec;
+    use crate::types::structs::VchaClusterNetworkSpec;
+    /// Blocking version of [`super::FailoverClusterConfigurator`].
+    pub struct FailoverClusterConfigurator {
+        inner: super::FailoverClusterConfigurator,
+        client: Arc<Client>,
+    }
+    impl FailoverClusterConfigurator {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::FailoverClusterConfigurator::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::configure_vcha_task`].
+        pub fn configure_vcha_task(&self, config_spec: &VchaClusterConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_vcha_task(config_spec))
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::create_passive_node_task`].
+        pub fn create_passive_node_task(&self, passive_deployment_spec: &PassiveNodeDeploymentSpec, source_vc_spec: &SourceNodeSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_passive_node_task(passive_deployment_spec, source_vc_spec))
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::create_witness_node_task`].
+        pub fn create_witness_node_task(&self, witness_deployment_spec: &dyn crate::types::traits::NodeDeploymentSpecTrait, source_vc_spec: &SourceNodeSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_witness_node_task(witness_deployment_spec, source_vc_spec))
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::deploy_vcha_task`].
+        pub fn deploy_vcha_task(&self, deployment_spec: &VchaClusterDeploymentSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.deploy_vcha_task(deployment_spec))
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::destroy_vcha_task`].
+        pub fn destroy_vcha_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_vcha_task())
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::get_vcha_config`].
+        pub fn get_vcha_config(&self) -> Result<VchaClusterConfigInfo> {
+            self.client.block_on(self.inner.get_vcha_config())
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::prepare_vcha_task`].
+        pub fn prepare_vcha_task(&self, network_spec: &VchaClusterNetworkSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.prepare_vcha_task(network_spec))
+        }
+        /// Blocking version of [`super::FailoverClusterConfigurator::disabled_configure_method`].
+        pub fn disabled_configure_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_configure_method())
+        }
+    }
+}
@@ -161,3 +161,44 @@ struct InitiateFailoverRequestType {
 struct SetClusterModeRequestType<'a> {
     mode: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VchaClusterHealth;
+    /// Blocking version of [`super::FailoverClusterManager`].
+    pub struct FailoverClusterManager {
+        inner: super::FailoverClusterManager,
+        client: Arc<Client>,
+    }
+    impl FailoverClusterManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::FailoverClusterManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::FailoverClusterManager::get_vcha_cluster_health`].
+        pub fn get_vcha_cluster_health(&self) -> Result<VchaClusterHealth> {
+            self.client.block_on(self.inner.get_vcha_cluster_health())
+        }
+        /// Blocking version of [`super::FailoverClusterManager::get_cluster_mode`].
+        pub fn get_cluster_mode(&self) -> Result<String> {
+            self.client.block_on(self.inner.get_cluster_mode())
+        }
+        /// Blocking version of [`super::FailoverClusterManager::initiate_failover_task`].
+        pub fn initiate_failover_task(&self, planned: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.initiate_failover_task(planned))
+        }
+        /// Blocking version of [`super::FailoverClusterManager::set_cluster_mode_task`].
+        pub fn set_cluster_mode_task(&self, mode: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.set_cluster_mode_task(mode))
+        }
+        /// Blocking version of [`super::FailoverClusterManager::disabled_cluster_method`].
+        pub fn disabled_cluster_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_cluster_method())
+        }
+    }
+}
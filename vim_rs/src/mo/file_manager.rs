@@ -502,3 +502,48 @@ struct QueryFileLockInfoRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     host: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::FileLockInfoResult;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::FileManager`].
+    pub struct FileManager {
+        inner: super::FileManager,
+        client: Arc<Client>,
+    }
+    impl FileManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::FileManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::FileManager::change_owner`].
+        pub fn change_owner(&self, name: &str, datacenter: Option<&ManagedObjectReference>, owner: &str) -> Result<()> {
+            self.client.block_on(self.inner.change_owner(name, datacenter, owner))
+        }
+        /// Blocking version of [`super::FileManager::copy_datastore_file_task`].
+        pub fn copy_datastore_file_task(&self, source_name: &str, source_datacenter: Option<&ManagedObjectReference>, destination_name: &str, destination_datacenter: Option<&ManagedObjectReference>, force: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.copy_datastore_file_task(source_name, source_datacenter, destination_name, destination_datacenter, force))
+        }
+        /// Blocking version of [`super::FileManager::delete_datastore_file_task`].
+        pub fn delete_datastore_file_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_datastore_file_task(name, datacenter))
+        }
+        /// Blocking version of [`super::FileManager::make_directory`].
+        pub fn make_directory(&self, name: &str, datacenter: Option<&ManagedObjectReference>, create_parent_directories: Option<bool>) -> Result<()> {
+            self.client.block_on(self.inner.make_directory(name, datacenter, create_parent_directories))
+        }
+        /// Blocking version of [`super::FileManager::move_datastore_file_task`].
+        pub fn move_datastore_file_task(&self, source_name: &str, source_datacenter: Option<&ManagedObjectReference>, destination_name: &str, destination_datacenter: Option<&ManagedObjectReference>, force: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_datastore_file_task(source_name, source_datacenter, destination_name, destination_datacenter, force))
+        }
+        /// Blocking version of [`super::FileManager::query_file_lock_info`].
+        pub fn query_file_lock_info(&self, path: &str, host: Option<&ManagedObjectReference>) -> Result<FileLockInfoResult> {
+            self.client.block_on(self.inner.query_file_lock_info(path, host))
+        }
+    }
+}
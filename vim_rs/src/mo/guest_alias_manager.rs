@@ -465,3 +465,46 @@ struct RemoveGuestAliasByCertRequestType<'a> {
     #[serde(rename = "base64Cert")]
     base_64_cert: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::GuestAliases;
+    use crate::types::structs::GuestAuthAliasInfo;
+    use crate::types::structs::GuestMappedAliases;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestAliasManager`].
+    pub struct GuestAliasManager {
+        inner: super::GuestAliasManager,
+        client: Arc<Client>,
+    }
+    impl GuestAliasManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestAliasManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestAliasManager::add_guest_alias`].
+        pub fn add_guest_alias(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, username: &str, map_cert: bool, base_64_cert: &str, alias_info: &GuestAuthAliasInfo) -> Result<()> {
+            self.client.block_on(self.inner.add_guest_alias(vm, auth, username, map_cert, base_64_cert, alias_info))
+        }
+        /// Blocking version of [`super::GuestAliasManager::list_guest_aliases`].
+        pub fn list_guest_aliases(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, username: &str) -> Result<Option<Vec<GuestAliases>>> {
+            self.client.block_on(self.inner.list_guest_aliases(vm, auth, username))
+        }
+        /// Blocking version of [`super::GuestAliasManager::list_guest_mapped_aliases`].
+        pub fn list_guest_mapped_aliases(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait) -> Result<Option<Vec<GuestMappedAliases>>> {
+            self.client.block_on(self.inner.list_guest_mapped_aliases(vm, auth))
+        }
+        /// Blocking version of [`super::GuestAliasManager::remove_guest_alias`].
+        pub fn remove_guest_alias(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, username: &str, base_64_cert: &str, subject: &dyn crate::types::traits::GuestAuthSubjectTrait) -> Result<()> {
+            self.client.block_on(self.inner.remove_guest_alias(vm, auth, username, base_64_cert, subject))
+        }
+        /// Blocking version of [`super::GuestAliasManager::remove_guest_alias_by_cert`].
+        pub fn remove_guest_alias_by_cert(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, username: &str, base_64_cert: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_guest_alias_by_cert(vm, auth, username, base_64_cert))
+        }
+    }
+}
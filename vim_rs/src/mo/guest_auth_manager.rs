@@ -214,3 +214,35 @@ struct ValidateCredentialsInGuestRequestType<'a> {
     vm: &'a ManagedObjectReference,
     auth: &'a dyn crate::types::traits::GuestAuthenticationTrait,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestAuthManager`].
+    pub struct GuestAuthManager {
+        inner: super::GuestAuthManager,
+        client: Arc<Client>,
+    }
+    impl GuestAuthManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestAuthManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestAuthManager::acquire_credentials_in_guest`].
+        pub fn acquire_credentials_in_guest(&self, vm: &ManagedObjectReference, requested_auth: &dyn crate::types::traits::GuestAuthenticationTrait, session_id: Option<i64>) -> Result<Box<dyn crate::types::traits::GuestAuthenticationTrait>> {
+            self.client.block_on(self.inner.acquire_credentials_in_guest(vm, requested_auth, session_id))
+        }
+        /// Blocking version of [`super::GuestAuthManager::release_credentials_in_guest`].
+        pub fn release_credentials_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait) -> Result<()> {
+            self.client.block_on(self.inner.release_credentials_in_guest(vm, auth))
+        }
+        /// Blocking version of [`super::GuestAuthManager::validate_credentials_in_guest`].
+        pub fn validate_credentials_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait) -> Result<()> {
+            self.client.block_on(self.inner.validate_credentials_in_guest(vm, auth))
+        }
+    }
+}
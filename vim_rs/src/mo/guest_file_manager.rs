@@ -907,3 +907,69 @@ struct MoveFileInGuestRequestType<'a> {
     dst_file_path: &'a str,
     overwrite: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::FileTransferInformation;
+    use crate::types::structs::GuestListFileInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestFileManager`].
+    pub struct GuestFileManager {
+        inner: super::GuestFileManager,
+        client: Arc<Client>,
+    }
+    impl GuestFileManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestFileManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestFileManager::change_file_attributes_in_guest`].
+        pub fn change_file_attributes_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, guest_file_path: &str, file_attributes: &dyn crate::types::traits::GuestFileAttributesTrait) -> Result<()> {
+            self.client.block_on(self.inner.change_file_attributes_in_guest(vm, auth, guest_file_path, file_attributes))
+        }
+        /// Blocking version of [`super::GuestFileManager::create_temporary_directory_in_guest`].
+        pub fn create_temporary_directory_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, prefix: &str, suffix: &str, directory_path: Option<&str>) -> Result<String> {
+            self.client.block_on(self.inner.create_temporary_directory_in_guest(vm, auth, prefix, suffix, directory_path))
+        }
+        /// Blocking version of [`super::GuestFileManager::create_temporary_file_in_guest`].
+        pub fn create_temporary_file_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, prefix: &str, suffix: &str, directory_path: Option<&str>) -> Result<String> {
+            self.client.block_on(self.inner.create_temporary_file_in_guest(vm, auth, prefix, suffix, directory_path))
+        }
+        /// Blocking version of [`super::GuestFileManager::delete_directory_in_guest`].
+        pub fn delete_directory_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, directory_path: &str, recursive: bool) -> Result<()> {
+            self.client.block_on(self.inner.delete_directory_in_guest(vm, auth, directory_path, recursive))
+        }
+        /// Blocking version of [`super::GuestFileManager::delete_file_in_guest`].
+        pub fn delete_file_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, file_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_file_in_guest(vm, auth, file_path))
+        }
+        /// Blocking version of [`super::GuestFileManager::initiate_file_transfer_from_guest`].
+        pub fn initiate_file_transfer_from_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, guest_file_path: &str) -> Result<FileTransferInformation> {
+            self.client.block_on(self.inner.initiate_file_transfer_from_guest(vm, auth, guest_file_path))
+        }
+        /// Blocking version of [`super::GuestFileManager::initiate_file_transfer_to_guest`].
+        pub fn initiate_file_transfer_to_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, guest_file_path: &str, file_attributes: &dyn crate::types::traits::GuestFileAttributesTrait, file_size: i64, overwrite: bool) -> Result<String> {
+            self.client.block_on(self.inner.initiate_file_transfer_to_guest(vm, auth, guest_file_path, file_attributes, file_size, overwrite))
+        }
+        /// Blocking version of [`super::GuestFileManager::list_files_in_guest`].
+        pub fn list_files_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, file_path: &str, index: Option<i32>, max_results: Option<i32>, match_pattern: Option<&str>) -> Result<GuestListFileInfo> {
+            self.client.block_on(self.inner.list_files_in_guest(vm, auth, file_path, index, max_results, match_pattern))
+        }
+        /// Blocking version of [`super::GuestFileManager::make_directory_in_guest`].
+        pub fn make_directory_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, directory_path: &str, create_parent_directories: bool) -> Result<()> {
+            self.client.block_on(self.inner.make_directory_in_guest(vm, auth, directory_path, create_parent_directories))
+        }
+        /// Blocking version of [`super::GuestFileManager::move_directory_in_guest`].
+        pub fn move_directory_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, src_directory_path: &str, dst_directory_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.move_directory_in_guest(vm, auth, src_directory_path, dst_directory_path))
+        }
+        /// Blocking version of [`super::GuestFileManager::move_file_in_guest`].
+        pub fn move_file_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, src_file_path: &str, dst_file_path: &str, overwrite: bool) -> Result<()> {
+            self.client.block_on(self.inner.move_file_in_guest(vm, auth, src_file_path, dst_file_path, overwrite))
+        }
+    }
+}
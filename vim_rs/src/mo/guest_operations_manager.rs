@@ -83,3 +83,43 @@ impl GuestOperationsManager {
         self.client.execute_option(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestOperationsManager`].
+    pub struct GuestOperationsManager {
+        inner: super::GuestOperationsManager,
+        client: Arc<Client>,
+    }
+    impl GuestOperationsManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestOperationsManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestOperationsManager::alias_manager`].
+        pub fn alias_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.alias_manager())
+        }
+        /// Blocking version of [`super::GuestOperationsManager::auth_manager`].
+        pub fn auth_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.auth_manager())
+        }
+        /// Blocking version of [`super::GuestOperationsManager::file_manager`].
+        pub fn file_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.file_manager())
+        }
+        /// Blocking version of [`super::GuestOperationsManager::guest_windows_registry_manager`].
+        pub fn guest_windows_registry_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.guest_windows_registry_manager())
+        }
+        /// Blocking version of [`super::GuestOperationsManager::process_manager`].
+        pub fn process_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.process_manager())
+        }
+    }
+}
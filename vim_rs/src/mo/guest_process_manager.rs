@@ -302,3 +302,40 @@ struct TerminateProcessInGuestRequestType<'a> {
     auth: &'a dyn crate::types::traits::GuestAuthenticationTrait,
     pid: i64,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::GuestProcessInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestProcessManager`].
+    pub struct GuestProcessManager {
+        inner: super::GuestProcessManager,
+        client: Arc<Client>,
+    }
+    impl GuestProcessManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestProcessManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestProcessManager::list_processes_in_guest`].
+        pub fn list_processes_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, pids: Option<&[i64]>) -> Result<Option<Vec<GuestProcessInfo>>> {
+            self.client.block_on(self.inner.list_processes_in_guest(vm, auth, pids))
+        }
+        /// Blocking version of [`super::GuestProcessManager::read_environment_variable_in_guest`].
+        pub fn read_environment_variable_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, names: Option<&[String]>) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.read_environment_variable_in_guest(vm, auth, names))
+        }
+        /// Blocking version of [`super::GuestProcessManager::start_program_in_guest`].
+        pub fn start_program_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, spec: &dyn crate::types::traits::GuestProgramSpecTrait) -> Result<i64> {
+            self.client.block_on(self.inner.start_program_in_guest(vm, auth, spec))
+        }
+        /// Blocking version of [`super::GuestProcessManager::terminate_process_in_guest`].
+        pub fn terminate_process_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, pid: i64) -> Result<()> {
+            self.client.block_on(self.inner.terminate_process_in_guest(vm, auth, pid))
+        }
+    }
+}
@@ -485,3 +485,51 @@ struct SetRegistryValueInGuestRequestType<'a> {
     auth: &'a dyn crate::types::traits::GuestAuthenticationTrait,
     value: &'a GuestRegValueSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::GuestRegKeyNameSpec;
+    use crate::types::structs::GuestRegKeyRecordSpec;
+    use crate::types::structs::GuestRegValueNameSpec;
+    use crate::types::structs::GuestRegValueSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::GuestWindowsRegistryManager`].
+    pub struct GuestWindowsRegistryManager {
+        inner: super::GuestWindowsRegistryManager,
+        client: Arc<Client>,
+    }
+    impl GuestWindowsRegistryManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::GuestWindowsRegistryManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::create_registry_key_in_guest`].
+        pub fn create_registry_key_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, key_name: &GuestRegKeyNameSpec, is_volatile: bool, class_type: Option<&str>) -> Result<()> {
+            self.client.block_on(self.inner.create_registry_key_in_guest(vm, auth, key_name, is_volatile, class_type))
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::delete_registry_key_in_guest`].
+        pub fn delete_registry_key_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, key_name: &GuestRegKeyNameSpec, recursive: bool) -> Result<()> {
+            self.client.block_on(self.inner.delete_registry_key_in_guest(vm, auth, key_name, recursive))
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::delete_registry_value_in_guest`].
+        pub fn delete_registry_value_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, value_name: &GuestRegValueNameSpec) -> Result<()> {
+            self.client.block_on(self.inner.delete_registry_value_in_guest(vm, auth, value_name))
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::list_registry_keys_in_guest`].
+        pub fn list_registry_keys_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, key_name: &GuestRegKeyNameSpec, recursive: bool, match_pattern: Option<&str>) -> Result<Option<Vec<GuestRegKeyRecordSpec>>> {
+            self.client.block_on(self.inner.list_registry_keys_in_guest(vm, auth, key_name, recursive, match_pattern))
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::list_registry_values_in_guest`].
+        pub fn list_registry_values_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, key_name: &GuestRegKeyNameSpec, expand_strings: bool, match_pattern: Option<&str>) -> Result<Option<Vec<GuestRegValueSpec>>> {
+            self.client.block_on(self.inner.list_registry_values_in_guest(vm, auth, key_name, expand_strings, match_pattern))
+        }
+        /// Blocking version of [`super::GuestWindowsRegistryManager::set_registry_value_in_guest`].
+        pub fn set_registry_value_in_guest(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, value: &GuestRegValueSpec) -> Result<()> {
+            self.client.block_on(self.inner.set_registry_value_in_guest(vm, auth, value))
+        }
+    }
+}
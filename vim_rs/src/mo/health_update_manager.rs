@@ -708,3 +708,109 @@ struct UnregisterHealthUpdateProviderRequestType<'a> {
     #[serde(rename = "providerId")]
     provider_id: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HealthUpdate;
+    use crate::types::structs::HealthUpdateInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HealthUpdateManager`].
+    pub struct HealthUpdateManager {
+        inner: super::HealthUpdateManager,
+        client: Arc<Client>,
+    }
+    impl HealthUpdateManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HealthUpdateManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HealthUpdateManager::add_filter`].
+        pub fn add_filter(&self, provider_id: &str, filter_name: &str, info_ids: Option<&[String]>) -> Result<String> {
+            self.client.block_on(self.inner.add_filter(provider_id, filter_name, info_ids))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::add_filter_entities`].
+        pub fn add_filter_entities(&self, filter_id: &str, entities: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.add_filter_entities(filter_id, entities))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::add_monitored_entities`].
+        pub fn add_monitored_entities(&self, provider_id: &str, entities: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.add_monitored_entities(provider_id, entities))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::has_monitored_entity`].
+        pub fn has_monitored_entity(&self, provider_id: &str, entity: &ManagedObjectReference) -> Result<bool> {
+            self.client.block_on(self.inner.has_monitored_entity(provider_id, entity))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::has_provider`].
+        pub fn has_provider(&self, id: &str) -> Result<bool> {
+            self.client.block_on(self.inner.has_provider(id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::post_health_updates`].
+        pub fn post_health_updates(&self, provider_id: &str, updates: Option<&[HealthUpdate]>) -> Result<()> {
+            self.client.block_on(self.inner.post_health_updates(provider_id, updates))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_filter_entities`].
+        pub fn query_filter_entities(&self, filter_id: &str) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_filter_entities(filter_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_filter_info_ids`].
+        pub fn query_filter_info_ids(&self, filter_id: &str) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_filter_info_ids(filter_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_filter_list`].
+        pub fn query_filter_list(&self, provider_id: &str) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_filter_list(provider_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_filter_name`].
+        pub fn query_filter_name(&self, filter_id: &str) -> Result<String> {
+            self.client.block_on(self.inner.query_filter_name(filter_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_health_update_infos`].
+        pub fn query_health_update_infos(&self, provider_id: &str) -> Result<Option<Vec<HealthUpdateInfo>>> {
+            self.client.block_on(self.inner.query_health_update_infos(provider_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_health_updates`].
+        pub fn query_health_updates(&self, provider_id: &str) -> Result<Option<Vec<HealthUpdate>>> {
+            self.client.block_on(self.inner.query_health_updates(provider_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_monitored_entities`].
+        pub fn query_monitored_entities(&self, provider_id: &str) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_monitored_entities(provider_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_provider_list`].
+        pub fn query_provider_list(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_provider_list())
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_provider_name`].
+        pub fn query_provider_name(&self, id: &str) -> Result<String> {
+            self.client.block_on(self.inner.query_provider_name(id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::query_unmonitored_hosts`].
+        pub fn query_unmonitored_hosts(&self, provider_id: &str, cluster: &ManagedObjectReference) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_unmonitored_hosts(provider_id, cluster))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::register_health_update_provider`].
+        pub fn register_health_update_provider(&self, name: &str, health_update_info: Option<&[HealthUpdateInfo]>) -> Result<String> {
+            self.client.block_on(self.inner.register_health_update_provider(name, health_update_info))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::remove_filter`].
+        pub fn remove_filter(&self, filter_id: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_filter(filter_id))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::remove_filter_entities`].
+        pub fn remove_filter_entities(&self, filter_id: &str, entities: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.remove_filter_entities(filter_id, entities))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::remove_monitored_entities`].
+        pub fn remove_monitored_entities(&self, provider_id: &str, entities: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.remove_monitored_entities(provider_id, entities))
+        }
+        /// Blocking version of [`super::HealthUpdateManager::unregister_health_update_provider`].
+        pub fn unregister_health_update_provider(&self, provider_id: &str) -> Result<()> {
+            self.client.block_on(self.inner.unregister_health_update_provider(provider_id))
+        }
+    }
+}
@@ -109,3 +109,43 @@ struct SetCollectorPageSizeRequestType {
     #[serde(rename = "maxCount")]
     max_count: i32,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::vim_any::VimAny;
+    /// Blocking version of [`super::HistoryCollector`].
+    pub struct HistoryCollector {
+        inner: super::HistoryCollector,
+        client: Arc<Client>,
+    }
+    impl HistoryCollector {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HistoryCollector::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HistoryCollector::destroy_collector`].
+        pub fn destroy_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_collector())
+        }
+        /// Blocking version of [`super::HistoryCollector::reset_collector`].
+        pub fn reset_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_collector())
+        }
+        /// Blocking version of [`super::HistoryCollector::rewind_collector`].
+        pub fn rewind_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.rewind_collector())
+        }
+        /// Blocking version of [`super::HistoryCollector::set_collector_page_size`].
+        pub fn set_collector_page_size(&self, max_count: i32) -> Result<()> {
+            self.client.block_on(self.inner.set_collector_page_size(max_count))
+        }
+        /// Blocking version of [`super::HistoryCollector::filter`].
+        pub fn filter(&self) -> Result<VimAny> {
+            self.client.block_on(self.inner.filter())
+        }
+    }
+}
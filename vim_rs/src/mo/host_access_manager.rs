@@ -276,3 +276,55 @@ struct UpdateSystemUsersRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     users: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostAccessControlEntry;
+    /// Blocking version of [`super::HostAccessManager`].
+    pub struct HostAccessManager {
+        inner: super::HostAccessManager,
+        client: Arc<Client>,
+    }
+    impl HostAccessManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostAccessManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostAccessManager::change_access_mode`].
+        pub fn change_access_mode(&self, principal: &str, is_group: bool, access_mode: crate::types::enums::HostAccessModeEnum) -> Result<()> {
+            self.client.block_on(self.inner.change_access_mode(principal, is_group, access_mode))
+        }
+        /// Blocking version of [`super::HostAccessManager::change_lockdown_mode`].
+        pub fn change_lockdown_mode(&self, mode: crate::types::enums::HostLockdownModeEnum) -> Result<()> {
+            self.client.block_on(self.inner.change_lockdown_mode(mode))
+        }
+        /// Blocking version of [`super::HostAccessManager::query_lockdown_exceptions`].
+        pub fn query_lockdown_exceptions(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_lockdown_exceptions())
+        }
+        /// Blocking version of [`super::HostAccessManager::query_system_users`].
+        pub fn query_system_users(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_system_users())
+        }
+        /// Blocking version of [`super::HostAccessManager::retrieve_host_access_control_entries`].
+        pub fn retrieve_host_access_control_entries(&self) -> Result<Option<Vec<HostAccessControlEntry>>> {
+            self.client.block_on(self.inner.retrieve_host_access_control_entries())
+        }
+        /// Blocking version of [`super::HostAccessManager::update_lockdown_exceptions`].
+        pub fn update_lockdown_exceptions(&self, users: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.update_lockdown_exceptions(users))
+        }
+        /// Blocking version of [`super::HostAccessManager::update_system_users`].
+        pub fn update_system_users(&self, users: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.update_system_users(users))
+        }
+        /// Blocking version of [`super::HostAccessManager::lockdown_mode`].
+        pub fn lockdown_mode(&self) -> Result<crate::types::enums::HostLockdownModeEnum> {
+            self.client.block_on(self.inner.lockdown_mode())
+        }
+    }
+}
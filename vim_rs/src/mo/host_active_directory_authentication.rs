@@ -313,7 +313,7 @@ impl HostActiveDirectoryAuthentication {
     ///
     /// ***HostConfigFault***: if the host configuration prevents the
     /// certificate from being removed.
-    pub async fn remove_smart_card_trust_anchor_by_fingerprint(&self, fingerprint: &str, digest: &str) -> Result<()> {
+    pub async fn remove_smart_card_trust_anchor_by_fingerprint(&self, fingerprint: &str, digest: crate::types::enums::HostActiveDirectoryAuthenticationCertificateDigestEnum) -> Result<()> {
         let input = RemoveSmartCardTrustAnchorByFingerprintRequestType {fingerprint, digest, };
         let path = format!("/HostActiveDirectoryAuthentication/{moId}/RemoveSmartCardTrustAnchorByFingerprint", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -386,7 +386,7 @@ struct RemoveSmartCardTrustAnchorRequestType<'a> {
 #[serde(tag="_typeName")]
 struct RemoveSmartCardTrustAnchorByFingerprintRequestType<'a> {
     fingerprint: &'a str,
-    digest: &'a str,
+    digest: crate::types::enums::HostActiveDirectoryAuthenticationCertificateDigestEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -394,3 +394,71 @@ struct ReplaceSmartCardTrustAnchorsRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     certs: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostActiveDirectoryAuthentication`].
+    pub struct HostActiveDirectoryAuthentication {
+        inner: super::HostActiveDirectoryAuthentication,
+        client: Arc<Client>,
+    }
+    impl HostActiveDirectoryAuthentication {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostActiveDirectoryAuthentication::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::disable_smart_card_authentication`].
+        pub fn disable_smart_card_authentication(&self) -> Result<()> {
+            self.client.block_on(self.inner.disable_smart_card_authentication())
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::enable_smart_card_authentication`].
+        pub fn enable_smart_card_authentication(&self) -> Result<()> {
+            self.client.block_on(self.inner.enable_smart_card_authentication())
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::import_certificate_for_cam_task`].
+        pub fn import_certificate_for_cam_task(&self, cert_path: &str, cam_server: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.import_certificate_for_cam_task(cert_path, cam_server))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::install_smart_card_trust_anchor`].
+        pub fn install_smart_card_trust_anchor(&self, cert: &str) -> Result<()> {
+            self.client.block_on(self.inner.install_smart_card_trust_anchor(cert))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::join_domain_task`].
+        pub fn join_domain_task(&self, domain_name: &str, user_name: &str, password: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.join_domain_task(domain_name, user_name, password))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::join_domain_with_cam_task`].
+        pub fn join_domain_with_cam_task(&self, domain_name: &str, cam_server: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.join_domain_with_cam_task(domain_name, cam_server))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::leave_current_domain_task`].
+        pub fn leave_current_domain_task(&self, force: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.leave_current_domain_task(force))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::list_smart_card_trust_anchors`].
+        pub fn list_smart_card_trust_anchors(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.list_smart_card_trust_anchors())
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::remove_smart_card_trust_anchor`].
+        pub fn remove_smart_card_trust_anchor(&self, issuer: &str, serial: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_smart_card_trust_anchor(issuer, serial))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::remove_smart_card_trust_anchor_by_fingerprint`].
+        pub fn remove_smart_card_trust_anchor_by_fingerprint(&self, fingerprint: &str, digest: crate::types::enums::HostActiveDirectoryAuthenticationCertificateDigestEnum) -> Result<()> {
+            self.client.block_on(self.inner.remove_smart_card_trust_anchor_by_fingerprint(fingerprint, digest))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::replace_smart_card_trust_anchors`].
+        pub fn replace_smart_card_trust_anchors(&self, certs: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.replace_smart_card_trust_anchors(certs))
+        }
+        /// Blocking version of [`super::HostActiveDirectoryAuthentication::info`].
+        pub fn info(&self) -> Result<Box<dyn crate::types::traits::HostAuthenticationStoreInfoTrait>> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
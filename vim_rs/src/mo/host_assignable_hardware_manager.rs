@@ -98,3 +98,50 @@ impl HostAssignableHardwareManager {
 struct UpdateAssignableHardwareConfigRequestType<'a> {
     config: &'a HostAssignableHardwareConfig,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostAssignableHardwareBinding;
+    use crate::types::structs::HostAssignableHardwareConfig;
+    use crate::types::structs::VirtualMachineDynamicPassthroughInfo;
+    use crate::types::structs::VirtualMachineVendorDeviceGroupInfo;
+    /// Blocking version of [`super::HostAssignableHardwareManager`].
+    pub struct HostAssignableHardwareManager {
+        inner: super::HostAssignableHardwareManager,
+        client: Arc<Client>,
+    }
+    impl HostAssignableHardwareManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostAssignableHardwareManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::download_description_tree`].
+        pub fn download_description_tree(&self) -> Result<Vec<u8>> {
+            self.client.block_on(self.inner.download_description_tree())
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::retrieve_dynamic_passthrough_info`].
+        pub fn retrieve_dynamic_passthrough_info(&self) -> Result<Option<Vec<VirtualMachineDynamicPassthroughInfo>>> {
+            self.client.block_on(self.inner.retrieve_dynamic_passthrough_info())
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::retrieve_vendor_device_group_info`].
+        pub fn retrieve_vendor_device_group_info(&self) -> Result<Option<Vec<VirtualMachineVendorDeviceGroupInfo>>> {
+            self.client.block_on(self.inner.retrieve_vendor_device_group_info())
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::update_assignable_hardware_config`].
+        pub fn update_assignable_hardware_config(&self, config: &HostAssignableHardwareConfig) -> Result<()> {
+            self.client.block_on(self.inner.update_assignable_hardware_config(config))
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::binding`].
+        pub fn binding(&self) -> Result<Option<Vec<HostAssignableHardwareBinding>>> {
+            self.client.block_on(self.inner.binding())
+        }
+        /// Blocking version of [`super::HostAssignableHardwareManager::config`].
+        pub fn config(&self) -> Result<HostAssignableHardwareConfig> {
+            self.client.block_on(self.inner.config())
+        }
+    }
+}
@@ -96,3 +96,32 @@ impl HostAuthenticationManager {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostAuthenticationManagerInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostAuthenticationManager`].
+    pub struct HostAuthenticationManager {
+        inner: super::HostAuthenticationManager,
+        client: Arc<Client>,
+    }
+    impl HostAuthenticationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostAuthenticationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostAuthenticationManager::info`].
+        pub fn info(&self) -> Result<HostAuthenticationManagerInfo> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::HostAuthenticationManager::supported_store`].
+        pub fn supported_store(&self) -> Result<Vec<ManagedObjectReference>> {
+            self.client.block_on(self.inner.supported_store())
+        }
+    }
+}
@@ -25,3 +25,26 @@ impl HostAuthenticationStore {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::HostAuthenticationStore`].
+    pub struct HostAuthenticationStore {
+        inner: super::HostAuthenticationStore,
+        client: Arc<Client>,
+    }
+    impl HostAuthenticationStore {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostAuthenticationStore::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostAuthenticationStore::info`].
+        pub fn info(&self) -> Result<Box<dyn crate::types::traits::HostAuthenticationStoreInfoTrait>> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
@@ -82,3 +82,39 @@ impl HostAutoStartManager {
 struct ReconfigureAutostartRequestType<'a> {
     spec: &'a HostAutoStartManagerConfig,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostAutoStartManagerConfig;
+    /// Blocking version of [`super::HostAutoStartManager`].
+    pub struct HostAutoStartManager {
+        inner: super::HostAutoStartManager,
+        client: Arc<Client>,
+    }
+    impl HostAutoStartManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostAutoStartManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostAutoStartManager::auto_start_power_off`].
+        pub fn auto_start_power_off(&self) -> Result<()> {
+            self.client.block_on(self.inner.auto_start_power_off())
+        }
+        /// Blocking version of [`super::HostAutoStartManager::auto_start_power_on`].
+        pub fn auto_start_power_on(&self) -> Result<()> {
+            self.client.block_on(self.inner.auto_start_power_on())
+        }
+        /// Blocking version of [`super::HostAutoStartManager::reconfigure_autostart`].
+        pub fn reconfigure_autostart(&self, spec: &HostAutoStartManagerConfig) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_autostart(spec))
+        }
+        /// Blocking version of [`super::HostAutoStartManager::config`].
+        pub fn config(&self) -> Result<HostAutoStartManagerConfig> {
+            self.client.block_on(self.inner.config())
+        }
+    }
+}
@@ -52,3 +52,31 @@ impl HostBootDeviceSystem {
 struct UpdateBootDeviceRequestType<'a> {
     key: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostBootDeviceInfo;
+    /// Blocking version of [`super::HostBootDeviceSystem`].
+    pub struct HostBootDeviceSystem {
+        inner: super::HostBootDeviceSystem,
+        client: Arc<Client>,
+    }
+    impl HostBootDeviceSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostBootDeviceSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostBootDeviceSystem::query_boot_devices`].
+        pub fn query_boot_devices(&self) -> Result<Option<HostBootDeviceInfo>> {
+            self.client.block_on(self.inner.query_boot_devices())
+        }
+        /// Blocking version of [`super::HostBootDeviceSystem::update_boot_device`].
+        pub fn update_boot_device(&self, key: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_boot_device(key))
+        }
+    }
+}
@@ -56,3 +56,33 @@ impl HostCacheConfigurationManager {
 struct ConfigureHostCacheRequestType<'a> {
     spec: &'a HostCacheConfigurationSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostCacheConfigurationInfo;
+    use crate::types::structs::HostCacheConfigurationSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostCacheConfigurationManager`].
+    pub struct HostCacheConfigurationManager {
+        inner: super::HostCacheConfigurationManager,
+        client: Arc<Client>,
+    }
+    impl HostCacheConfigurationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostCacheConfigurationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostCacheConfigurationManager::configure_host_cache_task`].
+        pub fn configure_host_cache_task(&self, spec: &HostCacheConfigurationSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_host_cache_task(spec))
+        }
+        /// Blocking version of [`super::HostCacheConfigurationManager::cache_configuration_info`].
+        pub fn cache_configuration_info(&self) -> Result<Option<Vec<HostCacheConfigurationInfo>>> {
+            self.client.block_on(self.inner.cache_configuration_info())
+        }
+    }
+}
@@ -212,3 +212,56 @@ struct ReplaceCaCertificatesAndCrLsRequestType<'a> {
     #[serde(rename = "caCrl")]
     ca_crl: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostCertificateManagerCertificateInfo;
+    use crate::types::structs::HostCertificateManagerCertificateSpec;
+    /// Blocking version of [`super::HostCertificateManager`].
+    pub struct HostCertificateManager {
+        inner: super::HostCertificateManager,
+        client: Arc<Client>,
+    }
+    impl HostCertificateManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostCertificateManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostCertificateManager::generate_certificate_signing_request`].
+        pub fn generate_certificate_signing_request(&self, use_ip_address_as_common_name: bool, spec: Option<&HostCertificateManagerCertificateSpec>) -> Result<String> {
+            self.client.block_on(self.inner.generate_certificate_signing_request(use_ip_address_as_common_name, spec))
+        }
+        /// Blocking version of [`super::HostCertificateManager::generate_certificate_signing_request_by_dn`].
+        pub fn generate_certificate_signing_request_by_dn(&self, distinguished_name: &str, spec: Option<&HostCertificateManagerCertificateSpec>) -> Result<String> {
+            self.client.block_on(self.inner.generate_certificate_signing_request_by_dn(distinguished_name, spec))
+        }
+        /// Blocking version of [`super::HostCertificateManager::install_server_certificate`].
+        pub fn install_server_certificate(&self, cert: &str) -> Result<()> {
+            self.client.block_on(self.inner.install_server_certificate(cert))
+        }
+        /// Blocking version of [`super::HostCertificateManager::list_ca_certificate_revocation_lists`].
+        pub fn list_ca_certificate_revocation_lists(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.list_ca_certificate_revocation_lists())
+        }
+        /// Blocking version of [`super::HostCertificateManager::list_ca_certificates`].
+        pub fn list_ca_certificates(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.list_ca_certificates())
+        }
+        /// Blocking version of [`super::HostCertificateManager::replace_ca_certificates_and_cr_ls`].
+        pub fn replace_ca_certificates_and_cr_ls(&self, ca_cert: &[String], ca_crl: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.replace_ca_certificates_and_cr_ls(ca_cert, ca_crl))
+        }
+        /// Blocking version of [`super::HostCertificateManager::retrieve_certificate_info_list`].
+        pub fn retrieve_certificate_info_list(&self) -> Result<Option<Vec<HostCertificateManagerCertificateInfo>>> {
+            self.client.block_on(self.inner.retrieve_certificate_info_list())
+        }
+        /// Blocking version of [`super::HostCertificateManager::certificate_info`].
+        pub fn certificate_info(&self) -> Result<HostCertificateManagerCertificateInfo> {
+            self.client.block_on(self.inner.certificate_info())
+        }
+    }
+}
@@ -104,3 +104,48 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostHyperThreadScheduleInfo;
+    /// Blocking version of [`super::HostCpuSchedulerSystem`].
+    pub struct HostCpuSchedulerSystem {
+        inner: super::HostCpuSchedulerSystem,
+        client: Arc<Client>,
+    }
+    impl HostCpuSchedulerSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostCpuSchedulerSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::disable_hyper_threading`].
+        pub fn disable_hyper_threading(&self) -> Result<()> {
+            self.client.block_on(self.inner.disable_hyper_threading())
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::enable_hyper_threading`].
+        pub fn enable_hyper_threading(&self) -> Result<()> {
+            self.client.block_on(self.inner.enable_hyper_threading())
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::hyperthread_info`].
+        pub fn hyperthread_info(&self) -> Result<Option<HostHyperThreadScheduleInfo>> {
+            self.client.block_on(self.inner.hyperthread_info())
+        }
+        /// Blocking version of [`super::HostCpuSchedulerSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -242,3 +242,44 @@ struct SearchDatastoreSubFoldersRequestType<'a> {
     #[serde(rename = "searchSpec")]
     search_spec: Option<&'a HostDatastoreBrowserSearchSpec>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostDatastoreBrowserSearchSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostDatastoreBrowser`].
+    pub struct HostDatastoreBrowser {
+        inner: super::HostDatastoreBrowser,
+        client: Arc<Client>,
+    }
+    impl HostDatastoreBrowser {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostDatastoreBrowser::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostDatastoreBrowser::delete_file`].
+        pub fn delete_file(&self, datastore_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_file(datastore_path))
+        }
+        /// Blocking version of [`super::HostDatastoreBrowser::search_datastore_task`].
+        pub fn search_datastore_task(&self, datastore_path: &str, search_spec: Option<&HostDatastoreBrowserSearchSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.search_datastore_task(datastore_path, search_spec))
+        }
+        /// Blocking version of [`super::HostDatastoreBrowser::search_datastore_sub_folders_task`].
+        pub fn search_datastore_sub_folders_task(&self, datastore_path: &str, search_spec: Option<&HostDatastoreBrowserSearchSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.search_datastore_sub_folders_task(datastore_path, search_spec))
+        }
+        /// Blocking version of [`super::HostDatastoreBrowser::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::HostDatastoreBrowser::supported_type`].
+        pub fn supported_type(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::FileQueryTrait>>>> {
+            self.client.block_on(self.inner.supported_type())
+        }
+    }
+}
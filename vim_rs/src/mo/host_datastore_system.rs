@@ -871,3 +871,121 @@ struct UpdateLocalSwapDatastoreRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     datastore: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostDatastoreSystemCapabilities;
+    use crate::types::structs::HostDatastoreSystemVvolDatastoreSpec;
+    use crate::types::structs::HostNasVolumeSpec;
+    use crate::types::structs::HostScsiDisk;
+    use crate::types::structs::HostUnresolvedVmfsResignatureSpec;
+    use crate::types::structs::HostUnresolvedVmfsVolume;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VmfsDatastoreCreateSpec;
+    use crate::types::structs::VmfsDatastoreExpandSpec;
+    use crate::types::structs::VmfsDatastoreExtendSpec;
+    use crate::types::structs::VmfsDatastoreOption;
+    /// Blocking version of [`super::HostDatastoreSystem`].
+    pub struct HostDatastoreSystem {
+        inner: super::HostDatastoreSystem,
+        client: Arc<Client>,
+    }
+    impl HostDatastoreSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostDatastoreSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::configure_datastore_principal`].
+        pub fn configure_datastore_principal(&self, user_name: &str, password: Option<&str>) -> Result<()> {
+            self.client.block_on(self.inner.configure_datastore_principal(user_name, password))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::create_local_datastore`].
+        pub fn create_local_datastore(&self, name: &str, path: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_local_datastore(name, path))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::create_nas_datastore`].
+        pub fn create_nas_datastore(&self, spec: &HostNasVolumeSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_nas_datastore(spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::create_vmfs_datastore`].
+        pub fn create_vmfs_datastore(&self, spec: &VmfsDatastoreCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_vmfs_datastore(spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::create_vvol_datastore`].
+        pub fn create_vvol_datastore(&self, spec: &HostDatastoreSystemVvolDatastoreSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_vvol_datastore(spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::disable_clustered_vmdk_support`].
+        pub fn disable_clustered_vmdk_support(&self, datastore: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.disable_clustered_vmdk_support(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::enable_clustered_vmdk_support`].
+        pub fn enable_clustered_vmdk_support(&self, datastore: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.enable_clustered_vmdk_support(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::expand_vmfs_datastore`].
+        pub fn expand_vmfs_datastore(&self, datastore: &ManagedObjectReference, spec: &VmfsDatastoreExpandSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.expand_vmfs_datastore(datastore, spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::extend_vmfs_datastore`].
+        pub fn extend_vmfs_datastore(&self, datastore: &ManagedObjectReference, spec: &VmfsDatastoreExtendSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.extend_vmfs_datastore(datastore, spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_available_disks_for_vmfs`].
+        pub fn query_available_disks_for_vmfs(&self, datastore: Option<&ManagedObjectReference>) -> Result<Option<Vec<HostScsiDisk>>> {
+            self.client.block_on(self.inner.query_available_disks_for_vmfs(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_max_queue_depth`].
+        pub fn query_max_queue_depth(&self, datastore: &ManagedObjectReference) -> Result<i64> {
+            self.client.block_on(self.inner.query_max_queue_depth(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_unresolved_vmfs_volumes`].
+        pub fn query_unresolved_vmfs_volumes(&self) -> Result<Option<Vec<HostUnresolvedVmfsVolume>>> {
+            self.client.block_on(self.inner.query_unresolved_vmfs_volumes())
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_vmfs_datastore_create_options`].
+        pub fn query_vmfs_datastore_create_options(&self, device_path: &str, vmfs_major_version: Option<i32>) -> Result<Option<Vec<VmfsDatastoreOption>>> {
+            self.client.block_on(self.inner.query_vmfs_datastore_create_options(device_path, vmfs_major_version))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_vmfs_datastore_expand_options`].
+        pub fn query_vmfs_datastore_expand_options(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<VmfsDatastoreOption>>> {
+            self.client.block_on(self.inner.query_vmfs_datastore_expand_options(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::query_vmfs_datastore_extend_options`].
+        pub fn query_vmfs_datastore_extend_options(&self, datastore: &ManagedObjectReference, device_path: &str, suppress_expand_candidates: Option<bool>) -> Result<Option<Vec<VmfsDatastoreOption>>> {
+            self.client.block_on(self.inner.query_vmfs_datastore_extend_options(datastore, device_path, suppress_expand_candidates))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::remove_datastore`].
+        pub fn remove_datastore(&self, datastore: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.remove_datastore(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::remove_datastore_ex_task`].
+        pub fn remove_datastore_ex_task(&self, datastore: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.remove_datastore_ex_task(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::resignature_unresolved_vmfs_volume_task`].
+        pub fn resignature_unresolved_vmfs_volume_task(&self, resolution_spec: &HostUnresolvedVmfsResignatureSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.resignature_unresolved_vmfs_volume_task(resolution_spec))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::set_max_queue_depth`].
+        pub fn set_max_queue_depth(&self, datastore: &ManagedObjectReference, max_qdepth: i64) -> Result<()> {
+            self.client.block_on(self.inner.set_max_queue_depth(datastore, max_qdepth))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::update_local_swap_datastore`].
+        pub fn update_local_swap_datastore(&self, datastore: Option<&ManagedObjectReference>) -> Result<()> {
+            self.client.block_on(self.inner.update_local_swap_datastore(datastore))
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::capabilities`].
+        pub fn capabilities(&self) -> Result<HostDatastoreSystemCapabilities> {
+            self.client.block_on(self.inner.capabilities())
+        }
+        /// Blocking version of [`super::HostDatastoreSystem::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+    }
+}
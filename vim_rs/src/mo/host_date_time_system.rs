@@ -138,3 +138,54 @@ struct UpdateDateTimeRequestType<'a> {
     #[serde(rename = "dateTime")]
     date_time: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostDateTimeConfig;
+    use crate::types::structs::HostDateTimeInfo;
+    use crate::types::structs::HostDateTimeSystemServiceTestResult;
+    use crate::types::structs::HostDateTimeSystemTimeZone;
+    /// Blocking version of [`super::HostDateTimeSystem`].
+    pub struct HostDateTimeSystem {
+        inner: super::HostDateTimeSystem,
+        client: Arc<Client>,
+    }
+    impl HostDateTimeSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostDateTimeSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::query_available_time_zones`].
+        pub fn query_available_time_zones(&self) -> Result<Option<Vec<HostDateTimeSystemTimeZone>>> {
+            self.client.block_on(self.inner.query_available_time_zones())
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::query_date_time`].
+        pub fn query_date_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.query_date_time())
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::refresh_date_time_system`].
+        pub fn refresh_date_time_system(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_date_time_system())
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::test_time_service`].
+        pub fn test_time_service(&self) -> Result<Option<HostDateTimeSystemServiceTestResult>> {
+            self.client.block_on(self.inner.test_time_service())
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::update_date_time_config`].
+        pub fn update_date_time_config(&self, config: &HostDateTimeConfig) -> Result<()> {
+            self.client.block_on(self.inner.update_date_time_config(config))
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::update_date_time`].
+        pub fn update_date_time(&self, date_time: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_date_time(date_time))
+        }
+        /// Blocking version of [`super::HostDateTimeSystem::date_time_info`].
+        pub fn date_time_info(&self) -> Result<HostDateTimeInfo> {
+            self.client.block_on(self.inner.date_time_info())
+        }
+    }
+}
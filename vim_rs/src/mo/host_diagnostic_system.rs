@@ -211,3 +211,51 @@ struct SelectActivePartitionRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     partition: Option<&'a HostScsiDiskPartition>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostDiagnosticPartition;
+    use crate::types::structs::HostDiagnosticPartitionCreateDescription;
+    use crate::types::structs::HostDiagnosticPartitionCreateOption;
+    use crate::types::structs::HostDiagnosticPartitionCreateSpec;
+    use crate::types::structs::HostScsiDiskPartition;
+    /// Blocking version of [`super::HostDiagnosticSystem`].
+    pub struct HostDiagnosticSystem {
+        inner: super::HostDiagnosticSystem,
+        client: Arc<Client>,
+    }
+    impl HostDiagnosticSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostDiagnosticSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::create_diagnostic_partition`].
+        pub fn create_diagnostic_partition(&self, spec: &HostDiagnosticPartitionCreateSpec) -> Result<()> {
+            self.client.block_on(self.inner.create_diagnostic_partition(spec))
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::query_available_partition`].
+        pub fn query_available_partition(&self) -> Result<Option<Vec<HostDiagnosticPartition>>> {
+            self.client.block_on(self.inner.query_available_partition())
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::query_partition_create_desc`].
+        pub fn query_partition_create_desc(&self, disk_uuid: &str, diagnostic_type: &str) -> Result<HostDiagnosticPartitionCreateDescription> {
+            self.client.block_on(self.inner.query_partition_create_desc(disk_uuid, diagnostic_type))
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::query_partition_create_options`].
+        pub fn query_partition_create_options(&self, storage_type: &str, diagnostic_type: &str) -> Result<Option<Vec<HostDiagnosticPartitionCreateOption>>> {
+            self.client.block_on(self.inner.query_partition_create_options(storage_type, diagnostic_type))
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::select_active_partition`].
+        pub fn select_active_partition(&self, partition: Option<&HostScsiDiskPartition>) -> Result<()> {
+            self.client.block_on(self.inner.select_active_partition(partition))
+        }
+        /// Blocking version of [`super::HostDiagnosticSystem::active_partition`].
+        pub fn active_partition(&self) -> Result<Option<HostDiagnosticPartition>> {
+            self.client.block_on(self.inner.active_partition())
+        }
+    }
+}
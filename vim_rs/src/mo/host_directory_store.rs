@@ -20,3 +20,26 @@ impl HostDirectoryStore {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::HostDirectoryStore`].
+    pub struct HostDirectoryStore {
+        inner: super::HostDirectoryStore,
+        client: Arc<Client>,
+    }
+    impl HostDirectoryStore {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostDirectoryStore::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostDirectoryStore::info`].
+        pub fn info(&self) -> Result<Box<dyn crate::types::traits::HostAuthenticationStoreInfoTrait>> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
@@ -51,3 +51,31 @@ struct EsxAgentHostManagerUpdateConfigRequestType<'a> {
     #[serde(rename = "configInfo")]
     config_info: &'a HostEsxAgentHostManagerConfigInfo,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostEsxAgentHostManagerConfigInfo;
+    /// Blocking version of [`super::HostEsxAgentHostManager`].
+    pub struct HostEsxAgentHostManager {
+        inner: super::HostEsxAgentHostManager,
+        client: Arc<Client>,
+    }
+    impl HostEsxAgentHostManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostEsxAgentHostManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostEsxAgentHostManager::esx_agent_host_manager_update_config`].
+        pub fn esx_agent_host_manager_update_config(&self, config_info: &HostEsxAgentHostManagerConfigInfo) -> Result<()> {
+            self.client.block_on(self.inner.esx_agent_host_manager_update_config(config_info))
+        }
+        /// Blocking version of [`super::HostEsxAgentHostManager::config_info`].
+        pub fn config_info(&self) -> Result<HostEsxAgentHostManagerConfigInfo> {
+            self.client.block_on(self.inner.config_info())
+        }
+    }
+}
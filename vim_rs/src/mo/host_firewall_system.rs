@@ -192,3 +192,62 @@ struct UpdateRulesetRequestType<'a> {
     id: &'a str,
     spec: &'a HostFirewallRulesetRulesetSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostFirewallDefaultPolicy;
+    use crate::types::structs::HostFirewallInfo;
+    use crate::types::structs::HostFirewallRulesetRulesetSpec;
+    /// Blocking version of [`super::HostFirewallSystem`].
+    pub struct HostFirewallSystem {
+        inner: super::HostFirewallSystem,
+        client: Arc<Client>,
+    }
+    impl HostFirewallSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostFirewallSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostFirewallSystem::disable_ruleset`].
+        pub fn disable_ruleset(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.disable_ruleset(id))
+        }
+        /// Blocking version of [`super::HostFirewallSystem::enable_ruleset`].
+        pub fn enable_ruleset(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.enable_ruleset(id))
+        }
+        /// Blocking version of [`super::HostFirewallSystem::refresh_firewall`].
+        pub fn refresh_firewall(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_firewall())
+        }
+        /// Blocking version of [`super::HostFirewallSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostFirewallSystem::update_default_policy`].
+        pub fn update_default_policy(&self, default_policy: &HostFirewallDefaultPolicy) -> Result<()> {
+            self.client.block_on(self.inner.update_default_policy(default_policy))
+        }
+        /// Blocking version of [`super::HostFirewallSystem::update_ruleset`].
+        pub fn update_ruleset(&self, id: &str, spec: &HostFirewallRulesetRulesetSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_ruleset(id, spec))
+        }
+        /// Blocking version of [`super::HostFirewallSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostFirewallSystem::firewall_info`].
+        pub fn firewall_info(&self) -> Result<Option<HostFirewallInfo>> {
+            self.client.block_on(self.inner.firewall_info())
+        }
+        /// Blocking version of [`super::HostFirewallSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -105,3 +105,38 @@ impl HostFirmwareSystem {
 struct RestoreFirmwareConfigurationRequestType {
     force: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::HostFirmwareSystem`].
+    pub struct HostFirmwareSystem {
+        inner: super::HostFirmwareSystem,
+        client: Arc<Client>,
+    }
+    impl HostFirmwareSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostFirmwareSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostFirmwareSystem::backup_firmware_configuration`].
+        pub fn backup_firmware_configuration(&self) -> Result<String> {
+            self.client.block_on(self.inner.backup_firmware_configuration())
+        }
+        /// Blocking version of [`super::HostFirmwareSystem::query_firmware_config_upload_url`].
+        pub fn query_firmware_config_upload_url(&self) -> Result<String> {
+            self.client.block_on(self.inner.query_firmware_config_upload_url())
+        }
+        /// Blocking version of [`super::HostFirmwareSystem::reset_firmware_to_factory_defaults`].
+        pub fn reset_firmware_to_factory_defaults(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_firmware_to_factory_defaults())
+        }
+        /// Blocking version of [`super::HostFirmwareSystem::restore_firmware_configuration`].
+        pub fn restore_firmware_configuration(&self, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.restore_firmware_configuration(force))
+        }
+    }
+}
@@ -156,3 +156,76 @@ struct SetCustomValueRequestType<'a> {
 struct UpdateGraphicsConfigRequestType<'a> {
     config: &'a HostGraphicsConfig,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostGraphicsConfig;
+    use crate::types::structs::HostGraphicsInfo;
+    use crate::types::structs::HostSharedGpuCapabilities;
+    use crate::types::structs::VirtualMachineVgpuDeviceInfo;
+    use crate::types::structs::VirtualMachineVgpuProfileInfo;
+    /// Blocking version of [`super::HostGraphicsManager`].
+    pub struct HostGraphicsManager {
+        inner: super::HostGraphicsManager,
+        client: Arc<Client>,
+    }
+    impl HostGraphicsManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostGraphicsManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostGraphicsManager::is_shared_graphics_active`].
+        pub fn is_shared_graphics_active(&self) -> Result<bool> {
+            self.client.block_on(self.inner.is_shared_graphics_active())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::refresh_graphics_manager`].
+        pub fn refresh_graphics_manager(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_graphics_manager())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::retrieve_vgpu_device_info`].
+        pub fn retrieve_vgpu_device_info(&self) -> Result<Option<Vec<VirtualMachineVgpuDeviceInfo>>> {
+            self.client.block_on(self.inner.retrieve_vgpu_device_info())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::retrieve_vgpu_profile_info`].
+        pub fn retrieve_vgpu_profile_info(&self) -> Result<Option<Vec<VirtualMachineVgpuProfileInfo>>> {
+            self.client.block_on(self.inner.retrieve_vgpu_profile_info())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostGraphicsManager::update_graphics_config`].
+        pub fn update_graphics_config(&self, config: &HostGraphicsConfig) -> Result<()> {
+            self.client.block_on(self.inner.update_graphics_config(config))
+        }
+        /// Blocking version of [`super::HostGraphicsManager::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::graphics_config`].
+        pub fn graphics_config(&self) -> Result<Option<HostGraphicsConfig>> {
+            self.client.block_on(self.inner.graphics_config())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::graphics_info`].
+        pub fn graphics_info(&self) -> Result<Option<Vec<HostGraphicsInfo>>> {
+            self.client.block_on(self.inner.graphics_info())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::shared_gpu_capabilities`].
+        pub fn shared_gpu_capabilities(&self) -> Result<Option<Vec<HostSharedGpuCapabilities>>> {
+            self.client.block_on(self.inner.shared_gpu_capabilities())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::shared_passthru_gpu_types`].
+        pub fn shared_passthru_gpu_types(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.shared_passthru_gpu_types())
+        }
+        /// Blocking version of [`super::HostGraphicsManager::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
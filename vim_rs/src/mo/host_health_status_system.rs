@@ -59,3 +59,44 @@ impl HostHealthStatusSystem {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HealthSystemRuntime;
+    use crate::types::structs::SystemEventInfo;
+    /// Blocking version of [`super::HostHealthStatusSystem`].
+    pub struct HostHealthStatusSystem {
+        inner: super::HostHealthStatusSystem,
+        client: Arc<Client>,
+    }
+    impl HostHealthStatusSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostHealthStatusSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostHealthStatusSystem::fetch_system_event_log`].
+        pub fn fetch_system_event_log(&self) -> Result<Option<Vec<SystemEventInfo>>> {
+            self.client.block_on(self.inner.fetch_system_event_log())
+        }
+        /// Blocking version of [`super::HostHealthStatusSystem::clear_system_event_log`].
+        pub fn clear_system_event_log(&self) -> Result<()> {
+            self.client.block_on(self.inner.clear_system_event_log())
+        }
+        /// Blocking version of [`super::HostHealthStatusSystem::refresh_health_status_system`].
+        pub fn refresh_health_status_system(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_health_status_system())
+        }
+        /// Blocking version of [`super::HostHealthStatusSystem::reset_system_health_info`].
+        pub fn reset_system_health_info(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_system_health_info())
+        }
+        /// Blocking version of [`super::HostHealthStatusSystem::runtime`].
+        pub fn runtime(&self) -> Result<HealthSystemRuntime> {
+            self.client.block_on(self.inner.runtime())
+        }
+    }
+}
@@ -95,3 +95,44 @@ struct UpdateHostImageAcceptanceLevelRequestType<'a> {
     #[serde(rename = "newAcceptanceLevel")]
     new_acceptance_level: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostImageProfileSummary;
+    use crate::types::structs::SoftwarePackage;
+    /// Blocking version of [`super::HostImageConfigManager`].
+    pub struct HostImageConfigManager {
+        inner: super::HostImageConfigManager,
+        client: Arc<Client>,
+    }
+    impl HostImageConfigManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostImageConfigManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostImageConfigManager::fetch_software_packages`].
+        pub fn fetch_software_packages(&self) -> Result<Option<Vec<SoftwarePackage>>> {
+            self.client.block_on(self.inner.fetch_software_packages())
+        }
+        /// Blocking version of [`super::HostImageConfigManager::install_date`].
+        pub fn install_date(&self) -> Result<String> {
+            self.client.block_on(self.inner.install_date())
+        }
+        /// Blocking version of [`super::HostImageConfigManager::host_image_config_get_acceptance`].
+        pub fn host_image_config_get_acceptance(&self) -> Result<String> {
+            self.client.block_on(self.inner.host_image_config_get_acceptance())
+        }
+        /// Blocking version of [`super::HostImageConfigManager::host_image_config_get_profile`].
+        pub fn host_image_config_get_profile(&self) -> Result<HostImageProfileSummary> {
+            self.client.block_on(self.inner.host_image_config_get_profile())
+        }
+        /// Blocking version of [`super::HostImageConfigManager::update_host_image_acceptance_level`].
+        pub fn update_host_image_acceptance_level(&self, new_acceptance_level: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_host_image_acceptance_level(new_acceptance_level))
+        }
+    }
+}
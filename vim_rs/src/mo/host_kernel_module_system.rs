@@ -81,3 +81,35 @@ struct UpdateModuleOptionStringRequestType<'a> {
     name: &'a str,
     options: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::KernelModuleInfo;
+    /// Blocking version of [`super::HostKernelModuleSystem`].
+    pub struct HostKernelModuleSystem {
+        inner: super::HostKernelModuleSystem,
+        client: Arc<Client>,
+    }
+    impl HostKernelModuleSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostKernelModuleSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostKernelModuleSystem::query_configured_module_option_string`].
+        pub fn query_configured_module_option_string(&self, name: &str) -> Result<String> {
+            self.client.block_on(self.inner.query_configured_module_option_string(name))
+        }
+        /// Blocking version of [`super::HostKernelModuleSystem::query_modules`].
+        pub fn query_modules(&self) -> Result<Option<Vec<KernelModuleInfo>>> {
+            self.client.block_on(self.inner.query_modules())
+        }
+        /// Blocking version of [`super::HostKernelModuleSystem::update_module_option_string`].
+        pub fn update_module_option_string(&self, name: &str, options: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_module_option_string(name, options))
+        }
+    }
+}
@@ -291,3 +291,54 @@ struct UnassignUserFromGroupRequestType<'a> {
 struct UpdateUserRequestType<'a> {
     user: &'a dyn crate::types::traits::HostAccountSpecTrait,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::HostLocalAccountManager`].
+    pub struct HostLocalAccountManager {
+        inner: super::HostLocalAccountManager,
+        client: Arc<Client>,
+    }
+    impl HostLocalAccountManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostLocalAccountManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::assign_user_to_group`].
+        pub fn assign_user_to_group(&self, user: &str, group: &str) -> Result<()> {
+            self.client.block_on(self.inner.assign_user_to_group(user, group))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::change_password`].
+        pub fn change_password(&self, user: &str, old_password: &str, new_password: &str) -> Result<()> {
+            self.client.block_on(self.inner.change_password(user, old_password, new_password))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::create_group`].
+        pub fn create_group(&self, group: &dyn crate::types::traits::HostAccountSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.create_group(group))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::create_user`].
+        pub fn create_user(&self, user: &dyn crate::types::traits::HostAccountSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.create_user(user))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::remove_group`].
+        pub fn remove_group(&self, group_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_group(group_name))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::remove_user`].
+        pub fn remove_user(&self, user_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_user(user_name))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::unassign_user_from_group`].
+        pub fn unassign_user_from_group(&self, user: &str, group: &str) -> Result<()> {
+            self.client.block_on(self.inner.unassign_user_from_group(user, group))
+        }
+        /// Blocking version of [`super::HostLocalAccountManager::update_user`].
+        pub fn update_user(&self, user: &dyn crate::types::traits::HostAccountSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_user(user))
+        }
+    }
+}
@@ -20,3 +20,26 @@ impl HostLocalAuthentication {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::HostLocalAuthentication`].
+    pub struct HostLocalAuthentication {
+        inner: super::HostLocalAuthentication,
+        client: Arc<Client>,
+    }
+    impl HostLocalAuthentication {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostLocalAuthentication::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostLocalAuthentication::info`].
+        pub fn info(&self) -> Result<Box<dyn crate::types::traits::HostAuthenticationStoreInfoTrait>> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
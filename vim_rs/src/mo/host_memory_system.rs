@@ -131,3 +131,54 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::ServiceConsoleReservationInfo;
+    use crate::types::structs::VirtualMachineMemoryReservationInfo;
+    use crate::types::structs::VirtualMachineMemoryReservationSpec;
+    /// Blocking version of [`super::HostMemorySystem`].
+    pub struct HostMemorySystem {
+        inner: super::HostMemorySystem,
+        client: Arc<Client>,
+    }
+    impl HostMemorySystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostMemorySystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostMemorySystem::reconfigure_service_console_reservation`].
+        pub fn reconfigure_service_console_reservation(&self, cfg_bytes: i64) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_service_console_reservation(cfg_bytes))
+        }
+        /// Blocking version of [`super::HostMemorySystem::reconfigure_virtual_machine_reservation`].
+        pub fn reconfigure_virtual_machine_reservation(&self, spec: &VirtualMachineMemoryReservationSpec) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_virtual_machine_reservation(spec))
+        }
+        /// Blocking version of [`super::HostMemorySystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostMemorySystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostMemorySystem::console_reservation_info`].
+        pub fn console_reservation_info(&self) -> Result<Option<ServiceConsoleReservationInfo>> {
+            self.client.block_on(self.inner.console_reservation_info())
+        }
+        /// Blocking version of [`super::HostMemorySystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::HostMemorySystem::virtual_machine_reservation_info`].
+        pub fn virtual_machine_reservation_info(&self) -> Result<Option<VirtualMachineMemoryReservationInfo>> {
+            self.client.block_on(self.inner.virtual_machine_reservation_info())
+        }
+    }
+}
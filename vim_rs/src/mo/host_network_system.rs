@@ -941,3 +941,158 @@ struct UpdateVirtualSwitchRequestType<'a> {
     vswitch_name: &'a str,
     spec: &'a HostVirtualSwitchSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostIpRouteTableConfig;
+    use crate::types::structs::HostNetCapabilities;
+    use crate::types::structs::HostNetOffloadCapabilities;
+    use crate::types::structs::HostNetworkConfig;
+    use crate::types::structs::HostNetworkConfigResult;
+    use crate::types::structs::HostNetworkInfo;
+    use crate::types::structs::HostPortGroupSpec;
+    use crate::types::structs::HostVirtualNicSpec;
+    use crate::types::structs::HostVirtualSwitchSpec;
+    use crate::types::structs::PhysicalNicHintInfo;
+    use crate::types::structs::PhysicalNicLinkInfo;
+    /// Blocking version of [`super::HostNetworkSystem`].
+    pub struct HostNetworkSystem {
+        inner: super::HostNetworkSystem,
+        client: Arc<Client>,
+    }
+    impl HostNetworkSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostNetworkSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostNetworkSystem::add_port_group`].
+        pub fn add_port_group(&self, portgrp: &HostPortGroupSpec) -> Result<()> {
+            self.client.block_on(self.inner.add_port_group(portgrp))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::add_service_console_virtual_nic`].
+        pub fn add_service_console_virtual_nic(&self, portgroup: &str, nic: &HostVirtualNicSpec) -> Result<String> {
+            self.client.block_on(self.inner.add_service_console_virtual_nic(portgroup, nic))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::add_virtual_nic`].
+        pub fn add_virtual_nic(&self, portgroup: &str, nic: &HostVirtualNicSpec) -> Result<String> {
+            self.client.block_on(self.inner.add_virtual_nic(portgroup, nic))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::add_virtual_switch`].
+        pub fn add_virtual_switch(&self, vswitch_name: &str, spec: Option<&HostVirtualSwitchSpec>) -> Result<()> {
+            self.client.block_on(self.inner.add_virtual_switch(vswitch_name, spec))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::query_network_hint`].
+        pub fn query_network_hint(&self, device: Option<&[String]>) -> Result<Option<Vec<PhysicalNicHintInfo>>> {
+            self.client.block_on(self.inner.query_network_hint(device))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::refresh_network_system`].
+        pub fn refresh_network_system(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_network_system())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::remove_port_group`].
+        pub fn remove_port_group(&self, pg_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_port_group(pg_name))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::remove_service_console_virtual_nic`].
+        pub fn remove_service_console_virtual_nic(&self, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_service_console_virtual_nic(device))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::remove_virtual_nic`].
+        pub fn remove_virtual_nic(&self, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_virtual_nic(device))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::remove_virtual_switch`].
+        pub fn remove_virtual_switch(&self, vswitch_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_virtual_switch(vswitch_name))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::restart_service_console_virtual_nic`].
+        pub fn restart_service_console_virtual_nic(&self, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.restart_service_console_virtual_nic(device))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_console_ip_route_config`].
+        pub fn update_console_ip_route_config(&self, config: &dyn crate::types::traits::HostIpRouteConfigTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_console_ip_route_config(config))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_dns_config`].
+        pub fn update_dns_config(&self, config: &dyn crate::types::traits::HostDnsConfigTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_dns_config(config))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_ip_route_config`].
+        pub fn update_ip_route_config(&self, config: &dyn crate::types::traits::HostIpRouteConfigTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_ip_route_config(config))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_ip_route_table_config`].
+        pub fn update_ip_route_table_config(&self, config: &HostIpRouteTableConfig) -> Result<()> {
+            self.client.block_on(self.inner.update_ip_route_table_config(config))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_network_config`].
+        pub fn update_network_config(&self, config: &HostNetworkConfig, change_mode: &str) -> Result<HostNetworkConfigResult> {
+            self.client.block_on(self.inner.update_network_config(config, change_mode))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_physical_nic_link_speed`].
+        pub fn update_physical_nic_link_speed(&self, device: &str, link_speed: Option<&PhysicalNicLinkInfo>) -> Result<()> {
+            self.client.block_on(self.inner.update_physical_nic_link_speed(device, link_speed))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_port_group`].
+        pub fn update_port_group(&self, pg_name: &str, portgrp: &HostPortGroupSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_port_group(pg_name, portgrp))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_service_console_virtual_nic`].
+        pub fn update_service_console_virtual_nic(&self, device: &str, nic: &HostVirtualNicSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_service_console_virtual_nic(device, nic))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_virtual_nic`].
+        pub fn update_virtual_nic(&self, device: &str, nic: &HostVirtualNicSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_virtual_nic(device, nic))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::update_virtual_switch`].
+        pub fn update_virtual_switch(&self, vswitch_name: &str, spec: &HostVirtualSwitchSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_virtual_switch(vswitch_name, spec))
+        }
+        /// Blocking version of [`super::HostNetworkSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::capabilities`].
+        pub fn capabilities(&self) -> Result<Option<HostNetCapabilities>> {
+            self.client.block_on(self.inner.capabilities())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::console_ip_route_config`].
+        pub fn console_ip_route_config(&self) -> Result<Option<Box<dyn crate::types::traits::HostIpRouteConfigTrait>>> {
+            self.client.block_on(self.inner.console_ip_route_config())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::dns_config`].
+        pub fn dns_config(&self) -> Result<Option<Box<dyn crate::types::traits::HostDnsConfigTrait>>> {
+            self.client.block_on(self.inner.dns_config())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::ip_route_config`].
+        pub fn ip_route_config(&self) -> Result<Option<Box<dyn crate::types::traits::HostIpRouteConfigTrait>>> {
+            self.client.block_on(self.inner.ip_route_config())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::network_config`].
+        pub fn network_config(&self) -> Result<Option<HostNetworkConfig>> {
+            self.client.block_on(self.inner.network_config())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::network_info`].
+        pub fn network_info(&self) -> Result<Option<HostNetworkInfo>> {
+            self.client.block_on(self.inner.network_info())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::offload_capabilities`].
+        pub fn offload_capabilities(&self) -> Result<Option<HostNetOffloadCapabilities>> {
+            self.client.block_on(self.inner.offload_capabilities())
+        }
+        /// Blocking version of [`super::HostNetworkSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
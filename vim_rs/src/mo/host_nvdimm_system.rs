@@ -246,3 +246,47 @@ struct DeleteNvdimmNamespaceRequestType<'a> {
     #[serde(rename = "deleteSpec")]
     delete_spec: &'a NvdimmNamespaceDeleteSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::NvdimmNamespaceCreateSpec;
+    use crate::types::structs::NvdimmNamespaceDeleteSpec;
+    use crate::types::structs::NvdimmPMemNamespaceCreateSpec;
+    use crate::types::structs::NvdimmSystemInfo;
+    /// Blocking version of [`super::HostNvdimmSystem`].
+    pub struct HostNvdimmSystem {
+        inner: super::HostNvdimmSystem,
+        client: Arc<Client>,
+    }
+    impl HostNvdimmSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostNvdimmSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostNvdimmSystem::create_nvdimm_namespace_task`].
+        pub fn create_nvdimm_namespace_task(&self, create_spec: &NvdimmNamespaceCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_nvdimm_namespace_task(create_spec))
+        }
+        /// Blocking version of [`super::HostNvdimmSystem::create_nvdimm_p_mem_namespace_task`].
+        pub fn create_nvdimm_p_mem_namespace_task(&self, create_spec: &NvdimmPMemNamespaceCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_nvdimm_p_mem_namespace_task(create_spec))
+        }
+        /// Blocking version of [`super::HostNvdimmSystem::delete_nvdimm_block_namespaces_task`].
+        pub fn delete_nvdimm_block_namespaces_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_nvdimm_block_namespaces_task())
+        }
+        /// Blocking version of [`super::HostNvdimmSystem::delete_nvdimm_namespace_task`].
+        pub fn delete_nvdimm_namespace_task(&self, delete_spec: &NvdimmNamespaceDeleteSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_nvdimm_namespace_task(delete_spec))
+        }
+        /// Blocking version of [`super::HostNvdimmSystem::nvdimm_system_info`].
+        pub fn nvdimm_system_info(&self) -> Result<NvdimmSystemInfo> {
+            self.client.block_on(self.inner.nvdimm_system_info())
+        }
+    }
+}
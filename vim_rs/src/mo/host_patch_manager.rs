@@ -498,3 +498,57 @@ struct UninstallHostPatchRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     spec: Option<&'a HostPatchManagerPatchManagerOperationSpec>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostPatchManagerLocator;
+    use crate::types::structs::HostPatchManagerPatchManagerOperationSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostPatchManager`].
+    pub struct HostPatchManager {
+        inner: super::HostPatchManager,
+        client: Arc<Client>,
+    }
+    impl HostPatchManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostPatchManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostPatchManager::check_host_patch_task`].
+        pub fn check_host_patch_task(&self, meta_urls: Option<&[String]>, bundle_urls: Option<&[String]>, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_host_patch_task(meta_urls, bundle_urls, spec))
+        }
+        /// Blocking version of [`super::HostPatchManager::install_host_patch_task`].
+        pub fn install_host_patch_task(&self, repository: &HostPatchManagerLocator, update_id: &str, force: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.install_host_patch_task(repository, update_id, force))
+        }
+        /// Blocking version of [`super::HostPatchManager::install_host_patch_v_2_task`].
+        pub fn install_host_patch_v_2_task(&self, meta_urls: Option<&[String]>, bundle_urls: Option<&[String]>, vib_urls: Option<&[String]>, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.install_host_patch_v_2_task(meta_urls, bundle_urls, vib_urls, spec))
+        }
+        /// Blocking version of [`super::HostPatchManager::query_host_patch_task`].
+        pub fn query_host_patch_task(&self, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.query_host_patch_task(spec))
+        }
+        /// Blocking version of [`super::HostPatchManager::scan_host_patch_task`].
+        pub fn scan_host_patch_task(&self, repository: &HostPatchManagerLocator, update_id: Option<&[String]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.scan_host_patch_task(repository, update_id))
+        }
+        /// Blocking version of [`super::HostPatchManager::scan_host_patch_v_2_task`].
+        pub fn scan_host_patch_v_2_task(&self, meta_urls: Option<&[String]>, bundle_urls: Option<&[String]>, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.scan_host_patch_v_2_task(meta_urls, bundle_urls, spec))
+        }
+        /// Blocking version of [`super::HostPatchManager::stage_host_patch_task`].
+        pub fn stage_host_patch_task(&self, meta_urls: Option<&[String]>, bundle_urls: Option<&[String]>, vib_urls: Option<&[String]>, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.stage_host_patch_task(meta_urls, bundle_urls, vib_urls, spec))
+        }
+        /// Blocking version of [`super::HostPatchManager::uninstall_host_patch_task`].
+        pub fn uninstall_host_patch_task(&self, bulletin_ids: Option<&[String]>, spec: Option<&HostPatchManagerPatchManagerOperationSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.uninstall_host_patch_task(bulletin_ids, spec))
+        }
+    }
+}
@@ -111,3 +111,51 @@ struct SetCustomValueRequestType<'a> {
 struct UpdatePassthruConfigRequestType<'a> {
     config: &'a [Box<dyn crate::types::traits::HostPciPassthruConfigTrait>],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    /// Blocking version of [`super::HostPciPassthruSystem`].
+    pub struct HostPciPassthruSystem {
+        inner: super::HostPciPassthruSystem,
+        client: Arc<Client>,
+    }
+    impl HostPciPassthruSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostPciPassthruSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::refresh`].
+        pub fn refresh(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh())
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::update_passthru_config`].
+        pub fn update_passthru_config(&self, config: &[Box<dyn crate::types::traits::HostPciPassthruConfigTrait>]) -> Result<()> {
+            self.client.block_on(self.inner.update_passthru_config(config))
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::pci_passthru_info`].
+        pub fn pci_passthru_info(&self) -> Result<Vec<Box<dyn crate::types::traits::HostPciPassthruInfoTrait>>> {
+            self.client.block_on(self.inner.pci_passthru_info())
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::sriov_device_pool_info`].
+        pub fn sriov_device_pool_info(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::HostSriovDevicePoolInfoTrait>>>> {
+            self.client.block_on(self.inner.sriov_device_pool_info())
+        }
+        /// Blocking version of [`super::HostPciPassthruSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -58,3 +58,36 @@ impl HostPowerSystem {
 struct ConfigurePowerPolicyRequestType {
     key: i32,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::PowerSystemCapability;
+    use crate::types::structs::PowerSystemInfo;
+    /// Blocking version of [`super::HostPowerSystem`].
+    pub struct HostPowerSystem {
+        inner: super::HostPowerSystem,
+        client: Arc<Client>,
+    }
+    impl HostPowerSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostPowerSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostPowerSystem::configure_power_policy`].
+        pub fn configure_power_policy(&self, key: i32) -> Result<()> {
+            self.client.block_on(self.inner.configure_power_policy(key))
+        }
+        /// Blocking version of [`super::HostPowerSystem::capability`].
+        pub fn capability(&self) -> Result<PowerSystemCapability> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::HostPowerSystem::info`].
+        pub fn info(&self) -> Result<PowerSystemInfo> {
+            self.client.block_on(self.inner.info())
+        }
+    }
+}
@@ -467,3 +467,114 @@ struct UpdateReferenceHostRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     host: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostProfileValidationFailureInfo;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfileDeferredPolicyOptionParameter;
+    use crate::types::structs::ProfileDescription;
+    /// Blocking version of [`super::HostProfile`].
+    pub struct HostProfile {
+        inner: super::HostProfile,
+        client: Arc<Client>,
+    }
+    impl HostProfile {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostProfile::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostProfile::host_profile_reset_validation_state`].
+        pub fn host_profile_reset_validation_state(&self) -> Result<()> {
+            self.client.block_on(self.inner.host_profile_reset_validation_state())
+        }
+        /// Blocking version of [`super::HostProfile::associate_profile`].
+        pub fn associate_profile(&self, entity: &[ManagedObjectReference]) -> Result<()> {
+            self.client.block_on(self.inner.associate_profile(entity))
+        }
+        /// Blocking version of [`super::HostProfile::check_profile_compliance_task`].
+        pub fn check_profile_compliance_task(&self, entity: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_profile_compliance_task(entity))
+        }
+        /// Blocking version of [`super::HostProfile::destroy_profile`].
+        pub fn destroy_profile(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_profile())
+        }
+        /// Blocking version of [`super::HostProfile::dissociate_profile`].
+        pub fn dissociate_profile(&self, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.dissociate_profile(entity))
+        }
+        /// Blocking version of [`super::HostProfile::execute_host_profile`].
+        pub fn execute_host_profile(&self, host: &ManagedObjectReference, deferred_param: Option<&[ProfileDeferredPolicyOptionParameter]>) -> Result<Box<dyn crate::types::traits::ProfileExecuteResultTrait>> {
+            self.client.block_on(self.inner.execute_host_profile(host, deferred_param))
+        }
+        /// Blocking version of [`super::HostProfile::export_profile`].
+        pub fn export_profile(&self) -> Result<String> {
+            self.client.block_on(self.inner.export_profile())
+        }
+        /// Blocking version of [`super::HostProfile::retrieve_description`].
+        pub fn retrieve_description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.retrieve_description())
+        }
+        /// Blocking version of [`super::HostProfile::update_host_profile`].
+        pub fn update_host_profile(&self, config: &dyn crate::types::traits::HostProfileConfigSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.update_host_profile(config))
+        }
+        /// Blocking version of [`super::HostProfile::update_reference_host`].
+        pub fn update_reference_host(&self, host: Option<&ManagedObjectReference>) -> Result<()> {
+            self.client.block_on(self.inner.update_reference_host(host))
+        }
+        /// Blocking version of [`super::HostProfile::compliance_check_time`].
+        pub fn compliance_check_time(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.compliance_check_time())
+        }
+        /// Blocking version of [`super::HostProfile::compliance_status`].
+        pub fn compliance_status(&self) -> Result<String> {
+            self.client.block_on(self.inner.compliance_status())
+        }
+        /// Blocking version of [`super::HostProfile::config`].
+        pub fn config(&self) -> Result<Box<dyn crate::types::traits::ProfileConfigInfoTrait>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::HostProfile::created_time`].
+        pub fn created_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.created_time())
+        }
+        /// Blocking version of [`super::HostProfile::description`].
+        pub fn description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::HostProfile::entity`].
+        pub fn entity(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.entity())
+        }
+        /// Blocking version of [`super::HostProfile::modified_time`].
+        pub fn modified_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.modified_time())
+        }
+        /// Blocking version of [`super::HostProfile::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::HostProfile::reference_host`].
+        pub fn reference_host(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.reference_host())
+        }
+        /// Blocking version of [`super::HostProfile::validation_failure_info`].
+        pub fn validation_failure_info(&self) -> Result<Option<HostProfileValidationFailureInfo>> {
+            self.client.block_on(self.inner.validation_failure_info())
+        }
+        /// Blocking version of [`super::HostProfile::validation_state`].
+        pub fn validation_state(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.validation_state())
+        }
+        /// Blocking version of [`super::HostProfile::validation_state_update_time`].
+        pub fn validation_state_update_time(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.validation_state_update_time())
+        }
+    }
+}
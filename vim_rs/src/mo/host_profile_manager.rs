@@ -958,3 +958,127 @@ struct ValidateHostProfileCompositionRequestType<'a> {
     #[serde(rename = "errorOnly")]
     error_only: Option<bool>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AnswerFile;
+    use crate::types::structs::AnswerFileStatusResult;
+    use crate::types::structs::ApplyHostProfileConfigurationSpec;
+    use crate::types::structs::HostApplyProfile;
+    use crate::types::structs::HostConfigSpec;
+    use crate::types::structs::HostProfileManagerConfigTaskList;
+    use crate::types::structs::HostProfileManagerHostToConfigSpecMap;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfileDeferredPolicyOptionParameter;
+    use crate::types::structs::ProfileMetadata;
+    use crate::types::structs::ProfilePolicyMetadata;
+    use crate::types::structs::ProfileProfileStructure;
+    use crate::types::structs::StructuredCustomizations;
+    /// Blocking version of [`super::HostProfileManager`].
+    pub struct HostProfileManager {
+        inner: super::HostProfileManager,
+        client: Arc<Client>,
+    }
+    impl HostProfileManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostProfileManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostProfileManager::apply_entities_config_task`].
+        pub fn apply_entities_config_task(&self, apply_config_specs: Option<&[ApplyHostProfileConfigurationSpec]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.apply_entities_config_task(apply_config_specs))
+        }
+        /// Blocking version of [`super::HostProfileManager::apply_host_config_task`].
+        pub fn apply_host_config_task(&self, host: &ManagedObjectReference, config_spec: &HostConfigSpec, user_input: Option<&[ProfileDeferredPolicyOptionParameter]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.apply_host_config_task(host, config_spec, user_input))
+        }
+        /// Blocking version of [`super::HostProfileManager::check_answer_file_status_task`].
+        pub fn check_answer_file_status_task(&self, host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_answer_file_status_task(host))
+        }
+        /// Blocking version of [`super::HostProfileManager::composite_host_profile_task`].
+        pub fn composite_host_profile_task(&self, source: &ManagedObjectReference, targets: Option<&[ManagedObjectReference]>, to_be_merged: Option<&HostApplyProfile>, to_be_replaced_with: Option<&HostApplyProfile>, to_be_deleted: Option<&HostApplyProfile>, enable_status_to_be_copied: Option<&HostApplyProfile>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.composite_host_profile_task(source, targets, to_be_merged, to_be_replaced_with, to_be_deleted, enable_status_to_be_copied))
+        }
+        /// Blocking version of [`super::HostProfileManager::create_default_profile`].
+        pub fn create_default_profile(&self, profile_type: &str, profile_type_name: Option<&str>, profile: Option<&ManagedObjectReference>) -> Result<Box<dyn crate::types::traits::ApplyProfileTrait>> {
+            self.client.block_on(self.inner.create_default_profile(profile_type, profile_type_name, profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::create_profile`].
+        pub fn create_profile(&self, create_spec: &dyn crate::types::traits::ProfileCreateSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_profile(create_spec))
+        }
+        /// Blocking version of [`super::HostProfileManager::export_answer_file_task`].
+        pub fn export_answer_file_task(&self, host: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.export_answer_file_task(host))
+        }
+        /// Blocking version of [`super::HostProfileManager::find_associated_profile`].
+        pub fn find_associated_profile(&self, entity: &ManagedObjectReference) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.find_associated_profile(entity))
+        }
+        /// Blocking version of [`super::HostProfileManager::generate_config_task_list`].
+        pub fn generate_config_task_list(&self, config_spec: &HostConfigSpec, host: &ManagedObjectReference) -> Result<HostProfileManagerConfigTaskList> {
+            self.client.block_on(self.inner.generate_config_task_list(config_spec, host))
+        }
+        /// Blocking version of [`super::HostProfileManager::generate_host_config_task_spec_task`].
+        pub fn generate_host_config_task_spec_task(&self, hosts_info: Option<&[StructuredCustomizations]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.generate_host_config_task_spec_task(hosts_info))
+        }
+        /// Blocking version of [`super::HostProfileManager::generate_host_profile_task_list_task`].
+        pub fn generate_host_profile_task_list_task(&self, config_spec: &HostConfigSpec, host: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.generate_host_profile_task_list_task(config_spec, host))
+        }
+        /// Blocking version of [`super::HostProfileManager::query_answer_file_status`].
+        pub fn query_answer_file_status(&self, host: &[ManagedObjectReference]) -> Result<Option<Vec<AnswerFileStatusResult>>> {
+            self.client.block_on(self.inner.query_answer_file_status(host))
+        }
+        /// Blocking version of [`super::HostProfileManager::query_policy_metadata`].
+        pub fn query_policy_metadata(&self, policy_name: Option<&[String]>, profile: Option<&ManagedObjectReference>) -> Result<Option<Vec<ProfilePolicyMetadata>>> {
+            self.client.block_on(self.inner.query_policy_metadata(policy_name, profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::query_host_profile_metadata`].
+        pub fn query_host_profile_metadata(&self, profile_name: Option<&[String]>, profile: Option<&ManagedObjectReference>) -> Result<Option<Vec<ProfileMetadata>>> {
+            self.client.block_on(self.inner.query_host_profile_metadata(profile_name, profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::query_profile_structure`].
+        pub fn query_profile_structure(&self, profile: Option<&ManagedObjectReference>) -> Result<ProfileProfileStructure> {
+            self.client.block_on(self.inner.query_profile_structure(profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::retrieve_answer_file`].
+        pub fn retrieve_answer_file(&self, host: &ManagedObjectReference) -> Result<Option<AnswerFile>> {
+            self.client.block_on(self.inner.retrieve_answer_file(host))
+        }
+        /// Blocking version of [`super::HostProfileManager::retrieve_answer_file_for_profile`].
+        pub fn retrieve_answer_file_for_profile(&self, host: &ManagedObjectReference, apply_profile: &HostApplyProfile) -> Result<Option<AnswerFile>> {
+            self.client.block_on(self.inner.retrieve_answer_file_for_profile(host, apply_profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::retrieve_host_customizations`].
+        pub fn retrieve_host_customizations(&self, hosts: Option<&[ManagedObjectReference]>) -> Result<Option<Vec<StructuredCustomizations>>> {
+            self.client.block_on(self.inner.retrieve_host_customizations(hosts))
+        }
+        /// Blocking version of [`super::HostProfileManager::retrieve_host_customizations_for_profile`].
+        pub fn retrieve_host_customizations_for_profile(&self, hosts: Option<&[ManagedObjectReference]>, apply_profile: &HostApplyProfile) -> Result<Option<Vec<StructuredCustomizations>>> {
+            self.client.block_on(self.inner.retrieve_host_customizations_for_profile(hosts, apply_profile))
+        }
+        /// Blocking version of [`super::HostProfileManager::update_answer_file_task`].
+        pub fn update_answer_file_task(&self, host: &ManagedObjectReference, config_spec: &dyn crate::types::traits::AnswerFileCreateSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_answer_file_task(host, config_spec))
+        }
+        /// Blocking version of [`super::HostProfileManager::update_host_customizations_task`].
+        pub fn update_host_customizations_task(&self, host_to_config_spec_map: Option<&[HostProfileManagerHostToConfigSpecMap]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_host_customizations_task(host_to_config_spec_map))
+        }
+        /// Blocking version of [`super::HostProfileManager::validate_host_profile_composition_task`].
+        pub fn validate_host_profile_composition_task(&self, source: &ManagedObjectReference, targets: Option<&[ManagedObjectReference]>, to_be_merged: Option<&HostApplyProfile>, to_replace_with: Option<&HostApplyProfile>, to_be_deleted: Option<&HostApplyProfile>, enable_status_to_be_copied: Option<&HostApplyProfile>, error_only: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.validate_host_profile_composition_task(source, targets, to_be_merged, to_replace_with, to_be_deleted, enable_status_to_be_copied, error_only))
+        }
+        /// Blocking version of [`super::HostProfileManager::profile`].
+        pub fn profile(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.profile())
+        }
+    }
+}
@@ -173,7 +173,7 @@ impl HostServiceSystem {
     /// or if the specified policy is undefined.
     /// 
     /// ***HostConfigFault***: for all other failures.
-    pub async fn update_service_policy(&self, id: &str, policy: &str) -> Result<()> {
+    pub async fn update_service_policy(&self, id: &str, policy: crate::types::enums::HostServicePolicyEnum) -> Result<()> {
         let input = UpdateServicePolicyRequestType {id, policy, };
         let path = format!("/HostServiceSystem/{moId}/UpdateServicePolicy", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -238,5 +238,66 @@ struct UninstallServiceRequestType<'a> {
 #[serde(tag="_typeName")]
 struct UpdateServicePolicyRequestType<'a> {
     id: &'a str,
-    policy: &'a str,
+    policy: crate::types::enums::HostServicePolicyEnum,
+}
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostServiceInfo;
+    /// Blocking version of [`super::HostServiceSystem`].
+    pub struct HostServiceSystem {
+        inner: super::HostServiceSystem,
+        client: Arc<Client>,
+    }
+    impl HostServiceSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostServiceSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostServiceSystem::refresh_services`].
+        pub fn refresh_services(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_services())
+        }
+        /// Blocking version of [`super::HostServiceSystem::restart_service`].
+        pub fn restart_service(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.restart_service(id))
+        }
+        /// Blocking version of [`super::HostServiceSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostServiceSystem::start_service`].
+        pub fn start_service(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.start_service(id))
+        }
+        /// Blocking version of [`super::HostServiceSystem::stop_service`].
+        pub fn stop_service(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.stop_service(id))
+        }
+        /// Blocking version of [`super::HostServiceSystem::uninstall_service`].
+        pub fn uninstall_service(&self, id: &str) -> Result<()> {
+            self.client.block_on(self.inner.uninstall_service(id))
+        }
+        /// Blocking version of [`super::HostServiceSystem::update_service_policy`].
+        pub fn update_service_policy(&self, id: &str, policy: crate::types::enums::HostServicePolicyEnum) -> Result<()> {
+            self.client.block_on(self.inner.update_service_policy(id, policy))
+        }
+        /// Blocking version of [`super::HostServiceSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostServiceSystem::service_info`].
+        pub fn service_info(&self) -> Result<HostServiceInfo> {
+            self.client.block_on(self.inner.service_info())
+        }
+        /// Blocking version of [`super::HostServiceSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
 }
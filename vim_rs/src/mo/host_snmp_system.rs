@@ -61,3 +61,40 @@ impl HostSnmpSystem {
 struct ReconfigureSnmpAgentRequestType<'a> {
     spec: &'a HostSnmpConfigSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostSnmpConfigSpec;
+    use crate::types::structs::HostSnmpSystemAgentLimits;
+    /// Blocking version of [`super::HostSnmpSystem`].
+    pub struct HostSnmpSystem {
+        inner: super::HostSnmpSystem,
+        client: Arc<Client>,
+    }
+    impl HostSnmpSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostSnmpSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostSnmpSystem::reconfigure_snmp_agent`].
+        pub fn reconfigure_snmp_agent(&self, spec: &HostSnmpConfigSpec) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_snmp_agent(spec))
+        }
+        /// Blocking version of [`super::HostSnmpSystem::send_test_notification`].
+        pub fn send_test_notification(&self) -> Result<()> {
+            self.client.block_on(self.inner.send_test_notification())
+        }
+        /// Blocking version of [`super::HostSnmpSystem::configuration`].
+        pub fn configuration(&self) -> Result<HostSnmpConfigSpec> {
+            self.client.block_on(self.inner.configuration())
+        }
+        /// Blocking version of [`super::HostSnmpSystem::limits`].
+        pub fn limits(&self) -> Result<HostSnmpSystemAgentLimits> {
+            self.client.block_on(self.inner.limits())
+        }
+    }
+}
@@ -236,3 +236,49 @@ struct UpdateHostSubSpecificationRequestType<'a> {
     #[serde(rename = "hostSubSpec")]
     host_sub_spec: &'a HostSubSpecification,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostSpecification;
+    use crate::types::structs::HostSubSpecification;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::HostSpecificationManager`].
+    pub struct HostSpecificationManager {
+        inner: super::HostSpecificationManager,
+        client: Arc<Client>,
+    }
+    impl HostSpecificationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostSpecificationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostSpecificationManager::delete_host_specification`].
+        pub fn delete_host_specification(&self, host: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.delete_host_specification(host))
+        }
+        /// Blocking version of [`super::HostSpecificationManager::delete_host_sub_specification`].
+        pub fn delete_host_sub_specification(&self, host: &ManagedObjectReference, sub_spec_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_host_sub_specification(host, sub_spec_name))
+        }
+        /// Blocking version of [`super::HostSpecificationManager::host_spec_get_updated_hosts`].
+        pub fn host_spec_get_updated_hosts(&self, start_change_id: Option<&str>, end_change_id: Option<&str>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.host_spec_get_updated_hosts(start_change_id, end_change_id))
+        }
+        /// Blocking version of [`super::HostSpecificationManager::retrieve_host_specification`].
+        pub fn retrieve_host_specification(&self, host: &ManagedObjectReference, from_host: bool) -> Result<HostSpecification> {
+            self.client.block_on(self.inner.retrieve_host_specification(host, from_host))
+        }
+        /// Blocking version of [`super::HostSpecificationManager::update_host_specification`].
+        pub fn update_host_specification(&self, host: &ManagedObjectReference, host_spec: &HostSpecification) -> Result<()> {
+            self.client.block_on(self.inner.update_host_specification(host, host_spec))
+        }
+        /// Blocking version of [`super::HostSpecificationManager::update_host_sub_specification`].
+        pub fn update_host_sub_specification(&self, host: &ManagedObjectReference, host_sub_spec: &HostSubSpecification) -> Result<()> {
+            self.client.block_on(self.inner.update_host_sub_specification(host, host_sub_spec))
+        }
+    }
+}
@@ -2328,7 +2328,7 @@ impl HostStorageSystem {
     /// ### unmap_priority
     /// Unmap priority. The supported values are defined in
     /// *HostVmfsVolumeUnmapPriority_enum*.
-    pub async fn update_vmfs_unmap_priority(&self, vmfs_uuid: &str, unmap_priority: &str) -> Result<()> {
+    pub async fn update_vmfs_unmap_priority(&self, vmfs_uuid: &str, unmap_priority: crate::types::enums::HostVmfsVolumeUnmapPriorityEnum) -> Result<()> {
         let input = UpdateVmfsUnmapPriorityRequestType {vmfs_uuid, unmap_priority, };
         let path = format!("/HostStorageSystem/{moId}/UpdateVmfsUnmapPriority", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -2912,7 +2912,7 @@ struct UpdateVmfsUnmapPriorityRequestType<'a> {
     #[serde(rename = "vmfsUuid")]
     vmfs_uuid: &'a str,
     #[serde(rename = "unmapPriority")]
-    unmap_priority: &'a str,
+    unmap_priority: crate::types::enums::HostVmfsVolumeUnmapPriorityEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -2920,3 +2920,411 @@ struct UpgradeVmfsRequestType<'a> {
     #[serde(rename = "vmfsPath")]
     vmfs_path: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::FcoeConfigFcoeSpecification;
+    use crate::types::structs::HostDiskPartitionBlockRange;
+    use crate::types::structs::HostDiskPartitionInfo;
+    use crate::types::structs::HostDiskPartitionLayout;
+    use crate::types::structs::HostDiskPartitionSpec;
+    use crate::types::structs::HostFileSystemVolumeInfo;
+    use crate::types::structs::HostInternetScsiHbaAuthenticationProperties;
+    use crate::types::structs::HostInternetScsiHbaDigestProperties;
+    use crate::types::structs::HostInternetScsiHbaDiscoveryProperties;
+    use crate::types::structs::HostInternetScsiHbaIpProperties;
+    use crate::types::structs::HostInternetScsiHbaParamValue;
+    use crate::types::structs::HostInternetScsiHbaSendTarget;
+    use crate::types::structs::HostInternetScsiHbaStaticTarget;
+    use crate::types::structs::HostInternetScsiHbaTargetSet;
+    use crate::types::structs::HostMultipathInfoHppLogicalUnitPolicy;
+    use crate::types::structs::HostMultipathStateInfo;
+    use crate::types::structs::HostNasVolumeUserInfo;
+    use crate::types::structs::HostNvmeConnectSpec;
+    use crate::types::structs::HostNvmeDisconnectSpec;
+    use crate::types::structs::HostNvmeDiscoverSpec;
+    use crate::types::structs::HostNvmeDiscoveryLog;
+    use crate::types::structs::HostPathSelectionPolicyOption;
+    use crate::types::structs::HostScsiDisk;
+    use crate::types::structs::HostScsiDiskPartition;
+    use crate::types::structs::HostStorageArrayTypePolicyOption;
+    use crate::types::structs::HostStorageDeviceInfo;
+    use crate::types::structs::HostUnresolvedVmfsResolutionResult;
+    use crate::types::structs::HostUnresolvedVmfsResolutionSpec;
+    use crate::types::structs::HostUnresolvedVmfsVolume;
+    use crate::types::structs::HostVffsSpec;
+    use crate::types::structs::HostVffsVolume;
+    use crate::types::structs::HostVmfsSpec;
+    use crate::types::structs::HostVmfsVolume;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VmfsConfigOption;
+    use crate::types::structs::VmfsUnmapBandwidthSpec;
+    /// Blocking version of [`super::HostStorageSystem`].
+    pub struct HostStorageSystem {
+        inner: super::HostStorageSystem,
+        client: Arc<Client>,
+    }
+    impl HostStorageSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostStorageSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostStorageSystem::add_internet_scsi_send_targets`].
+        pub fn add_internet_scsi_send_targets(&self, i_scsi_hba_device: &str, targets: &[HostInternetScsiHbaSendTarget]) -> Result<()> {
+            self.client.block_on(self.inner.add_internet_scsi_send_targets(i_scsi_hba_device, targets))
+        }
+        /// Blocking version of [`super::HostStorageSystem::add_internet_scsi_static_targets`].
+        pub fn add_internet_scsi_static_targets(&self, i_scsi_hba_device: &str, targets: &[HostInternetScsiHbaStaticTarget]) -> Result<()> {
+            self.client.block_on(self.inner.add_internet_scsi_static_targets(i_scsi_hba_device, targets))
+        }
+        /// Blocking version of [`super::HostStorageSystem::attach_scsi_lun`].
+        pub fn attach_scsi_lun(&self, lun_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.attach_scsi_lun(lun_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::attach_scsi_lun_ex_task`].
+        pub fn attach_scsi_lun_ex_task(&self, lun_uuid: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.attach_scsi_lun_ex_task(lun_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::attach_vmfs_extent`].
+        pub fn attach_vmfs_extent(&self, vmfs_path: &str, extent: &HostScsiDiskPartition) -> Result<()> {
+            self.client.block_on(self.inner.attach_vmfs_extent(vmfs_path, extent))
+        }
+        /// Blocking version of [`super::HostStorageSystem::change_nfs_user_password`].
+        pub fn change_nfs_user_password(&self, password: &str) -> Result<()> {
+            self.client.block_on(self.inner.change_nfs_user_password(password))
+        }
+        /// Blocking version of [`super::HostStorageSystem::clear_nfs_user`].
+        pub fn clear_nfs_user(&self) -> Result<()> {
+            self.client.block_on(self.inner.clear_nfs_user())
+        }
+        /// Blocking version of [`super::HostStorageSystem::compute_disk_partition_info`].
+        pub fn compute_disk_partition_info(&self, device_path: &str, layout: &HostDiskPartitionLayout, partition_format: Option<&str>) -> Result<HostDiskPartitionInfo> {
+            self.client.block_on(self.inner.compute_disk_partition_info(device_path, layout, partition_format))
+        }
+        /// Blocking version of [`super::HostStorageSystem::compute_disk_partition_info_for_resize`].
+        pub fn compute_disk_partition_info_for_resize(&self, partition: &HostScsiDiskPartition, block_range: &HostDiskPartitionBlockRange, partition_format: Option<&str>) -> Result<HostDiskPartitionInfo> {
+            self.client.block_on(self.inner.compute_disk_partition_info_for_resize(partition, block_range, partition_format))
+        }
+        /// Blocking version of [`super::HostStorageSystem::connect_nvme_controller`].
+        pub fn connect_nvme_controller(&self, connect_spec: &HostNvmeConnectSpec) -> Result<()> {
+            self.client.block_on(self.inner.connect_nvme_controller(connect_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::connect_nvme_controller_ex_task`].
+        pub fn connect_nvme_controller_ex_task(&self, connect_spec: Option<&[HostNvmeConnectSpec]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.connect_nvme_controller_ex_task(connect_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::create_nvme_over_rdma_adapter`].
+        pub fn create_nvme_over_rdma_adapter(&self, rdma_device_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.create_nvme_over_rdma_adapter(rdma_device_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::create_software_adapter`].
+        pub fn create_software_adapter(&self, spec: &dyn crate::types::traits::HostHbaCreateSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.create_software_adapter(spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::delete_scsi_lun_state`].
+        pub fn delete_scsi_lun_state(&self, lun_canonical_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_scsi_lun_state(lun_canonical_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::delete_vffs_volume_state`].
+        pub fn delete_vffs_volume_state(&self, vffs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_vffs_volume_state(vffs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::delete_vmfs_volume_state`].
+        pub fn delete_vmfs_volume_state(&self, vmfs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.delete_vmfs_volume_state(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::destroy_vffs`].
+        pub fn destroy_vffs(&self, vffs_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.destroy_vffs(vffs_path))
+        }
+        /// Blocking version of [`super::HostStorageSystem::detach_scsi_lun`].
+        pub fn detach_scsi_lun(&self, lun_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.detach_scsi_lun(lun_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::detach_scsi_lun_ex_task`].
+        pub fn detach_scsi_lun_ex_task(&self, lun_uuid: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.detach_scsi_lun_ex_task(lun_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::disable_multipath_path`].
+        pub fn disable_multipath_path(&self, path_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.disable_multipath_path(path_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::disconnect_nvme_controller`].
+        pub fn disconnect_nvme_controller(&self, disconnect_spec: &HostNvmeDisconnectSpec) -> Result<()> {
+            self.client.block_on(self.inner.disconnect_nvme_controller(disconnect_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::disconnect_nvme_controller_ex_task`].
+        pub fn disconnect_nvme_controller_ex_task(&self, disconnect_spec: Option<&[HostNvmeDisconnectSpec]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.disconnect_nvme_controller_ex_task(disconnect_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::discover_fcoe_hbas`].
+        pub fn discover_fcoe_hbas(&self, fcoe_spec: &FcoeConfigFcoeSpecification) -> Result<()> {
+            self.client.block_on(self.inner.discover_fcoe_hbas(fcoe_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::discover_nvme_controllers`].
+        pub fn discover_nvme_controllers(&self, discover_spec: &HostNvmeDiscoverSpec) -> Result<HostNvmeDiscoveryLog> {
+            self.client.block_on(self.inner.discover_nvme_controllers(discover_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::enable_multipath_path`].
+        pub fn enable_multipath_path(&self, path_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.enable_multipath_path(path_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::expand_vmfs_extent`].
+        pub fn expand_vmfs_extent(&self, vmfs_path: &str, extent: &HostScsiDiskPartition) -> Result<()> {
+            self.client.block_on(self.inner.expand_vmfs_extent(vmfs_path, extent))
+        }
+        /// Blocking version of [`super::HostStorageSystem::extend_vffs`].
+        pub fn extend_vffs(&self, vffs_path: &str, device_path: &str, spec: Option<&HostDiskPartitionSpec>) -> Result<()> {
+            self.client.block_on(self.inner.extend_vffs(vffs_path, device_path, spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::format_vffs`].
+        pub fn format_vffs(&self, create_spec: &HostVffsSpec) -> Result<HostVffsVolume> {
+            self.client.block_on(self.inner.format_vffs(create_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::format_vmfs`].
+        pub fn format_vmfs(&self, create_spec: &HostVmfsSpec) -> Result<HostVmfsVolume> {
+            self.client.block_on(self.inner.format_vmfs(create_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_as_local_task`].
+        pub fn mark_as_local_task(&self, scsi_disk_uuid: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mark_as_local_task(scsi_disk_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_as_non_local_task`].
+        pub fn mark_as_non_local_task(&self, scsi_disk_uuid: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mark_as_non_local_task(scsi_disk_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_as_non_ssd_task`].
+        pub fn mark_as_non_ssd_task(&self, scsi_disk_uuid: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mark_as_non_ssd_task(scsi_disk_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_as_ssd_task`].
+        pub fn mark_as_ssd_task(&self, scsi_disk_uuid: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mark_as_ssd_task(scsi_disk_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_for_removal`].
+        pub fn mark_for_removal(&self, hba_name: &str, remove: bool) -> Result<()> {
+            self.client.block_on(self.inner.mark_for_removal(hba_name, remove))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_perennially_reserved`].
+        pub fn mark_perennially_reserved(&self, lun_uuid: &str, state: bool) -> Result<()> {
+            self.client.block_on(self.inner.mark_perennially_reserved(lun_uuid, state))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mark_perennially_reserved_ex_task`].
+        pub fn mark_perennially_reserved_ex_task(&self, lun_uuid: Option<&[String]>, state: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mark_perennially_reserved_ex_task(lun_uuid, state))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mount_vffs_volume`].
+        pub fn mount_vffs_volume(&self, vffs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.mount_vffs_volume(vffs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mount_vmfs_volume`].
+        pub fn mount_vmfs_volume(&self, vmfs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.mount_vmfs_volume(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::mount_vmfs_volume_ex_task`].
+        pub fn mount_vmfs_volume_ex_task(&self, vmfs_uuid: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.mount_vmfs_volume_ex_task(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_available_ssds`].
+        pub fn query_available_ssds(&self, vffs_path: Option<&str>) -> Result<Option<Vec<HostScsiDisk>>> {
+            self.client.block_on(self.inner.query_available_ssds(vffs_path))
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_nfs_user`].
+        pub fn query_nfs_user(&self) -> Result<Option<HostNasVolumeUserInfo>> {
+            self.client.block_on(self.inner.query_nfs_user())
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_path_selection_policy_options`].
+        pub fn query_path_selection_policy_options(&self) -> Result<Option<Vec<HostPathSelectionPolicyOption>>> {
+            self.client.block_on(self.inner.query_path_selection_policy_options())
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_storage_array_type_policy_options`].
+        pub fn query_storage_array_type_policy_options(&self) -> Result<Option<Vec<HostStorageArrayTypePolicyOption>>> {
+            self.client.block_on(self.inner.query_storage_array_type_policy_options())
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_unresolved_vmfs_volume`].
+        pub fn query_unresolved_vmfs_volume(&self) -> Result<Option<Vec<HostUnresolvedVmfsVolume>>> {
+            self.client.block_on(self.inner.query_unresolved_vmfs_volume())
+        }
+        /// Blocking version of [`super::HostStorageSystem::query_vmfs_config_option`].
+        pub fn query_vmfs_config_option(&self) -> Result<Option<Vec<VmfsConfigOption>>> {
+            self.client.block_on(self.inner.query_vmfs_config_option())
+        }
+        /// Blocking version of [`super::HostStorageSystem::refresh_storage_system`].
+        pub fn refresh_storage_system(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_storage_system())
+        }
+        /// Blocking version of [`super::HostStorageSystem::remove_internet_scsi_send_targets`].
+        pub fn remove_internet_scsi_send_targets(&self, i_scsi_hba_device: &str, targets: &[HostInternetScsiHbaSendTarget], force: Option<bool>) -> Result<()> {
+            self.client.block_on(self.inner.remove_internet_scsi_send_targets(i_scsi_hba_device, targets, force))
+        }
+        /// Blocking version of [`super::HostStorageSystem::remove_internet_scsi_static_targets`].
+        pub fn remove_internet_scsi_static_targets(&self, i_scsi_hba_device: &str, targets: &[HostInternetScsiHbaStaticTarget]) -> Result<()> {
+            self.client.block_on(self.inner.remove_internet_scsi_static_targets(i_scsi_hba_device, targets))
+        }
+        /// Blocking version of [`super::HostStorageSystem::remove_nvme_over_rdma_adapter`].
+        pub fn remove_nvme_over_rdma_adapter(&self, hba_device_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_nvme_over_rdma_adapter(hba_device_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::remove_software_adapter`].
+        pub fn remove_software_adapter(&self, hba_device_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_software_adapter(hba_device_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::rescan_all_hba`].
+        pub fn rescan_all_hba(&self) -> Result<()> {
+            self.client.block_on(self.inner.rescan_all_hba())
+        }
+        /// Blocking version of [`super::HostStorageSystem::rescan_hba`].
+        pub fn rescan_hba(&self, hba_device: &str) -> Result<()> {
+            self.client.block_on(self.inner.rescan_hba(hba_device))
+        }
+        /// Blocking version of [`super::HostStorageSystem::rescan_vffs`].
+        pub fn rescan_vffs(&self) -> Result<()> {
+            self.client.block_on(self.inner.rescan_vffs())
+        }
+        /// Blocking version of [`super::HostStorageSystem::rescan_vmfs`].
+        pub fn rescan_vmfs(&self) -> Result<()> {
+            self.client.block_on(self.inner.rescan_vmfs())
+        }
+        /// Blocking version of [`super::HostStorageSystem::resolve_multiple_unresolved_vmfs_volumes`].
+        pub fn resolve_multiple_unresolved_vmfs_volumes(&self, resolution_spec: &[HostUnresolvedVmfsResolutionSpec]) -> Result<Option<Vec<HostUnresolvedVmfsResolutionResult>>> {
+            self.client.block_on(self.inner.resolve_multiple_unresolved_vmfs_volumes(resolution_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::resolve_multiple_unresolved_vmfs_volumes_ex_task`].
+        pub fn resolve_multiple_unresolved_vmfs_volumes_ex_task(&self, resolution_spec: &[HostUnresolvedVmfsResolutionSpec]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.resolve_multiple_unresolved_vmfs_volumes_ex_task(resolution_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::retrieve_disk_partition_info`].
+        pub fn retrieve_disk_partition_info(&self, device_path: &[String]) -> Result<Option<Vec<HostDiskPartitionInfo>>> {
+            self.client.block_on(self.inner.retrieve_disk_partition_info(device_path))
+        }
+        /// Blocking version of [`super::HostStorageSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostStorageSystem::set_multipath_lun_policy`].
+        pub fn set_multipath_lun_policy(&self, lun_id: &str, policy: &dyn crate::types::traits::HostMultipathInfoLogicalUnitPolicyTrait) -> Result<()> {
+            self.client.block_on(self.inner.set_multipath_lun_policy(lun_id, policy))
+        }
+        /// Blocking version of [`super::HostStorageSystem::set_nfs_user`].
+        pub fn set_nfs_user(&self, user: &str, password: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_nfs_user(user, password))
+        }
+        /// Blocking version of [`super::HostStorageSystem::turn_disk_locator_led_off_task`].
+        pub fn turn_disk_locator_led_off_task(&self, scsi_disk_uuids: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.turn_disk_locator_led_off_task(scsi_disk_uuids))
+        }
+        /// Blocking version of [`super::HostStorageSystem::turn_disk_locator_led_on_task`].
+        pub fn turn_disk_locator_led_on_task(&self, scsi_disk_uuids: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.turn_disk_locator_led_on_task(scsi_disk_uuids))
+        }
+        /// Blocking version of [`super::HostStorageSystem::unmap_vmfs_volume_ex_task`].
+        pub fn unmap_vmfs_volume_ex_task(&self, vmfs_uuid: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.unmap_vmfs_volume_ex_task(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::unmount_force_mounted_vmfs_volume`].
+        pub fn unmount_force_mounted_vmfs_volume(&self, vmfs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.unmount_force_mounted_vmfs_volume(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::unmount_vffs_volume`].
+        pub fn unmount_vffs_volume(&self, vffs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.unmount_vffs_volume(vffs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::unmount_vmfs_volume`].
+        pub fn unmount_vmfs_volume(&self, vmfs_uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.unmount_vmfs_volume(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::unmount_vmfs_volume_ex_task`].
+        pub fn unmount_vmfs_volume_ex_task(&self, vmfs_uuid: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.unmount_vmfs_volume_ex_task(vmfs_uuid))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_disk_partitions`].
+        pub fn update_disk_partitions(&self, device_path: &str, spec: &HostDiskPartitionSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_disk_partitions(device_path, spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_hpp_multipath_lun_policy`].
+        pub fn update_hpp_multipath_lun_policy(&self, lun_id: &str, policy: &HostMultipathInfoHppLogicalUnitPolicy) -> Result<()> {
+            self.client.block_on(self.inner.update_hpp_multipath_lun_policy(lun_id, policy))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_advanced_options`].
+        pub fn update_internet_scsi_advanced_options(&self, i_scsi_hba_device: &str, target_set: Option<&HostInternetScsiHbaTargetSet>, options: &[HostInternetScsiHbaParamValue]) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_advanced_options(i_scsi_hba_device, target_set, options))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_alias`].
+        pub fn update_internet_scsi_alias(&self, i_scsi_hba_device: &str, i_scsi_alias: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_alias(i_scsi_hba_device, i_scsi_alias))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_authentication_properties`].
+        pub fn update_internet_scsi_authentication_properties(&self, i_scsi_hba_device: &str, authentication_properties: &HostInternetScsiHbaAuthenticationProperties, target_set: Option<&HostInternetScsiHbaTargetSet>) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_authentication_properties(i_scsi_hba_device, authentication_properties, target_set))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_digest_properties`].
+        pub fn update_internet_scsi_digest_properties(&self, i_scsi_hba_device: &str, target_set: Option<&HostInternetScsiHbaTargetSet>, digest_properties: &HostInternetScsiHbaDigestProperties) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_digest_properties(i_scsi_hba_device, target_set, digest_properties))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_discovery_properties`].
+        pub fn update_internet_scsi_discovery_properties(&self, i_scsi_hba_device: &str, discovery_properties: &HostInternetScsiHbaDiscoveryProperties) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_discovery_properties(i_scsi_hba_device, discovery_properties))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_ip_properties`].
+        pub fn update_internet_scsi_ip_properties(&self, i_scsi_hba_device: &str, ip_properties: &HostInternetScsiHbaIpProperties) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_ip_properties(i_scsi_hba_device, ip_properties))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_internet_scsi_name`].
+        pub fn update_internet_scsi_name(&self, i_scsi_hba_device: &str, i_scsi_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_internet_scsi_name(i_scsi_hba_device, i_scsi_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_scsi_lun_display_name`].
+        pub fn update_scsi_lun_display_name(&self, lun_uuid: &str, display_name: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_scsi_lun_display_name(lun_uuid, display_name))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_software_internet_scsi_enabled`].
+        pub fn update_software_internet_scsi_enabled(&self, enabled: bool) -> Result<()> {
+            self.client.block_on(self.inner.update_software_internet_scsi_enabled(enabled))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_vmfs_unmap_bandwidth`].
+        pub fn update_vmfs_unmap_bandwidth(&self, vmfs_uuid: &str, unmap_bandwidth_spec: &VmfsUnmapBandwidthSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_vmfs_unmap_bandwidth(vmfs_uuid, unmap_bandwidth_spec))
+        }
+        /// Blocking version of [`super::HostStorageSystem::update_vmfs_unmap_priority`].
+        pub fn update_vmfs_unmap_priority(&self, vmfs_uuid: &str, unmap_priority: crate::types::enums::HostVmfsVolumeUnmapPriorityEnum) -> Result<()> {
+            self.client.block_on(self.inner.update_vmfs_unmap_priority(vmfs_uuid, unmap_priority))
+        }
+        /// Blocking version of [`super::HostStorageSystem::upgrade_vm_layout`].
+        pub fn upgrade_vm_layout(&self) -> Result<()> {
+            self.client.block_on(self.inner.upgrade_vm_layout())
+        }
+        /// Blocking version of [`super::HostStorageSystem::upgrade_vmfs`].
+        pub fn upgrade_vmfs(&self, vmfs_path: &str) -> Result<()> {
+            self.client.block_on(self.inner.upgrade_vmfs(vmfs_path))
+        }
+        /// Blocking version of [`super::HostStorageSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostStorageSystem::file_system_volume_info`].
+        pub fn file_system_volume_info(&self) -> Result<HostFileSystemVolumeInfo> {
+            self.client.block_on(self.inner.file_system_volume_info())
+        }
+        /// Blocking version of [`super::HostStorageSystem::multipath_state_info`].
+        pub fn multipath_state_info(&self) -> Result<Option<HostMultipathStateInfo>> {
+            self.client.block_on(self.inner.multipath_state_info())
+        }
+        /// Blocking version of [`super::HostStorageSystem::storage_device_info`].
+        pub fn storage_device_info(&self) -> Result<Option<HostStorageDeviceInfo>> {
+            self.client.block_on(self.inner.storage_device_info())
+        }
+        /// Blocking version of [`super::HostStorageSystem::system_file`].
+        pub fn system_file(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.system_file())
+        }
+        /// Blocking version of [`super::HostStorageSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
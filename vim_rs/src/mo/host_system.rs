@@ -1519,3 +1519,318 @@ struct UpdateSystemSwapConfigurationRequestType<'a> {
     #[serde(rename = "sysSwapConfig")]
     sys_swap_config: &'a HostSystemSwapConfiguration,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::AnswerFileStatusResult;
+    use crate::types::structs::ApplyHostProfileConfigurationResult;
+    use crate::types::structs::ApplyHostProfileConfigurationSpec;
+    use crate::types::structs::ComplianceResult;
+    use crate::types::structs::CryptoKeyId;
+    use crate::types::structs::CryptoKeyPlain;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::Event;
+    use crate::types::structs::HostCapability;
+    use crate::types::structs::HostConfigInfo;
+    use crate::types::structs::HostConfigManager;
+    use crate::types::structs::HostConnectInfo;
+    use crate::types::structs::HostConnectSpec;
+    use crate::types::structs::HostFlagInfo;
+    use crate::types::structs::HostHardwareInfo;
+    use crate::types::structs::HostIpmiInfo;
+    use crate::types::structs::HostLicensableResourceInfo;
+    use crate::types::structs::HostListSummary;
+    use crate::types::structs::HostMaintenanceSpec;
+    use crate::types::structs::HostRuntimeInfo;
+    use crate::types::structs::HostServiceTicket;
+    use crate::types::structs::HostSystemComplianceCheckState;
+    use crate::types::structs::HostSystemReconnectSpec;
+    use crate::types::structs::HostSystemRemediationState;
+    use crate::types::structs::HostSystemResourceInfo;
+    use crate::types::structs::HostSystemSwapConfiguration;
+    use crate::types::structs::HostTpmAttestationReport;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VirtualMachineConfigInfo;
+    /// Blocking version of [`super::HostSystem`].
+    pub struct HostSystem {
+        inner: super::HostSystem,
+        client: Arc<Client>,
+    }
+    impl HostSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostSystem::acquire_cim_services_ticket`].
+        pub fn acquire_cim_services_ticket(&self) -> Result<HostServiceTicket> {
+            self.client.block_on(self.inner.acquire_cim_services_ticket())
+        }
+        /// Blocking version of [`super::HostSystem::configure_crypto_key`].
+        pub fn configure_crypto_key(&self, key_id: Option<&CryptoKeyId>) -> Result<()> {
+            self.client.block_on(self.inner.configure_crypto_key(key_id))
+        }
+        /// Blocking version of [`super::HostSystem::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::HostSystem::disconnect_host_task`].
+        pub fn disconnect_host_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.disconnect_host_task())
+        }
+        /// Blocking version of [`super::HostSystem::enable_crypto`].
+        pub fn enable_crypto(&self, key_plain: &CryptoKeyPlain) -> Result<()> {
+            self.client.block_on(self.inner.enable_crypto(key_plain))
+        }
+        /// Blocking version of [`super::HostSystem::enter_lockdown_mode`].
+        pub fn enter_lockdown_mode(&self) -> Result<()> {
+            self.client.block_on(self.inner.enter_lockdown_mode())
+        }
+        /// Blocking version of [`super::HostSystem::enter_maintenance_mode_task`].
+        pub fn enter_maintenance_mode_task(&self, timeout: i32, evacuate_powered_off_vms: Option<bool>, maintenance_spec: Option<&HostMaintenanceSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.enter_maintenance_mode_task(timeout, evacuate_powered_off_vms, maintenance_spec))
+        }
+        /// Blocking version of [`super::HostSystem::power_down_host_to_stand_by_task`].
+        pub fn power_down_host_to_stand_by_task(&self, timeout_sec: i32, evacuate_powered_off_vms: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_down_host_to_stand_by_task(timeout_sec, evacuate_powered_off_vms))
+        }
+        /// Blocking version of [`super::HostSystem::exit_lockdown_mode`].
+        pub fn exit_lockdown_mode(&self) -> Result<()> {
+            self.client.block_on(self.inner.exit_lockdown_mode())
+        }
+        /// Blocking version of [`super::HostSystem::exit_maintenance_mode_task`].
+        pub fn exit_maintenance_mode_task(&self, timeout: i32) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.exit_maintenance_mode_task(timeout))
+        }
+        /// Blocking version of [`super::HostSystem::power_up_host_from_stand_by_task`].
+        pub fn power_up_host_from_stand_by_task(&self, timeout_sec: i32) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_up_host_from_stand_by_task(timeout_sec))
+        }
+        /// Blocking version of [`super::HostSystem::prepare_crypto`].
+        pub fn prepare_crypto(&self) -> Result<()> {
+            self.client.block_on(self.inner.prepare_crypto())
+        }
+        /// Blocking version of [`super::HostSystem::query_host_connection_info`].
+        pub fn query_host_connection_info(&self) -> Result<HostConnectInfo> {
+            self.client.block_on(self.inner.query_host_connection_info())
+        }
+        /// Blocking version of [`super::HostSystem::query_memory_overhead`].
+        pub fn query_memory_overhead(&self, memory_size: i64, video_ram_size: Option<i32>, num_vcpus: i32) -> Result<i64> {
+            self.client.block_on(self.inner.query_memory_overhead(memory_size, video_ram_size, num_vcpus))
+        }
+        /// Blocking version of [`super::HostSystem::query_memory_overhead_ex`].
+        pub fn query_memory_overhead_ex(&self, vm_config_info: &VirtualMachineConfigInfo) -> Result<i64> {
+            self.client.block_on(self.inner.query_memory_overhead_ex(vm_config_info))
+        }
+        /// Blocking version of [`super::HostSystem::query_product_locker_location`].
+        pub fn query_product_locker_location(&self) -> Result<String> {
+            self.client.block_on(self.inner.query_product_locker_location())
+        }
+        /// Blocking version of [`super::HostSystem::query_tpm_attestation_report`].
+        pub fn query_tpm_attestation_report(&self) -> Result<Option<HostTpmAttestationReport>> {
+            self.client.block_on(self.inner.query_tpm_attestation_report())
+        }
+        /// Blocking version of [`super::HostSystem::reboot_host_task`].
+        pub fn reboot_host_task(&self, force: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reboot_host_task(force))
+        }
+        /// Blocking version of [`super::HostSystem::reconfigure_host_for_das_task`].
+        pub fn reconfigure_host_for_das_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_host_for_das_task())
+        }
+        /// Blocking version of [`super::HostSystem::reconnect_host_task`].
+        pub fn reconnect_host_task(&self, cnx_spec: Option<&HostConnectSpec>, reconnect_spec: Option<&HostSystemReconnectSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconnect_host_task(cnx_spec, reconnect_spec))
+        }
+        /// Blocking version of [`super::HostSystem::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::HostSystem::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::HostSystem::retrieve_free_epc_memory`].
+        pub fn retrieve_free_epc_memory(&self) -> Result<i64> {
+            self.client.block_on(self.inner.retrieve_free_epc_memory())
+        }
+        /// Blocking version of [`super::HostSystem::retrieve_hardware_uptime`].
+        pub fn retrieve_hardware_uptime(&self) -> Result<i64> {
+            self.client.block_on(self.inner.retrieve_hardware_uptime())
+        }
+        /// Blocking version of [`super::HostSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostSystem::shutdown_host_task`].
+        pub fn shutdown_host_task(&self, force: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.shutdown_host_task(force))
+        }
+        /// Blocking version of [`super::HostSystem::update_flags`].
+        pub fn update_flags(&self, flag_info: &HostFlagInfo) -> Result<()> {
+            self.client.block_on(self.inner.update_flags(flag_info))
+        }
+        /// Blocking version of [`super::HostSystem::update_ipmi`].
+        pub fn update_ipmi(&self, ipmi_info: &HostIpmiInfo) -> Result<()> {
+            self.client.block_on(self.inner.update_ipmi(ipmi_info))
+        }
+        /// Blocking version of [`super::HostSystem::update_product_locker_location_task`].
+        pub fn update_product_locker_location_task(&self, path: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_product_locker_location_task(path))
+        }
+        /// Blocking version of [`super::HostSystem::update_system_resources`].
+        pub fn update_system_resources(&self, resource_info: &HostSystemResourceInfo) -> Result<()> {
+            self.client.block_on(self.inner.update_system_resources(resource_info))
+        }
+        /// Blocking version of [`super::HostSystem::update_system_swap_configuration`].
+        pub fn update_system_swap_configuration(&self, sys_swap_config: &HostSystemSwapConfiguration) -> Result<()> {
+            self.client.block_on(self.inner.update_system_swap_configuration(sys_swap_config))
+        }
+        /// Blocking version of [`super::HostSystem::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::HostSystem::answer_file_validation_result`].
+        pub fn answer_file_validation_result(&self) -> Result<Option<AnswerFileStatusResult>> {
+            self.client.block_on(self.inner.answer_file_validation_result())
+        }
+        /// Blocking version of [`super::HostSystem::answer_file_validation_state`].
+        pub fn answer_file_validation_state(&self) -> Result<Option<AnswerFileStatusResult>> {
+            self.client.block_on(self.inner.answer_file_validation_state())
+        }
+        /// Blocking version of [`super::HostSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostSystem::capability`].
+        pub fn capability(&self) -> Result<Option<HostCapability>> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::HostSystem::compliance_check_result`].
+        pub fn compliance_check_result(&self) -> Result<Option<ComplianceResult>> {
+            self.client.block_on(self.inner.compliance_check_result())
+        }
+        /// Blocking version of [`super::HostSystem::compliance_check_state`].
+        pub fn compliance_check_state(&self) -> Result<Option<HostSystemComplianceCheckState>> {
+            self.client.block_on(self.inner.compliance_check_state())
+        }
+        /// Blocking version of [`super::HostSystem::config`].
+        pub fn config(&self) -> Result<Option<HostConfigInfo>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::HostSystem::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::HostSystem::config_manager`].
+        pub fn config_manager(&self) -> Result<HostConfigManager> {
+            self.client.block_on(self.inner.config_manager())
+        }
+        /// Blocking version of [`super::HostSystem::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::HostSystem::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::HostSystem::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::HostSystem::datastore_browser`].
+        pub fn datastore_browser(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.datastore_browser())
+        }
+        /// Blocking version of [`super::HostSystem::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::HostSystem::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::HostSystem::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::HostSystem::hardware`].
+        pub fn hardware(&self) -> Result<Option<HostHardwareInfo>> {
+            self.client.block_on(self.inner.hardware())
+        }
+        /// Blocking version of [`super::HostSystem::licensable_resource`].
+        pub fn licensable_resource(&self) -> Result<HostLicensableResourceInfo> {
+            self.client.block_on(self.inner.licensable_resource())
+        }
+        /// Blocking version of [`super::HostSystem::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::HostSystem::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::HostSystem::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::HostSystem::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::HostSystem::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::HostSystem::precheck_remediation_result`].
+        pub fn precheck_remediation_result(&self) -> Result<Option<ApplyHostProfileConfigurationSpec>> {
+            self.client.block_on(self.inner.precheck_remediation_result())
+        }
+        /// Blocking version of [`super::HostSystem::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::HostSystem::remediation_result`].
+        pub fn remediation_result(&self) -> Result<Option<ApplyHostProfileConfigurationResult>> {
+            self.client.block_on(self.inner.remediation_result())
+        }
+        /// Blocking version of [`super::HostSystem::remediation_state`].
+        pub fn remediation_state(&self) -> Result<Option<HostSystemRemediationState>> {
+            self.client.block_on(self.inner.remediation_state())
+        }
+        /// Blocking version of [`super::HostSystem::runtime`].
+        pub fn runtime(&self) -> Result<HostRuntimeInfo> {
+            self.client.block_on(self.inner.runtime())
+        }
+        /// Blocking version of [`super::HostSystem::summary`].
+        pub fn summary(&self) -> Result<HostListSummary> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::HostSystem::system_resources`].
+        pub fn system_resources(&self) -> Result<Option<HostSystemResourceInfo>> {
+            self.client.block_on(self.inner.system_resources())
+        }
+        /// Blocking version of [`super::HostSystem::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::HostSystem::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::HostSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::HostSystem::vm`].
+        pub fn vm(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
@@ -174,3 +174,51 @@ struct HostGetVFlashModuleDefaultConfigRequestType<'a> {
     #[serde(rename = "vFlashModule")]
     v_flash_module: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostVFlashManagerVFlashCacheConfigSpec;
+    use crate::types::structs::HostVFlashManagerVFlashConfigInfo;
+    use crate::types::structs::HostVFlashManagerVFlashResourceConfigSpec;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualDiskVFlashCacheConfigInfo;
+    /// Blocking version of [`super::HostVFlashManager`].
+    pub struct HostVFlashManager {
+        inner: super::HostVFlashManager,
+        client: Arc<Client>,
+    }
+    impl HostVFlashManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVFlashManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVFlashManager::host_config_v_flash_cache`].
+        pub fn host_config_v_flash_cache(&self, spec: &HostVFlashManagerVFlashCacheConfigSpec) -> Result<()> {
+            self.client.block_on(self.inner.host_config_v_flash_cache(spec))
+        }
+        /// Blocking version of [`super::HostVFlashManager::host_configure_v_flash_resource`].
+        pub fn host_configure_v_flash_resource(&self, spec: &HostVFlashManagerVFlashResourceConfigSpec) -> Result<()> {
+            self.client.block_on(self.inner.host_configure_v_flash_resource(spec))
+        }
+        /// Blocking version of [`super::HostVFlashManager::configure_v_flash_resource_ex_task`].
+        pub fn configure_v_flash_resource_ex_task(&self, device_path: Option<&[String]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_v_flash_resource_ex_task(device_path))
+        }
+        /// Blocking version of [`super::HostVFlashManager::host_get_v_flash_module_default_config`].
+        pub fn host_get_v_flash_module_default_config(&self, v_flash_module: &str) -> Result<VirtualDiskVFlashCacheConfigInfo> {
+            self.client.block_on(self.inner.host_get_v_flash_module_default_config(v_flash_module))
+        }
+        /// Blocking version of [`super::HostVFlashManager::host_remove_v_flash_resource`].
+        pub fn host_remove_v_flash_resource(&self) -> Result<()> {
+            self.client.block_on(self.inner.host_remove_v_flash_resource())
+        }
+        /// Blocking version of [`super::HostVFlashManager::v_flash_config_info`].
+        pub fn v_flash_config_info(&self) -> Result<Option<HostVFlashManagerVFlashConfigInfo>> {
+            self.client.block_on(self.inner.v_flash_config_info())
+        }
+    }
+}
@@ -144,3 +144,57 @@ struct UpdateIpConfigRequestType<'a> {
     #[serde(rename = "ipConfig")]
     ip_config: &'a HostIpConfig,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostIpConfig;
+    use crate::types::structs::HostVMotionNetConfig;
+    /// Blocking version of [`super::HostVMotionSystem`].
+    pub struct HostVMotionSystem {
+        inner: super::HostVMotionSystem,
+        client: Arc<Client>,
+    }
+    impl HostVMotionSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVMotionSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVMotionSystem::deselect_vnic`].
+        pub fn deselect_vnic(&self) -> Result<()> {
+            self.client.block_on(self.inner.deselect_vnic())
+        }
+        /// Blocking version of [`super::HostVMotionSystem::select_vnic`].
+        pub fn select_vnic(&self, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.select_vnic(device))
+        }
+        /// Blocking version of [`super::HostVMotionSystem::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostVMotionSystem::update_ip_config`].
+        pub fn update_ip_config(&self, ip_config: &HostIpConfig) -> Result<()> {
+            self.client.block_on(self.inner.update_ip_config(ip_config))
+        }
+        /// Blocking version of [`super::HostVMotionSystem::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostVMotionSystem::ip_config`].
+        pub fn ip_config(&self) -> Result<Option<HostIpConfig>> {
+            self.client.block_on(self.inner.ip_config())
+        }
+        /// Blocking version of [`super::HostVMotionSystem::net_config`].
+        pub fn net_config(&self) -> Result<Option<HostVMotionNetConfig>> {
+            self.client.block_on(self.inner.net_config())
+        }
+        /// Blocking version of [`super::HostVMotionSystem::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
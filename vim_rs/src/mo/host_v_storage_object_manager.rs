@@ -251,7 +251,7 @@ impl HostVStorageObjectManager {
     /// ***InvalidState***: If the operation cannot be performed on the disk.
     /// 
     /// ***NotFound***: If specified virtual storage object cannot be found.
-    pub async fn host_v_storage_object_create_disk_from_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id, name: &str, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>, crypto: Option<&dyn crate::types::traits::CryptoSpecTrait>, path: Option<&str>, provisioning_type: Option<&str>) -> Result<ManagedObjectReference> {
+    pub async fn host_v_storage_object_create_disk_from_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id, name: &str, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>, crypto: Option<&dyn crate::types::traits::CryptoSpecTrait>, path: Option<&str>, provisioning_type: Option<crate::types::enums::BaseConfigInfoDiskFileBackingInfoProvisioningTypeEnum>) -> Result<ManagedObjectReference> {
         let input = HostVStorageObjectCreateDiskFromSnapshotRequestType {id, datastore, snapshot_id, name, profile, crypto, path, provisioning_type, };
         let path = format!("/HostVStorageObjectManager/{moId}/HostVStorageObjectCreateDiskFromSnapshot_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1010,7 +1010,7 @@ impl HostVStorageObjectManager {
     /// datastore.
     /// 
     /// ***NotFound***: If specified virtual storage object cannot be found.
-    pub async fn host_retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[String]>) -> Result<VStorageObject> {
+    pub async fn host_retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[crate::types::enums::VslmDiskInfoFlagEnum]>) -> Result<VStorageObject> {
         let input = HostRetrieveVStorageObjectRequestType {id, datastore, disk_info_flags, };
         let path = format!("/HostVStorageObjectManager/{moId}/HostRetrieveVStorageObject", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1410,7 +1410,7 @@ struct HostVStorageObjectCreateDiskFromSnapshotRequestType<'a> {
     path: Option<&'a str>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "provisioningType")]
-    provisioning_type: Option<&'a str>,
+    provisioning_type: Option<crate::types::enums::BaseConfigInfoDiskFileBackingInfoProvisioningTypeEnum>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -1535,7 +1535,7 @@ struct HostRetrieveVStorageObjectRequestType<'a> {
     datastore: &'a ManagedObjectReference,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "diskInfoFlags")]
-    disk_info_flags: Option<&'a [String]>,
+    disk_info_flags: Option<&'a [crate::types::enums::VslmDiskInfoFlagEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -1608,3 +1608,157 @@ struct HostUpdateVStorageObjectMetadataExRequestType<'a> {
     #[serde(rename = "deleteKeys")]
     delete_keys: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Id;
+    use crate::types::structs::KeyValue;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VStorageObject;
+    use crate::types::structs::VStorageObjectSnapshotInfo;
+    use crate::types::structs::VStorageObjectStateInfo;
+    use crate::types::structs::VslmCloneSpec;
+    use crate::types::structs::VslmCreateSpec;
+    use crate::types::structs::VslmInfrastructureObjectPolicy;
+    use crate::types::structs::VslmRelocateSpec;
+    use crate::types::structs::VslmVClockInfo;
+    /// Blocking version of [`super::HostVStorageObjectManager`].
+    pub struct HostVStorageObjectManager {
+        inner: super::HostVStorageObjectManager,
+        client: Arc<Client>,
+    }
+    impl HostVStorageObjectManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVStorageObjectManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_v_storage_object_revert_task`].
+        pub fn host_v_storage_object_revert_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_v_storage_object_revert_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_clear_v_storage_object_control_flags`].
+        pub fn host_clear_v_storage_object_control_flags(&self, id: &Id, datastore: &ManagedObjectReference, control_flags: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.host_clear_v_storage_object_control_flags(id, datastore, control_flags))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_clone_v_storage_object_task`].
+        pub fn host_clone_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference, spec: &VslmCloneSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_clone_v_storage_object_task(id, datastore, spec))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_create_disk_task`].
+        pub fn host_create_disk_task(&self, spec: &VslmCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_create_disk_task(spec))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_v_storage_object_create_disk_from_snapshot_task`].
+        pub fn host_v_storage_object_create_disk_from_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id, name: &str, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>, crypto: Option<&dyn crate::types::traits::CryptoSpecTrait>, path: Option<&str>, provisioning_type: Option<crate::types::enums::BaseConfigInfoDiskFileBackingInfoProvisioningTypeEnum>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_v_storage_object_create_disk_from_snapshot_task(id, datastore, snapshot_id, name, profile, crypto, path, provisioning_type))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_v_storage_object_create_snapshot_task`].
+        pub fn host_v_storage_object_create_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, description: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_v_storage_object_create_snapshot_task(id, datastore, description))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::v_storage_object_create_snapshot_ex_task`].
+        pub fn v_storage_object_create_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, description: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_create_snapshot_ex_task(id, datastore, description))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_v_storage_object_delete_snapshot_task`].
+        pub fn host_v_storage_object_delete_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_v_storage_object_delete_snapshot_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::v_storage_object_delete_snapshot_ex_task`].
+        pub fn v_storage_object_delete_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_delete_snapshot_ex_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_delete_v_storage_object_task`].
+        pub fn host_delete_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_delete_v_storage_object_task(id, datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_delete_v_storage_object_ex_task`].
+        pub fn host_delete_v_storage_object_ex_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_delete_v_storage_object_ex_task(id, datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_extend_disk_task`].
+        pub fn host_extend_disk_task(&self, id: &Id, datastore: &ManagedObjectReference, new_capacity_in_mb: i64) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_extend_disk_task(id, datastore, new_capacity_in_mb))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::v_storage_object_extend_disk_ex_task`].
+        pub fn v_storage_object_extend_disk_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, new_capacity_in_mb: i64) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_extend_disk_ex_task(id, datastore, new_capacity_in_mb))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_inflate_disk_task`].
+        pub fn host_inflate_disk_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_inflate_disk_task(id, datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_list_v_storage_object`].
+        pub fn host_list_v_storage_object(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<Id>>> {
+            self.client.block_on(self.inner.host_list_v_storage_object(datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_reconcile_datastore_inventory_task`].
+        pub fn host_reconcile_datastore_inventory_task(&self, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_reconcile_datastore_inventory_task(datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_register_disk`].
+        pub fn host_register_disk(&self, path: &str, name: Option<&str>, modify_control_flags: Option<bool>) -> Result<VStorageObject> {
+            self.client.block_on(self.inner.host_register_disk(path, name, modify_control_flags))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_relocate_v_storage_object_task`].
+        pub fn host_relocate_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference, spec: &VslmRelocateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_relocate_v_storage_object_task(id, datastore, spec))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_rename_v_storage_object`].
+        pub fn host_rename_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, name: &str) -> Result<()> {
+            self.client.block_on(self.inner.host_rename_v_storage_object(id, datastore, name))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::rename_v_storage_object_ex`].
+        pub fn rename_v_storage_object_ex(&self, id: &Id, datastore: &ManagedObjectReference, name: &str) -> Result<VslmVClockInfo> {
+            self.client.block_on(self.inner.rename_v_storage_object_ex(id, datastore, name))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_v_storage_object_retrieve_snapshot_info`].
+        pub fn host_v_storage_object_retrieve_snapshot_info(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<VStorageObjectSnapshotInfo> {
+            self.client.block_on(self.inner.host_v_storage_object_retrieve_snapshot_info(id, datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_retrieve_v_storage_infrastructure_object_policy`].
+        pub fn host_retrieve_v_storage_infrastructure_object_policy(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<VslmInfrastructureObjectPolicy>>> {
+            self.client.block_on(self.inner.host_retrieve_v_storage_infrastructure_object_policy(datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_retrieve_v_storage_object`].
+        pub fn host_retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[crate::types::enums::VslmDiskInfoFlagEnum]>) -> Result<VStorageObject> {
+            self.client.block_on(self.inner.host_retrieve_v_storage_object(id, datastore, disk_info_flags))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_retrieve_v_storage_object_metadata`].
+        pub fn host_retrieve_v_storage_object_metadata(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: Option<&Id>, prefix: Option<&str>) -> Result<Option<Vec<KeyValue>>> {
+            self.client.block_on(self.inner.host_retrieve_v_storage_object_metadata(id, datastore, snapshot_id, prefix))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_retrieve_v_storage_object_metadata_value`].
+        pub fn host_retrieve_v_storage_object_metadata_value(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: Option<&Id>, key: &str) -> Result<String> {
+            self.client.block_on(self.inner.host_retrieve_v_storage_object_metadata_value(id, datastore, snapshot_id, key))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_retrieve_v_storage_object_state`].
+        pub fn host_retrieve_v_storage_object_state(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<VStorageObjectStateInfo> {
+            self.client.block_on(self.inner.host_retrieve_v_storage_object_state(id, datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::revert_v_storage_object_ex_task`].
+        pub fn revert_v_storage_object_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_v_storage_object_ex_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_schedule_reconcile_datastore_inventory`].
+        pub fn host_schedule_reconcile_datastore_inventory(&self, datastore: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.host_schedule_reconcile_datastore_inventory(datastore))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_set_v_storage_object_control_flags`].
+        pub fn host_set_v_storage_object_control_flags(&self, id: &Id, datastore: &ManagedObjectReference, control_flags: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.host_set_v_storage_object_control_flags(id, datastore, control_flags))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_update_v_storage_object_metadata_task`].
+        pub fn host_update_v_storage_object_metadata_task(&self, id: &Id, datastore: &ManagedObjectReference, metadata: Option<&[KeyValue]>, delete_keys: Option<&[String]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_update_v_storage_object_metadata_task(id, datastore, metadata, delete_keys))
+        }
+        /// Blocking version of [`super::HostVStorageObjectManager::host_update_v_storage_object_metadata_ex_task`].
+        pub fn host_update_v_storage_object_metadata_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, metadata: Option<&[KeyValue]>, delete_keys: Option<&[String]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.host_update_v_storage_object_metadata_ex_task(id, datastore, metadata, delete_keys))
+        }
+    }
+}
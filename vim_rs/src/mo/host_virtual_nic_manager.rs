@@ -55,7 +55,7 @@ impl HostVirtualNicManager {
     /// ***InvalidArgument***: if nicType is invalid
     /// 
     /// ***HostConfigFault***: for any other failure.
-    pub async fn query_net_config(&self, nic_type: &str) -> Result<Option<VirtualNicManagerNetConfig>> {
+    pub async fn query_net_config(&self, nic_type: crate::types::enums::HostVirtualNicManagerNicTypeEnum) -> Result<Option<VirtualNicManagerNetConfig>> {
         let input = QueryNetConfigRequestType {nic_type, };
         let path = format!("/HostVirtualNicManager/{moId}/QueryNetConfig", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -148,9 +148,9 @@ struct DeselectVnicForNicTypeRequestType<'a> {
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
-struct QueryNetConfigRequestType<'a> {
+struct QueryNetConfigRequestType {
     #[serde(rename = "nicType")]
-    nic_type: &'a str,
+    nic_type: crate::types::enums::HostVirtualNicManagerNicTypeEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -165,3 +165,53 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::HostVirtualNicManagerInfo;
+    use crate::types::structs::VirtualNicManagerNetConfig;
+    /// Blocking version of [`super::HostVirtualNicManager`].
+    pub struct HostVirtualNicManager {
+        inner: super::HostVirtualNicManager,
+        client: Arc<Client>,
+    }
+    impl HostVirtualNicManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVirtualNicManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::deselect_vnic_for_nic_type`].
+        pub fn deselect_vnic_for_nic_type(&self, nic_type: &str, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.deselect_vnic_for_nic_type(nic_type, device))
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::query_net_config`].
+        pub fn query_net_config(&self, nic_type: crate::types::enums::HostVirtualNicManagerNicTypeEnum) -> Result<Option<VirtualNicManagerNetConfig>> {
+            self.client.block_on(self.inner.query_net_config(nic_type))
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::select_vnic_for_nic_type`].
+        pub fn select_vnic_for_nic_type(&self, nic_type: &str, device: &str) -> Result<()> {
+            self.client.block_on(self.inner.select_vnic_for_nic_type(nic_type, device))
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::info`].
+        pub fn info(&self) -> Result<HostVirtualNicManagerInfo> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::HostVirtualNicManager::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
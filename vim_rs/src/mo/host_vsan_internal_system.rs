@@ -556,3 +556,89 @@ struct UpgradeVsanObjectsRequestType<'a> {
     #[serde(rename = "newVersion")]
     new_version: i32,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostVsanInternalSystemCmmdsQuery;
+    use crate::types::structs::HostVsanInternalSystemDeleteVsanObjectsResult;
+    use crate::types::structs::HostVsanInternalSystemVsanObjectOperationResult;
+    use crate::types::structs::HostVsanInternalSystemVsanPhysicalDiskDiagnosticsResult;
+    use crate::types::structs::VsanNewPolicyBatch;
+    use crate::types::structs::VsanPolicyChangeBatch;
+    use crate::types::structs::VsanPolicySatisfiability;
+    /// Blocking version of [`super::HostVsanInternalSystem`].
+    pub struct HostVsanInternalSystem {
+        inner: super::HostVsanInternalSystem,
+        client: Arc<Client>,
+    }
+    impl HostVsanInternalSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVsanInternalSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::abdicate_dom_ownership`].
+        pub fn abdicate_dom_ownership(&self, uuids: &[String]) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.abdicate_dom_ownership(uuids))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::can_provision_objects`].
+        pub fn can_provision_objects(&self, npbs: &[VsanNewPolicyBatch], ignore_satisfiability: Option<bool>) -> Result<Vec<VsanPolicySatisfiability>> {
+            self.client.block_on(self.inner.can_provision_objects(npbs, ignore_satisfiability))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::delete_vsan_objects`].
+        pub fn delete_vsan_objects(&self, uuids: &[String], force: Option<bool>) -> Result<Vec<HostVsanInternalSystemDeleteVsanObjectsResult>> {
+            self.client.block_on(self.inner.delete_vsan_objects(uuids, force))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::get_vsan_obj_ext_attrs`].
+        pub fn get_vsan_obj_ext_attrs(&self, uuids: &[String]) -> Result<String> {
+            self.client.block_on(self.inner.get_vsan_obj_ext_attrs(uuids))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_cmmds`].
+        pub fn query_cmmds(&self, queries: &[HostVsanInternalSystemCmmdsQuery]) -> Result<String> {
+            self.client.block_on(self.inner.query_cmmds(queries))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_objects_on_physical_vsan_disk`].
+        pub fn query_objects_on_physical_vsan_disk(&self, disks: &[String]) -> Result<String> {
+            self.client.block_on(self.inner.query_objects_on_physical_vsan_disk(disks))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_physical_vsan_disks`].
+        pub fn query_physical_vsan_disks(&self, props: Option<&[String]>) -> Result<String> {
+            self.client.block_on(self.inner.query_physical_vsan_disks(props))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_syncing_vsan_objects`].
+        pub fn query_syncing_vsan_objects(&self, uuids: Option<&[String]>) -> Result<String> {
+            self.client.block_on(self.inner.query_syncing_vsan_objects(uuids))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_vsan_object_uuids_by_filter`].
+        pub fn query_vsan_object_uuids_by_filter(&self, uuids: Option<&[String]>, limit: Option<i32>, version: Option<i32>) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_vsan_object_uuids_by_filter(uuids, limit, version))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_vsan_objects`].
+        pub fn query_vsan_objects(&self, uuids: Option<&[String]>) -> Result<String> {
+            self.client.block_on(self.inner.query_vsan_objects(uuids))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::query_vsan_statistics`].
+        pub fn query_vsan_statistics(&self, labels: &[String]) -> Result<String> {
+            self.client.block_on(self.inner.query_vsan_statistics(labels))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::reconfiguration_satisfiable`].
+        pub fn reconfiguration_satisfiable(&self, pcbs: &[VsanPolicyChangeBatch], ignore_satisfiability: Option<bool>) -> Result<Vec<VsanPolicySatisfiability>> {
+            self.client.block_on(self.inner.reconfiguration_satisfiable(pcbs, ignore_satisfiability))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::reconfigure_dom_object`].
+        pub fn reconfigure_dom_object(&self, uuid: &str, policy: &str) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_dom_object(uuid, policy))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::run_vsan_physical_disk_diagnostics`].
+        pub fn run_vsan_physical_disk_diagnostics(&self, disks: Option<&[String]>) -> Result<Vec<HostVsanInternalSystemVsanPhysicalDiskDiagnosticsResult>> {
+            self.client.block_on(self.inner.run_vsan_physical_disk_diagnostics(disks))
+        }
+        /// Blocking version of [`super::HostVsanInternalSystem::upgrade_vsan_objects`].
+        pub fn upgrade_vsan_objects(&self, uuids: &[String], new_version: i32) -> Result<Option<Vec<HostVsanInternalSystemVsanObjectOperationResult>>> {
+            self.client.block_on(self.inner.upgrade_vsan_objects(uuids, new_version))
+        }
+    }
+}
@@ -445,3 +445,73 @@ struct UnmountDiskMappingRequestType<'a> {
 struct UpdateVsanRequestType<'a> {
     config: &'a VsanHostConfigInfo,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostMaintenanceSpec;
+    use crate::types::structs::HostScsiDisk;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VsanHostClusterStatus;
+    use crate::types::structs::VsanHostConfigInfo;
+    use crate::types::structs::VsanHostDiskMapping;
+    use crate::types::structs::VsanHostDiskResult;
+    /// Blocking version of [`super::HostVsanSystem`].
+    pub struct HostVsanSystem {
+        inner: super::HostVsanSystem,
+        client: Arc<Client>,
+    }
+    impl HostVsanSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HostVsanSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HostVsanSystem::add_disks_task`].
+        pub fn add_disks_task(&self, disk: &[HostScsiDisk]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.add_disks_task(disk))
+        }
+        /// Blocking version of [`super::HostVsanSystem::evacuate_vsan_node_task`].
+        pub fn evacuate_vsan_node_task(&self, maintenance_spec: &HostMaintenanceSpec, timeout: i32) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.evacuate_vsan_node_task(maintenance_spec, timeout))
+        }
+        /// Blocking version of [`super::HostVsanSystem::initialize_disks_task`].
+        pub fn initialize_disks_task(&self, mapping: &[VsanHostDiskMapping]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.initialize_disks_task(mapping))
+        }
+        /// Blocking version of [`super::HostVsanSystem::query_disks_for_vsan`].
+        pub fn query_disks_for_vsan(&self, canonical_name: Option<&[String]>) -> Result<Option<Vec<VsanHostDiskResult>>> {
+            self.client.block_on(self.inner.query_disks_for_vsan(canonical_name))
+        }
+        /// Blocking version of [`super::HostVsanSystem::query_host_status`].
+        pub fn query_host_status(&self) -> Result<VsanHostClusterStatus> {
+            self.client.block_on(self.inner.query_host_status())
+        }
+        /// Blocking version of [`super::HostVsanSystem::recommission_vsan_node_task`].
+        pub fn recommission_vsan_node_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.recommission_vsan_node_task())
+        }
+        /// Blocking version of [`super::HostVsanSystem::remove_disk_task`].
+        pub fn remove_disk_task(&self, disk: &[HostScsiDisk], maintenance_spec: Option<&HostMaintenanceSpec>, timeout: Option<i32>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.remove_disk_task(disk, maintenance_spec, timeout))
+        }
+        /// Blocking version of [`super::HostVsanSystem::remove_disk_mapping_task`].
+        pub fn remove_disk_mapping_task(&self, mapping: &[VsanHostDiskMapping], maintenance_spec: Option<&HostMaintenanceSpec>, timeout: Option<i32>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.remove_disk_mapping_task(mapping, maintenance_spec, timeout))
+        }
+        /// Blocking version of [`super::HostVsanSystem::unmount_disk_mapping_task`].
+        pub fn unmount_disk_mapping_task(&self, mapping: &[VsanHostDiskMapping]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.unmount_disk_mapping_task(mapping))
+        }
+        /// Blocking version of [`super::HostVsanSystem::update_vsan_task`].
+        pub fn update_vsan_task(&self, config: &VsanHostConfigInfo) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_vsan_task(config))
+        }
+        /// Blocking version of [`super::HostVsanSystem::config`].
+        pub fn config(&self) -> Result<VsanHostConfigInfo> {
+            self.client.block_on(self.inner.config())
+        }
+    }
+}
@@ -299,3 +299,86 @@ struct HttpNfcLeaseSetManifestChecksumTypeRequestType<'a> {
     #[serde(rename = "deviceUrlsToChecksumTypes")]
     device_urls_to_checksum_types: Option<&'a [KeyValue]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HttpNfcLeaseCapabilities;
+    use crate::types::structs::HttpNfcLeaseInfo;
+    use crate::types::structs::HttpNfcLeaseManifestEntry;
+    use crate::types::structs::HttpNfcLeaseProbeResult;
+    use crate::types::structs::HttpNfcLeaseSourceFile;
+    use crate::types::structs::KeyValue;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::MethodFault;
+    /// Blocking version of [`super::HttpNfcLease`].
+    pub struct HttpNfcLease {
+        inner: super::HttpNfcLease,
+        client: Arc<Client>,
+    }
+    impl HttpNfcLease {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::HttpNfcLease::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_abort`].
+        pub fn http_nfc_lease_abort(&self, fault: Option<&MethodFault>) -> Result<()> {
+            self.client.block_on(self.inner.http_nfc_lease_abort(fault))
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_complete`].
+        pub fn http_nfc_lease_complete(&self) -> Result<()> {
+            self.client.block_on(self.inner.http_nfc_lease_complete())
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_get_manifest`].
+        pub fn http_nfc_lease_get_manifest(&self) -> Result<Option<Vec<HttpNfcLeaseManifestEntry>>> {
+            self.client.block_on(self.inner.http_nfc_lease_get_manifest())
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_probe_urls`].
+        pub fn http_nfc_lease_probe_urls(&self, files: Option<&[HttpNfcLeaseSourceFile]>, timeout: Option<i32>) -> Result<Option<Vec<HttpNfcLeaseProbeResult>>> {
+            self.client.block_on(self.inner.http_nfc_lease_probe_urls(files, timeout))
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_progress`].
+        pub fn http_nfc_lease_progress(&self, percent: i32) -> Result<()> {
+            self.client.block_on(self.inner.http_nfc_lease_progress(percent))
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_pull_from_urls_task`].
+        pub fn http_nfc_lease_pull_from_urls_task(&self, files: Option<&[HttpNfcLeaseSourceFile]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.http_nfc_lease_pull_from_urls_task(files))
+        }
+        /// Blocking version of [`super::HttpNfcLease::http_nfc_lease_set_manifest_checksum_type`].
+        pub fn http_nfc_lease_set_manifest_checksum_type(&self, device_urls_to_checksum_types: Option<&[KeyValue]>) -> Result<()> {
+            self.client.block_on(self.inner.http_nfc_lease_set_manifest_checksum_type(device_urls_to_checksum_types))
+        }
+        /// Blocking version of [`super::HttpNfcLease::capabilities`].
+        pub fn capabilities(&self) -> Result<HttpNfcLeaseCapabilities> {
+            self.client.block_on(self.inner.capabilities())
+        }
+        /// Blocking version of [`super::HttpNfcLease::error`].
+        pub fn error(&self) -> Result<Option<MethodFault>> {
+            self.client.block_on(self.inner.error())
+        }
+        /// Blocking version of [`super::HttpNfcLease::info`].
+        pub fn info(&self) -> Result<Option<HttpNfcLeaseInfo>> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::HttpNfcLease::initialize_progress`].
+        pub fn initialize_progress(&self) -> Result<i32> {
+            self.client.block_on(self.inner.initialize_progress())
+        }
+        /// Blocking version of [`super::HttpNfcLease::mode`].
+        pub fn mode(&self) -> Result<String> {
+            self.client.block_on(self.inner.mode())
+        }
+        /// Blocking version of [`super::HttpNfcLease::state`].
+        pub fn state(&self) -> Result<crate::types::enums::HttpNfcLeaseStateEnum> {
+            self.client.block_on(self.inner.state())
+        }
+        /// Blocking version of [`super::HttpNfcLease::transfer_progress`].
+        pub fn transfer_progress(&self) -> Result<i32> {
+            self.client.block_on(self.inner.transfer_progress())
+        }
+    }
+}
@@ -136,3 +136,39 @@ struct CloseInventoryViewFolderRequestType<'a> {
 struct OpenInventoryViewFolderRequestType<'a> {
     entity: &'a [ManagedObjectReference],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::InventoryView`].
+    pub struct InventoryView {
+        inner: super::InventoryView,
+        client: Arc<Client>,
+    }
+    impl InventoryView {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::InventoryView::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::InventoryView::close_inventory_view_folder`].
+        pub fn close_inventory_view_folder(&self, entity: &[ManagedObjectReference]) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.close_inventory_view_folder(entity))
+        }
+        /// Blocking version of [`super::InventoryView::destroy_view`].
+        pub fn destroy_view(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_view())
+        }
+        /// Blocking version of [`super::InventoryView::open_inventory_view_folder`].
+        pub fn open_inventory_view_folder(&self, entity: &[ManagedObjectReference]) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.open_inventory_view_folder(entity))
+        }
+        /// Blocking version of [`super::InventoryView::view`].
+        pub fn view(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.view())
+        }
+    }
+}
@@ -390,3 +390,58 @@ struct UpgradeIoFilterRequestType<'a> {
     #[serde(rename = "vibUrl")]
     vib_url: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ClusterIoFilterInfo;
+    use crate::types::structs::IoFilterQueryIssueResult;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualDiskId;
+    /// Blocking version of [`super::IoFilterManager`].
+    pub struct IoFilterManager {
+        inner: super::IoFilterManager,
+        client: Arc<Client>,
+    }
+    impl IoFilterManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::IoFilterManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::IoFilterManager::install_io_filter_task`].
+        pub fn install_io_filter_task(&self, vib_url: &str, comp_res: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.install_io_filter_task(vib_url, comp_res))
+        }
+        /// Blocking version of [`super::IoFilterManager::query_disks_using_filter`].
+        pub fn query_disks_using_filter(&self, filter_id: &str, comp_res: &ManagedObjectReference) -> Result<Vec<VirtualDiskId>> {
+            self.client.block_on(self.inner.query_disks_using_filter(filter_id, comp_res))
+        }
+        /// Blocking version of [`super::IoFilterManager::query_io_filter_info`].
+        pub fn query_io_filter_info(&self, comp_res: &ManagedObjectReference) -> Result<Option<Vec<ClusterIoFilterInfo>>> {
+            self.client.block_on(self.inner.query_io_filter_info(comp_res))
+        }
+        /// Blocking version of [`super::IoFilterManager::query_io_filter_issues`].
+        pub fn query_io_filter_issues(&self, filter_id: &str, comp_res: &ManagedObjectReference) -> Result<IoFilterQueryIssueResult> {
+            self.client.block_on(self.inner.query_io_filter_issues(filter_id, comp_res))
+        }
+        /// Blocking version of [`super::IoFilterManager::resolve_installation_errors_on_cluster_task`].
+        pub fn resolve_installation_errors_on_cluster_task(&self, filter_id: &str, cluster: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.resolve_installation_errors_on_cluster_task(filter_id, cluster))
+        }
+        /// Blocking version of [`super::IoFilterManager::resolve_installation_errors_on_host_task`].
+        pub fn resolve_installation_errors_on_host_task(&self, filter_id: &str, host: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.resolve_installation_errors_on_host_task(filter_id, host))
+        }
+        /// Blocking version of [`super::IoFilterManager::uninstall_io_filter_task`].
+        pub fn uninstall_io_filter_task(&self, filter_id: &str, comp_res: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.uninstall_io_filter_task(filter_id, comp_res))
+        }
+        /// Blocking version of [`super::IoFilterManager::upgrade_io_filter_task`].
+        pub fn upgrade_io_filter_task(&self, filter_id: &str, comp_res: &ManagedObjectReference, vib_url: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.upgrade_io_filter_task(filter_id, comp_res, vib_url))
+        }
+    }
+}
@@ -309,3 +309,57 @@ struct UpdateIpPoolRequestType<'a> {
     dc: &'a ManagedObjectReference,
     pool: &'a IpPool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::IpPool;
+    use crate::types::structs::IpPoolManagerIpAllocation;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::IpPoolManager`].
+    pub struct IpPoolManager {
+        inner: super::IpPoolManager,
+        client: Arc<Client>,
+    }
+    impl IpPoolManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::IpPoolManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::IpPoolManager::allocate_ipv_4_address`].
+        pub fn allocate_ipv_4_address(&self, dc: &ManagedObjectReference, pool_id: i32, allocation_id: &str) -> Result<String> {
+            self.client.block_on(self.inner.allocate_ipv_4_address(dc, pool_id, allocation_id))
+        }
+        /// Blocking version of [`super::IpPoolManager::allocate_ipv_6_address`].
+        pub fn allocate_ipv_6_address(&self, dc: &ManagedObjectReference, pool_id: i32, allocation_id: &str) -> Result<String> {
+            self.client.block_on(self.inner.allocate_ipv_6_address(dc, pool_id, allocation_id))
+        }
+        /// Blocking version of [`super::IpPoolManager::create_ip_pool`].
+        pub fn create_ip_pool(&self, dc: &ManagedObjectReference, pool: &IpPool) -> Result<i32> {
+            self.client.block_on(self.inner.create_ip_pool(dc, pool))
+        }
+        /// Blocking version of [`super::IpPoolManager::destroy_ip_pool`].
+        pub fn destroy_ip_pool(&self, dc: &ManagedObjectReference, id: i32, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.destroy_ip_pool(dc, id, force))
+        }
+        /// Blocking version of [`super::IpPoolManager::query_ip_allocations`].
+        pub fn query_ip_allocations(&self, dc: &ManagedObjectReference, pool_id: i32, extension_key: &str) -> Result<Vec<IpPoolManagerIpAllocation>> {
+            self.client.block_on(self.inner.query_ip_allocations(dc, pool_id, extension_key))
+        }
+        /// Blocking version of [`super::IpPoolManager::query_ip_pools`].
+        pub fn query_ip_pools(&self, dc: &ManagedObjectReference) -> Result<Option<Vec<IpPool>>> {
+            self.client.block_on(self.inner.query_ip_pools(dc))
+        }
+        /// Blocking version of [`super::IpPoolManager::release_ip_allocation`].
+        pub fn release_ip_allocation(&self, dc: &ManagedObjectReference, pool_id: i32, allocation_id: &str) -> Result<()> {
+            self.client.block_on(self.inner.release_ip_allocation(dc, pool_id, allocation_id))
+        }
+        /// Blocking version of [`super::IpPoolManager::update_ip_pool`].
+        pub fn update_ip_pool(&self, dc: &ManagedObjectReference, pool: &IpPool) -> Result<()> {
+            self.client.block_on(self.inner.update_ip_pool(dc, pool))
+        }
+    }
+}
@@ -280,3 +280,53 @@ struct UnbindVnicRequestType<'a> {
     vnic_device: &'a str,
     force: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::IscsiMigrationDependency;
+    use crate::types::structs::IscsiPortInfo;
+    use crate::types::structs::IscsiStatus;
+    /// Blocking version of [`super::IscsiManager`].
+    pub struct IscsiManager {
+        inner: super::IscsiManager,
+        client: Arc<Client>,
+    }
+    impl IscsiManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::IscsiManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::IscsiManager::bind_vnic`].
+        pub fn bind_vnic(&self, i_scsi_hba_name: &str, vnic_device: &str) -> Result<()> {
+            self.client.block_on(self.inner.bind_vnic(i_scsi_hba_name, vnic_device))
+        }
+        /// Blocking version of [`super::IscsiManager::query_bound_vnics`].
+        pub fn query_bound_vnics(&self, i_scsi_hba_name: &str) -> Result<Option<Vec<IscsiPortInfo>>> {
+            self.client.block_on(self.inner.query_bound_vnics(i_scsi_hba_name))
+        }
+        /// Blocking version of [`super::IscsiManager::query_candidate_nics`].
+        pub fn query_candidate_nics(&self, i_scsi_hba_name: &str) -> Result<Option<Vec<IscsiPortInfo>>> {
+            self.client.block_on(self.inner.query_candidate_nics(i_scsi_hba_name))
+        }
+        /// Blocking version of [`super::IscsiManager::query_migration_dependencies`].
+        pub fn query_migration_dependencies(&self, pnic_device: &[String]) -> Result<IscsiMigrationDependency> {
+            self.client.block_on(self.inner.query_migration_dependencies(pnic_device))
+        }
+        /// Blocking version of [`super::IscsiManager::query_pnic_status`].
+        pub fn query_pnic_status(&self, pnic_device: &str) -> Result<IscsiStatus> {
+            self.client.block_on(self.inner.query_pnic_status(pnic_device))
+        }
+        /// Blocking version of [`super::IscsiManager::query_vnic_status`].
+        pub fn query_vnic_status(&self, vnic_device: &str) -> Result<IscsiStatus> {
+            self.client.block_on(self.inner.query_vnic_status(vnic_device))
+        }
+        /// Blocking version of [`super::IscsiManager::unbind_vnic`].
+        pub fn unbind_vnic(&self, i_scsi_hba_name: &str, vnic_device: &str, force: bool) -> Result<()> {
+            self.client.block_on(self.inner.unbind_vnic(i_scsi_hba_name, vnic_device, force))
+        }
+    }
+}
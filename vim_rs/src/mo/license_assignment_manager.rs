@@ -97,3 +97,36 @@ struct UpdateAssignedLicenseRequestType<'a> {
     #[serde(rename = "entityDisplayName")]
     entity_display_name: Option<&'a str>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::LicenseAssignmentManagerLicenseAssignment;
+    use crate::types::structs::LicenseManagerLicenseInfo;
+    /// Blocking version of [`super::LicenseAssignmentManager`].
+    pub struct LicenseAssignmentManager {
+        inner: super::LicenseAssignmentManager,
+        client: Arc<Client>,
+    }
+    impl LicenseAssignmentManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::LicenseAssignmentManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::LicenseAssignmentManager::query_assigned_licenses`].
+        pub fn query_assigned_licenses(&self, entity_id: Option<&str>) -> Result<Option<Vec<LicenseAssignmentManagerLicenseAssignment>>> {
+            self.client.block_on(self.inner.query_assigned_licenses(entity_id))
+        }
+        /// Blocking version of [`super::LicenseAssignmentManager::remove_assigned_license`].
+        pub fn remove_assigned_license(&self, entity_id: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_assigned_license(entity_id))
+        }
+        /// Blocking version of [`super::LicenseAssignmentManager::update_assigned_license`].
+        pub fn update_assigned_license(&self, entity: &str, license_key: &str, entity_display_name: Option<&str>) -> Result<LicenseManagerLicenseInfo> {
+            self.client.block_on(self.inner.update_assigned_license(entity, license_key, entity_display_name))
+        }
+    }
+}
@@ -621,3 +621,118 @@ struct UpdateLicenseRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     labels: Option<&'a [KeyValue]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::KeyValue;
+    use crate::types::structs::LicenseAvailabilityInfo;
+    use crate::types::structs::LicenseDiagnostics;
+    use crate::types::structs::LicenseFeatureInfo;
+    use crate::types::structs::LicenseManagerEvaluationInfo;
+    use crate::types::structs::LicenseManagerLicenseInfo;
+    use crate::types::structs::LicenseUsageInfo;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::LicenseManager`].
+    pub struct LicenseManager {
+        inner: super::LicenseManager,
+        client: Arc<Client>,
+    }
+    impl LicenseManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::LicenseManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::LicenseManager::add_license`].
+        pub fn add_license(&self, license_key: &str, labels: Option<&[KeyValue]>) -> Result<LicenseManagerLicenseInfo> {
+            self.client.block_on(self.inner.add_license(license_key, labels))
+        }
+        /// Blocking version of [`super::LicenseManager::check_license_feature`].
+        pub fn check_license_feature(&self, host: Option<&ManagedObjectReference>, feature_key: &str) -> Result<bool> {
+            self.client.block_on(self.inner.check_license_feature(host, feature_key))
+        }
+        /// Blocking version of [`super::LicenseManager::configure_license_source`].
+        pub fn configure_license_source(&self, host: Option<&ManagedObjectReference>, license_source: &dyn crate::types::traits::LicenseSourceTrait) -> Result<()> {
+            self.client.block_on(self.inner.configure_license_source(host, license_source))
+        }
+        /// Blocking version of [`super::LicenseManager::decode_license`].
+        pub fn decode_license(&self, license_key: &str) -> Result<LicenseManagerLicenseInfo> {
+            self.client.block_on(self.inner.decode_license(license_key))
+        }
+        /// Blocking version of [`super::LicenseManager::disable_feature`].
+        pub fn disable_feature(&self, host: Option<&ManagedObjectReference>, feature_key: &str) -> Result<bool> {
+            self.client.block_on(self.inner.disable_feature(host, feature_key))
+        }
+        /// Blocking version of [`super::LicenseManager::enable_feature`].
+        pub fn enable_feature(&self, host: Option<&ManagedObjectReference>, feature_key: &str) -> Result<bool> {
+            self.client.block_on(self.inner.enable_feature(host, feature_key))
+        }
+        /// Blocking version of [`super::LicenseManager::query_license_source_availability`].
+        pub fn query_license_source_availability(&self, host: Option<&ManagedObjectReference>) -> Result<Option<Vec<LicenseAvailabilityInfo>>> {
+            self.client.block_on(self.inner.query_license_source_availability(host))
+        }
+        /// Blocking version of [`super::LicenseManager::query_supported_features`].
+        pub fn query_supported_features(&self, host: Option<&ManagedObjectReference>) -> Result<Option<Vec<LicenseFeatureInfo>>> {
+            self.client.block_on(self.inner.query_supported_features(host))
+        }
+        /// Blocking version of [`super::LicenseManager::query_license_usage`].
+        pub fn query_license_usage(&self, host: Option<&ManagedObjectReference>) -> Result<LicenseUsageInfo> {
+            self.client.block_on(self.inner.query_license_usage(host))
+        }
+        /// Blocking version of [`super::LicenseManager::remove_license_label`].
+        pub fn remove_license_label(&self, license_key: &str, label_key: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_license_label(license_key, label_key))
+        }
+        /// Blocking version of [`super::LicenseManager::remove_license`].
+        pub fn remove_license(&self, license_key: &str) -> Result<()> {
+            self.client.block_on(self.inner.remove_license(license_key))
+        }
+        /// Blocking version of [`super::LicenseManager::set_license_edition`].
+        pub fn set_license_edition(&self, host: Option<&ManagedObjectReference>, feature_key: Option<&str>) -> Result<()> {
+            self.client.block_on(self.inner.set_license_edition(host, feature_key))
+        }
+        /// Blocking version of [`super::LicenseManager::update_license_label`].
+        pub fn update_license_label(&self, license_key: &str, label_key: &str, label_value: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_license_label(license_key, label_key, label_value))
+        }
+        /// Blocking version of [`super::LicenseManager::update_license`].
+        pub fn update_license(&self, license_key: &str, labels: Option<&[KeyValue]>) -> Result<LicenseManagerLicenseInfo> {
+            self.client.block_on(self.inner.update_license(license_key, labels))
+        }
+        /// Blocking version of [`super::LicenseManager::diagnostics`].
+        pub fn diagnostics(&self) -> Result<Option<LicenseDiagnostics>> {
+            self.client.block_on(self.inner.diagnostics())
+        }
+        /// Blocking version of [`super::LicenseManager::evaluation`].
+        pub fn evaluation(&self) -> Result<LicenseManagerEvaluationInfo> {
+            self.client.block_on(self.inner.evaluation())
+        }
+        /// Blocking version of [`super::LicenseManager::feature_info`].
+        pub fn feature_info(&self) -> Result<Option<Vec<LicenseFeatureInfo>>> {
+            self.client.block_on(self.inner.feature_info())
+        }
+        /// Blocking version of [`super::LicenseManager::license_assignment_manager`].
+        pub fn license_assignment_manager(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.license_assignment_manager())
+        }
+        /// Blocking version of [`super::LicenseManager::licensed_edition`].
+        pub fn licensed_edition(&self) -> Result<String> {
+            self.client.block_on(self.inner.licensed_edition())
+        }
+        /// Blocking version of [`super::LicenseManager::licenses`].
+        pub fn licenses(&self) -> Result<Vec<LicenseManagerLicenseInfo>> {
+            self.client.block_on(self.inner.licenses())
+        }
+        /// Blocking version of [`super::LicenseManager::source`].
+        pub fn source(&self) -> Result<Box<dyn crate::types::traits::LicenseSourceTrait>> {
+            self.client.block_on(self.inner.source())
+        }
+        /// Blocking version of [`super::LicenseManager::source_available`].
+        pub fn source_available(&self) -> Result<bool> {
+            self.client.block_on(self.inner.source_available())
+        }
+    }
+}
@@ -123,3 +123,43 @@ struct ResetListViewRequestType<'a> {
 struct ResetListViewFromViewRequestType<'a> {
     view: &'a ManagedObjectReference,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ListView`].
+    pub struct ListView {
+        inner: super::ListView,
+        client: Arc<Client>,
+    }
+    impl ListView {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ListView::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ListView::destroy_view`].
+        pub fn destroy_view(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_view())
+        }
+        /// Blocking version of [`super::ListView::modify_list_view`].
+        pub fn modify_list_view(&self, add: Option<&[ManagedObjectReference]>, remove: Option<&[ManagedObjectReference]>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.modify_list_view(add, remove))
+        }
+        /// Blocking version of [`super::ListView::reset_list_view`].
+        pub fn reset_list_view(&self, obj: Option<&[ManagedObjectReference]>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.reset_list_view(obj))
+        }
+        /// Blocking version of [`super::ListView::reset_list_view_from_view`].
+        pub fn reset_list_view_from_view(&self, view: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.reset_list_view_from_view(view))
+        }
+        /// Blocking version of [`super::ListView::view`].
+        pub fn view(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.view())
+        }
+    }
+}
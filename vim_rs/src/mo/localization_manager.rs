@@ -57,3 +57,27 @@ impl LocalizationManager {
         self.client.execute_option(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::LocalizationManagerMessageCatalog;
+    /// Blocking version of [`super::LocalizationManager`].
+    pub struct LocalizationManager {
+        inner: super::LocalizationManager,
+        client: Arc<Client>,
+    }
+    impl LocalizationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::LocalizationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::LocalizationManager::catalog`].
+        pub fn catalog(&self) -> Result<Option<Vec<LocalizationManagerMessageCatalog>>> {
+            self.client.block_on(self.inner.catalog())
+        }
+    }
+}
@@ -33,3 +33,31 @@ impl ManagedObjectView {
         self.client.execute_option(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ManagedObjectView`].
+    pub struct ManagedObjectView {
+        inner: super::ManagedObjectView,
+        client: Arc<Client>,
+    }
+    impl ManagedObjectView {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ManagedObjectView::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ManagedObjectView::destroy_view`].
+        pub fn destroy_view(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_view())
+        }
+        /// Blocking version of [`super::ManagedObjectView::view`].
+        pub fn view(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.view())
+        }
+    }
+}
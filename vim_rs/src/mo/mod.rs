@@ -1,273 +1,815 @@
+#[cfg(feature = "mo-alarm")]
 pub mod alarm;
+#[cfg(feature = "mo-alarm-manager")]
 pub mod alarm_manager;
+#[cfg(feature = "mo-authorization-manager")]
 pub mod authorization_manager;
+#[cfg(feature = "mo-certificate-manager")]
 pub mod certificate_manager;
+#[cfg(feature = "mo-cluster-compute-resource")]
 pub mod cluster_compute_resource;
+#[cfg(feature = "mo-cluster-evc-manager")]
 pub mod cluster_evc_manager;
+#[cfg(feature = "mo-compute-resource")]
 pub mod compute_resource;
+#[cfg(feature = "mo-container-view")]
 pub mod container_view;
+#[cfg(feature = "mo-crypto-manager")]
 pub mod crypto_manager;
+#[cfg(feature = "mo-crypto-manager-kmip")]
 pub mod crypto_manager_kmip;
+#[cfg(feature = "mo-custom-fields-manager")]
 pub mod custom_fields_manager;
+#[cfg(feature = "mo-customization-spec-manager")]
 pub mod customization_spec_manager;
+#[cfg(feature = "mo-datacenter")]
 pub mod datacenter;
+#[cfg(feature = "mo-datastore")]
 pub mod datastore;
+#[cfg(feature = "mo-datastore-namespace-manager")]
 pub mod datastore_namespace_manager;
+#[cfg(feature = "mo-diagnostic-manager")]
 pub mod diagnostic_manager;
+#[cfg(feature = "mo-distributed-virtual-portgroup")]
 pub mod distributed_virtual_portgroup;
+#[cfg(feature = "mo-distributed-virtual-switch")]
 pub mod distributed_virtual_switch;
+#[cfg(feature = "mo-distributed-virtual-switch-manager")]
 pub mod distributed_virtual_switch_manager;
+#[cfg(feature = "mo-environment-browser")]
 pub mod environment_browser;
+#[cfg(feature = "mo-event-history-collector")]
 pub mod event_history_collector;
+#[cfg(feature = "mo-event-manager")]
 pub mod event_manager;
+#[cfg(feature = "mo-extensible-managed-object")]
 pub mod extensible_managed_object;
+#[cfg(feature = "mo-extension-manager")]
 pub mod extension_manager;
+#[cfg(feature = "mo-failover-cluster-configurator")]
 pub mod failover_cluster_configurator;
+#[cfg(feature = "mo-failover-cluster-manager")]
 pub mod failover_cluster_manager;
+#[cfg(feature = "mo-file-manager")]
 pub mod file_manager;
+#[cfg(feature = "mo-folder")]
 pub mod folder;
+#[cfg(feature = "mo-guest-alias-manager")]
 pub mod guest_alias_manager;
+#[cfg(feature = "mo-guest-auth-manager")]
 pub mod guest_auth_manager;
+#[cfg(feature = "mo-guest-file-manager")]
 pub mod guest_file_manager;
+#[cfg(feature = "mo-guest-operations-manager")]
 pub mod guest_operations_manager;
+#[cfg(feature = "mo-guest-process-manager")]
 pub mod guest_process_manager;
+#[cfg(feature = "mo-guest-windows-registry-manager")]
 pub mod guest_windows_registry_manager;
+#[cfg(feature = "mo-history-collector")]
 pub mod history_collector;
+#[cfg(feature = "mo-host-access-manager")]
 pub mod host_access_manager;
+#[cfg(feature = "mo-host-active-directory-authentication")]
 pub mod host_active_directory_authentication;
+#[cfg(feature = "mo-host-assignable-hardware-manager")]
 pub mod host_assignable_hardware_manager;
+#[cfg(feature = "mo-host-authentication-manager")]
 pub mod host_authentication_manager;
+#[cfg(feature = "mo-host-authentication-store")]
 pub mod host_authentication_store;
+#[cfg(feature = "mo-host-auto-start-manager")]
 pub mod host_auto_start_manager;
+#[cfg(feature = "mo-host-boot-device-system")]
 pub mod host_boot_device_system;
+#[cfg(feature = "mo-host-cache-configuration-manager")]
 pub mod host_cache_configuration_manager;
+#[cfg(feature = "mo-host-certificate-manager")]
 pub mod host_certificate_manager;
+#[cfg(feature = "mo-host-cpu-scheduler-system")]
 pub mod host_cpu_scheduler_system;
+#[cfg(feature = "mo-host-datastore-browser")]
 pub mod host_datastore_browser;
+#[cfg(feature = "mo-host-datastore-system")]
 pub mod host_datastore_system;
+#[cfg(feature = "mo-host-date-time-system")]
 pub mod host_date_time_system;
+#[cfg(feature = "mo-host-diagnostic-system")]
 pub mod host_diagnostic_system;
+#[cfg(feature = "mo-host-directory-store")]
 pub mod host_directory_store;
+#[cfg(feature = "mo-host-esx-agent-host-manager")]
 pub mod host_esx_agent_host_manager;
+#[cfg(feature = "mo-host-firewall-system")]
 pub mod host_firewall_system;
+#[cfg(feature = "mo-host-firmware-system")]
 pub mod host_firmware_system;
+#[cfg(feature = "mo-host-graphics-manager")]
 pub mod host_graphics_manager;
+#[cfg(feature = "mo-host-health-status-system")]
 pub mod host_health_status_system;
+#[cfg(feature = "mo-host-image-config-manager")]
 pub mod host_image_config_manager;
+#[cfg(feature = "mo-host-kernel-module-system")]
 pub mod host_kernel_module_system;
+#[cfg(feature = "mo-host-local-account-manager")]
 pub mod host_local_account_manager;
+#[cfg(feature = "mo-host-local-authentication")]
 pub mod host_local_authentication;
+#[cfg(feature = "mo-host-memory-system")]
 pub mod host_memory_system;
+#[cfg(feature = "mo-host-network-system")]
 pub mod host_network_system;
+#[cfg(feature = "mo-host-nvdimm-system")]
 pub mod host_nvdimm_system;
+#[cfg(feature = "mo-host-patch-manager")]
 pub mod host_patch_manager;
+#[cfg(feature = "mo-host-pci-passthru-system")]
 pub mod host_pci_passthru_system;
+#[cfg(feature = "mo-host-power-system")]
 pub mod host_power_system;
+#[cfg(feature = "mo-host-profile")]
 pub mod host_profile;
+#[cfg(feature = "mo-host-profile-manager")]
 pub mod host_profile_manager;
+#[cfg(feature = "mo-host-service-system")]
 pub mod host_service_system;
+#[cfg(feature = "mo-host-snmp-system")]
 pub mod host_snmp_system;
+#[cfg(feature = "mo-host-specification-manager")]
 pub mod host_specification_manager;
+#[cfg(feature = "mo-host-storage-system")]
 pub mod host_storage_system;
+#[cfg(feature = "mo-host-system")]
 pub mod host_system;
+#[cfg(feature = "mo-host-v-flash-manager")]
 pub mod host_v_flash_manager;
+#[cfg(feature = "mo-host-v-motion-system")]
 pub mod host_v_motion_system;
+#[cfg(feature = "mo-host-v-storage-object-manager")]
 pub mod host_v_storage_object_manager;
+#[cfg(feature = "mo-host-virtual-nic-manager")]
 pub mod host_virtual_nic_manager;
+#[cfg(feature = "mo-host-vsan-internal-system")]
 pub mod host_vsan_internal_system;
+#[cfg(feature = "mo-host-vsan-system")]
 pub mod host_vsan_system;
+#[cfg(feature = "mo-http-nfc-lease")]
 pub mod http_nfc_lease;
+#[cfg(feature = "mo-inventory-view")]
 pub mod inventory_view;
+#[cfg(feature = "mo-io-filter-manager")]
 pub mod io_filter_manager;
+#[cfg(feature = "mo-ip-pool-manager")]
 pub mod ip_pool_manager;
+#[cfg(feature = "mo-iscsi-manager")]
 pub mod iscsi_manager;
+#[cfg(feature = "mo-license-manager")]
 pub mod license_manager;
+#[cfg(feature = "mo-list-view")]
 pub mod list_view;
+#[cfg(feature = "mo-localization-manager")]
 pub mod localization_manager;
+#[cfg(feature = "mo-managed-entity")]
 pub mod managed_entity;
+#[cfg(feature = "mo-managed-object-view")]
 pub mod managed_object_view;
+#[cfg(feature = "mo-network")]
 pub mod network;
+#[cfg(feature = "mo-opaque-network")]
 pub mod opaque_network;
+#[cfg(feature = "mo-option-manager")]
 pub mod option_manager;
+#[cfg(feature = "mo-overhead-memory-manager")]
 pub mod overhead_memory_manager;
+#[cfg(feature = "mo-ovf-manager")]
 pub mod ovf_manager;
+#[cfg(feature = "mo-performance-manager")]
 pub mod performance_manager;
+#[cfg(feature = "mo-profile")]
 pub mod profile;
+#[cfg(feature = "mo-profile-compliance-manager")]
 pub mod profile_compliance_manager;
+#[cfg(feature = "mo-profile-manager")]
 pub mod profile_manager;
+#[cfg(feature = "mo-property-collector")]
 pub mod property_collector;
+#[cfg(feature = "mo-property-filter")]
 pub mod property_filter;
+#[cfg(feature = "mo-resource-pool")]
 pub mod resource_pool;
+#[cfg(feature = "mo-scheduled-task")]
 pub mod scheduled_task;
+#[cfg(feature = "mo-scheduled-task-manager")]
 pub mod scheduled_task_manager;
+#[cfg(feature = "mo-search-index")]
 pub mod search_index;
 pub mod service_instance;
+#[cfg(feature = "mo-service-manager")]
 pub mod service_manager;
 pub mod session_manager;
+#[cfg(feature = "mo-simple-command")]
 pub mod simple_command;
+#[cfg(feature = "mo-site-info-manager")]
 pub mod site_info_manager;
+#[cfg(feature = "mo-storage-pod")]
 pub mod storage_pod;
+#[cfg(feature = "mo-storage-query-manager")]
 pub mod storage_query_manager;
+#[cfg(feature = "mo-storage-resource-manager")]
 pub mod storage_resource_manager;
+#[cfg(feature = "mo-task")]
 pub mod task;
+#[cfg(feature = "mo-task-history-collector")]
 pub mod task_history_collector;
+#[cfg(feature = "mo-task-manager")]
 pub mod task_manager;
+#[cfg(feature = "mo-tenant-tenant-manager")]
 pub mod tenant_tenant_manager;
+#[cfg(feature = "mo-user-directory")]
 pub mod user_directory;
+#[cfg(feature = "mo-v-storage-object-manager-base")]
 pub mod v_storage_object_manager_base;
+#[cfg(feature = "mo-vcenter-v-storage-object-manager")]
 pub mod vcenter_v_storage_object_manager;
+#[cfg(feature = "mo-view")]
 pub mod view;
+#[cfg(feature = "mo-view-manager")]
 pub mod view_manager;
+#[cfg(feature = "mo-virtual-app")]
 pub mod virtual_app;
+#[cfg(feature = "mo-virtual-disk-manager")]
 pub mod virtual_disk_manager;
+#[cfg(feature = "mo-virtual-machine")]
 pub mod virtual_machine;
+#[cfg(feature = "mo-virtual-machine-compatibility-checker")]
 pub mod virtual_machine_compatibility_checker;
+#[cfg(feature = "mo-virtual-machine-guest-customization-manager")]
 pub mod virtual_machine_guest_customization_manager;
+#[cfg(feature = "mo-virtual-machine-provisioning-checker")]
 pub mod virtual_machine_provisioning_checker;
+#[cfg(feature = "mo-virtual-machine-snapshot")]
 pub mod virtual_machine_snapshot;
+#[cfg(feature = "mo-vmware-distributed-virtual-switch")]
 pub mod vmware_distributed_virtual_switch;
+#[cfg(feature = "mo-vsan-upgrade-system")]
 pub mod vsan_upgrade_system;
+#[cfg(feature = "mo-cluster-profile")]
 pub mod cluster_profile;
+#[cfg(feature = "mo-cluster-profile-manager")]
 pub mod cluster_profile_manager;
+#[cfg(feature = "mo-crypto-manager-host")]
 pub mod crypto_manager_host;
+#[cfg(feature = "mo-crypto-manager-host-kms")]
 pub mod crypto_manager_host_kms;
+#[cfg(feature = "mo-health-update-manager")]
 pub mod health_update_manager;
+#[cfg(feature = "mo-license-assignment-manager")]
 pub mod license_assignment_manager;
+#[cfg(feature = "mo-resource-planning-manager")]
 pub mod resource_planning_manager;
 
+#[cfg(feature = "mo-alarm")]
 pub use alarm::*;
+#[cfg(feature = "mo-alarm-manager")]
 pub use alarm_manager::*;
+#[cfg(feature = "mo-authorization-manager")]
 pub use authorization_manager::*;
+#[cfg(feature = "mo-certificate-manager")]
 pub use certificate_manager::*;
+#[cfg(feature = "mo-cluster-compute-resource")]
 pub use cluster_compute_resource::*;
+#[cfg(feature = "mo-cluster-evc-manager")]
 pub use cluster_evc_manager::*;
+#[cfg(feature = "mo-compute-resource")]
 pub use compute_resource::*;
+#[cfg(feature = "mo-container-view")]
 pub use container_view::*;
+#[cfg(feature = "mo-crypto-manager")]
 pub use crypto_manager::*;
+#[cfg(feature = "mo-crypto-manager-kmip")]
 pub use crypto_manager_kmip::*;
+#[cfg(feature = "mo-custom-fields-manager")]
 pub use custom_fields_manager::*;
+#[cfg(feature = "mo-customization-spec-manager")]
 pub use customization_spec_manager::*;
+#[cfg(feature = "mo-datacenter")]
 pub use datacenter::*;
+#[cfg(feature = "mo-datastore")]
 pub use datastore::*;
+#[cfg(feature = "mo-datastore-namespace-manager")]
 pub use datastore_namespace_manager::*;
+#[cfg(feature = "mo-diagnostic-manager")]
 pub use diagnostic_manager::*;
+#[cfg(feature = "mo-distributed-virtual-portgroup")]
 pub use distributed_virtual_portgroup::*;
+#[cfg(feature = "mo-distributed-virtual-switch")]
 pub use distributed_virtual_switch::*;
+#[cfg(feature = "mo-distributed-virtual-switch-manager")]
 pub use distributed_virtual_switch_manager::*;
+#[cfg(feature = "mo-environment-browser")]
 pub use environment_browser::*;
+#[cfg(feature = "mo-event-history-collector")]
 pub use event_history_collector::*;
+#[cfg(feature = "mo-event-manager")]
 pub use event_manager::*;
+#[cfg(feature = "mo-extensible-managed-object")]
 pub use extensible_managed_object::*;
+#[cfg(feature = "mo-extension-manager")]
 pub use extension_manager::*;
+#[cfg(feature = "mo-failover-cluster-configurator")]
 pub use failover_cluster_configurator::*;
+#[cfg(feature = "mo-failover-cluster-manager")]
 pub use failover_cluster_manager::*;
+#[cfg(feature = "mo-file-manager")]
 pub use file_manager::*;
+#[cfg(feature = "mo-folder")]
 pub use folder::*;
+#[cfg(feature = "mo-guest-alias-manager")]
 pub use guest_alias_manager::*;
+#[cfg(feature = "mo-guest-auth-manager")]
 pub use guest_auth_manager::*;
+#[cfg(feature = "mo-guest-file-manager")]
 pub use guest_file_manager::*;
+#[cfg(feature = "mo-guest-operations-manager")]
 pub use guest_operations_manager::*;
+#[cfg(feature = "mo-guest-process-manager")]
 pub use guest_process_manager::*;
+#[cfg(feature = "mo-guest-windows-registry-manager")]
 pub use guest_windows_registry_manager::*;
+#[cfg(feature = "mo-history-collector")]
 pub use history_collector::*;
+#[cfg(feature = "mo-host-access-manager")]
 pub use host_access_manager::*;
+#[cfg(feature = "mo-host-active-directory-authentication")]
 pub use host_active_directory_authentication::*;
+#[cfg(feature = "mo-host-assignable-hardware-manager")]
 pub use host_assignable_hardware_manager::*;
+#[cfg(feature = "mo-host-authentication-manager")]
 pub use host_authentication_manager::*;
+#[cfg(feature = "mo-host-authentication-store")]
 pub use host_authentication_store::*;
+#[cfg(feature = "mo-host-auto-start-manager")]
 pub use host_auto_start_manager::*;
+#[cfg(feature = "mo-host-boot-device-system")]
 pub use host_boot_device_system::*;
+#[cfg(feature = "mo-host-cache-configuration-manager")]
 pub use host_cache_configuration_manager::*;
+#[cfg(feature = "mo-host-certificate-manager")]
 pub use host_certificate_manager::*;
+#[cfg(feature = "mo-host-cpu-scheduler-system")]
 pub use host_cpu_scheduler_system::*;
+#[cfg(feature = "mo-host-datastore-browser")]
 pub use host_datastore_browser::*;
+#[cfg(feature = "mo-host-datastore-system")]
 pub use host_datastore_system::*;
+#[cfg(feature = "mo-host-date-time-system")]
 pub use host_date_time_system::*;
+#[cfg(feature = "mo-host-diagnostic-system")]
 pub use host_diagnostic_system::*;
+#[cfg(feature = "mo-host-directory-store")]
 pub use host_directory_store::*;
+#[cfg(feature = "mo-host-esx-agent-host-manager")]
 pub use host_esx_agent_host_manager::*;
+#[cfg(feature = "mo-host-firewall-system")]
 pub use host_firewall_system::*;
+#[cfg(feature = "mo-host-firmware-system")]
 pub use host_firmware_system::*;
+#[cfg(feature = "mo-host-graphics-manager")]
 pub use host_graphics_manager::*;
+#[cfg(feature = "mo-host-health-status-system")]
 pub use host_health_status_system::*;
+#[cfg(feature = "mo-host-image-config-manager")]
 pub use host_image_config_manager::*;
+#[cfg(feature = "mo-host-kernel-module-system")]
 pub use host_kernel_module_system::*;
+#[cfg(feature = "mo-host-local-account-manager")]
 pub use host_local_account_manager::*;
+#[cfg(feature = "mo-host-local-authentication")]
 pub use host_local_authentication::*;
+#[cfg(feature = "mo-host-memory-system")]
 pub use host_memory_system::*;
+#[cfg(feature = "mo-host-network-system")]
 pub use host_network_system::*;
+#[cfg(feature = "mo-host-nvdimm-system")]
 pub use host_nvdimm_system::*;
+#[cfg(feature = "mo-host-patch-manager")]
 pub use host_patch_manager::*;
+#[cfg(feature = "mo-host-pci-passthru-system")]
 pub use host_pci_passthru_system::*;
+#[cfg(feature = "mo-host-power-system")]
 pub use host_power_system::*;
+#[cfg(feature = "mo-host-profile")]
 pub use host_profile::*;
+#[cfg(feature = "mo-host-profile-manager")]
 pub use host_profile_manager::*;
+#[cfg(feature = "mo-host-service-system")]
 pub use host_service_system::*;
+#[cfg(feature = "mo-host-snmp-system")]
 pub use host_snmp_system::*;
+#[cfg(feature = "mo-host-specification-manager")]
 pub use host_specification_manager::*;
+#[cfg(feature = "mo-host-storage-system")]
 pub use host_storage_system::*;
+#[cfg(feature = "mo-host-system")]
 pub use host_system::*;
+#[cfg(feature = "mo-host-v-flash-manager")]
 pub use host_v_flash_manager::*;
+#[cfg(feature = "mo-host-v-motion-system")]
 pub use host_v_motion_system::*;
+#[cfg(feature = "mo-host-v-storage-object-manager")]
 pub use host_v_storage_object_manager::*;
+#[cfg(feature = "mo-host-virtual-nic-manager")]
 pub use host_virtual_nic_manager::*;
+#[cfg(feature = "mo-host-vsan-internal-system")]
 pub use host_vsan_internal_system::*;
+#[cfg(feature = "mo-host-vsan-system")]
 pub use host_vsan_system::*;
+#[cfg(feature = "mo-http-nfc-lease")]
 pub use http_nfc_lease::*;
+#[cfg(feature = "mo-inventory-view")]
 pub use inventory_view::*;
+#[cfg(feature = "mo-io-filter-manager")]
 pub use io_filter_manager::*;
+#[cfg(feature = "mo-ip-pool-manager")]
 pub use ip_pool_manager::*;
+#[cfg(feature = "mo-iscsi-manager")]
 pub use iscsi_manager::*;
+#[cfg(feature = "mo-license-manager")]
 pub use license_manager::*;
+#[cfg(feature = "mo-list-view")]
 pub use list_view::*;
+#[cfg(feature = "mo-localization-manager")]
 pub use localization_manager::*;
+#[cfg(feature = "mo-managed-entity")]
 pub use managed_entity::*;
+#[cfg(feature = "mo-managed-object-view")]
 pub use managed_object_view::*;
+#[cfg(feature = "mo-network")]
 pub use network::*;
+#[cfg(feature = "mo-opaque-network")]
 pub use opaque_network::*;
+#[cfg(feature = "mo-option-manager")]
 pub use option_manager::*;
+#[cfg(feature = "mo-overhead-memory-manager")]
 pub use overhead_memory_manager::*;
+#[cfg(feature = "mo-ovf-manager")]
 pub use ovf_manager::*;
+#[cfg(feature = "mo-performance-manager")]
 pub use performance_manager::*;
+#[cfg(feature = "mo-profile")]
 pub use profile::*;
+#[cfg(feature = "mo-profile-compliance-manager")]
 pub use profile_compliance_manager::*;
+#[cfg(feature = "mo-profile-manager")]
 pub use profile_manager::*;
+#[cfg(feature = "mo-property-collector")]
 pub use property_collector::*;
+#[cfg(feature = "mo-property-filter")]
 pub use property_filter::*;
+#[cfg(feature = "mo-resource-pool")]
 pub use resource_pool::*;
+#[cfg(feature = "mo-scheduled-task")]
 pub use scheduled_task::*;
+#[cfg(feature = "mo-scheduled-task-manager")]
 pub use scheduled_task_manager::*;
+#[cfg(feature = "mo-search-index")]
 pub use search_index::*;
 pub use service_instance::*;
+#[cfg(feature = "mo-service-manager")]
 pub use service_manager::*;
 pub use session_manager::*;
+#[cfg(feature = "mo-simple-command")]
 pub use simple_command::*;
+#[cfg(feature = "mo-site-info-manager")]
 pub use site_info_manager::*;
+#[cfg(feature = "mo-storage-pod")]
 pub use storage_pod::*;
+#[cfg(feature = "mo-storage-query-manager")]
 pub use storage_query_manager::*;
+#[cfg(feature = "mo-storage-resource-manager")]
 pub use storage_resource_manager::*;
+#[cfg(feature = "mo-task")]
 pub use task::*;
+#[cfg(feature = "mo-task-history-collector")]
 pub use task_history_collector::*;
+#[cfg(feature = "mo-task-manager")]
 pub use task_manager::*;
+#[cfg(feature = "mo-tenant-tenant-manager")]
 pub use tenant_tenant_manager::*;
+#[cfg(feature = "mo-user-directory")]
 pub use user_directory::*;
+#[cfg(feature = "mo-v-storage-object-manager-base")]
 pub use v_storage_object_manager_base::*;
+#[cfg(feature = "mo-vcenter-v-storage-object-manager")]
 pub use vcenter_v_storage_object_manager::*;
+#[cfg(feature = "mo-view")]
 pub use view::*;
+#[cfg(feature = "mo-view-manager")]
 pub use view_manager::*;
+#[cfg(feature = "mo-virtual-app")]
 pub use virtual_app::*;
+#[cfg(feature = "mo-virtual-disk-manager")]
 pub use virtual_disk_manager::*;
+#[cfg(feature = "mo-virtual-machine")]
 pub use virtual_machine::*;
+#[cfg(feature = "mo-virtual-machine-compatibility-checker")]
 pub use virtual_machine_compatibility_checker::*;
+#[cfg(feature = "mo-virtual-machine-guest-customization-manager")]
 pub use virtual_machine_guest_customization_manager::*;
+#[cfg(feature = "mo-virtual-machine-provisioning-checker")]
 pub use virtual_machine_provisioning_checker::*;
+#[cfg(feature = "mo-virtual-machine-snapshot")]
 pub use virtual_machine_snapshot::*;
+#[cfg(feature = "mo-vmware-distributed-virtual-switch")]
 pub use vmware_distributed_virtual_switch::*;
+#[cfg(feature = "mo-vsan-upgrade-system")]
 pub use vsan_upgrade_system::*;
+#[cfg(feature = "mo-cluster-profile")]
 pub use cluster_profile::*;
+#[cfg(feature = "mo-cluster-profile-manager")]
 pub use cluster_profile_manager::*;
+#[cfg(feature = "mo-crypto-manager-host")]
 pub use crypto_manager_host::*;
+#[cfg(feature = "mo-crypto-manager-host-kms")]
 pub use crypto_manager_host_kms::*;
+#[cfg(feature = "mo-health-update-manager")]
 pub use health_update_manager::*;
+#[cfg(feature = "mo-license-assignment-manager")]
 pub use license_assignment_manager::*;
+#[cfg(feature = "mo-resource-planning-manager")]
 pub use resource_planning_manager::*;
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    #[cfg(feature = "mo-alarm")]
+    pub use super::alarm::blocking::*;
+    #[cfg(feature = "mo-alarm-manager")]
+    pub use super::alarm_manager::blocking::*;
+    #[cfg(feature = "mo-authorization-manager")]
+    pub use super::authorization_manager::blocking::*;
+    #[cfg(feature = "mo-certificate-manager")]
+    pub use super::certificate_manager::blocking::*;
+    #[cfg(feature = "mo-cluster-compute-resource")]
+    pub use super::cluster_compute_resource::blocking::*;
+    #[cfg(feature = "mo-cluster-evc-manager")]
+    pub use super::cluster_evc_manager::blocking::*;
+    #[cfg(feature = "mo-compute-resource")]
+    pub use super::compute_resource::blocking::*;
+    #[cfg(feature = "mo-container-view")]
+    pub use super::container_view::blocking::*;
+    #[cfg(feature = "mo-crypto-manager")]
+    pub use super::crypto_manager::blocking::*;
+    #[cfg(feature = "mo-crypto-manager-kmip")]
+    pub use super::crypto_manager_kmip::blocking::*;
+    #[cfg(feature = "mo-custom-fields-manager")]
+    pub use super::custom_fields_manager::blocking::*;
+    #[cfg(feature = "mo-customization-spec-manager")]
+    pub use super::customization_spec_manager::blocking::*;
+    #[cfg(feature = "mo-datacenter")]
+    pub use super::datacenter::blocking::*;
+    #[cfg(feature = "mo-datastore")]
+    pub use super::datastore::blocking::*;
+    #[cfg(feature = "mo-datastore-namespace-manager")]
+    pub use super::datastore_namespace_manager::blocking::*;
+    #[cfg(feature = "mo-diagnostic-manager")]
+    pub use super::diagnostic_manager::blocking::*;
+    #[cfg(feature = "mo-distributed-virtual-portgroup")]
+    pub use super::distributed_virtual_portgroup::blocking::*;
+    #[cfg(feature = "mo-distributed-virtual-switch")]
+    pub use super::distributed_virtual_switch::blocking::*;
+    #[cfg(feature = "mo-distributed-virtual-switch-manager")]
+    pub use super::distributed_virtual_switch_manager::blocking::*;
+    #[cfg(feature = "mo-environment-browser")]
+    pub use super::environment_browser::blocking::*;
+    #[cfg(feature = "mo-event-history-collector")]
+    pub use super::event_history_collector::blocking::*;
+    #[cfg(feature = "mo-event-manager")]
+    pub use super::event_manager::blocking::*;
+    #[cfg(feature = "mo-extensible-managed-object")]
+    pub use super::extensible_managed_object::blocking::*;
+    #[cfg(feature = "mo-extension-manager")]
+    pub use super::extension_manager::blocking::*;
+    #[cfg(feature = "mo-failover-cluster-configurator")]
+    pub use super::failover_cluster_configurator::blocking::*;
+    #[cfg(feature = "mo-failover-cluster-manager")]
+    pub use super::failover_cluster_manager::blocking::*;
+    #[cfg(feature = "mo-file-manager")]
+    pub use super::file_manager::blocking::*;
+    #[cfg(feature = "mo-folder")]
+    pub use super::folder::blocking::*;
+    #[cfg(feature = "mo-guest-alias-manager")]
+    pub use super::guest_alias_manager::blocking::*;
+    #[cfg(feature = "mo-guest-auth-manager")]
+    pub use super::guest_auth_manager::blocking::*;
+    #[cfg(feature = "mo-guest-file-manager")]
+    pub use super::guest_file_manager::blocking::*;
+    #[cfg(feature = "mo-guest-operations-manager")]
+    pub use super::guest_operations_manager::blocking::*;
+    #[cfg(feature = "mo-guest-process-manager")]
+    pub use super::guest_process_manager::blocking::*;
+    #[cfg(feature = "mo-guest-windows-registry-manager")]
+    pub use super::guest_windows_registry_manager::blocking::*;
+    #[cfg(feature = "mo-history-collector")]
+    pub use super::history_collector::blocking::*;
+    #[cfg(feature = "mo-host-access-manager")]
+    pub use super::host_access_manager::blocking::*;
+    #[cfg(feature = "mo-host-active-directory-authentication")]
+    pub use super::host_active_directory_authentication::blocking::*;
+    #[cfg(feature = "mo-host-assignable-hardware-manager")]
+    pub use super::host_assignable_hardware_manager::blocking::*;
+    #[cfg(feature = "mo-host-authentication-manager")]
+    pub use super::host_authentication_manager::blocking::*;
+    #[cfg(feature = "mo-host-authentication-store")]
+    pub use super::host_authentication_store::blocking::*;
+    #[cfg(feature = "mo-host-auto-start-manager")]
+    pub use super::host_auto_start_manager::blocking::*;
+    #[cfg(feature = "mo-host-boot-device-system")]
+    pub use super::host_boot_device_system::blocking::*;
+    #[cfg(feature = "mo-host-cache-configuration-manager")]
+    pub use super::host_cache_configuration_manager::blocking::*;
+    #[cfg(feature = "mo-host-certificate-manager")]
+    pub use super::host_certificate_manager::blocking::*;
+    #[cfg(feature = "mo-host-cpu-scheduler-system")]
+    pub use super::host_cpu_scheduler_system::blocking::*;
+    #[cfg(feature = "mo-host-datastore-browser")]
+    pub use super::host_datastore_browser::blocking::*;
+    #[cfg(feature = "mo-host-datastore-system")]
+    pub use super::host_datastore_system::blocking::*;
+    #[cfg(feature = "mo-host-date-time-system")]
+    pub use super::host_date_time_system::blocking::*;
+    #[cfg(feature = "mo-host-diagnostic-system")]
+    pub use super::host_diagnostic_system::blocking::*;
+    #[cfg(feature = "mo-host-directory-store")]
+    pub use super::host_directory_store::blocking::*;
+    #[cfg(feature = "mo-host-esx-agent-host-manager")]
+    pub use super::host_esx_agent_host_manager::blocking::*;
+    #[cfg(feature = "mo-host-firewall-system")]
+    pub use super::host_firewall_system::blocking::*;
+    #[cfg(feature = "mo-host-firmware-system")]
+    pub use super::host_firmware_system::blocking::*;
+    #[cfg(feature = "mo-host-graphics-manager")]
+    pub use super::host_graphics_manager::blocking::*;
+    #[cfg(feature = "mo-host-health-status-system")]
+    pub use super::host_health_status_system::blocking::*;
+    #[cfg(feature = "mo-host-image-config-manager")]
+    pub use super::host_image_config_manager::blocking::*;
+    #[cfg(feature = "mo-host-kernel-module-system")]
+    pub use super::host_kernel_module_system::blocking::*;
+    #[cfg(feature = "mo-host-local-account-manager")]
+    pub use super::host_local_account_manager::blocking::*;
+    #[cfg(feature = "mo-host-local-authentication")]
+    pub use super::host_local_authentication::blocking::*;
+    #[cfg(feature = "mo-host-memory-system")]
+    pub use super::host_memory_system::blocking::*;
+    #[cfg(feature = "mo-host-network-system")]
+    pub use super::host_network_system::blocking::*;
+    #[cfg(feature = "mo-host-nvdimm-system")]
+    pub use super::host_nvdimm_system::blocking::*;
+    #[cfg(feature = "mo-host-patch-manager")]
+    pub use super::host_patch_manager::blocking::*;
+    #[cfg(feature = "mo-host-pci-passthru-system")]
+    pub use super::host_pci_passthru_system::blocking::*;
+    #[cfg(feature = "mo-host-power-system")]
+    pub use super::host_power_system::blocking::*;
+    #[cfg(feature = "mo-host-profile")]
+    pub use super::host_profile::blocking::*;
+    #[cfg(feature = "mo-host-profile-manager")]
+    pub use super::host_profile_manager::blocking::*;
+    #[cfg(feature = "mo-host-service-system")]
+    pub use super::host_service_system::blocking::*;
+    #[cfg(feature = "mo-host-snmp-system")]
+    pub use super::host_snmp_system::blocking::*;
+    #[cfg(feature = "mo-host-specification-manager")]
+    pub use super::host_specification_manager::blocking::*;
+    #[cfg(feature = "mo-host-storage-system")]
+    pub use super::host_storage_system::blocking::*;
+    #[cfg(feature = "mo-host-system")]
+    pub use super::host_system::blocking::*;
+    #[cfg(feature = "mo-host-v-flash-manager")]
+    pub use super::host_v_flash_manager::blocking::*;
+    #[cfg(feature = "mo-host-v-motion-system")]
+    pub use super::host_v_motion_system::blocking::*;
+    #[cfg(feature = "mo-host-v-storage-object-manager")]
+    pub use super::host_v_storage_object_manager::blocking::*;
+    #[cfg(feature = "mo-host-virtual-nic-manager")]
+    pub use super::host_virtual_nic_manager::blocking::*;
+    #[cfg(feature = "mo-host-vsan-internal-system")]
+    pub use super::host_vsan_internal_system::blocking::*;
+    #[cfg(feature = "mo-host-vsan-system")]
+    pub use super::host_vsan_system::blocking::*;
+    #[cfg(feature = "mo-http-nfc-lease")]
+    pub use super::http_nfc_lease::blocking::*;
+    #[cfg(feature = "mo-inventory-view")]
+    pub use super::inventory_view::blocking::*;
+    #[cfg(feature = "mo-io-filter-manager")]
+    pub use super::io_filter_manager::blocking::*;
+    #[cfg(feature = "mo-ip-pool-manager")]
+    pub use super::ip_pool_manager::blocking::*;
+    #[cfg(feature = "mo-iscsi-manager")]
+    pub use super::iscsi_manager::blocking::*;
+    #[cfg(feature = "mo-license-manager")]
+    pub use super::license_manager::blocking::*;
+    #[cfg(feature = "mo-list-view")]
+    pub use super::list_view::blocking::*;
+    #[cfg(feature = "mo-localization-manager")]
+    pub use super::localization_manager::blocking::*;
+    #[cfg(feature = "mo-managed-entity")]
+    pub use super::managed_entity::blocking::*;
+    #[cfg(feature = "mo-managed-object-view")]
+    pub use super::managed_object_view::blocking::*;
+    #[cfg(feature = "mo-network")]
+    pub use super::network::blocking::*;
+    #[cfg(feature = "mo-opaque-network")]
+    pub use super::opaque_network::blocking::*;
+    #[cfg(feature = "mo-option-manager")]
+    pub use super::option_manager::blocking::*;
+    #[cfg(feature = "mo-overhead-memory-manager")]
+    pub use super::overhead_memory_manager::blocking::*;
+    #[cfg(feature = "mo-ovf-manager")]
+    pub use super::ovf_manager::blocking::*;
+    #[cfg(feature = "mo-performance-manager")]
+    pub use super::performance_manager::blocking::*;
+    #[cfg(feature = "mo-profile")]
+    pub use super::profile::blocking::*;
+    #[cfg(feature = "mo-profile-compliance-manager")]
+    pub use super::profile_compliance_manager::blocking::*;
+    #[cfg(feature = "mo-profile-manager")]
+    pub use super::profile_manager::blocking::*;
+    #[cfg(feature = "mo-property-collector")]
+    pub use super::property_collector::blocking::*;
+    #[cfg(feature = "mo-property-filter")]
+    pub use super::property_filter::blocking::*;
+    #[cfg(feature = "mo-resource-pool")]
+    pub use super::resource_pool::blocking::*;
+    #[cfg(feature = "mo-scheduled-task")]
+    pub use super::scheduled_task::blocking::*;
+    #[cfg(feature = "mo-scheduled-task-manager")]
+    pub use super::scheduled_task_manager::blocking::*;
+    #[cfg(feature = "mo-search-index")]
+    pub use super::search_index::blocking::*;
+    pub use super::service_instance::blocking::*;
+    #[cfg(feature = "mo-service-manager")]
+    pub use super::service_manager::blocking::*;
+    pub use super::session_manager::blocking::*;
+    #[cfg(feature = "mo-simple-command")]
+    pub use super::simple_command::blocking::*;
+    #[cfg(feature = "mo-site-info-manager")]
+    pub use super::site_info_manager::blocking::*;
+    #[cfg(feature = "mo-storage-pod")]
+    pub use super::storage_pod::blocking::*;
+    #[cfg(feature = "mo-storage-query-manager")]
+    pub use super::storage_query_manager::blocking::*;
+    #[cfg(feature = "mo-storage-resource-manager")]
+    pub use super::storage_resource_manager::blocking::*;
+    #[cfg(feature = "mo-task")]
+    pub use super::task::blocking::*;
+    #[cfg(feature = "mo-task-history-collector")]
+    pub use super::task_history_collector::blocking::*;
+    #[cfg(feature = "mo-task-manager")]
+    pub use super::task_manager::blocking::*;
+    #[cfg(feature = "mo-tenant-tenant-manager")]
+    pub use super::tenant_tenant_manager::blocking::*;
+    #[cfg(feature = "mo-user-directory")]
+    pub use super::user_directory::blocking::*;
+    #[cfg(feature = "mo-v-storage-object-manager-base")]
+    pub use super::v_storage_object_manager_base::blocking::*;
+    #[cfg(feature = "mo-vcenter-v-storage-object-manager")]
+    pub use super::vcenter_v_storage_object_manager::blocking::*;
+    #[cfg(feature = "mo-view")]
+    pub use super::view::blocking::*;
+    #[cfg(feature = "mo-view-manager")]
+    pub use super::view_manager::blocking::*;
+    #[cfg(feature = "mo-virtual-app")]
+    pub use super::virtual_app::blocking::*;
+    #[cfg(feature = "mo-virtual-disk-manager")]
+    pub use super::virtual_disk_manager::blocking::*;
+    #[cfg(feature = "mo-virtual-machine")]
+    pub use super::virtual_machine::blocking::*;
+    #[cfg(feature = "mo-virtual-machine-compatibility-checker")]
+    pub use super::virtual_machine_compatibility_checker::blocking::*;
+    #[cfg(feature = "mo-virtual-machine-guest-customization-manager")]
+    pub use super::virtual_machine_guest_customization_manager::blocking::*;
+    #[cfg(feature = "mo-virtual-machine-provisioning-checker")]
+    pub use super::virtual_machine_provisioning_checker::blocking::*;
+    #[cfg(feature = "mo-virtual-machine-snapshot")]
+    pub use super::virtual_machine_snapshot::blocking::*;
+    #[cfg(feature = "mo-vmware-distributed-virtual-switch")]
+    pub use super::vmware_distributed_virtual_switch::blocking::*;
+    #[cfg(feature = "mo-vsan-upgrade-system")]
+    pub use super::vsan_upgrade_system::blocking::*;
+    #[cfg(feature = "mo-cluster-profile")]
+    pub use super::cluster_profile::blocking::*;
+    #[cfg(feature = "mo-cluster-profile-manager")]
+    pub use super::cluster_profile_manager::blocking::*;
+    #[cfg(feature = "mo-crypto-manager-host")]
+    pub use super::crypto_manager_host::blocking::*;
+    #[cfg(feature = "mo-crypto-manager-host-kms")]
+    pub use super::crypto_manager_host_kms::blocking::*;
+    #[cfg(feature = "mo-health-update-manager")]
+    pub use super::health_update_manager::blocking::*;
+    #[cfg(feature = "mo-license-assignment-manager")]
+    pub use super::license_assignment_manager::blocking::*;
+    #[cfg(feature = "mo-resource-planning-manager")]
+    pub use super::resource_planning_manager::blocking::*;
+}
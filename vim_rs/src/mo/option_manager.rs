@@ -117,3 +117,39 @@ struct UpdateOptionsRequestType<'a> {
     #[serde(rename = "changedValue")]
     changed_value: &'a [Box<dyn crate::types::traits::OptionValueTrait>],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::OptionDef;
+    /// Blocking version of [`super::OptionManager`].
+    pub struct OptionManager {
+        inner: super::OptionManager,
+        client: Arc<Client>,
+    }
+    impl OptionManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::OptionManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::OptionManager::query_options`].
+        pub fn query_options(&self, name: Option<&str>) -> Result<Option<Vec<Box<dyn crate::types::traits::OptionValueTrait>>>> {
+            self.client.block_on(self.inner.query_options(name))
+        }
+        /// Blocking version of [`super::OptionManager::update_options`].
+        pub fn update_options(&self, changed_value: &[Box<dyn crate::types::traits::OptionValueTrait>]) -> Result<()> {
+            self.client.block_on(self.inner.update_options(changed_value))
+        }
+        /// Blocking version of [`super::OptionManager::setting`].
+        pub fn setting(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::OptionValueTrait>>>> {
+            self.client.block_on(self.inner.setting())
+        }
+        /// Blocking version of [`super::OptionManager::supported_option`].
+        pub fn supported_option(&self) -> Result<Option<Vec<OptionDef>>> {
+            self.client.block_on(self.inner.supported_option())
+        }
+    }
+}
@@ -58,3 +58,27 @@ struct LookupVmOverheadMemoryRequestType<'a> {
     vm: &'a ManagedObjectReference,
     host: &'a ManagedObjectReference,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::OverheadMemoryManager`].
+    pub struct OverheadMemoryManager {
+        inner: super::OverheadMemoryManager,
+        client: Arc<Client>,
+    }
+    impl OverheadMemoryManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::OverheadMemoryManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::OverheadMemoryManager::lookup_vm_overhead_memory`].
+        pub fn lookup_vm_overhead_memory(&self, vm: &ManagedObjectReference, host: &ManagedObjectReference) -> Result<i64> {
+            self.client.block_on(self.inner.lookup_vm_overhead_memory(vm, host))
+        }
+    }
+}
@@ -334,3 +334,56 @@ struct ValidateHostRequestType<'a> {
     host: &'a ManagedObjectReference,
     vhp: &'a OvfValidateHostParams,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::OvfCreateDescriptorParams;
+    use crate::types::structs::OvfCreateDescriptorResult;
+    use crate::types::structs::OvfCreateImportSpecParams;
+    use crate::types::structs::OvfCreateImportSpecResult;
+    use crate::types::structs::OvfOptionInfo;
+    use crate::types::structs::OvfParseDescriptorParams;
+    use crate::types::structs::OvfParseDescriptorResult;
+    use crate::types::structs::OvfValidateHostParams;
+    use crate::types::structs::OvfValidateHostResult;
+    /// Blocking version of [`super::OvfManager`].
+    pub struct OvfManager {
+        inner: super::OvfManager,
+        client: Arc<Client>,
+    }
+    impl OvfManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::OvfManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::OvfManager::create_descriptor`].
+        pub fn create_descriptor(&self, obj: &ManagedObjectReference, cdp: &OvfCreateDescriptorParams) -> Result<OvfCreateDescriptorResult> {
+            self.client.block_on(self.inner.create_descriptor(obj, cdp))
+        }
+        /// Blocking version of [`super::OvfManager::create_import_spec`].
+        pub fn create_import_spec(&self, ovf_descriptor: &str, resource_pool: &ManagedObjectReference, datastore: &ManagedObjectReference, cisp: &OvfCreateImportSpecParams) -> Result<OvfCreateImportSpecResult> {
+            self.client.block_on(self.inner.create_import_spec(ovf_descriptor, resource_pool, datastore, cisp))
+        }
+        /// Blocking version of [`super::OvfManager::parse_descriptor`].
+        pub fn parse_descriptor(&self, ovf_descriptor: &str, pdp: &OvfParseDescriptorParams) -> Result<OvfParseDescriptorResult> {
+            self.client.block_on(self.inner.parse_descriptor(ovf_descriptor, pdp))
+        }
+        /// Blocking version of [`super::OvfManager::validate_host`].
+        pub fn validate_host(&self, ovf_descriptor: &str, host: &ManagedObjectReference, vhp: &OvfValidateHostParams) -> Result<OvfValidateHostResult> {
+            self.client.block_on(self.inner.validate_host(ovf_descriptor, host, vhp))
+        }
+        /// Blocking version of [`super::OvfManager::ovf_export_option`].
+        pub fn ovf_export_option(&self) -> Result<Option<Vec<OvfOptionInfo>>> {
+            self.client.block_on(self.inner.ovf_export_option())
+        }
+        /// Blocking version of [`super::OvfManager::ovf_import_option`].
+        pub fn ovf_import_option(&self) -> Result<Option<Vec<OvfOptionInfo>>> {
+            self.client.block_on(self.inner.ovf_import_option())
+        }
+    }
+}
@@ -621,3 +621,87 @@ struct UpdateCounterLevelMappingRequestType<'a> {
 struct UpdatePerfIntervalRequestType<'a> {
     interval: &'a PerfInterval,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::PerfCompositeMetric;
+    use crate::types::structs::PerfCounterInfo;
+    use crate::types::structs::PerfInterval;
+    use crate::types::structs::PerfMetricId;
+    use crate::types::structs::PerfProviderSummary;
+    use crate::types::structs::PerfQuerySpec;
+    use crate::types::structs::PerformanceDescription;
+    use crate::types::structs::PerformanceManagerCounterLevelMapping;
+    /// Blocking version of [`super::PerformanceManager`].
+    pub struct PerformanceManager {
+        inner: super::PerformanceManager,
+        client: Arc<Client>,
+    }
+    impl PerformanceManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::PerformanceManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::PerformanceManager::create_perf_interval`].
+        pub fn create_perf_interval(&self, interval_id: &PerfInterval) -> Result<()> {
+            self.client.block_on(self.inner.create_perf_interval(interval_id))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_available_perf_metric`].
+        pub fn query_available_perf_metric(&self, entity: &ManagedObjectReference, begin_time: Option<&str>, end_time: Option<&str>, interval_id: Option<i32>) -> Result<Option<Vec<PerfMetricId>>> {
+            self.client.block_on(self.inner.query_available_perf_metric(entity, begin_time, end_time, interval_id))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_perf_composite`].
+        pub fn query_perf_composite(&self, query_spec: &PerfQuerySpec) -> Result<PerfCompositeMetric> {
+            self.client.block_on(self.inner.query_perf_composite(query_spec))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_perf_counter`].
+        pub fn query_perf_counter(&self, counter_id: &[i32]) -> Result<Option<Vec<PerfCounterInfo>>> {
+            self.client.block_on(self.inner.query_perf_counter(counter_id))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_perf_counter_by_level`].
+        pub fn query_perf_counter_by_level(&self, level: i32) -> Result<Vec<PerfCounterInfo>> {
+            self.client.block_on(self.inner.query_perf_counter_by_level(level))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_perf_provider_summary`].
+        pub fn query_perf_provider_summary(&self, entity: &ManagedObjectReference) -> Result<PerfProviderSummary> {
+            self.client.block_on(self.inner.query_perf_provider_summary(entity))
+        }
+        /// Blocking version of [`super::PerformanceManager::query_perf`].
+        pub fn query_perf(&self, query_spec: &[PerfQuerySpec]) -> Result<Option<Vec<Box<dyn crate::types::traits::PerfEntityMetricBaseTrait>>>> {
+            self.client.block_on(self.inner.query_perf(query_spec))
+        }
+        /// Blocking version of [`super::PerformanceManager::remove_perf_interval`].
+        pub fn remove_perf_interval(&self, sample_period: i32) -> Result<()> {
+            self.client.block_on(self.inner.remove_perf_interval(sample_period))
+        }
+        /// Blocking version of [`super::PerformanceManager::reset_counter_level_mapping`].
+        pub fn reset_counter_level_mapping(&self, counters: &[i32]) -> Result<()> {
+            self.client.block_on(self.inner.reset_counter_level_mapping(counters))
+        }
+        /// Blocking version of [`super::PerformanceManager::update_counter_level_mapping`].
+        pub fn update_counter_level_mapping(&self, counter_level_map: &[PerformanceManagerCounterLevelMapping]) -> Result<()> {
+            self.client.block_on(self.inner.update_counter_level_mapping(counter_level_map))
+        }
+        /// Blocking version of [`super::PerformanceManager::update_perf_interval`].
+        pub fn update_perf_interval(&self, interval: &PerfInterval) -> Result<()> {
+            self.client.block_on(self.inner.update_perf_interval(interval))
+        }
+        /// Blocking version of [`super::PerformanceManager::description`].
+        pub fn description(&self) -> Result<PerformanceDescription> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::PerformanceManager::historical_interval`].
+        pub fn historical_interval(&self) -> Result<Option<Vec<PerfInterval>>> {
+            self.client.block_on(self.inner.historical_interval())
+        }
+        /// Blocking version of [`super::PerformanceManager::perf_counter`].
+        pub fn perf_counter(&self) -> Result<Option<Vec<PerfCounterInfo>>> {
+            self.client.block_on(self.inner.perf_counter())
+        }
+    }
+}
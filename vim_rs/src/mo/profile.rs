@@ -195,3 +195,76 @@ struct DissociateProfileRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     entity: Option<&'a [ManagedObjectReference]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfileDescription;
+    /// Blocking version of [`super::Profile`].
+    pub struct Profile {
+        inner: super::Profile,
+        client: Arc<Client>,
+    }
+    impl Profile {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::Profile::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::Profile::associate_profile`].
+        pub fn associate_profile(&self, entity: &[ManagedObjectReference]) -> Result<()> {
+            self.client.block_on(self.inner.associate_profile(entity))
+        }
+        /// Blocking version of [`super::Profile::check_profile_compliance_task`].
+        pub fn check_profile_compliance_task(&self, entity: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_profile_compliance_task(entity))
+        }
+        /// Blocking version of [`super::Profile::destroy_profile`].
+        pub fn destroy_profile(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_profile())
+        }
+        /// Blocking version of [`super::Profile::dissociate_profile`].
+        pub fn dissociate_profile(&self, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.dissociate_profile(entity))
+        }
+        /// Blocking version of [`super::Profile::export_profile`].
+        pub fn export_profile(&self) -> Result<String> {
+            self.client.block_on(self.inner.export_profile())
+        }
+        /// Blocking version of [`super::Profile::retrieve_description`].
+        pub fn retrieve_description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.retrieve_description())
+        }
+        /// Blocking version of [`super::Profile::compliance_status`].
+        pub fn compliance_status(&self) -> Result<String> {
+            self.client.block_on(self.inner.compliance_status())
+        }
+        /// Blocking version of [`super::Profile::config`].
+        pub fn config(&self) -> Result<Box<dyn crate::types::traits::ProfileConfigInfoTrait>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::Profile::created_time`].
+        pub fn created_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.created_time())
+        }
+        /// Blocking version of [`super::Profile::description`].
+        pub fn description(&self) -> Result<Option<ProfileDescription>> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::Profile::entity`].
+        pub fn entity(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.entity())
+        }
+        /// Blocking version of [`super::Profile::modified_time`].
+        pub fn modified_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.modified_time())
+        }
+        /// Blocking version of [`super::Profile::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+    }
+}
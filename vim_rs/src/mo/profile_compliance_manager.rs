@@ -174,3 +174,41 @@ struct QueryExpressionMetadataRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     profile: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ComplianceResult;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfileExpressionMetadata;
+    /// Blocking version of [`super::ProfileComplianceManager`].
+    pub struct ProfileComplianceManager {
+        inner: super::ProfileComplianceManager,
+        client: Arc<Client>,
+    }
+    impl ProfileComplianceManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ProfileComplianceManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ProfileComplianceManager::check_compliance_task`].
+        pub fn check_compliance_task(&self, profile: Option<&[ManagedObjectReference]>, entity: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_compliance_task(profile, entity))
+        }
+        /// Blocking version of [`super::ProfileComplianceManager::clear_compliance_status`].
+        pub fn clear_compliance_status(&self, profile: Option<&[ManagedObjectReference]>, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.clear_compliance_status(profile, entity))
+        }
+        /// Blocking version of [`super::ProfileComplianceManager::query_compliance_status`].
+        pub fn query_compliance_status(&self, profile: Option<&[ManagedObjectReference]>, entity: Option<&[ManagedObjectReference]>) -> Result<Option<Vec<ComplianceResult>>> {
+            self.client.block_on(self.inner.query_compliance_status(profile, entity))
+        }
+        /// Blocking version of [`super::ProfileComplianceManager::query_expression_metadata`].
+        pub fn query_expression_metadata(&self, expression_name: Option<&[String]>, profile: Option<&ManagedObjectReference>) -> Result<Option<Vec<ProfileExpressionMetadata>>> {
+            self.client.block_on(self.inner.query_expression_metadata(expression_name, profile))
+        }
+    }
+}
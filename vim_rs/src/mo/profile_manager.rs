@@ -126,3 +126,40 @@ struct QueryPolicyMetadataRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     profile: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProfilePolicyMetadata;
+    /// Blocking version of [`super::ProfileManager`].
+    pub struct ProfileManager {
+        inner: super::ProfileManager,
+        client: Arc<Client>,
+    }
+    impl ProfileManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ProfileManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ProfileManager::create_profile`].
+        pub fn create_profile(&self, create_spec: &dyn crate::types::traits::ProfileCreateSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_profile(create_spec))
+        }
+        /// Blocking version of [`super::ProfileManager::find_associated_profile`].
+        pub fn find_associated_profile(&self, entity: &ManagedObjectReference) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.find_associated_profile(entity))
+        }
+        /// Blocking version of [`super::ProfileManager::query_policy_metadata`].
+        pub fn query_policy_metadata(&self, policy_name: Option<&[String]>, profile: Option<&ManagedObjectReference>) -> Result<Option<Vec<ProfilePolicyMetadata>>> {
+            self.client.block_on(self.inner.query_policy_metadata(policy_name, profile))
+        }
+        /// Blocking version of [`super::ProfileManager::profile`].
+        pub fn profile(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.profile())
+        }
+    }
+}
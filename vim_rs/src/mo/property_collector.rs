@@ -467,3 +467,77 @@ struct WaitForUpdatesExRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     options: Option<&'a WaitOptions>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ObjectContent;
+    use crate::types::structs::PropertyFilterSpec;
+    use crate::types::structs::RetrieveOptions;
+    use crate::types::structs::RetrieveResult;
+    use crate::types::structs::UpdateSet;
+    use crate::types::structs::WaitOptions;
+    /// Blocking version of [`super::PropertyCollector`].
+    pub struct PropertyCollector {
+        inner: super::PropertyCollector,
+        client: Arc<Client>,
+    }
+    impl PropertyCollector {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::PropertyCollector::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::PropertyCollector::cancel_retrieve_properties_ex`].
+        pub fn cancel_retrieve_properties_ex(&self, token: &str) -> Result<()> {
+            self.client.block_on(self.inner.cancel_retrieve_properties_ex(token))
+        }
+        /// Blocking version of [`super::PropertyCollector::cancel_wait_for_updates`].
+        pub fn cancel_wait_for_updates(&self) -> Result<()> {
+            self.client.block_on(self.inner.cancel_wait_for_updates())
+        }
+        /// Blocking version of [`super::PropertyCollector::check_for_updates`].
+        pub fn check_for_updates(&self, version: Option<&str>) -> Result<Option<UpdateSet>> {
+            self.client.block_on(self.inner.check_for_updates(version))
+        }
+        /// Blocking version of [`super::PropertyCollector::continue_retrieve_properties_ex`].
+        pub fn continue_retrieve_properties_ex(&self, token: &str) -> Result<RetrieveResult> {
+            self.client.block_on(self.inner.continue_retrieve_properties_ex(token))
+        }
+        /// Blocking version of [`super::PropertyCollector::create_filter`].
+        pub fn create_filter(&self, spec: &PropertyFilterSpec, partial_updates: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_filter(spec, partial_updates))
+        }
+        /// Blocking version of [`super::PropertyCollector::create_property_collector`].
+        pub fn create_property_collector(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_property_collector())
+        }
+        /// Blocking version of [`super::PropertyCollector::destroy_property_collector`].
+        pub fn destroy_property_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_property_collector())
+        }
+        /// Blocking version of [`super::PropertyCollector::retrieve_properties`].
+        pub fn retrieve_properties(&self, spec_set: &[PropertyFilterSpec]) -> Result<Option<Vec<ObjectContent>>> {
+            self.client.block_on(self.inner.retrieve_properties(spec_set))
+        }
+        /// Blocking version of [`super::PropertyCollector::retrieve_properties_ex`].
+        pub fn retrieve_properties_ex(&self, spec_set: &[PropertyFilterSpec], options: &RetrieveOptions) -> Result<Option<RetrieveResult>> {
+            self.client.block_on(self.inner.retrieve_properties_ex(spec_set, options))
+        }
+        /// Blocking version of [`super::PropertyCollector::wait_for_updates`].
+        pub fn wait_for_updates(&self, version: Option<&str>) -> Result<UpdateSet> {
+            self.client.block_on(self.inner.wait_for_updates(version))
+        }
+        /// Blocking version of [`super::PropertyCollector::wait_for_updates_ex`].
+        pub fn wait_for_updates_ex(&self, version: Option<&str>, options: Option<&WaitOptions>) -> Result<Option<UpdateSet>> {
+            self.client.block_on(self.inner.wait_for_updates_ex(version, options))
+        }
+        /// Blocking version of [`super::PropertyCollector::filter`].
+        pub fn filter(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.filter())
+        }
+    }
+}
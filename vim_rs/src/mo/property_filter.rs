@@ -47,3 +47,35 @@ impl PropertyFilter {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::PropertyFilterSpec;
+    /// Blocking version of [`super::PropertyFilter`].
+    pub struct PropertyFilter {
+        inner: super::PropertyFilter,
+        client: Arc<Client>,
+    }
+    impl PropertyFilter {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::PropertyFilter::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::PropertyFilter::destroy_property_filter`].
+        pub fn destroy_property_filter(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_property_filter())
+        }
+        /// Blocking version of [`super::PropertyFilter::partial_updates`].
+        pub fn partial_updates(&self) -> Result<bool> {
+            self.client.block_on(self.inner.partial_updates())
+        }
+        /// Blocking version of [`super::PropertyFilter::spec`].
+        pub fn spec(&self) -> Result<PropertyFilterSpec> {
+            self.client.block_on(self.inner.spec())
+        }
+    }
+}
@@ -47,3 +47,28 @@ struct EstimateDatabaseSizeRequestType<'a> {
     #[serde(rename = "dbSizeParam")]
     db_size_param: &'a DatabaseSizeParam,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::DatabaseSizeEstimate;
+    use crate::types::structs::DatabaseSizeParam;
+    /// Blocking version of [`super::ResourcePlanningManager`].
+    pub struct ResourcePlanningManager {
+        inner: super::ResourcePlanningManager,
+        client: Arc<Client>,
+    }
+    impl ResourcePlanningManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ResourcePlanningManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ResourcePlanningManager::estimate_database_size`].
+        pub fn estimate_database_size(&self, db_size_param: &DatabaseSizeParam) -> Result<DatabaseSizeEstimate> {
+            self.client.block_on(self.inner.estimate_database_size(db_size_param))
+        }
+    }
+}
@@ -1234,3 +1234,189 @@ struct UpdateConfigRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     config: Option<&'a ResourceConfigSpec>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::ResourceConfigOption;
+    use crate::types::structs::ResourceConfigSpec;
+    use crate::types::structs::ResourcePoolRuntimeInfo;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VAppConfigSpec;
+    use crate::types::structs::VirtualMachineConfigSpec;
+    /// Blocking version of [`super::ResourcePool`].
+    pub struct ResourcePool {
+        inner: super::ResourcePool,
+        client: Arc<Client>,
+    }
+    impl ResourcePool {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ResourcePool::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ResourcePool::create_resource_pool`].
+        pub fn create_resource_pool(&self, name: &str, spec: &ResourceConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_resource_pool(name, spec))
+        }
+        /// Blocking version of [`super::ResourcePool::create_v_app`].
+        pub fn create_v_app(&self, name: &str, res_spec: &ResourceConfigSpec, config_spec: &VAppConfigSpec, vm_folder: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_v_app(name, res_spec, config_spec, vm_folder))
+        }
+        /// Blocking version of [`super::ResourcePool::create_child_vm_task`].
+        pub fn create_child_vm_task(&self, config: &VirtualMachineConfigSpec, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_child_vm_task(config, host))
+        }
+        /// Blocking version of [`super::ResourcePool::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::ResourcePool::destroy_children`].
+        pub fn destroy_children(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_children())
+        }
+        /// Blocking version of [`super::ResourcePool::import_v_app`].
+        pub fn import_v_app(&self, spec: &dyn crate::types::traits::ImportSpecTrait, folder: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.import_v_app(spec, folder, host))
+        }
+        /// Blocking version of [`super::ResourcePool::move_into_resource_pool`].
+        pub fn move_into_resource_pool(&self, list: &[ManagedObjectReference]) -> Result<()> {
+            self.client.block_on(self.inner.move_into_resource_pool(list))
+        }
+        /// Blocking version of [`super::ResourcePool::query_resource_config_option`].
+        pub fn query_resource_config_option(&self) -> Result<ResourceConfigOption> {
+            self.client.block_on(self.inner.query_resource_config_option())
+        }
+        /// Blocking version of [`super::ResourcePool::refresh_runtime`].
+        pub fn refresh_runtime(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_runtime())
+        }
+        /// Blocking version of [`super::ResourcePool::register_child_vm_task`].
+        pub fn register_child_vm_task(&self, path: &str, name: Option<&str>, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.register_child_vm_task(path, name, host))
+        }
+        /// Blocking version of [`super::ResourcePool::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::ResourcePool::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::ResourcePool::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::ResourcePool::update_child_resource_configuration`].
+        pub fn update_child_resource_configuration(&self, spec: &[ResourceConfigSpec]) -> Result<()> {
+            self.client.block_on(self.inner.update_child_resource_configuration(spec))
+        }
+        /// Blocking version of [`super::ResourcePool::update_config`].
+        pub fn update_config(&self, name: Option<&str>, config: Option<&ResourceConfigSpec>) -> Result<()> {
+            self.client.block_on(self.inner.update_config(name, config))
+        }
+        /// Blocking version of [`super::ResourcePool::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::ResourcePool::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::ResourcePool::child_configuration`].
+        pub fn child_configuration(&self) -> Result<Option<Vec<ResourceConfigSpec>>> {
+            self.client.block_on(self.inner.child_configuration())
+        }
+        /// Blocking version of [`super::ResourcePool::config`].
+        pub fn config(&self) -> Result<ResourceConfigSpec> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::ResourcePool::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::ResourcePool::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::ResourcePool::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::ResourcePool::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::ResourcePool::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::ResourcePool::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::ResourcePool::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::ResourcePool::namespace`].
+        pub fn namespace(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.namespace())
+        }
+        /// Blocking version of [`super::ResourcePool::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::ResourcePool::owner`].
+        pub fn owner(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.owner())
+        }
+        /// Blocking version of [`super::ResourcePool::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::ResourcePool::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::ResourcePool::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::ResourcePool::resource_pool`].
+        pub fn resource_pool(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.resource_pool())
+        }
+        /// Blocking version of [`super::ResourcePool::runtime`].
+        pub fn runtime(&self) -> Result<ResourcePoolRuntimeInfo> {
+            self.client.block_on(self.inner.runtime())
+        }
+        /// Blocking version of [`super::ResourcePool::summary`].
+        pub fn summary(&self) -> Result<Box<dyn crate::types::traits::ResourcePoolSummaryTrait>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::ResourcePool::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::ResourcePool::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::ResourcePool::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::ResourcePool::vm`].
+        pub fn vm(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
@@ -124,3 +124,52 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::ScheduledTaskInfo;
+    /// Blocking version of [`super::ScheduledTask`].
+    pub struct ScheduledTask {
+        inner: super::ScheduledTask,
+        client: Arc<Client>,
+    }
+    impl ScheduledTask {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ScheduledTask::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ScheduledTask::reconfigure_scheduled_task`].
+        pub fn reconfigure_scheduled_task(&self, spec: &dyn crate::types::traits::ScheduledTaskSpecTrait) -> Result<()> {
+            self.client.block_on(self.inner.reconfigure_scheduled_task(spec))
+        }
+        /// Blocking version of [`super::ScheduledTask::remove_scheduled_task`].
+        pub fn remove_scheduled_task(&self) -> Result<()> {
+            self.client.block_on(self.inner.remove_scheduled_task())
+        }
+        /// Blocking version of [`super::ScheduledTask::run_scheduled_task`].
+        pub fn run_scheduled_task(&self) -> Result<()> {
+            self.client.block_on(self.inner.run_scheduled_task())
+        }
+        /// Blocking version of [`super::ScheduledTask::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::ScheduledTask::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::ScheduledTask::info`].
+        pub fn info(&self) -> Result<ScheduledTaskInfo> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::ScheduledTask::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
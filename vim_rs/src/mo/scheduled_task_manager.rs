@@ -177,3 +177,48 @@ struct RetrieveObjectScheduledTaskRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     obj: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ScheduledTaskDescription;
+    /// Blocking version of [`super::ScheduledTaskManager`].
+    pub struct ScheduledTaskManager {
+        inner: super::ScheduledTaskManager,
+        client: Arc<Client>,
+    }
+    impl ScheduledTaskManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ScheduledTaskManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::create_scheduled_task`].
+        pub fn create_scheduled_task(&self, entity: &ManagedObjectReference, spec: &dyn crate::types::traits::ScheduledTaskSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_scheduled_task(entity, spec))
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::create_object_scheduled_task`].
+        pub fn create_object_scheduled_task(&self, obj: &ManagedObjectReference, spec: &dyn crate::types::traits::ScheduledTaskSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_object_scheduled_task(obj, spec))
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::retrieve_entity_scheduled_task`].
+        pub fn retrieve_entity_scheduled_task(&self, entity: Option<&ManagedObjectReference>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.retrieve_entity_scheduled_task(entity))
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::retrieve_object_scheduled_task`].
+        pub fn retrieve_object_scheduled_task(&self, obj: Option<&ManagedObjectReference>) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.retrieve_object_scheduled_task(obj))
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::description`].
+        pub fn description(&self) -> Result<ScheduledTaskDescription> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::ScheduledTaskManager::scheduled_task`].
+        pub fn scheduled_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.scheduled_task())
+        }
+    }
+}
@@ -426,3 +426,59 @@ struct FindChildRequestType<'a> {
     entity: &'a ManagedObjectReference,
     name: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::SearchIndex`].
+    pub struct SearchIndex {
+        inner: super::SearchIndex,
+        client: Arc<Client>,
+    }
+    impl SearchIndex {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::SearchIndex::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::SearchIndex::find_all_by_dns_name`].
+        pub fn find_all_by_dns_name(&self, datacenter: Option<&ManagedObjectReference>, dns_name: &str, vm_search: bool) -> Result<Vec<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_all_by_dns_name(datacenter, dns_name, vm_search))
+        }
+        /// Blocking version of [`super::SearchIndex::find_all_by_ip`].
+        pub fn find_all_by_ip(&self, datacenter: Option<&ManagedObjectReference>, ip: &str, vm_search: bool) -> Result<Vec<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_all_by_ip(datacenter, ip, vm_search))
+        }
+        /// Blocking version of [`super::SearchIndex::find_all_by_uuid`].
+        pub fn find_all_by_uuid(&self, datacenter: Option<&ManagedObjectReference>, uuid: &str, vm_search: bool, instance_uuid: Option<bool>) -> Result<Vec<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_all_by_uuid(datacenter, uuid, vm_search, instance_uuid))
+        }
+        /// Blocking version of [`super::SearchIndex::find_by_datastore_path`].
+        pub fn find_by_datastore_path(&self, datacenter: &ManagedObjectReference, path: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_by_datastore_path(datacenter, path))
+        }
+        /// Blocking version of [`super::SearchIndex::find_by_dns_name`].
+        pub fn find_by_dns_name(&self, datacenter: Option<&ManagedObjectReference>, dns_name: &str, vm_search: bool) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_by_dns_name(datacenter, dns_name, vm_search))
+        }
+        /// Blocking version of [`super::SearchIndex::find_by_inventory_path`].
+        pub fn find_by_inventory_path(&self, inventory_path: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_by_inventory_path(inventory_path))
+        }
+        /// Blocking version of [`super::SearchIndex::find_by_ip`].
+        pub fn find_by_ip(&self, datacenter: Option<&ManagedObjectReference>, ip: &str, vm_search: bool) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_by_ip(datacenter, ip, vm_search))
+        }
+        /// Blocking version of [`super::SearchIndex::find_by_uuid`].
+        pub fn find_by_uuid(&self, datacenter: Option<&ManagedObjectReference>, uuid: &str, vm_search: bool, instance_uuid: Option<bool>) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_by_uuid(datacenter, uuid, vm_search, instance_uuid))
+        }
+        /// Blocking version of [`super::SearchIndex::find_child`].
+        pub fn find_child(&self, entity: &ManagedObjectReference, name: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.find_child(entity, name))
+        }
+    }
+}
@@ -292,3 +292,60 @@ struct ValidateMigrationRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     host: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Capability;
+    use crate::types::structs::Event;
+    use crate::types::structs::HostVMotionCompatibility;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::ProductComponentInfo;
+    use crate::types::structs::ServiceContent;
+    /// Blocking version of [`super::ServiceInstance`].
+    pub struct ServiceInstance {
+        inner: super::ServiceInstance,
+        client: Arc<Client>,
+    }
+    impl ServiceInstance {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ServiceInstance::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ServiceInstance::current_time`].
+        pub fn current_time(&self) -> Result<String> {
+            self.client.block_on(self.inner.current_time())
+        }
+        /// Blocking version of [`super::ServiceInstance::query_v_motion_compatibility`].
+        pub fn query_v_motion_compatibility(&self, vm: &ManagedObjectReference, host: &[ManagedObjectReference], compatibility: Option<&[String]>) -> Result<Option<Vec<HostVMotionCompatibility>>> {
+            self.client.block_on(self.inner.query_v_motion_compatibility(vm, host, compatibility))
+        }
+        /// Blocking version of [`super::ServiceInstance::retrieve_service_content`].
+        pub fn retrieve_service_content(&self) -> Result<ServiceContent> {
+            self.client.block_on(self.inner.retrieve_service_content())
+        }
+        /// Blocking version of [`super::ServiceInstance::retrieve_product_components`].
+        pub fn retrieve_product_components(&self) -> Result<Option<Vec<ProductComponentInfo>>> {
+            self.client.block_on(self.inner.retrieve_product_components())
+        }
+        /// Blocking version of [`super::ServiceInstance::validate_migration`].
+        pub fn validate_migration(&self, vm: &[ManagedObjectReference], state: Option<crate::types::enums::VirtualMachinePowerStateEnum>, test_type: Option<&[String]>, pool: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.validate_migration(vm, state, test_type, pool, host))
+        }
+        /// Blocking version of [`super::ServiceInstance::capability`].
+        pub fn capability(&self) -> Result<Capability> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::ServiceInstance::content`].
+        pub fn content(&self) -> Result<ServiceContent> {
+            self.client.block_on(self.inner.content())
+        }
+        /// Blocking version of [`super::ServiceInstance::server_clock`].
+        pub fn server_clock(&self) -> Result<String> {
+            self.client.block_on(self.inner.server_clock())
+        }
+    }
+}
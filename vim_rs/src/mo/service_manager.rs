@@ -61,3 +61,31 @@ struct QueryServiceListRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     location: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ServiceManagerServiceInfo;
+    /// Blocking version of [`super::ServiceManager`].
+    pub struct ServiceManager {
+        inner: super::ServiceManager,
+        client: Arc<Client>,
+    }
+    impl ServiceManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ServiceManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ServiceManager::query_service_list`].
+        pub fn query_service_list(&self, service_name: Option<&str>, location: Option<&[String]>) -> Result<Option<Vec<ServiceManagerServiceInfo>>> {
+            self.client.block_on(self.inner.query_service_list(service_name, location))
+        }
+        /// Blocking version of [`super::ServiceManager::service`].
+        pub fn service(&self) -> Result<Option<Vec<ServiceManagerServiceInfo>>> {
+            self.client.block_on(self.inner.service())
+        }
+    }
+}
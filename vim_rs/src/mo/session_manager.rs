@@ -761,3 +761,113 @@ struct TerminateSessionRequestType<'a> {
 struct UpdateServiceMessageRequestType<'a> {
     message: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::SessionManagerGenericServiceTicket;
+    use crate::types::structs::SessionManagerLocalTicket;
+    use crate::types::structs::UserSession;
+    /// Blocking version of [`super::SessionManager`].
+    pub struct SessionManager {
+        inner: super::SessionManager,
+        client: Arc<Client>,
+    }
+    impl SessionManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::SessionManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::SessionManager::acquire_clone_ticket`].
+        pub fn acquire_clone_ticket(&self) -> Result<String> {
+            self.client.block_on(self.inner.acquire_clone_ticket())
+        }
+        /// Blocking version of [`super::SessionManager::acquire_generic_service_ticket`].
+        pub fn acquire_generic_service_ticket(&self, spec: &dyn crate::types::traits::SessionManagerServiceRequestSpecTrait) -> Result<SessionManagerGenericServiceTicket> {
+            self.client.block_on(self.inner.acquire_generic_service_ticket(spec))
+        }
+        /// Blocking version of [`super::SessionManager::acquire_local_ticket`].
+        pub fn acquire_local_ticket(&self, user_name: &str) -> Result<SessionManagerLocalTicket> {
+            self.client.block_on(self.inner.acquire_local_ticket(user_name))
+        }
+        /// Blocking version of [`super::SessionManager::clone_session`].
+        pub fn clone_session(&self, clone_ticket: &str) -> Result<UserSession> {
+            self.client.block_on(self.inner.clone_session(clone_ticket))
+        }
+        /// Blocking version of [`super::SessionManager::impersonate_user`].
+        pub fn impersonate_user(&self, user_name: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.impersonate_user(user_name, locale))
+        }
+        /// Blocking version of [`super::SessionManager::login`].
+        pub fn login(&self, user_name: &str, password: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login(user_name, password, locale))
+        }
+        /// Blocking version of [`super::SessionManager::login_by_sspi`].
+        pub fn login_by_sspi(&self, base_64_token: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login_by_sspi(base_64_token, locale))
+        }
+        /// Blocking version of [`super::SessionManager::login_by_token`].
+        pub fn login_by_token(&self, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login_by_token(locale))
+        }
+        /// Blocking version of [`super::SessionManager::login_extension`].
+        pub fn login_extension(&self, extension_key: &str, base_64_signed_credentials: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login_extension(extension_key, base_64_signed_credentials, locale))
+        }
+        /// Blocking version of [`super::SessionManager::login_extension_by_certificate`].
+        pub fn login_extension_by_certificate(&self, extension_key: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login_extension_by_certificate(extension_key, locale))
+        }
+        /// Blocking version of [`super::SessionManager::login_extension_by_subject_name`].
+        pub fn login_extension_by_subject_name(&self, extension_key: &str, locale: Option<&str>) -> Result<UserSession> {
+            self.client.block_on(self.inner.login_extension_by_subject_name(extension_key, locale))
+        }
+        /// Blocking version of [`super::SessionManager::logout`].
+        pub fn logout(&self) -> Result<()> {
+            self.client.block_on(self.inner.logout())
+        }
+        /// Blocking version of [`super::SessionManager::session_is_active`].
+        pub fn session_is_active(&self, session_id: &str, user_name: &str) -> Result<bool> {
+            self.client.block_on(self.inner.session_is_active(session_id, user_name))
+        }
+        /// Blocking version of [`super::SessionManager::set_locale`].
+        pub fn set_locale(&self, locale: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_locale(locale))
+        }
+        /// Blocking version of [`super::SessionManager::terminate_session`].
+        pub fn terminate_session(&self, session_id: &[String]) -> Result<()> {
+            self.client.block_on(self.inner.terminate_session(session_id))
+        }
+        /// Blocking version of [`super::SessionManager::update_service_message`].
+        pub fn update_service_message(&self, message: &str) -> Result<()> {
+            self.client.block_on(self.inner.update_service_message(message))
+        }
+        /// Blocking version of [`super::SessionManager::current_session`].
+        pub fn current_session(&self) -> Result<Option<UserSession>> {
+            self.client.block_on(self.inner.current_session())
+        }
+        /// Blocking version of [`super::SessionManager::default_locale`].
+        pub fn default_locale(&self) -> Result<String> {
+            self.client.block_on(self.inner.default_locale())
+        }
+        /// Blocking version of [`super::SessionManager::message`].
+        pub fn message(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.message())
+        }
+        /// Blocking version of [`super::SessionManager::message_locale_list`].
+        pub fn message_locale_list(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.message_locale_list())
+        }
+        /// Blocking version of [`super::SessionManager::session_list`].
+        pub fn session_list(&self) -> Result<Option<Vec<UserSession>>> {
+            self.client.block_on(self.inner.session_list())
+        }
+        /// Blocking version of [`super::SessionManager::supported_locale_list`].
+        pub fn supported_locale_list(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.supported_locale_list())
+        }
+    }
+}
@@ -54,3 +54,35 @@ struct ExecuteSimpleCommandRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     arguments: Option<&'a [String]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ServiceManagerServiceInfo;
+    /// Blocking version of [`super::SimpleCommand`].
+    pub struct SimpleCommand {
+        inner: super::SimpleCommand,
+        client: Arc<Client>,
+    }
+    impl SimpleCommand {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::SimpleCommand::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::SimpleCommand::execute_simple_command`].
+        pub fn execute_simple_command(&self, arguments: Option<&[String]>) -> Result<String> {
+            self.client.block_on(self.inner.execute_simple_command(arguments))
+        }
+        /// Blocking version of [`super::SimpleCommand::encoding_type`].
+        pub fn encoding_type(&self) -> Result<crate::types::enums::SimpleCommandEncodingEnum> {
+            self.client.block_on(self.inner.encoding_type())
+        }
+        /// Blocking version of [`super::SimpleCommand::entity`].
+        pub fn entity(&self) -> Result<ServiceManagerServiceInfo> {
+            self.client.block_on(self.inner.entity())
+        }
+    }
+}
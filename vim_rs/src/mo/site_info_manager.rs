@@ -23,3 +23,27 @@ impl SiteInfoManager {
         self.client.execute(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::SiteInfo;
+    /// Blocking version of [`super::SiteInfoManager`].
+    pub struct SiteInfoManager {
+        inner: super::SiteInfoManager,
+        client: Arc<Client>,
+    }
+    impl SiteInfoManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::SiteInfoManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::SiteInfoManager::get_site_info`].
+        pub fn get_site_info(&self) -> Result<SiteInfo> {
+            self.client.block_on(self.inner.get_site_info())
+        }
+    }
+}
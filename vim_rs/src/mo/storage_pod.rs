@@ -176,7 +176,7 @@ impl StoragePod {
     /// the operation.
     /// 
     /// Refers instance of *Task*.
-    pub async fn batch_add_hosts_to_cluster_task(&self, cluster: &ManagedObjectReference, new_hosts: Option<&[FolderNewHostSpec]>, existing_hosts: Option<&[ManagedObjectReference]>, comp_res_spec: Option<&dyn crate::types::traits::ComputeResourceConfigSpecTrait>, desired_state: Option<&str>) -> Result<ManagedObjectReference> {
+    pub async fn batch_add_hosts_to_cluster_task(&self, cluster: &ManagedObjectReference, new_hosts: Option<&[FolderNewHostSpec]>, existing_hosts: Option<&[ManagedObjectReference]>, comp_res_spec: Option<&dyn crate::types::traits::ComputeResourceConfigSpecTrait>, desired_state: Option<crate::types::enums::FolderDesiredHostStateEnum>) -> Result<ManagedObjectReference> {
         let input = BatchAddHostsToClusterRequestType {cluster, new_hosts, existing_hosts, comp_res_spec, desired_state, };
         let path = format!("/StoragePod/{moId}/BatchAddHostsToCluster_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1318,7 +1318,7 @@ struct BatchAddHostsToClusterRequestType<'a> {
     comp_res_spec: Option<&'a dyn crate::types::traits::ComputeResourceConfigSpecTrait>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "desiredState")]
-    desired_state: Option<&'a str>,
+    desired_state: Option<crate::types::enums::FolderDesiredHostStateEnum>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -1402,3 +1402,188 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::ClusterConfigSpec;
+    use crate::types::structs::ClusterConfigSpecEx;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::DvsCreateSpec;
+    use crate::types::structs::Event;
+    use crate::types::structs::FolderNewHostSpec;
+    use crate::types::structs::HostConnectSpec;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::PodStorageDrsEntry;
+    use crate::types::structs::StoragePodSummary;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VirtualMachineConfigSpec;
+    /// Blocking version of [`super::StoragePod`].
+    pub struct StoragePod {
+        inner: super::StoragePod,
+        client: Arc<Client>,
+    }
+    impl StoragePod {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::StoragePod::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::StoragePod::add_standalone_host_task`].
+        pub fn add_standalone_host_task(&self, spec: &HostConnectSpec, comp_res_spec: Option<&dyn crate::types::traits::ComputeResourceConfigSpecTrait>, add_connected: bool, license: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.add_standalone_host_task(spec, comp_res_spec, add_connected, license))
+        }
+        /// Blocking version of [`super::StoragePod::batch_add_hosts_to_cluster_task`].
+        pub fn batch_add_hosts_to_cluster_task(&self, cluster: &ManagedObjectReference, new_hosts: Option<&[FolderNewHostSpec]>, existing_hosts: Option<&[ManagedObjectReference]>, comp_res_spec: Option<&dyn crate::types::traits::ComputeResourceConfigSpecTrait>, desired_state: Option<crate::types::enums::FolderDesiredHostStateEnum>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.batch_add_hosts_to_cluster_task(cluster, new_hosts, existing_hosts, comp_res_spec, desired_state))
+        }
+        /// Blocking version of [`super::StoragePod::batch_add_standalone_hosts_task`].
+        pub fn batch_add_standalone_hosts_task(&self, new_hosts: Option<&[FolderNewHostSpec]>, comp_res_spec: Option<&dyn crate::types::traits::ComputeResourceConfigSpecTrait>, add_connected: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.batch_add_standalone_hosts_task(new_hosts, comp_res_spec, add_connected))
+        }
+        /// Blocking version of [`super::StoragePod::create_cluster`].
+        pub fn create_cluster(&self, name: &str, spec: &ClusterConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_cluster(name, spec))
+        }
+        /// Blocking version of [`super::StoragePod::create_cluster_ex`].
+        pub fn create_cluster_ex(&self, name: &str, spec: &ClusterConfigSpecEx) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_cluster_ex(name, spec))
+        }
+        /// Blocking version of [`super::StoragePod::create_datacenter`].
+        pub fn create_datacenter(&self, name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_datacenter(name))
+        }
+        /// Blocking version of [`super::StoragePod::create_dvs_task`].
+        pub fn create_dvs_task(&self, spec: &DvsCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_dvs_task(spec))
+        }
+        /// Blocking version of [`super::StoragePod::create_folder`].
+        pub fn create_folder(&self, name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_folder(name))
+        }
+        /// Blocking version of [`super::StoragePod::create_storage_pod`].
+        pub fn create_storage_pod(&self, name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_storage_pod(name))
+        }
+        /// Blocking version of [`super::StoragePod::create_vm_task`].
+        pub fn create_vm_task(&self, config: &VirtualMachineConfigSpec, pool: &ManagedObjectReference, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_vm_task(config, pool, host))
+        }
+        /// Blocking version of [`super::StoragePod::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::StoragePod::move_into_folder_task`].
+        pub fn move_into_folder_task(&self, list: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_into_folder_task(list))
+        }
+        /// Blocking version of [`super::StoragePod::register_vm_task`].
+        pub fn register_vm_task(&self, path: &str, name: Option<&str>, as_template: bool, pool: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.register_vm_task(path, name, as_template, pool, host))
+        }
+        /// Blocking version of [`super::StoragePod::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::StoragePod::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::StoragePod::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::StoragePod::unregister_and_destroy_task`].
+        pub fn unregister_and_destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.unregister_and_destroy_task())
+        }
+        /// Blocking version of [`super::StoragePod::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::StoragePod::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::StoragePod::child_entity`].
+        pub fn child_entity(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.child_entity())
+        }
+        /// Blocking version of [`super::StoragePod::child_type`].
+        pub fn child_type(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.child_type())
+        }
+        /// Blocking version of [`super::StoragePod::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::StoragePod::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::StoragePod::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::StoragePod::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::StoragePod::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::StoragePod::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::StoragePod::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::StoragePod::namespace`].
+        pub fn namespace(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.namespace())
+        }
+        /// Blocking version of [`super::StoragePod::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::StoragePod::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::StoragePod::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::StoragePod::pod_storage_drs_entry`].
+        pub fn pod_storage_drs_entry(&self) -> Result<Option<PodStorageDrsEntry>> {
+            self.client.block_on(self.inner.pod_storage_drs_entry())
+        }
+        /// Blocking version of [`super::StoragePod::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::StoragePod::summary`].
+        pub fn summary(&self) -> Result<Option<StoragePodSummary>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::StoragePod::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::StoragePod::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::StoragePod::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -46,3 +46,27 @@ struct QueryHostsWithAttachedLunRequestType<'a> {
     #[serde(rename = "lunUuid")]
     lun_uuid: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::StorageQueryManager`].
+    pub struct StorageQueryManager {
+        inner: super::StorageQueryManager,
+        client: Arc<Client>,
+    }
+    impl StorageQueryManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::StorageQueryManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::StorageQueryManager::query_hosts_with_attached_lun`].
+        pub fn query_hosts_with_attached_lun(&self, lun_uuid: &str) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.query_hosts_with_attached_lun(lun_uuid))
+        }
+    }
+}
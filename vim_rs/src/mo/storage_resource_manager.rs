@@ -447,3 +447,74 @@ struct ValidateStoragePodConfigRequestType<'a> {
     pod: &'a ManagedObjectReference,
     spec: &'a StorageDrsConfigSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::MethodFault;
+    use crate::types::structs::StorageDrsConfigSpec;
+    use crate::types::structs::StorageIormConfigOption;
+    use crate::types::structs::StorageIormConfigSpec;
+    use crate::types::structs::StoragePerformanceSummary;
+    use crate::types::structs::StoragePlacementResult;
+    use crate::types::structs::StoragePlacementSpec;
+    /// Blocking version of [`super::StorageResourceManager`].
+    pub struct StorageResourceManager {
+        inner: super::StorageResourceManager,
+        client: Arc<Client>,
+    }
+    impl StorageResourceManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::StorageResourceManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::StorageResourceManager::configure_datastore_iorm_task`].
+        pub fn configure_datastore_iorm_task(&self, datastore: &ManagedObjectReference, spec: &StorageIormConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_datastore_iorm_task(datastore, spec))
+        }
+        /// Blocking version of [`super::StorageResourceManager::query_iorm_config_option`].
+        pub fn query_iorm_config_option(&self, host: &ManagedObjectReference) -> Result<StorageIormConfigOption> {
+            self.client.block_on(self.inner.query_iorm_config_option(host))
+        }
+        /// Blocking version of [`super::StorageResourceManager::apply_storage_drs_recommendation_task`].
+        pub fn apply_storage_drs_recommendation_task(&self, key: &[String]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.apply_storage_drs_recommendation_task(key))
+        }
+        /// Blocking version of [`super::StorageResourceManager::apply_storage_drs_recommendation_to_pod_task`].
+        pub fn apply_storage_drs_recommendation_to_pod_task(&self, pod: &ManagedObjectReference, key: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.apply_storage_drs_recommendation_to_pod_task(pod, key))
+        }
+        /// Blocking version of [`super::StorageResourceManager::cancel_storage_drs_recommendation`].
+        pub fn cancel_storage_drs_recommendation(&self, key: &[String]) -> Result<()> {
+            self.client.block_on(self.inner.cancel_storage_drs_recommendation(key))
+        }
+        /// Blocking version of [`super::StorageResourceManager::configure_storage_drs_for_pod_task`].
+        pub fn configure_storage_drs_for_pod_task(&self, pod: &ManagedObjectReference, spec: &StorageDrsConfigSpec, modify: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.configure_storage_drs_for_pod_task(pod, spec, modify))
+        }
+        /// Blocking version of [`super::StorageResourceManager::query_datastore_performance_summary`].
+        pub fn query_datastore_performance_summary(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<StoragePerformanceSummary>>> {
+            self.client.block_on(self.inner.query_datastore_performance_summary(datastore))
+        }
+        /// Blocking version of [`super::StorageResourceManager::recommend_datastores`].
+        pub fn recommend_datastores(&self, storage_spec: &StoragePlacementSpec) -> Result<StoragePlacementResult> {
+            self.client.block_on(self.inner.recommend_datastores(storage_spec))
+        }
+        /// Blocking version of [`super::StorageResourceManager::refresh_storage_drs_recommendation`].
+        pub fn refresh_storage_drs_recommendation(&self, pod: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.refresh_storage_drs_recommendation(pod))
+        }
+        /// Blocking version of [`super::StorageResourceManager::refresh_storage_drs_recommendations_for_pod_task`].
+        pub fn refresh_storage_drs_recommendations_for_pod_task(&self, pod: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.refresh_storage_drs_recommendations_for_pod_task(pod))
+        }
+        /// Blocking version of [`super::StorageResourceManager::validate_storage_pod_config`].
+        pub fn validate_storage_pod_config(&self, pod: &ManagedObjectReference, spec: &StorageDrsConfigSpec) -> Result<Option<MethodFault>> {
+            self.client.block_on(self.inner.validate_storage_pod_config(pod, spec))
+        }
+    }
+}
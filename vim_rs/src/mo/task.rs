@@ -187,3 +187,59 @@ struct SetTaskStateRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     fault: Option<&'a MethodFault>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::LocalizableMessage;
+    use crate::types::structs::MethodFault;
+    use crate::types::structs::TaskInfo;
+    use crate::types::vim_any::VimAny;
+    /// Blocking version of [`super::Task`].
+    pub struct Task {
+        inner: super::Task,
+        client: Arc<Client>,
+    }
+    impl Task {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::Task::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::Task::set_task_description`].
+        pub fn set_task_description(&self, description: &LocalizableMessage) -> Result<()> {
+            self.client.block_on(self.inner.set_task_description(description))
+        }
+        /// Blocking version of [`super::Task::update_progress`].
+        pub fn update_progress(&self, percent_done: i32) -> Result<()> {
+            self.client.block_on(self.inner.update_progress(percent_done))
+        }
+        /// Blocking version of [`super::Task::cancel_task`].
+        pub fn cancel_task(&self) -> Result<()> {
+            self.client.block_on(self.inner.cancel_task())
+        }
+        /// Blocking version of [`super::Task::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::Task::set_task_state`].
+        pub fn set_task_state(&self, state: crate::types::enums::TaskInfoStateEnum, result: Option<VimAny>, fault: Option<&MethodFault>) -> Result<()> {
+            self.client.block_on(self.inner.set_task_state(state, result, fault))
+        }
+        /// Blocking version of [`super::Task::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::Task::info`].
+        pub fn info(&self) -> Result<TaskInfo> {
+            self.client.block_on(self.inner.info())
+        }
+        /// Blocking version of [`super::Task::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
@@ -135,3 +135,56 @@ struct SetCollectorPageSizeRequestType {
     #[serde(rename = "maxCount")]
     max_count: i32,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::TaskInfo;
+    use crate::types::vim_any::VimAny;
+    /// Blocking version of [`super::TaskHistoryCollector`].
+    pub struct TaskHistoryCollector {
+        inner: super::TaskHistoryCollector,
+        client: Arc<Client>,
+    }
+    impl TaskHistoryCollector {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::TaskHistoryCollector::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::read_next_tasks`].
+        pub fn read_next_tasks(&self, max_count: i32) -> Result<Option<Vec<TaskInfo>>> {
+            self.client.block_on(self.inner.read_next_tasks(max_count))
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::read_previous_tasks`].
+        pub fn read_previous_tasks(&self, max_count: i32) -> Result<Option<Vec<TaskInfo>>> {
+            self.client.block_on(self.inner.read_previous_tasks(max_count))
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::destroy_collector`].
+        pub fn destroy_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_collector())
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::reset_collector`].
+        pub fn reset_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_collector())
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::rewind_collector`].
+        pub fn rewind_collector(&self) -> Result<()> {
+            self.client.block_on(self.inner.rewind_collector())
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::set_collector_page_size`].
+        pub fn set_collector_page_size(&self, max_count: i32) -> Result<()> {
+            self.client.block_on(self.inner.set_collector_page_size(max_count))
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::filter`].
+        pub fn filter(&self) -> Result<VimAny> {
+            self.client.block_on(self.inner.filter())
+        }
+        /// Blocking version of [`super::TaskHistoryCollector::latest_page`].
+        pub fn latest_page(&self) -> Result<Option<Vec<TaskInfo>>> {
+            self.client.block_on(self.inner.latest_page())
+        }
+    }
+}
@@ -174,3 +174,46 @@ struct CreateTaskRequestType<'a> {
     #[serde(rename = "activationId")]
     activation_id: Option<&'a str>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::TaskDescription;
+    use crate::types::structs::TaskFilterSpec;
+    use crate::types::structs::TaskInfo;
+    /// Blocking version of [`super::TaskManager`].
+    pub struct TaskManager {
+        inner: super::TaskManager,
+        client: Arc<Client>,
+    }
+    impl TaskManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::TaskManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::TaskManager::create_collector_for_tasks`].
+        pub fn create_collector_for_tasks(&self, filter: &TaskFilterSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_collector_for_tasks(filter))
+        }
+        /// Blocking version of [`super::TaskManager::create_task`].
+        pub fn create_task(&self, obj: &ManagedObjectReference, task_type_id: &str, initiated_by: Option<&str>, cancelable: bool, parent_task_key: Option<&str>, activation_id: Option<&str>) -> Result<TaskInfo> {
+            self.client.block_on(self.inner.create_task(obj, task_type_id, initiated_by, cancelable, parent_task_key, activation_id))
+        }
+        /// Blocking version of [`super::TaskManager::description`].
+        pub fn description(&self) -> Result<TaskDescription> {
+            self.client.block_on(self.inner.description())
+        }
+        /// Blocking version of [`super::TaskManager::max_collector`].
+        pub fn max_collector(&self) -> Result<i32> {
+            self.client.block_on(self.inner.max_collector())
+        }
+        /// Blocking version of [`super::TaskManager::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+    }
+}
@@ -114,3 +114,35 @@ struct UnmarkServiceProviderEntitiesRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     entity: Option<&'a [ManagedObjectReference]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::TenantTenantManager`].
+    pub struct TenantTenantManager {
+        inner: super::TenantTenantManager,
+        client: Arc<Client>,
+    }
+    impl TenantTenantManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::TenantTenantManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::TenantTenantManager::mark_service_provider_entities`].
+        pub fn mark_service_provider_entities(&self, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.mark_service_provider_entities(entity))
+        }
+        /// Blocking version of [`super::TenantTenantManager::retrieve_service_provider_entities`].
+        pub fn retrieve_service_provider_entities(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.retrieve_service_provider_entities())
+        }
+        /// Blocking version of [`super::TenantTenantManager::unmark_service_provider_entities`].
+        pub fn unmark_service_provider_entities(&self, entity: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.unmark_service_provider_entities(entity))
+        }
+    }
+}
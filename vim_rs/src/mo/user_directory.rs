@@ -133,3 +133,30 @@ struct RetrieveUserGroupsRequestType<'a> {
     #[serde(rename = "findGroups")]
     find_groups: bool,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::UserDirectory`].
+    pub struct UserDirectory {
+        inner: super::UserDirectory,
+        client: Arc<Client>,
+    }
+    impl UserDirectory {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::UserDirectory::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::UserDirectory::retrieve_user_groups`].
+        pub fn retrieve_user_groups(&self, domain: Option<&str>, search_str: &str, belongs_to_group: Option<&str>, belongs_to_user: Option<&str>, exact_match: bool, find_users: bool, find_groups: bool) -> Result<Option<Vec<Box<dyn crate::types::traits::UserSearchResultTrait>>>> {
+            self.client.block_on(self.inner.retrieve_user_groups(domain, search_str, belongs_to_group, belongs_to_user, exact_match, find_users, find_groups))
+        }
+        /// Blocking version of [`super::UserDirectory::domain_list`].
+        pub fn domain_list(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.domain_list())
+        }
+    }
+}
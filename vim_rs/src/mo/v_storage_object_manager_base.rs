@@ -296,3 +296,45 @@ struct RevertVStorageObjectExRequestType<'a> {
     #[serde(rename = "snapshotId")]
     snapshot_id: &'a Id,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::Id;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VslmVClockInfo;
+    /// Blocking version of [`super::VStorageObjectManagerBase`].
+    pub struct VStorageObjectManagerBase {
+        inner: super::VStorageObjectManagerBase,
+        client: Arc<Client>,
+    }
+    impl VStorageObjectManagerBase {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VStorageObjectManagerBase::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VStorageObjectManagerBase::v_storage_object_create_snapshot_ex_task`].
+        pub fn v_storage_object_create_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, description: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_create_snapshot_ex_task(id, datastore, description))
+        }
+        /// Blocking version of [`super::VStorageObjectManagerBase::v_storage_object_delete_snapshot_ex_task`].
+        pub fn v_storage_object_delete_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_delete_snapshot_ex_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VStorageObjectManagerBase::v_storage_object_extend_disk_ex_task`].
+        pub fn v_storage_object_extend_disk_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, new_capacity_in_mb: i64) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_extend_disk_ex_task(id, datastore, new_capacity_in_mb))
+        }
+        /// Blocking version of [`super::VStorageObjectManagerBase::rename_v_storage_object_ex`].
+        pub fn rename_v_storage_object_ex(&self, id: &Id, datastore: &ManagedObjectReference, name: &str) -> Result<VslmVClockInfo> {
+            self.client.block_on(self.inner.rename_v_storage_object_ex(id, datastore, name))
+        }
+        /// Blocking version of [`super::VStorageObjectManagerBase::revert_v_storage_object_ex_task`].
+        pub fn revert_v_storage_object_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_v_storage_object_ex_task(id, datastore, snapshot_id))
+        }
+    }
+}
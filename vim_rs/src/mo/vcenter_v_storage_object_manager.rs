@@ -1250,7 +1250,7 @@ impl VcenterVStorageObjectManager {
     /// datastore.
     /// 
     /// ***NotFound***: If specified virtual storage object cannot be found.
-    pub async fn retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[String]>) -> Result<VStorageObject> {
+    pub async fn retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[crate::types::enums::VslmDiskInfoFlagEnum]>) -> Result<VStorageObject> {
         let input = RetrieveVStorageObjectRequestType {id, datastore, disk_info_flags, };
         let path = format!("/VcenterVStorageObjectManager/{moId}/RetrieveVStorageObject", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1833,7 +1833,7 @@ struct RetrieveVStorageObjectRequestType<'a> {
     datastore: &'a ManagedObjectReference,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "diskInfoFlags")]
-    disk_info_flags: Option<&'a [String]>,
+    disk_info_flags: Option<&'a [crate::types::enums::VslmDiskInfoFlagEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -1904,3 +1904,192 @@ struct UpdateVStorageObjectPolicyRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     profile: Option<&'a [Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::DiskChangeInfo;
+    use crate::types::structs::DiskCryptoSpec;
+    use crate::types::structs::Id;
+    use crate::types::structs::KeyValue;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::RetrieveVStorageObjSpec;
+    use crate::types::structs::VStorageObject;
+    use crate::types::structs::VStorageObjectAssociations;
+    use crate::types::structs::VStorageObjectSnapshotDetails;
+    use crate::types::structs::VStorageObjectSnapshotInfo;
+    use crate::types::structs::VStorageObjectStateInfo;
+    use crate::types::structs::VslmCloneSpec;
+    use crate::types::structs::VslmCreateSpec;
+    use crate::types::structs::VslmInfrastructureObjectPolicy;
+    use crate::types::structs::VslmInfrastructureObjectPolicySpec;
+    use crate::types::structs::VslmRelocateSpec;
+    use crate::types::structs::VslmTagEntry;
+    use crate::types::structs::VslmVClockInfo;
+    /// Blocking version of [`super::VcenterVStorageObjectManager`].
+    pub struct VcenterVStorageObjectManager {
+        inner: super::VcenterVStorageObjectManager,
+        client: Arc<Client>,
+    }
+    impl VcenterVStorageObjectManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VcenterVStorageObjectManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::revert_v_storage_object_task`].
+        pub fn revert_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_v_storage_object_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::attach_tag_to_v_storage_object`].
+        pub fn attach_tag_to_v_storage_object(&self, id: &Id, category: &str, tag: &str) -> Result<()> {
+            self.client.block_on(self.inner.attach_tag_to_v_storage_object(id, category, tag))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::clear_v_storage_object_control_flags`].
+        pub fn clear_v_storage_object_control_flags(&self, id: &Id, datastore: &ManagedObjectReference, control_flags: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.clear_v_storage_object_control_flags(id, datastore, control_flags))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::clone_v_storage_object_task`].
+        pub fn clone_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference, spec: &VslmCloneSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.clone_v_storage_object_task(id, datastore, spec))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::create_disk_task`].
+        pub fn create_disk_task(&self, spec: &VslmCreateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_disk_task(spec))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::create_disk_from_snapshot_task`].
+        pub fn create_disk_from_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id, name: &str, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>, crypto: Option<&dyn crate::types::traits::CryptoSpecTrait>, path: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_disk_from_snapshot_task(id, datastore, snapshot_id, name, profile, crypto, path))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::v_storage_object_create_snapshot_task`].
+        pub fn v_storage_object_create_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, description: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_create_snapshot_task(id, datastore, description))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::v_storage_object_create_snapshot_ex_task`].
+        pub fn v_storage_object_create_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, description: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_create_snapshot_ex_task(id, datastore, description))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::delete_snapshot_task`].
+        pub fn delete_snapshot_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_snapshot_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::v_storage_object_delete_snapshot_ex_task`].
+        pub fn v_storage_object_delete_snapshot_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_delete_snapshot_ex_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::delete_v_storage_object_task`].
+        pub fn delete_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_v_storage_object_task(id, datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::delete_v_storage_object_ex_task`].
+        pub fn delete_v_storage_object_ex_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_v_storage_object_ex_task(id, datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::detach_tag_from_v_storage_object`].
+        pub fn detach_tag_from_v_storage_object(&self, id: &Id, category: &str, tag: &str) -> Result<()> {
+            self.client.block_on(self.inner.detach_tag_from_v_storage_object(id, category, tag))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::extend_disk_task`].
+        pub fn extend_disk_task(&self, id: &Id, datastore: &ManagedObjectReference, new_capacity_in_mb: i64) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.extend_disk_task(id, datastore, new_capacity_in_mb))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::v_storage_object_extend_disk_ex_task`].
+        pub fn v_storage_object_extend_disk_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, new_capacity_in_mb: i64) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_storage_object_extend_disk_ex_task(id, datastore, new_capacity_in_mb))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::inflate_disk_task`].
+        pub fn inflate_disk_task(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.inflate_disk_task(id, datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::list_tags_attached_to_v_storage_object`].
+        pub fn list_tags_attached_to_v_storage_object(&self, id: &Id) -> Result<Option<Vec<VslmTagEntry>>> {
+            self.client.block_on(self.inner.list_tags_attached_to_v_storage_object(id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::list_v_storage_object`].
+        pub fn list_v_storage_object(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<Id>>> {
+            self.client.block_on(self.inner.list_v_storage_object(datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::list_v_storage_objects_attached_to_tag`].
+        pub fn list_v_storage_objects_attached_to_tag(&self, category: &str, tag: &str) -> Result<Option<Vec<Id>>> {
+            self.client.block_on(self.inner.list_v_storage_objects_attached_to_tag(category, tag))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::vstorage_object_v_center_query_changed_disk_areas`].
+        pub fn vstorage_object_v_center_query_changed_disk_areas(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id, start_offset: i64, change_id: &str) -> Result<DiskChangeInfo> {
+            self.client.block_on(self.inner.vstorage_object_v_center_query_changed_disk_areas(id, datastore, snapshot_id, start_offset, change_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::reconcile_datastore_inventory_task`].
+        pub fn reconcile_datastore_inventory_task(&self, datastore: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconcile_datastore_inventory_task(datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::register_disk`].
+        pub fn register_disk(&self, path: &str, name: Option<&str>) -> Result<VStorageObject> {
+            self.client.block_on(self.inner.register_disk(path, name))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::relocate_v_storage_object_task`].
+        pub fn relocate_v_storage_object_task(&self, id: &Id, datastore: &ManagedObjectReference, spec: &VslmRelocateSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.relocate_v_storage_object_task(id, datastore, spec))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::rename_v_storage_object`].
+        pub fn rename_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, name: &str) -> Result<()> {
+            self.client.block_on(self.inner.rename_v_storage_object(id, datastore, name))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::rename_v_storage_object_ex`].
+        pub fn rename_v_storage_object_ex(&self, id: &Id, datastore: &ManagedObjectReference, name: &str) -> Result<VslmVClockInfo> {
+            self.client.block_on(self.inner.rename_v_storage_object_ex(id, datastore, name))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_snapshot_details`].
+        pub fn retrieve_snapshot_details(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<VStorageObjectSnapshotDetails> {
+            self.client.block_on(self.inner.retrieve_snapshot_details(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_snapshot_info`].
+        pub fn retrieve_snapshot_info(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<VStorageObjectSnapshotInfo> {
+            self.client.block_on(self.inner.retrieve_snapshot_info(id, datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_v_storage_infrastructure_object_policy`].
+        pub fn retrieve_v_storage_infrastructure_object_policy(&self, datastore: &ManagedObjectReference) -> Result<Option<Vec<VslmInfrastructureObjectPolicy>>> {
+            self.client.block_on(self.inner.retrieve_v_storage_infrastructure_object_policy(datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_v_storage_object`].
+        pub fn retrieve_v_storage_object(&self, id: &Id, datastore: &ManagedObjectReference, disk_info_flags: Option<&[crate::types::enums::VslmDiskInfoFlagEnum]>) -> Result<VStorageObject> {
+            self.client.block_on(self.inner.retrieve_v_storage_object(id, datastore, disk_info_flags))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_v_storage_object_associations`].
+        pub fn retrieve_v_storage_object_associations(&self, ids: Option<&[RetrieveVStorageObjSpec]>) -> Result<Option<Vec<VStorageObjectAssociations>>> {
+            self.client.block_on(self.inner.retrieve_v_storage_object_associations(ids))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::retrieve_v_storage_object_state`].
+        pub fn retrieve_v_storage_object_state(&self, id: &Id, datastore: &ManagedObjectReference) -> Result<VStorageObjectStateInfo> {
+            self.client.block_on(self.inner.retrieve_v_storage_object_state(id, datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::revert_v_storage_object_ex_task`].
+        pub fn revert_v_storage_object_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, snapshot_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_v_storage_object_ex_task(id, datastore, snapshot_id))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::schedule_reconcile_datastore_inventory`].
+        pub fn schedule_reconcile_datastore_inventory(&self, datastore: &ManagedObjectReference) -> Result<()> {
+            self.client.block_on(self.inner.schedule_reconcile_datastore_inventory(datastore))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::set_v_storage_object_control_flags`].
+        pub fn set_v_storage_object_control_flags(&self, id: &Id, datastore: &ManagedObjectReference, control_flags: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.set_v_storage_object_control_flags(id, datastore, control_flags))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::update_v_storage_infrastructure_object_policy_task`].
+        pub fn update_v_storage_infrastructure_object_policy_task(&self, spec: &VslmInfrastructureObjectPolicySpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_v_storage_infrastructure_object_policy_task(spec))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::update_v_storage_object_crypto_task`].
+        pub fn update_v_storage_object_crypto_task(&self, id: &Id, datastore: &ManagedObjectReference, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>, disks_crypto: Option<&DiskCryptoSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_v_storage_object_crypto_task(id, datastore, profile, disks_crypto))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::v_center_update_v_storage_object_metadata_ex_task`].
+        pub fn v_center_update_v_storage_object_metadata_ex_task(&self, id: &Id, datastore: &ManagedObjectReference, metadata: Option<&[KeyValue]>, delete_keys: Option<&[String]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.v_center_update_v_storage_object_metadata_ex_task(id, datastore, metadata, delete_keys))
+        }
+        /// Blocking version of [`super::VcenterVStorageObjectManager::update_v_storage_object_policy_task`].
+        pub fn update_v_storage_object_policy_task(&self, id: &Id, datastore: &ManagedObjectReference, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_v_storage_object_policy_task(id, datastore, profile))
+        }
+    }
+}
@@ -40,3 +40,26 @@ impl View {
         self.client.execute_void(req).await
     }
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    /// Blocking version of [`super::View`].
+    pub struct View {
+        inner: super::View,
+        client: Arc<Client>,
+    }
+    impl View {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::View::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::View::destroy_view`].
+        pub fn destroy_view(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_view())
+        }
+    }
+}
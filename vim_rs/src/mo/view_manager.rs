@@ -144,7 +144,7 @@ impl ViewManager {
     /// ## Returns:
     ///
     /// Refers instance of *ContainerView*.
-    pub async fn create_container_view(&self, container: &ManagedObjectReference, r#type: Option<&[String]>, recursive: bool) -> Result<ManagedObjectReference> {
+    pub async fn create_container_view(&self, container: &ManagedObjectReference, r#type: Option<&[crate::types::enums::MoTypesEnum]>, recursive: bool) -> Result<ManagedObjectReference> {
         let input = CreateContainerViewRequestType {container, r#type, recursive, };
         let path = format!("/ViewManager/{moId}/CreateContainerView", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -233,7 +233,7 @@ struct CreateContainerViewRequestType<'a> {
     container: &'a ManagedObjectReference,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    r#type: Option<&'a [String]>,
+    r#type: Option<&'a [crate::types::enums::MoTypesEnum]>,
     recursive: bool,
 }
 #[derive(serde::Serialize)]
@@ -247,3 +247,43 @@ struct CreateListViewRequestType<'a> {
 struct CreateListViewFromViewRequestType<'a> {
     view: &'a ManagedObjectReference,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::ViewManager`].
+    pub struct ViewManager {
+        inner: super::ViewManager,
+        client: Arc<Client>,
+    }
+    impl ViewManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::ViewManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::ViewManager::create_container_view`].
+        pub fn create_container_view(&self, container: &ManagedObjectReference, r#type: Option<&[crate::types::enums::MoTypesEnum]>, recursive: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_container_view(container, r#type, recursive))
+        }
+        /// Blocking version of [`super::ViewManager::create_inventory_view`].
+        pub fn create_inventory_view(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_inventory_view())
+        }
+        /// Blocking version of [`super::ViewManager::create_list_view`].
+        pub fn create_list_view(&self, obj: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_list_view(obj))
+        }
+        /// Blocking version of [`super::ViewManager::create_list_view_from_view`].
+        pub fn create_list_view_from_view(&self, view: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_list_view_from_view(view))
+        }
+        /// Blocking version of [`super::ViewManager::view_list`].
+        pub fn view_list(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.view_list())
+        }
+    }
+}
@@ -1599,3 +1599,248 @@ struct UpdateLinkedChildrenRequestType<'a> {
 struct UpdateVAppConfigRequestType<'a> {
     spec: &'a VAppConfigSpec,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::ResourceConfigOption;
+    use crate::types::structs::ResourceConfigSpec;
+    use crate::types::structs::ResourcePoolRuntimeInfo;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VAppCloneSpec;
+    use crate::types::structs::VAppConfigInfo;
+    use crate::types::structs::VAppConfigSpec;
+    use crate::types::structs::VirtualAppLinkInfo;
+    use crate::types::structs::VirtualMachineConfigSpec;
+    /// Blocking version of [`super::VirtualApp`].
+    pub struct VirtualApp {
+        inner: super::VirtualApp,
+        client: Arc<Client>,
+    }
+    impl VirtualApp {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualApp::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualApp::clone_v_app_task`].
+        pub fn clone_v_app_task(&self, name: &str, target: &ManagedObjectReference, spec: &VAppCloneSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.clone_v_app_task(name, target, spec))
+        }
+        /// Blocking version of [`super::VirtualApp::create_resource_pool`].
+        pub fn create_resource_pool(&self, name: &str, spec: &ResourceConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_resource_pool(name, spec))
+        }
+        /// Blocking version of [`super::VirtualApp::create_v_app`].
+        pub fn create_v_app(&self, name: &str, res_spec: &ResourceConfigSpec, config_spec: &VAppConfigSpec, vm_folder: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_v_app(name, res_spec, config_spec, vm_folder))
+        }
+        /// Blocking version of [`super::VirtualApp::create_child_vm_task`].
+        pub fn create_child_vm_task(&self, config: &VirtualMachineConfigSpec, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_child_vm_task(config, host))
+        }
+        /// Blocking version of [`super::VirtualApp::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::VirtualApp::destroy_children`].
+        pub fn destroy_children(&self) -> Result<()> {
+            self.client.block_on(self.inner.destroy_children())
+        }
+        /// Blocking version of [`super::VirtualApp::export_v_app`].
+        pub fn export_v_app(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.export_v_app())
+        }
+        /// Blocking version of [`super::VirtualApp::import_v_app`].
+        pub fn import_v_app(&self, spec: &dyn crate::types::traits::ImportSpecTrait, folder: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.import_v_app(spec, folder, host))
+        }
+        /// Blocking version of [`super::VirtualApp::move_into_resource_pool`].
+        pub fn move_into_resource_pool(&self, list: &[ManagedObjectReference]) -> Result<()> {
+            self.client.block_on(self.inner.move_into_resource_pool(list))
+        }
+        /// Blocking version of [`super::VirtualApp::power_off_v_app_task`].
+        pub fn power_off_v_app_task(&self, force: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_off_v_app_task(force))
+        }
+        /// Blocking version of [`super::VirtualApp::power_on_v_app_task`].
+        pub fn power_on_v_app_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_on_v_app_task())
+        }
+        /// Blocking version of [`super::VirtualApp::query_resource_config_option`].
+        pub fn query_resource_config_option(&self) -> Result<ResourceConfigOption> {
+            self.client.block_on(self.inner.query_resource_config_option())
+        }
+        /// Blocking version of [`super::VirtualApp::refresh_runtime`].
+        pub fn refresh_runtime(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_runtime())
+        }
+        /// Blocking version of [`super::VirtualApp::register_child_vm_task`].
+        pub fn register_child_vm_task(&self, path: &str, name: Option<&str>, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.register_child_vm_task(path, name, host))
+        }
+        /// Blocking version of [`super::VirtualApp::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::VirtualApp::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::VirtualApp::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::VirtualApp::suspend_v_app_task`].
+        pub fn suspend_v_app_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.suspend_v_app_task())
+        }
+        /// Blocking version of [`super::VirtualApp::unregister_v_app_task`].
+        pub fn unregister_v_app_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.unregister_v_app_task())
+        }
+        /// Blocking version of [`super::VirtualApp::update_child_resource_configuration`].
+        pub fn update_child_resource_configuration(&self, spec: &[ResourceConfigSpec]) -> Result<()> {
+            self.client.block_on(self.inner.update_child_resource_configuration(spec))
+        }
+        /// Blocking version of [`super::VirtualApp::update_config`].
+        pub fn update_config(&self, name: Option<&str>, config: Option<&ResourceConfigSpec>) -> Result<()> {
+            self.client.block_on(self.inner.update_config(name, config))
+        }
+        /// Blocking version of [`super::VirtualApp::update_linked_children`].
+        pub fn update_linked_children(&self, add_change_set: Option<&[VirtualAppLinkInfo]>, remove_set: Option<&[ManagedObjectReference]>) -> Result<()> {
+            self.client.block_on(self.inner.update_linked_children(add_change_set, remove_set))
+        }
+        /// Blocking version of [`super::VirtualApp::update_v_app_config`].
+        pub fn update_v_app_config(&self, spec: &VAppConfigSpec) -> Result<()> {
+            self.client.block_on(self.inner.update_v_app_config(spec))
+        }
+        /// Blocking version of [`super::VirtualApp::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::VirtualApp::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::VirtualApp::child_configuration`].
+        pub fn child_configuration(&self) -> Result<Option<Vec<ResourceConfigSpec>>> {
+            self.client.block_on(self.inner.child_configuration())
+        }
+        /// Blocking version of [`super::VirtualApp::child_link`].
+        pub fn child_link(&self) -> Result<Option<Vec<VirtualAppLinkInfo>>> {
+            self.client.block_on(self.inner.child_link())
+        }
+        /// Blocking version of [`super::VirtualApp::config`].
+        pub fn config(&self) -> Result<ResourceConfigSpec> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::VirtualApp::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::VirtualApp::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::VirtualApp::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::VirtualApp::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::VirtualApp::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::VirtualApp::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::VirtualApp::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::VirtualApp::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::VirtualApp::namespace`].
+        pub fn namespace(&self) -> Result<Option<String>> {
+            self.client.block_on(self.inner.namespace())
+        }
+        /// Blocking version of [`super::VirtualApp::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::VirtualApp::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::VirtualApp::owner`].
+        pub fn owner(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.owner())
+        }
+        /// Blocking version of [`super::VirtualApp::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::VirtualApp::parent_folder`].
+        pub fn parent_folder(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent_folder())
+        }
+        /// Blocking version of [`super::VirtualApp::parent_v_app`].
+        pub fn parent_v_app(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent_v_app())
+        }
+        /// Blocking version of [`super::VirtualApp::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::VirtualApp::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::VirtualApp::resource_pool`].
+        pub fn resource_pool(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.resource_pool())
+        }
+        /// Blocking version of [`super::VirtualApp::runtime`].
+        pub fn runtime(&self) -> Result<ResourcePoolRuntimeInfo> {
+            self.client.block_on(self.inner.runtime())
+        }
+        /// Blocking version of [`super::VirtualApp::summary`].
+        pub fn summary(&self) -> Result<Box<dyn crate::types::traits::ResourcePoolSummaryTrait>> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::VirtualApp::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::VirtualApp::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::VirtualApp::v_app_config`].
+        pub fn v_app_config(&self) -> Result<Option<VAppConfigInfo>> {
+            self.client.block_on(self.inner.v_app_config())
+        }
+        /// Blocking version of [`super::VirtualApp::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::VirtualApp::vm`].
+        pub fn vm(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
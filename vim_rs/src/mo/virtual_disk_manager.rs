@@ -966,3 +966,88 @@ struct ZeroFillVirtualDiskRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     datacenter: Option<&'a ManagedObjectReference>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::HostDiskDimensionsChs;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::VirtualDiskManager`].
+    pub struct VirtualDiskManager {
+        inner: super::VirtualDiskManager,
+        client: Arc<Client>,
+    }
+    impl VirtualDiskManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualDiskManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualDiskManager::copy_virtual_disk_task`].
+        pub fn copy_virtual_disk_task(&self, source_name: &str, source_datacenter: Option<&ManagedObjectReference>, dest_name: &str, dest_datacenter: Option<&ManagedObjectReference>, dest_spec: Option<&dyn crate::types::traits::VirtualDiskSpecTrait>, force: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.copy_virtual_disk_task(source_name, source_datacenter, dest_name, dest_datacenter, dest_spec, force))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::create_virtual_disk_task`].
+        pub fn create_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>, spec: &dyn crate::types::traits::VirtualDiskSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_virtual_disk_task(name, datacenter, spec))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::defragment_virtual_disk_task`].
+        pub fn defragment_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.defragment_virtual_disk_task(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::delete_virtual_disk_task`].
+        pub fn delete_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.delete_virtual_disk_task(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::eager_zero_virtual_disk_task`].
+        pub fn eager_zero_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.eager_zero_virtual_disk_task(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::extend_virtual_disk_task`].
+        pub fn extend_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>, new_capacity_kb: i64, eager_zero: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.extend_virtual_disk_task(name, datacenter, new_capacity_kb, eager_zero))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::import_unmanaged_snapshot`].
+        pub fn import_unmanaged_snapshot(&self, vdisk: &str, datacenter: Option<&ManagedObjectReference>, vvol_id: &str) -> Result<()> {
+            self.client.block_on(self.inner.import_unmanaged_snapshot(vdisk, datacenter, vvol_id))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::inflate_virtual_disk_task`].
+        pub fn inflate_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.inflate_virtual_disk_task(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::move_virtual_disk_task`].
+        pub fn move_virtual_disk_task(&self, source_name: &str, source_datacenter: Option<&ManagedObjectReference>, dest_name: &str, dest_datacenter: Option<&ManagedObjectReference>, force: Option<bool>, profile: Option<&[Box<dyn crate::types::traits::VirtualMachineProfileSpecTrait>]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_virtual_disk_task(source_name, source_datacenter, dest_name, dest_datacenter, force, profile))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::query_virtual_disk_fragmentation`].
+        pub fn query_virtual_disk_fragmentation(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<i32> {
+            self.client.block_on(self.inner.query_virtual_disk_fragmentation(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::query_virtual_disk_geometry`].
+        pub fn query_virtual_disk_geometry(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<HostDiskDimensionsChs> {
+            self.client.block_on(self.inner.query_virtual_disk_geometry(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::query_virtual_disk_uuid`].
+        pub fn query_virtual_disk_uuid(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<String> {
+            self.client.block_on(self.inner.query_virtual_disk_uuid(name, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::release_managed_snapshot`].
+        pub fn release_managed_snapshot(&self, vdisk: &str, datacenter: Option<&ManagedObjectReference>) -> Result<()> {
+            self.client.block_on(self.inner.release_managed_snapshot(vdisk, datacenter))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::set_virtual_disk_uuid`].
+        pub fn set_virtual_disk_uuid(&self, name: &str, datacenter: Option<&ManagedObjectReference>, uuid: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_virtual_disk_uuid(name, datacenter, uuid))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::shrink_virtual_disk_task`].
+        pub fn shrink_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>, copy: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.shrink_virtual_disk_task(name, datacenter, copy))
+        }
+        /// Blocking version of [`super::VirtualDiskManager::zero_fill_virtual_disk_task`].
+        pub fn zero_fill_virtual_disk_task(&self, name: &str, datacenter: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.zero_fill_virtual_disk_task(name, datacenter))
+        }
+    }
+}
@@ -114,7 +114,7 @@ impl VirtualMachine {
     /// ## Errors:
     ///
     /// ***InvalidState***: if the virtual machine is not connected.
-    pub async fn acquire_ticket(&self, ticket_type: &str) -> Result<VirtualMachineTicket> {
+    pub async fn acquire_ticket(&self, ticket_type: crate::types::enums::VirtualMachineTicketTypeEnum) -> Result<VirtualMachineTicket> {
         let input = AcquireTicketRequestType {ticket_type, };
         let path = format!("/VirtualMachine/{moId}/AcquireTicket", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -3529,9 +3529,9 @@ impl VirtualMachine {
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
-struct AcquireTicketRequestType<'a> {
+struct AcquireTicketRequestType {
     #[serde(rename = "ticketType")]
-    ticket_type: &'a str,
+    ticket_type: crate::types::enums::VirtualMachineTicketTypeEnum,
 }
 #[derive(serde::Serialize)]
 #[serde(rename = "AnswerVMRequestType", tag = "_typeName")]
@@ -3792,3 +3792,457 @@ struct UpgradeVmRequestType<'a> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     version: Option<&'a str>,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::CustomizationSpec;
+    use crate::types::structs::DiskChangeInfo;
+    use crate::types::structs::Event;
+    use crate::types::structs::FaultToleranceConfigSpec;
+    use crate::types::structs::GuestInfo;
+    use crate::types::structs::HostFeatureMask;
+    use crate::types::structs::Id;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::MethodFault;
+    use crate::types::structs::Permission;
+    use crate::types::structs::ResourceConfigSpec;
+    use crate::types::structs::Tag;
+    use crate::types::structs::UsbScanCodeSpec;
+    use crate::types::structs::VirtualDisk;
+    use crate::types::structs::VirtualMachineCapability;
+    use crate::types::structs::VirtualMachineCloneSpec;
+    use crate::types::structs::VirtualMachineConfigInfo;
+    use crate::types::structs::VirtualMachineConfigSpec;
+    use crate::types::structs::VirtualMachineDisplayTopology;
+    use crate::types::structs::VirtualMachineFileLayout;
+    use crate::types::structs::VirtualMachineFileLayoutEx;
+    use crate::types::structs::VirtualMachineInstantCloneSpec;
+    use crate::types::structs::VirtualMachineMksTicket;
+    use crate::types::structs::VirtualMachineRelocateSpec;
+    use crate::types::structs::VirtualMachineRuntimeInfo;
+    use crate::types::structs::VirtualMachineSnapshotInfo;
+    use crate::types::structs::VirtualMachineStorageInfo;
+    use crate::types::structs::VirtualMachineSummary;
+    use crate::types::structs::VirtualMachineTicket;
+    /// Blocking version of [`super::VirtualMachine`].
+    pub struct VirtualMachine {
+        inner: super::VirtualMachine,
+        client: Arc<Client>,
+    }
+    impl VirtualMachine {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualMachine::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualMachine::acquire_mks_ticket`].
+        pub fn acquire_mks_ticket(&self) -> Result<VirtualMachineMksTicket> {
+            self.client.block_on(self.inner.acquire_mks_ticket())
+        }
+        /// Blocking version of [`super::VirtualMachine::acquire_ticket`].
+        pub fn acquire_ticket(&self, ticket_type: crate::types::enums::VirtualMachineTicketTypeEnum) -> Result<VirtualMachineTicket> {
+            self.client.block_on(self.inner.acquire_ticket(ticket_type))
+        }
+        /// Blocking version of [`super::VirtualMachine::answer_vm`].
+        pub fn answer_vm(&self, question_id: &str, answer_choice: &str) -> Result<()> {
+            self.client.block_on(self.inner.answer_vm(question_id, answer_choice))
+        }
+        /// Blocking version of [`super::VirtualMachine::apply_evc_mode_vm_task`].
+        pub fn apply_evc_mode_vm_task(&self, mask: Option<&[HostFeatureMask]>, complete_masks: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.apply_evc_mode_vm_task(mask, complete_masks))
+        }
+        /// Blocking version of [`super::VirtualMachine::attach_disk_task`].
+        pub fn attach_disk_task(&self, disk_id: &Id, datastore: &ManagedObjectReference, controller_key: Option<i32>, unit_number: Option<i32>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.attach_disk_task(disk_id, datastore, controller_key, unit_number))
+        }
+        /// Blocking version of [`super::VirtualMachine::check_customization_spec`].
+        pub fn check_customization_spec(&self, spec: &CustomizationSpec) -> Result<()> {
+            self.client.block_on(self.inner.check_customization_spec(spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::clone_vm_task`].
+        pub fn clone_vm_task(&self, folder: &ManagedObjectReference, name: &str, spec: &VirtualMachineCloneSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.clone_vm_task(folder, name, spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::consolidate_vm_disks_task`].
+        pub fn consolidate_vm_disks_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.consolidate_vm_disks_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::create_screenshot_task`].
+        pub fn create_screenshot_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_screenshot_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::create_secondary_vm_task`].
+        pub fn create_secondary_vm_task(&self, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_secondary_vm_task(host))
+        }
+        /// Blocking version of [`super::VirtualMachine::create_secondary_vm_ex_task`].
+        pub fn create_secondary_vm_ex_task(&self, host: Option<&ManagedObjectReference>, spec: Option<&FaultToleranceConfigSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_secondary_vm_ex_task(host, spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::create_snapshot_task`].
+        pub fn create_snapshot_task(&self, name: &str, description: Option<&str>, memory: bool, quiesce: bool) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_snapshot_task(name, description, memory, quiesce))
+        }
+        /// Blocking version of [`super::VirtualMachine::create_snapshot_ex_task`].
+        pub fn create_snapshot_ex_task(&self, name: &str, description: Option<&str>, memory: bool, quiesce_spec: Option<&dyn crate::types::traits::VirtualMachineGuestQuiesceSpecTrait>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_snapshot_ex_task(name, description, memory, quiesce_spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::crypto_unlock_task`].
+        pub fn crypto_unlock_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.crypto_unlock_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::customize_vm_task`].
+        pub fn customize_vm_task(&self, spec: &CustomizationSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.customize_vm_task(spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::defragment_all_disks`].
+        pub fn defragment_all_disks(&self) -> Result<()> {
+            self.client.block_on(self.inner.defragment_all_disks())
+        }
+        /// Blocking version of [`super::VirtualMachine::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::detach_disk_task`].
+        pub fn detach_disk_task(&self, disk_id: &Id) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.detach_disk_task(disk_id))
+        }
+        /// Blocking version of [`super::VirtualMachine::disable_secondary_vm_task`].
+        pub fn disable_secondary_vm_task(&self, vm: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.disable_secondary_vm_task(vm))
+        }
+        /// Blocking version of [`super::VirtualMachine::drop_connections`].
+        pub fn drop_connections(&self, list_of_connections: Option<&[Box<dyn crate::types::traits::VirtualMachineConnectionTrait>]>) -> Result<bool> {
+            self.client.block_on(self.inner.drop_connections(list_of_connections))
+        }
+        /// Blocking version of [`super::VirtualMachine::enable_secondary_vm_task`].
+        pub fn enable_secondary_vm_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.enable_secondary_vm_task(vm, host))
+        }
+        /// Blocking version of [`super::VirtualMachine::estimate_storage_for_consolidate_snapshots_task`].
+        pub fn estimate_storage_for_consolidate_snapshots_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.estimate_storage_for_consolidate_snapshots_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::export_vm`].
+        pub fn export_vm(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.export_vm())
+        }
+        /// Blocking version of [`super::VirtualMachine::extract_ovf_environment`].
+        pub fn extract_ovf_environment(&self) -> Result<String> {
+            self.client.block_on(self.inner.extract_ovf_environment())
+        }
+        /// Blocking version of [`super::VirtualMachine::instant_clone_task`].
+        pub fn instant_clone_task(&self, spec: &VirtualMachineInstantCloneSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.instant_clone_task(spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::make_primary_vm_task`].
+        pub fn make_primary_vm_task(&self, vm: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.make_primary_vm_task(vm))
+        }
+        /// Blocking version of [`super::VirtualMachine::mark_as_template`].
+        pub fn mark_as_template(&self) -> Result<()> {
+            self.client.block_on(self.inner.mark_as_template())
+        }
+        /// Blocking version of [`super::VirtualMachine::mark_as_virtual_machine`].
+        pub fn mark_as_virtual_machine(&self, pool: &ManagedObjectReference, host: Option<&ManagedObjectReference>) -> Result<()> {
+            self.client.block_on(self.inner.mark_as_virtual_machine(pool, host))
+        }
+        /// Blocking version of [`super::VirtualMachine::migrate_vm_task`].
+        pub fn migrate_vm_task(&self, pool: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>, priority: crate::types::enums::VirtualMachineMovePriorityEnum, state: Option<crate::types::enums::VirtualMachinePowerStateEnum>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.migrate_vm_task(pool, host, priority, state))
+        }
+        /// Blocking version of [`super::VirtualMachine::mount_tools_installer`].
+        pub fn mount_tools_installer(&self) -> Result<()> {
+            self.client.block_on(self.inner.mount_tools_installer())
+        }
+        /// Blocking version of [`super::VirtualMachine::power_off_vm_task`].
+        pub fn power_off_vm_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_off_vm_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::power_on_vm_task`].
+        pub fn power_on_vm_task(&self, host: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.power_on_vm_task(host))
+        }
+        /// Blocking version of [`super::VirtualMachine::promote_disks_task`].
+        pub fn promote_disks_task(&self, unlink: bool, disks: Option<&[VirtualDisk]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.promote_disks_task(unlink, disks))
+        }
+        /// Blocking version of [`super::VirtualMachine::put_usb_scan_codes`].
+        pub fn put_usb_scan_codes(&self, spec: &UsbScanCodeSpec) -> Result<i32> {
+            self.client.block_on(self.inner.put_usb_scan_codes(spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::query_changed_disk_areas`].
+        pub fn query_changed_disk_areas(&self, snapshot: Option<&ManagedObjectReference>, device_key: i32, start_offset: i64, change_id: &str) -> Result<DiskChangeInfo> {
+            self.client.block_on(self.inner.query_changed_disk_areas(snapshot, device_key, start_offset, change_id))
+        }
+        /// Blocking version of [`super::VirtualMachine::query_connections`].
+        pub fn query_connections(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::VirtualMachineConnectionTrait>>>> {
+            self.client.block_on(self.inner.query_connections())
+        }
+        /// Blocking version of [`super::VirtualMachine::query_fault_tolerance_compatibility`].
+        pub fn query_fault_tolerance_compatibility(&self) -> Result<Option<Vec<MethodFault>>> {
+            self.client.block_on(self.inner.query_fault_tolerance_compatibility())
+        }
+        /// Blocking version of [`super::VirtualMachine::query_fault_tolerance_compatibility_ex`].
+        pub fn query_fault_tolerance_compatibility_ex(&self, for_legacy_ft: Option<bool>) -> Result<Option<Vec<MethodFault>>> {
+            self.client.block_on(self.inner.query_fault_tolerance_compatibility_ex(for_legacy_ft))
+        }
+        /// Blocking version of [`super::VirtualMachine::query_unowned_files`].
+        pub fn query_unowned_files(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.query_unowned_files())
+        }
+        /// Blocking version of [`super::VirtualMachine::reboot_guest`].
+        pub fn reboot_guest(&self) -> Result<()> {
+            self.client.block_on(self.inner.reboot_guest())
+        }
+        /// Blocking version of [`super::VirtualMachine::reconfig_vm_task`].
+        pub fn reconfig_vm_task(&self, spec: &VirtualMachineConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfig_vm_task(spec))
+        }
+        /// Blocking version of [`super::VirtualMachine::refresh_storage_info`].
+        pub fn refresh_storage_info(&self) -> Result<()> {
+            self.client.block_on(self.inner.refresh_storage_info())
+        }
+        /// Blocking version of [`super::VirtualMachine::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::VirtualMachine::reload_virtual_machine_from_path_task`].
+        pub fn reload_virtual_machine_from_path_task(&self, configuration_path: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reload_virtual_machine_from_path_task(configuration_path))
+        }
+        /// Blocking version of [`super::VirtualMachine::relocate_vm_task`].
+        pub fn relocate_vm_task(&self, spec: &VirtualMachineRelocateSpec, priority: Option<crate::types::enums::VirtualMachineMovePriorityEnum>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.relocate_vm_task(spec, priority))
+        }
+        /// Blocking version of [`super::VirtualMachine::remove_all_snapshots_task`].
+        pub fn remove_all_snapshots_task(&self, consolidate: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.remove_all_snapshots_task(consolidate))
+        }
+        /// Blocking version of [`super::VirtualMachine::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::VirtualMachine::reset_vm_task`].
+        pub fn reset_vm_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reset_vm_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::reset_guest_information`].
+        pub fn reset_guest_information(&self) -> Result<()> {
+            self.client.block_on(self.inner.reset_guest_information())
+        }
+        /// Blocking version of [`super::VirtualMachine::revert_to_current_snapshot_task`].
+        pub fn revert_to_current_snapshot_task(&self, host: Option<&ManagedObjectReference>, suppress_power_on: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_to_current_snapshot_task(host, suppress_power_on))
+        }
+        /// Blocking version of [`super::VirtualMachine::send_nmi`].
+        pub fn send_nmi(&self) -> Result<()> {
+            self.client.block_on(self.inner.send_nmi())
+        }
+        /// Blocking version of [`super::VirtualMachine::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::VirtualMachine::set_display_topology`].
+        pub fn set_display_topology(&self, displays: &[VirtualMachineDisplayTopology]) -> Result<()> {
+            self.client.block_on(self.inner.set_display_topology(displays))
+        }
+        /// Blocking version of [`super::VirtualMachine::set_screen_resolution`].
+        pub fn set_screen_resolution(&self, width: i32, height: i32) -> Result<()> {
+            self.client.block_on(self.inner.set_screen_resolution(width, height))
+        }
+        /// Blocking version of [`super::VirtualMachine::shutdown_guest`].
+        pub fn shutdown_guest(&self) -> Result<()> {
+            self.client.block_on(self.inner.shutdown_guest())
+        }
+        /// Blocking version of [`super::VirtualMachine::standby_guest`].
+        pub fn standby_guest(&self) -> Result<()> {
+            self.client.block_on(self.inner.standby_guest())
+        }
+        /// Blocking version of [`super::VirtualMachine::start_recording_task`].
+        pub fn start_recording_task(&self, name: &str, description: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.start_recording_task(name, description))
+        }
+        /// Blocking version of [`super::VirtualMachine::start_replaying_task`].
+        pub fn start_replaying_task(&self, replay_snapshot: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.start_replaying_task(replay_snapshot))
+        }
+        /// Blocking version of [`super::VirtualMachine::stop_recording_task`].
+        pub fn stop_recording_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.stop_recording_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::stop_replaying_task`].
+        pub fn stop_replaying_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.stop_replaying_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::suspend_vm_task`].
+        pub fn suspend_vm_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.suspend_vm_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::terminate_vm`].
+        pub fn terminate_vm(&self) -> Result<()> {
+            self.client.block_on(self.inner.terminate_vm())
+        }
+        /// Blocking version of [`super::VirtualMachine::terminate_fault_tolerant_vm_task`].
+        pub fn terminate_fault_tolerant_vm_task(&self, vm: Option<&ManagedObjectReference>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.terminate_fault_tolerant_vm_task(vm))
+        }
+        /// Blocking version of [`super::VirtualMachine::turn_off_fault_tolerance_for_vm_task`].
+        pub fn turn_off_fault_tolerance_for_vm_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.turn_off_fault_tolerance_for_vm_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::unmount_tools_installer`].
+        pub fn unmount_tools_installer(&self) -> Result<()> {
+            self.client.block_on(self.inner.unmount_tools_installer())
+        }
+        /// Blocking version of [`super::VirtualMachine::unregister_vm`].
+        pub fn unregister_vm(&self) -> Result<()> {
+            self.client.block_on(self.inner.unregister_vm())
+        }
+        /// Blocking version of [`super::VirtualMachine::upgrade_tools_task`].
+        pub fn upgrade_tools_task(&self, installer_options: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.upgrade_tools_task(installer_options))
+        }
+        /// Blocking version of [`super::VirtualMachine::upgrade_vm_task`].
+        pub fn upgrade_vm_task(&self, version: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.upgrade_vm_task(version))
+        }
+        /// Blocking version of [`super::VirtualMachine::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::VirtualMachine::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::VirtualMachine::capability`].
+        pub fn capability(&self) -> Result<VirtualMachineCapability> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::VirtualMachine::config`].
+        pub fn config(&self) -> Result<Option<VirtualMachineConfigInfo>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::VirtualMachine::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::VirtualMachine::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::VirtualMachine::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::VirtualMachine::datastore`].
+        pub fn datastore(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.datastore())
+        }
+        /// Blocking version of [`super::VirtualMachine::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::VirtualMachine::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::VirtualMachine::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::VirtualMachine::environment_browser`].
+        pub fn environment_browser(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.environment_browser())
+        }
+        /// Blocking version of [`super::VirtualMachine::guest`].
+        pub fn guest(&self) -> Result<Option<GuestInfo>> {
+            self.client.block_on(self.inner.guest())
+        }
+        /// Blocking version of [`super::VirtualMachine::guest_heartbeat_status`].
+        pub fn guest_heartbeat_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.guest_heartbeat_status())
+        }
+        /// Blocking version of [`super::VirtualMachine::layout`].
+        pub fn layout(&self) -> Result<Option<VirtualMachineFileLayout>> {
+            self.client.block_on(self.inner.layout())
+        }
+        /// Blocking version of [`super::VirtualMachine::layout_ex`].
+        pub fn layout_ex(&self) -> Result<Option<VirtualMachineFileLayoutEx>> {
+            self.client.block_on(self.inner.layout_ex())
+        }
+        /// Blocking version of [`super::VirtualMachine::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::VirtualMachine::network`].
+        pub fn network(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.network())
+        }
+        /// Blocking version of [`super::VirtualMachine::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::VirtualMachine::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::VirtualMachine::parent_v_app`].
+        pub fn parent_v_app(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent_v_app())
+        }
+        /// Blocking version of [`super::VirtualMachine::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::VirtualMachine::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::VirtualMachine::resource_config`].
+        pub fn resource_config(&self) -> Result<Option<ResourceConfigSpec>> {
+            self.client.block_on(self.inner.resource_config())
+        }
+        /// Blocking version of [`super::VirtualMachine::resource_pool`].
+        pub fn resource_pool(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.resource_pool())
+        }
+        /// Blocking version of [`super::VirtualMachine::root_snapshot`].
+        pub fn root_snapshot(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.root_snapshot())
+        }
+        /// Blocking version of [`super::VirtualMachine::runtime`].
+        pub fn runtime(&self) -> Result<VirtualMachineRuntimeInfo> {
+            self.client.block_on(self.inner.runtime())
+        }
+        /// Blocking version of [`super::VirtualMachine::snapshot`].
+        pub fn snapshot(&self) -> Result<Option<VirtualMachineSnapshotInfo>> {
+            self.client.block_on(self.inner.snapshot())
+        }
+        /// Blocking version of [`super::VirtualMachine::storage`].
+        pub fn storage(&self) -> Result<Option<VirtualMachineStorageInfo>> {
+            self.client.block_on(self.inner.storage())
+        }
+        /// Blocking version of [`super::VirtualMachine::summary`].
+        pub fn summary(&self) -> Result<VirtualMachineSummary> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::VirtualMachine::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::VirtualMachine::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::VirtualMachine::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
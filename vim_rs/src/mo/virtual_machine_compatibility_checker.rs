@@ -68,7 +68,7 @@ impl VirtualMachineCompatibilityChecker {
     /// 
     /// ***DatacenterMismatch***: if the provided host and pool do not belong
     /// to the same datacenter.
-    pub async fn check_compatibility_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_compatibility_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckCompatibilityRequestType {vm, host, pool, test_type, };
         let path = format!("/VirtualMachineCompatibilityChecker/{moId}/CheckCompatibility_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -121,7 +121,7 @@ impl VirtualMachineCompatibilityChecker {
     /// 
     /// ***DatacenterMismatch***: if the provided host and pool do not belong
     /// to the same datacenter.
-    pub async fn check_power_on_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_power_on_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckPowerOnRequestType {vm, host, pool, test_type, };
         let path = format!("/VirtualMachineCompatibilityChecker/{moId}/CheckPowerOn_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -180,7 +180,7 @@ impl VirtualMachineCompatibilityChecker {
     /// 
     /// ***DatacenterMismatch***: if the provided host and pool do not belong
     /// to the same datacenter.
-    pub async fn check_vm_config_task(&self, spec: &VirtualMachineConfigSpec, vm: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_vm_config_task(&self, spec: &VirtualMachineConfigSpec, vm: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckVmConfigRequestType {spec, vm, host, pool, test_type, };
         let path = format!("/VirtualMachineCompatibilityChecker/{moId}/CheckVmConfig_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -197,7 +197,7 @@ struct CheckCompatibilityRequestType<'a> {
     pool: Option<&'a ManagedObjectReference>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -209,7 +209,7 @@ struct CheckPowerOnRequestType<'a> {
     pool: Option<&'a ManagedObjectReference>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -223,5 +223,38 @@ struct CheckVmConfigRequestType<'a> {
     pool: Option<&'a ManagedObjectReference>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
+}
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualMachineConfigSpec;
+    /// Blocking version of [`super::VirtualMachineCompatibilityChecker`].
+    pub struct VirtualMachineCompatibilityChecker {
+        inner: super::VirtualMachineCompatibilityChecker,
+        client: Arc<Client>,
+    }
+    impl VirtualMachineCompatibilityChecker {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualMachineCompatibilityChecker::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualMachineCompatibilityChecker::check_compatibility_task`].
+        pub fn check_compatibility_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_compatibility_task(vm, host, pool, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineCompatibilityChecker::check_power_on_task`].
+        pub fn check_power_on_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_power_on_task(vm, host, pool, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineCompatibilityChecker::check_vm_config_task`].
+        pub fn check_vm_config_task(&self, spec: &VirtualMachineConfigSpec, vm: Option<&ManagedObjectReference>, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_vm_config_task(spec, vm, host, pool, test_type))
+        }
+    }
 }
@@ -203,3 +203,36 @@ struct StartGuestNetworkRequestType<'a> {
     vm: &'a ManagedObjectReference,
     auth: &'a dyn crate::types::traits::GuestAuthenticationTrait,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomizationSpec;
+    use crate::types::structs::ManagedObjectReference;
+    /// Blocking version of [`super::VirtualMachineGuestCustomizationManager`].
+    pub struct VirtualMachineGuestCustomizationManager {
+        inner: super::VirtualMachineGuestCustomizationManager,
+        client: Arc<Client>,
+    }
+    impl VirtualMachineGuestCustomizationManager {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualMachineGuestCustomizationManager::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualMachineGuestCustomizationManager::abort_customization_task`].
+        pub fn abort_customization_task(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.abort_customization_task(vm, auth))
+        }
+        /// Blocking version of [`super::VirtualMachineGuestCustomizationManager::customize_guest_task`].
+        pub fn customize_guest_task(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait, spec: &CustomizationSpec, config_params: Option<&[Box<dyn crate::types::traits::OptionValueTrait>]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.customize_guest_task(vm, auth, spec, config_params))
+        }
+        /// Blocking version of [`super::VirtualMachineGuestCustomizationManager::start_guest_network_task`].
+        pub fn start_guest_network_task(&self, vm: &ManagedObjectReference, auth: &dyn crate::types::traits::GuestAuthenticationTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.start_guest_network_task(vm, auth))
+        }
+    }
+}
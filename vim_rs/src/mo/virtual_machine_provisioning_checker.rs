@@ -71,7 +71,7 @@ impl VirtualMachineProvisioningChecker {
     /// ***InvalidState***: if the operation cannot be performed because of the
     /// virtual machine's current state. For example, if the virtual machine
     /// configuration information is not available.
-    pub async fn check_clone_task(&self, vm: &ManagedObjectReference, folder: &ManagedObjectReference, name: &str, spec: &VirtualMachineCloneSpec, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_clone_task(&self, vm: &ManagedObjectReference, folder: &ManagedObjectReference, name: &str, spec: &VirtualMachineCloneSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckCloneRequestType {vm, folder, name, spec, test_type, };
         let path = format!("/VirtualMachineProvisioningChecker/{moId}/CheckClone_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -116,7 +116,7 @@ impl VirtualMachineProvisioningChecker {
     /// ***InvalidState***: if the operation cannot be performed because of the
     /// virtual machine's current state. For example, if the virtual
     /// machine configuration information is not available.
-    pub async fn check_instant_clone_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineInstantCloneSpec, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_instant_clone_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineInstantCloneSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckInstantCloneRequestType {vm, spec, test_type, };
         let path = format!("/VirtualMachineProvisioningChecker/{moId}/CheckInstantClone_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -179,7 +179,7 @@ impl VirtualMachineProvisioningChecker {
     /// 
     /// ***InvalidPowerState***: if the state argument is set and at least one
     /// of the specified virtual machines is not in that power state.
-    pub async fn check_migrate_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, state: Option<crate::types::enums::VirtualMachinePowerStateEnum>, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_migrate_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, state: Option<crate::types::enums::VirtualMachinePowerStateEnum>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckMigrateRequestType {vm, host, pool, state, test_type, };
         let path = format!("/VirtualMachineProvisioningChecker/{moId}/CheckMigrate_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -241,7 +241,7 @@ impl VirtualMachineProvisioningChecker {
     /// host or virtual machine's current state. For example, if the host is in
     /// maintenance mode, or if the virtual machine's configuration information
     /// is not available.
-    pub async fn check_relocate_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineRelocateSpec, test_type: Option<&[String]>) -> Result<ManagedObjectReference> {
+    pub async fn check_relocate_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineRelocateSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
         let input = CheckRelocateRequestType {vm, spec, test_type, };
         let path = format!("/VirtualMachineProvisioningChecker/{moId}/CheckRelocate_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -290,7 +290,7 @@ struct CheckCloneRequestType<'a> {
     spec: &'a VirtualMachineCloneSpec,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -299,7 +299,7 @@ struct CheckInstantCloneRequestType<'a> {
     spec: &'a VirtualMachineInstantCloneSpec,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -313,7 +313,7 @@ struct CheckMigrateRequestType<'a> {
     state: Option<crate::types::enums::VirtualMachinePowerStateEnum>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -322,7 +322,7 @@ struct CheckRelocateRequestType<'a> {
     spec: &'a VirtualMachineRelocateSpec,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "testType")]
-    test_type: Option<&'a [String]>,
+    test_type: Option<&'a [crate::types::enums::CheckTestTypeEnum]>,
 }
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
@@ -330,3 +330,46 @@ struct QueryVMotionCompatibilityExRequestType<'a> {
     vm: &'a [ManagedObjectReference],
     host: &'a [ManagedObjectReference],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualMachineCloneSpec;
+    use crate::types::structs::VirtualMachineInstantCloneSpec;
+    use crate::types::structs::VirtualMachineRelocateSpec;
+    /// Blocking version of [`super::VirtualMachineProvisioningChecker`].
+    pub struct VirtualMachineProvisioningChecker {
+        inner: super::VirtualMachineProvisioningChecker,
+        client: Arc<Client>,
+    }
+    impl VirtualMachineProvisioningChecker {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualMachineProvisioningChecker::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualMachineProvisioningChecker::check_clone_task`].
+        pub fn check_clone_task(&self, vm: &ManagedObjectReference, folder: &ManagedObjectReference, name: &str, spec: &VirtualMachineCloneSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_clone_task(vm, folder, name, spec, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineProvisioningChecker::check_instant_clone_task`].
+        pub fn check_instant_clone_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineInstantCloneSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_instant_clone_task(vm, spec, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineProvisioningChecker::check_migrate_task`].
+        pub fn check_migrate_task(&self, vm: &ManagedObjectReference, host: Option<&ManagedObjectReference>, pool: Option<&ManagedObjectReference>, state: Option<crate::types::enums::VirtualMachinePowerStateEnum>, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_migrate_task(vm, host, pool, state, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineProvisioningChecker::check_relocate_task`].
+        pub fn check_relocate_task(&self, vm: &ManagedObjectReference, spec: &VirtualMachineRelocateSpec, test_type: Option<&[crate::types::enums::CheckTestTypeEnum]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.check_relocate_task(vm, spec, test_type))
+        }
+        /// Blocking version of [`super::VirtualMachineProvisioningChecker::query_v_motion_compatibility_ex_task`].
+        pub fn query_v_motion_compatibility_ex_task(&self, vm: &[ManagedObjectReference], host: &[ManagedObjectReference]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.query_v_motion_compatibility_ex_task(vm, host))
+        }
+    }
+}
@@ -279,3 +279,65 @@ struct SetCustomValueRequestType<'a> {
     key: &'a str,
     value: &'a str,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VirtualMachineConfigInfo;
+    /// Blocking version of [`super::VirtualMachineSnapshot`].
+    pub struct VirtualMachineSnapshot {
+        inner: super::VirtualMachineSnapshot,
+        client: Arc<Client>,
+    }
+    impl VirtualMachineSnapshot {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VirtualMachineSnapshot::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::export_snapshot`].
+        pub fn export_snapshot(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.export_snapshot())
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::remove_snapshot_task`].
+        pub fn remove_snapshot_task(&self, remove_children: bool, consolidate: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.remove_snapshot_task(remove_children, consolidate))
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::rename_snapshot`].
+        pub fn rename_snapshot(&self, name: Option<&str>, description: Option<&str>) -> Result<()> {
+            self.client.block_on(self.inner.rename_snapshot(name, description))
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::revert_to_snapshot_task`].
+        pub fn revert_to_snapshot_task(&self, host: Option<&ManagedObjectReference>, suppress_power_on: Option<bool>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.revert_to_snapshot_task(host, suppress_power_on))
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::child_snapshot`].
+        pub fn child_snapshot(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.child_snapshot())
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::config`].
+        pub fn config(&self) -> Result<VirtualMachineConfigInfo> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+        /// Blocking version of [`super::VirtualMachineSnapshot::vm`].
+        pub fn vm(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.vm())
+        }
+    }
+}
@@ -400,7 +400,7 @@ impl VmwareDistributedVirtualSwitch {
     /// ***DvsNotAuthorized***: if login-session's extension key does not match
     /// the switch's configured
     /// *extensionKey*.
-    pub async fn perform_dvs_product_spec_operation_task(&self, operation: &str, product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<ManagedObjectReference> {
+    pub async fn perform_dvs_product_spec_operation_task(&self, operation: crate::types::enums::DistributedVirtualSwitchProductSpecOperationTypeEnum, product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<ManagedObjectReference> {
         let input = PerformDvsProductSpecOperationRequestType {operation, product_spec, };
         let path = format!("/VmwareDistributedVirtualSwitch/{moId}/PerformDvsProductSpecOperation_Task", moId = &self.mo_id);
         let req = self.client.post_request(&path, &input);
@@ -1305,7 +1305,7 @@ struct MoveDvPortRequestType<'a> {
 #[derive(serde::Serialize)]
 #[serde(tag="_typeName")]
 struct PerformDvsProductSpecOperationRequestType<'a> {
-    operation: &'a str,
+    operation: crate::types::enums::DistributedVirtualSwitchProductSpecOperationTypeEnum,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "productSpec")]
     product_spec: Option<&'a DistributedVirtualSwitchProductSpec>,
@@ -1386,3 +1386,237 @@ struct UpdateNetworkResourcePoolRequestType<'a> {
     #[serde(rename = "configSpec")]
     config_spec: &'a [DvsNetworkResourcePoolConfigSpec],
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::AlarmState;
+    use crate::types::structs::CustomFieldDef;
+    use crate::types::structs::DistributedVirtualPort;
+    use crate::types::structs::DistributedVirtualSwitchPortCriteria;
+    use crate::types::structs::DistributedVirtualSwitchProductSpec;
+    use crate::types::structs::DvPortConfigSpec;
+    use crate::types::structs::DvPortgroupConfigSpec;
+    use crate::types::structs::DvsCapability;
+    use crate::types::structs::DvsNetworkResourcePool;
+    use crate::types::structs::DvsNetworkResourcePoolConfigSpec;
+    use crate::types::structs::DvsRuntimeInfo;
+    use crate::types::structs::DvsSummary;
+    use crate::types::structs::DvsVmVnicResourcePoolConfigSpec;
+    use crate::types::structs::EntityBackupConfig;
+    use crate::types::structs::Event;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::Permission;
+    use crate::types::structs::Tag;
+    use crate::types::structs::VMwareDvsLacpGroupSpec;
+    /// Blocking version of [`super::VmwareDistributedVirtualSwitch`].
+    pub struct VmwareDistributedVirtualSwitch {
+        inner: super::VmwareDistributedVirtualSwitch,
+        client: Arc<Client>,
+    }
+    impl VmwareDistributedVirtualSwitch {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VmwareDistributedVirtualSwitch::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::add_network_resource_pool`].
+        pub fn add_network_resource_pool(&self, config_spec: &[DvsNetworkResourcePoolConfigSpec]) -> Result<()> {
+            self.client.block_on(self.inner.add_network_resource_pool(config_spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::create_dv_portgroup_task`].
+        pub fn create_dv_portgroup_task(&self, spec: &DvPortgroupConfigSpec) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.create_dv_portgroup_task(spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::add_dv_portgroup_task`].
+        pub fn add_dv_portgroup_task(&self, spec: &[DvPortgroupConfigSpec]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.add_dv_portgroup_task(spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::destroy_task`].
+        pub fn destroy_task(&self) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.destroy_task())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::enable_network_resource_management`].
+        pub fn enable_network_resource_management(&self, enable: bool) -> Result<()> {
+            self.client.block_on(self.inner.enable_network_resource_management(enable))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::fetch_dv_port_keys`].
+        pub fn fetch_dv_port_keys(&self, criteria: Option<&DistributedVirtualSwitchPortCriteria>) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.fetch_dv_port_keys(criteria))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::fetch_dv_ports`].
+        pub fn fetch_dv_ports(&self, criteria: Option<&DistributedVirtualSwitchPortCriteria>) -> Result<Option<Vec<DistributedVirtualPort>>> {
+            self.client.block_on(self.inner.fetch_dv_ports(criteria))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::lookup_dv_port_group`].
+        pub fn lookup_dv_port_group(&self, portgroup_key: &str) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.lookup_dv_port_group(portgroup_key))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::merge_dvs_task`].
+        pub fn merge_dvs_task(&self, dvs: &ManagedObjectReference) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.merge_dvs_task(dvs))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::move_dv_port_task`].
+        pub fn move_dv_port_task(&self, port_key: &[String], destination_portgroup_key: Option<&str>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.move_dv_port_task(port_key, destination_portgroup_key))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::perform_dvs_product_spec_operation_task`].
+        pub fn perform_dvs_product_spec_operation_task(&self, operation: crate::types::enums::DistributedVirtualSwitchProductSpecOperationTypeEnum, product_spec: Option<&DistributedVirtualSwitchProductSpec>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.perform_dvs_product_spec_operation_task(operation, product_spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::query_used_vlan_id_in_dvs`].
+        pub fn query_used_vlan_id_in_dvs(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.query_used_vlan_id_in_dvs())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::reconfigure_dvs_task`].
+        pub fn reconfigure_dvs_task(&self, spec: &dyn crate::types::traits::DvsConfigSpecTrait) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_dvs_task(spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::reconfigure_dv_port_task`].
+        pub fn reconfigure_dv_port_task(&self, port: &[DvPortConfigSpec]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.reconfigure_dv_port_task(port))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::dvs_reconfigure_vm_vnic_network_resource_pool_task`].
+        pub fn dvs_reconfigure_vm_vnic_network_resource_pool_task(&self, config_spec: &[DvsVmVnicResourcePoolConfigSpec]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.dvs_reconfigure_vm_vnic_network_resource_pool_task(config_spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::rectify_dvs_host_task`].
+        pub fn rectify_dvs_host_task(&self, hosts: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rectify_dvs_host_task(hosts))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::refresh_dv_port_state`].
+        pub fn refresh_dv_port_state(&self, port_keys: Option<&[String]>) -> Result<()> {
+            self.client.block_on(self.inner.refresh_dv_port_state(port_keys))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::reload`].
+        pub fn reload(&self) -> Result<()> {
+            self.client.block_on(self.inner.reload())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::remove_network_resource_pool`].
+        pub fn remove_network_resource_pool(&self, key: &[String]) -> Result<()> {
+            self.client.block_on(self.inner.remove_network_resource_pool(key))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::rename_task`].
+        pub fn rename_task(&self, new_name: &str) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.rename_task(new_name))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::dvs_rollback_task`].
+        pub fn dvs_rollback_task(&self, entity_backup: Option<&EntityBackupConfig>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.dvs_rollback_task(entity_backup))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::set_custom_value`].
+        pub fn set_custom_value(&self, key: &str, value: &str) -> Result<()> {
+            self.client.block_on(self.inner.set_custom_value(key, value))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::update_dvs_capability`].
+        pub fn update_dvs_capability(&self, capability: &DvsCapability) -> Result<()> {
+            self.client.block_on(self.inner.update_dvs_capability(capability))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::update_dvs_health_check_config_task`].
+        pub fn update_dvs_health_check_config_task(&self, health_check_config: &[Box<dyn crate::types::traits::DvsHealthCheckConfigTrait>]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_dvs_health_check_config_task(health_check_config))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::update_dvs_lacp_group_config_task`].
+        pub fn update_dvs_lacp_group_config_task(&self, lacp_group_spec: &[VMwareDvsLacpGroupSpec]) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.update_dvs_lacp_group_config_task(lacp_group_spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::update_network_resource_pool`].
+        pub fn update_network_resource_pool(&self, config_spec: &[DvsNetworkResourcePoolConfigSpec]) -> Result<()> {
+            self.client.block_on(self.inner.update_network_resource_pool(config_spec))
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::alarm_actions_enabled`].
+        pub fn alarm_actions_enabled(&self) -> Result<Option<bool>> {
+            self.client.block_on(self.inner.alarm_actions_enabled())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::available_field`].
+        pub fn available_field(&self) -> Result<Option<Vec<CustomFieldDef>>> {
+            self.client.block_on(self.inner.available_field())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::capability`].
+        pub fn capability(&self) -> Result<DvsCapability> {
+            self.client.block_on(self.inner.capability())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::config`].
+        pub fn config(&self) -> Result<Box<dyn crate::types::traits::DvsConfigInfoTrait>> {
+            self.client.block_on(self.inner.config())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::config_issue`].
+        pub fn config_issue(&self) -> Result<Option<Vec<Event>>> {
+            self.client.block_on(self.inner.config_issue())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::config_status`].
+        pub fn config_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.config_status())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::custom_value`].
+        pub fn custom_value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.custom_value())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::declared_alarm_state`].
+        pub fn declared_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.declared_alarm_state())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::disabled_method`].
+        pub fn disabled_method(&self) -> Result<Option<Vec<String>>> {
+            self.client.block_on(self.inner.disabled_method())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::effective_role`].
+        pub fn effective_role(&self) -> Result<Option<Vec<i32>>> {
+            self.client.block_on(self.inner.effective_role())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::name`].
+        pub fn name(&self) -> Result<String> {
+            self.client.block_on(self.inner.name())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::network_resource_pool`].
+        pub fn network_resource_pool(&self) -> Result<Option<Vec<DvsNetworkResourcePool>>> {
+            self.client.block_on(self.inner.network_resource_pool())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::overall_status`].
+        pub fn overall_status(&self) -> Result<crate::types::enums::ManagedEntityStatusEnum> {
+            self.client.block_on(self.inner.overall_status())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::parent`].
+        pub fn parent(&self) -> Result<Option<ManagedObjectReference>> {
+            self.client.block_on(self.inner.parent())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::permission`].
+        pub fn permission(&self) -> Result<Option<Vec<Permission>>> {
+            self.client.block_on(self.inner.permission())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::portgroup`].
+        pub fn portgroup(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.portgroup())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::recent_task`].
+        pub fn recent_task(&self) -> Result<Option<Vec<ManagedObjectReference>>> {
+            self.client.block_on(self.inner.recent_task())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::runtime`].
+        pub fn runtime(&self) -> Result<Option<DvsRuntimeInfo>> {
+            self.client.block_on(self.inner.runtime())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::summary`].
+        pub fn summary(&self) -> Result<DvsSummary> {
+            self.client.block_on(self.inner.summary())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::tag`].
+        pub fn tag(&self) -> Result<Option<Vec<Tag>>> {
+            self.client.block_on(self.inner.tag())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::triggered_alarm_state`].
+        pub fn triggered_alarm_state(&self) -> Result<Option<Vec<AlarmState>>> {
+            self.client.block_on(self.inner.triggered_alarm_state())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::uuid`].
+        pub fn uuid(&self) -> Result<String> {
+            self.client.block_on(self.inner.uuid())
+        }
+        /// Blocking version of [`super::VmwareDistributedVirtualSwitch::value`].
+        pub fn value(&self) -> Result<Option<Vec<Box<dyn crate::types::traits::CustomFieldValueTrait>>>> {
+            self.client.block_on(self.inner.value())
+        }
+    }
+}
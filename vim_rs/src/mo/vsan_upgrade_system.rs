@@ -190,3 +190,37 @@ struct PerformVsanUpgradePreflightCheckRequestType<'a> {
 struct QueryVsanUpgradeStatusRequestType<'a> {
     cluster: &'a ManagedObjectReference,
 }
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use std::sync::Arc;
+    use crate::core::blocking::Client;
+    use crate::core::client::Result;
+    use crate::types::structs::ManagedObjectReference;
+    use crate::types::structs::VsanUpgradeSystemPreflightCheckResult;
+    use crate::types::structs::VsanUpgradeSystemUpgradeStatus;
+    /// Blocking version of [`super::VsanUpgradeSystem`].
+    pub struct VsanUpgradeSystem {
+        inner: super::VsanUpgradeSystem,
+        client: Arc<Client>,
+    }
+    impl VsanUpgradeSystem {
+        pub fn new(client: Arc<Client>, mo_id: &str) -> Self {
+            Self {
+                inner: super::VsanUpgradeSystem::new(client.inner().clone(), mo_id),
+                client,
+            }
+        }
+        /// Blocking version of [`super::VsanUpgradeSystem::perform_vsan_upgrade_task`].
+        pub fn perform_vsan_upgrade_task(&self, cluster: &ManagedObjectReference, perform_object_upgrade: Option<bool>, downgrade_format: Option<bool>, allow_reduced_redundancy: Option<bool>, exclude_hosts: Option<&[ManagedObjectReference]>) -> Result<ManagedObjectReference> {
+            self.client.block_on(self.inner.perform_vsan_upgrade_task(cluster, perform_object_upgrade, downgrade_format, allow_reduced_redundancy, exclude_hosts))
+        }
+        /// Blocking version of [`super::VsanUpgradeSystem::perform_vsan_upgrade_preflight_check`].
+        pub fn perform_vsan_upgrade_preflight_check(&self, cluster: &ManagedObjectReference, downgrade_format: Option<bool>) -> Result<VsanUpgradeSystemPreflightCheckResult> {
+            self.client.block_on(self.inner.perform_vsan_upgrade_preflight_check(cluster, downgrade_format))
+        }
+        /// Blocking version of [`super::VsanUpgradeSystem::query_vsan_upgrade_status`].
+        pub fn query_vsan_upgrade_status(&self, cluster: &ManagedObjectReference) -> Result<VsanUpgradeSystemUpgradeStatus> {
+            self.client.block_on(self.inner.query_vsan_upgrade_status(cluster))
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// Error returned by the generated builders when a required field is not set.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Missing required field {field} of {type_name}")]
+pub struct BuilderError {
+    /// Name of the data type being built.
+    pub type_name: &'static str,
+    /// Name of the missing property.
+    pub field: &'static str,
+}
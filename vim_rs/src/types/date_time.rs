@@ -0,0 +1,217 @@
+//! Date and time values. VIM `xsd:dateTime` properties are represented by [`DateTime`]. By default
+//! it is a `String` holding the value as sent by the server. With the `chrono` feature it is
+//! `chrono::DateTime<chrono::Utc>` and with the `time` feature `time::OffsetDateTime` in UTC. If
+//! both features are enabled `chrono` is used.
+//!
+//! Typed values are parsed leniently: RFC 3339 timestamps with `Z` or a numeric offset and any
+//! number of fractional second digits are accepted as well as timestamps without an offset which
+//! are taken as UTC. Typed values are sent in RFC 3339 format.
+
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type DateTime = time::OffsetDateTime;
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type DateTime = String;
+
+/// Borrowed [`DateTime`] as returned by the getters of required properties and taken by method
+/// parameters. It is `str` by default and [`DateTime`] with the `chrono` or `time` feature.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub type DateTimeRef = DateTime;
+
+/// Borrowed [`DateTime`] as returned by the getters of required properties and taken by method
+/// parameters. It is `str` by default and [`DateTime`] with the `chrono` or `time` feature.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type DateTimeRef = str;
+
+/// Error parsing a date and time value.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid date and time value: {0}")]
+pub struct ParseError(pub String);
+
+/// Parse a date and time value in one of the formats sent by vCenter.
+#[cfg(feature = "chrono")]
+pub fn parse(text: &str) -> Result<DateTime, ParseError> {
+    if let Ok(value) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Ok(value.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|value| value.and_utc())
+        .map_err(|_| ParseError(text.to_string()))
+}
+
+/// Parse a date and time value in one of the formats sent by vCenter.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub fn parse(text: &str) -> Result<DateTime, ParseError> {
+    use time::format_description::well_known::Rfc3339;
+    if let Ok(value) = time::OffsetDateTime::parse(text, &Rfc3339) {
+        return Ok(value.to_offset(time::UtcOffset::UTC));
+    }
+    let format = time::macros::format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]"
+    );
+    time::PrimitiveDateTime::parse(text, format)
+        .map(|value| value.assume_utc())
+        .map_err(|_| ParseError(text.to_string()))
+}
+
+/// Values are kept as sent by the server.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub fn parse(text: &str) -> Result<DateTime, ParseError> {
+    Ok(text.to_string())
+}
+
+/// Format a date and time value for the server.
+#[cfg(feature = "chrono")]
+pub fn format(value: &DateTime) -> String {
+    value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+}
+
+/// Format a date and time value for the server. Years outside of the RFC 3339 range are
+/// formatted in the `time` default format.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub fn format(value: &DateTime) -> String {
+    value
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_else(|_| value.to_string())
+}
+
+/// Values are sent as they are.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub fn format(value: &DateTime) -> String {
+    value.clone()
+}
+
+/// Format a borrowed date and time value for the server.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn format_ref(value: &DateTimeRef) -> String {
+    format(value)
+}
+
+/// Values are sent as they are.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn format_ref(value: &DateTimeRef) -> String {
+    value.to_string()
+}
+
+// The parameter serializers are used by the request types of the generated methods and of calls
+// made with `Client::post_request` e.g. `#[serde(serialize_with = "vim_rs::types::date_time::serialize_param")]`.
+
+/// Serialize a date and time method parameter in the wire format.
+pub fn serialize_param<S: serde::Serializer>(value: &&DateTimeRef, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_ref(value))
+}
+
+/// Serialize an optional date and time method parameter in the wire format.
+pub fn serialize_option_param<S: serde::Serializer>(value: &Option<&DateTimeRef>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(&format_ref(value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a date and time array method parameter in the wire format.
+pub fn serialize_array_param<S: serde::Serializer>(values: &&[DateTime], serializer: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&SerializeDateTimes { values }, serializer)
+}
+
+/// Serialize an optional date and time array method parameter in the wire format.
+pub fn serialize_option_array_param<S: serde::Serializer>(values: &Option<&[DateTime]>, serializer: S) -> Result<S::Ok, S::Error> {
+    match values {
+        Some(values) => serializer.serialize_some(&SerializeDateTimes { values }),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a date and time value in the wire format.
+pub(crate) struct SerializeDateTime<'a> {
+    pub value: &'a DateTime,
+}
+
+impl serde::Serialize for SerializeDateTime<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format(self.value))
+    }
+}
+
+/// Deserialize a date and time value from the wire format.
+pub(crate) struct DeserializeDateTime {
+    pub value: DateTime,
+}
+
+impl<'de> serde::Deserialize<'de> for DeserializeDateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse(&text)
+            .map(|value| DeserializeDateTime { value })
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialize an array of date and time values in the wire format.
+pub(crate) struct SerializeDateTimes<'a> {
+    pub values: &'a [DateTime],
+}
+
+impl serde::Serialize for SerializeDateTimes<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.values.iter().map(|value| SerializeDateTime { value }))
+    }
+}
+
+/// Deserialize an array of date and time values from the wire format.
+pub(crate) struct DeserializeDateTimes {
+    pub values: Vec<DateTime>,
+}
+
+impl<'de> serde::Deserialize<'de> for DeserializeDateTimes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<DeserializeDateTime>::deserialize(deserializer)?;
+        Ok(DeserializeDateTimes {
+            values: values.into_iter().map(|value| value.value).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn test_parse_formats() {
+        let expected = parse("2024-03-01T10:20:30Z").unwrap();
+        assert_eq!(parse("2024-03-01T10:20:30").unwrap(), expected);
+        assert_eq!(parse("2024-03-01T12:20:30+02:00").unwrap(), expected);
+        let fractional = parse("2024-03-01T10:20:30.123456Z").unwrap();
+        assert_eq!(parse("2024-03-01T10:20:30.123456").unwrap(), fractional);
+        assert_eq!(format(&fractional), "2024-03-01T10:20:30.123456Z");
+        assert_eq!(format(&expected), "2024-03-01T10:20:30Z");
+        assert!(parse("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_serialize_params() {
+        let value = parse("2024-03-01T10:20:30Z").unwrap();
+        let mut serializer = serde_json::Serializer::new(Vec::new());
+        serialize_param(&value_ref(&value), &mut serializer).unwrap();
+        assert_eq!(serializer.into_inner(), br#""2024-03-01T10:20:30Z""#);
+        let mut serializer = serde_json::Serializer::new(Vec::new());
+        serialize_option_array_param(&Some(&[value][..]), &mut serializer).unwrap();
+        assert_eq!(serializer.into_inner(), br#"["2024-03-01T10:20:30Z"]"#);
+    }
+
+    fn value_ref(value: &DateTime) -> &DateTimeRef {
+        value
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let json = r#"["2024-03-01T10:20:30Z"]"#;
+        let values: DeserializeDateTimes = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&SerializeDateTimes { values: &values.values }).unwrap();
+        assert_eq!(serialized, json);
+    }
+}
@@ -0,0 +1,176 @@
+use serde_json::{Map, Value};
+
+/// Kind of change of a property between two versions of a data object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The property is set in the new object only.
+    Added,
+    /// The property is set in the old object only.
+    Removed,
+    /// The property value or data type differs.
+    Modified,
+}
+
+/// A changed property reported by [`diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDiff {
+    /// Property path in VIM dotted notation e.g. `config.hardware.device[4000].backing.fileName`.
+    /// Array elements with a `key` property are addressed by key, others by index. String keys
+    /// are quoted as in property collector paths e.g. `config.extraConfig["guestinfo.ip"]`.
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// Compare two data objects and report the changed property paths. Nested objects are walked
+/// down to the changed leaf properties unless their data type changed in which case the object
+/// itself is reported as modified.
+///
+/// Example: `let changes = diff(&old_config, &new_config)?;`
+pub fn diff<T: serde::Serialize + ?Sized>(old: &T, new: &T) -> serde_json::Result<Vec<PropertyDiff>> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+    let mut changes = Vec::new();
+    diff_values("", &old, &new, &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<PropertyDiff>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) if old.get("_typeName") == new.get("_typeName") => {
+            diff_objects(path, old, new, changes)
+        }
+        (Value::Array(old), Value::Array(new)) => diff_arrays(path, old, new, changes),
+        (old, new) if old != new => changes.push(PropertyDiff { path: path.to_string(), kind: ChangeKind::Modified }),
+        _ => {}
+    }
+}
+
+fn diff_objects(path: &str, old: &Map<String, Value>, new: &Map<String, Value>, changes: &mut Vec<PropertyDiff>) {
+    for (name, old_value) in old {
+        if name == "_typeName" {
+            continue;
+        }
+        let property_path = if path.is_empty() { name.clone() } else { format!("{path}.{name}") };
+        match new.get(name) {
+            Some(new_value) => diff_values(&property_path, old_value, new_value, changes),
+            None => changes.push(PropertyDiff { path: property_path, kind: ChangeKind::Removed }),
+        }
+    }
+    for name in new.keys() {
+        if !old.contains_key(name) {
+            let property_path = if path.is_empty() { name.clone() } else { format!("{path}.{name}") };
+            changes.push(PropertyDiff { path: property_path, kind: ChangeKind::Added });
+        }
+    }
+}
+
+fn diff_arrays(path: &str, old: &[Value], new: &[Value], changes: &mut Vec<PropertyDiff>) {
+    let (Some(old_keys), Some(new_keys)) = (element_keys(old), element_keys(new)) else {
+        for (index, (old_value, new_value)) in old.iter().zip(new).enumerate() {
+            diff_values(&format!("{path}[{index}]"), old_value, new_value, changes);
+        }
+        for index in new.len()..old.len() {
+            changes.push(PropertyDiff { path: format!("{path}[{index}]"), kind: ChangeKind::Removed });
+        }
+        for index in old.len()..new.len() {
+            changes.push(PropertyDiff { path: format!("{path}[{index}]"), kind: ChangeKind::Added });
+        }
+        return;
+    };
+    for (key, old_value) in old_keys.iter().zip(old) {
+        let element_path = format!("{path}[{key}]");
+        match new_keys.iter().position(|new_key| new_key == key) {
+            Some(index) => diff_values(&element_path, old_value, &new[index], changes),
+            None => changes.push(PropertyDiff { path: element_path, kind: ChangeKind::Removed }),
+        }
+    }
+    for key in &new_keys {
+        if !old_keys.contains(key) {
+            changes.push(PropertyDiff { path: format!("{path}[{key}]"), kind: ChangeKind::Added });
+        }
+    }
+}
+
+/// Keys of array elements if all elements are data objects with a scalar `key` property. The keys
+/// are rendered as path selectors.
+fn element_keys(values: &[Value]) -> Option<Vec<String>> {
+    values.iter().map(|value| element_key(value).map(key_selector)).collect()
+}
+
+/// Key of an array element if it is a data object with a scalar `key` property.
+pub(crate) fn element_key(value: &Value) -> Option<&Value> {
+    match value.get("key")? {
+        key @ (Value::String(_) | Value::Number(_) | Value::Bool(_)) => Some(key),
+        _ => None,
+    }
+}
+
+/// Render an element key as a path selector. String keys are quoted so that keys containing `.`
+/// or `]` can be parsed back.
+fn key_selector(key: &Value) -> String {
+    key.to_string()
+}
+
+/// Check an element key against a parsed selector i.e. with the quotes of string keys removed.
+pub(crate) fn key_matches(key: &Value, selector: &str) -> bool {
+    match key {
+        Value::String(key) => key == selector,
+        key => key_selector(key) == selector,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn change(path: &str, kind: ChangeKind) -> PropertyDiff {
+        PropertyDiff { path: path.to_string(), kind }
+    }
+
+    #[test]
+    fn test_diff_keyed_devices() {
+        let old = json!({"config": {"hardware": {"numCPU": 2, "device": [
+            {"_typeName": "VirtualDisk", "key": 2000, "backing": {"_typeName": "VirtualDiskFlatVer2BackingInfo", "fileName": "[ds] a.vmdk"}},
+            {"_typeName": "VirtualE1000", "key": 4000, "macAddress": "00:50:56:00:00:01"}
+        ]}}});
+        let new = json!({"config": {"hardware": {"numCPU": 2, "device": [
+            {"_typeName": "VirtualE1000", "key": 4000, "macAddress": "00:50:56:00:00:02"},
+            {"_typeName": "VirtualDisk", "key": 2001, "backing": {"_typeName": "VirtualDiskFlatVer2BackingInfo", "fileName": "[ds] b.vmdk"}}
+        ]}}});
+        assert_eq!(diff(&old, &new).unwrap(), vec![
+            change("config.hardware.device[2000]", ChangeKind::Removed),
+            change("config.hardware.device[4000].macAddress", ChangeKind::Modified),
+            change("config.hardware.device[2001]", ChangeKind::Added),
+        ]);
+    }
+
+    #[test]
+    fn test_diff_type_change_and_index() {
+        let old = json!({"backing": {"_typeName": "VirtualDiskFlatVer2BackingInfo", "fileName": "a"}, "tags": ["x", "y"]});
+        let new = json!({"backing": {"_typeName": "VirtualDiskRawDiskMappingVer1BackingInfo", "fileName": "a"}, "tags": ["x"], "name": "vm"});
+        assert_eq!(diff(&old, &new).unwrap(), vec![
+            change("backing", ChangeKind::Modified),
+            change("tags[1]", ChangeKind::Removed),
+            change("name", ChangeKind::Added),
+        ]);
+        assert!(diff(&old, &old).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_string_keys() {
+        let old = json!({"extraConfig": [
+            {"_typeName": "OptionValue", "key": "guestinfo.ip", "value": "10.0.0.1"},
+            {"_typeName": "OptionValue", "key": "1", "value": "a"}
+        ]});
+        let new = json!({"extraConfig": [
+            {"_typeName": "OptionValue", "key": "guestinfo.ip", "value": "10.0.0.2"},
+            {"_typeName": "OptionValue", "key": "say \"hi\"", "value": "b"}
+        ]});
+        assert_eq!(diff(&old, &new).unwrap(), vec![
+            change(r#"extraConfig["guestinfo.ip"].value"#, ChangeKind::Modified),
+            change(r#"extraConfig["1"]"#, ChangeKind::Removed),
+            change(r#"extraConfig["say \"hi\""]"#, ChangeKind::Added),
+        ]);
+    }
+}
@@ -0,0 +1,86 @@
+//! Conversion of VIM objects to and from `serde_json::Value`.
+//!
+//! The JSON representation is the wire format of the VIM JSON API. Data objects carry their
+//! `_typeName` discriminator and boxed values in `VimAny` use the `_typeName` and `_value` pair so
+//! that the conversion is lossless. Polymorphic values are decoded according to the expected type
+//! e.g. `from_value::<Box<dyn VirtualDeviceTrait>>(value)` accepts any virtual device while
+//! `from_value::<VimAny>(value)` accepts any VIM value.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Convert a VIM object, `VimAny` or trait object to its JSON wire representation.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Value> {
+    serde_json::to_value(value)
+}
+
+/// Convert a JSON wire representation to a VIM object, `VimAny` or trait object.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> serde_json::Result<T> {
+    serde_json::from_value(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::vim_any::VimAny;
+    use crate::types::vim_object_trait::VimObjectTrait;
+    #[cfg(feature = "reflection")]
+    use crate::types::reflection::{self, DataType, TypeInfo};
+    use serde_json::json;
+    #[cfg(feature = "reflection")]
+    use serde_json::Map;
+
+    /// Sample wire value of a data type with all fields set on the top level object and the
+    /// required fields set on nested objects.
+    #[cfg(feature = "reflection")]
+    fn sample(info: &TypeInfo, nested: bool) -> Value {
+        let mut properties = Map::new();
+        properties.insert("_typeName".to_string(), info.name.into());
+        for field in info.all_fields() {
+            if field.optional && nested {
+                continue;
+            }
+            let value = sample_value(field.data_type);
+            let value = if field.array { Value::Array(vec![value]) } else { value };
+            properties.insert(field.name.to_string(), value);
+        }
+        Value::Object(properties)
+    }
+
+    #[cfg(feature = "reflection")]
+    fn sample_value(data_type: DataType) -> Value {
+        match data_type {
+            DataType::Boolean => true.into(),
+            DataType::String | DataType::Enum(_) => "sample".into(),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => 1.into(),
+            DataType::Float | DataType::Double => 1.5.into(),
+            DataType::DateTime => "2024-01-01T00:00:00Z".into(),
+            DataType::Binary => "AQI=".into(),
+            DataType::Any => json!({"_typeName": "boolean", "_value": true}),
+            DataType::Struct(struct_type) => sample(struct_type.type_info(), true),
+        }
+    }
+
+    #[cfg(feature = "reflection")]
+    #[test]
+    fn test_round_trip_all_types() {
+        for info in reflection::types() {
+            let value = sample(info, false);
+            let any: VimAny = from_value(value.clone()).unwrap_or_else(|e| panic!("{}: {}", info.name, e));
+            assert_eq!(to_value(&any).unwrap(), value, "{}", info.name);
+            if let VimAny::Object(object) = any {
+                let object: Box<dyn VimObjectTrait> = from_value(to_value(&object).unwrap()).unwrap();
+                assert_eq!(to_value(&object).unwrap(), value, "{}", info.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_typed_json() {
+        let any = VimAny::from_typed_json("boolean", json!(true)).unwrap();
+        assert_eq!(to_value(&any).unwrap(), json!({"_typeName": "boolean", "_value": true}));
+        let any = VimAny::from_typed_json("ManagedObjectReference", json!({"type": "Folder", "value": "group-d1"})).unwrap();
+        assert_eq!(to_value(&any).unwrap(), json!({"_typeName": "ManagedObjectReference", "type": "Folder", "value": "group-d1"}));
+        assert!(from_value::<Box<dyn VimObjectTrait>>(json!({"_typeName": "boolean", "_value": true})).is_err());
+    }
+}
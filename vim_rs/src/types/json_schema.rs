@@ -0,0 +1,267 @@
+//! JSON Schema (draft 2020-12) of the VIM data types and validation of VI/JSON documents against
+//! it. The schema is generated by vim_build next to the crate sources in `schema/vim.schema.json`
+//! and can be fed to editors and other tooling as well.
+//!
+//! Each data type `S` is described by `#/$defs/S`. Types with descendants also have
+//! `#/$defs/STrait` that requires `_typeName` and validates the object as the type it names.
+//!
+//! Example: `let errors = json_schema::validate(StructType::VirtualMachineConfigSpec, &value);`
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+use super::struct_enum::StructType;
+
+/// The schema document as generated by vim_build.
+pub const SCHEMA: &str = include_str!("../../schema/vim.schema.json");
+
+/// Suffix of the definitions accepting a data type or any of its descendants.
+const TRAIT_SUFFIX: &str = "Trait";
+
+/// Validation failure at a location of the validated document.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{}: {message}", if pointer.is_empty() { "/" } else { pointer })]
+pub struct ValidationError {
+    /// JSON Pointer of the offending value e.g. `/deviceChange/0/device/key`. Empty for the
+    /// document itself.
+    pub pointer: String,
+    pub message: String,
+}
+
+/// The parsed schema document.
+pub fn schema() -> &'static Value {
+    static DOCUMENT: OnceLock<Value> = OnceLock::new();
+    DOCUMENT.get_or_init(|| serde_json::from_str(SCHEMA).expect("vim.schema.json is generated as valid JSON"))
+}
+
+/// Reference to the definition accepting `struct_type` as a field of that type would i.e.
+/// including its descendants e.g. `#/$defs/VirtualDeviceTrait`.
+pub fn definition_ref(struct_type: StructType) -> String {
+    let name: &str = struct_type.into();
+    let trait_name = format!("{name}{TRAIT_SUFFIX}");
+    if schema()["$defs"].get(&trait_name).is_some() {
+        format!("#/$defs/{trait_name}")
+    } else {
+        format!("#/$defs/{name}")
+    }
+}
+
+/// Validate `value` as a `struct_type` object or one of its descendants. Returns all failures,
+/// empty if the value is valid.
+pub fn validate(struct_type: StructType, value: &Value) -> Vec<ValidationError> {
+    let mut validator = Validator { root: schema(), errors: vec![] };
+    validator.validate_ref(&definition_ref(struct_type), value, &mut String::new());
+    validator.errors
+}
+
+/// Check that `value` is valid for `struct_type`.
+pub fn is_valid(struct_type: StructType, value: &Value) -> bool {
+    validate(struct_type, value).is_empty()
+}
+
+/// Interpreter of the keywords vim_build emits. Annotations such as `format` and
+/// `contentEncoding` are not asserted.
+struct Validator<'a> {
+    root: &'a Value,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn validate(&mut self, schema: &'a Value, value: &Value, pointer: &mut String) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            self.validate_ref(reference, value, pointer);
+        }
+        if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+            if !has_type(value, expected) {
+                self.error(pointer, format!("expected {expected}, found {}", type_name(value)));
+                return;
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if value != expected {
+                self.error(pointer, format!("expected {expected}, found {value}"));
+            }
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            if !allowed.contains(value) {
+                self.error(pointer, enum_message(value, allowed));
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+                if number < minimum {
+                    self.error(pointer, format!("{value} is less than the minimum {minimum}"));
+                }
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+                if number > maximum {
+                    self.error(pointer, format!("{value} is greater than the maximum {maximum}"));
+                }
+            }
+        }
+        if let Value::Object(object) = value {
+            self.validate_object(schema, object, pointer);
+        }
+        if let (Some(items), Value::Array(array)) = (schema.get("items"), value) {
+            for (index, item) in array.iter().enumerate() {
+                let len = pointer.len();
+                pointer.push_str(&format!("/{index}"));
+                self.validate(items, item, pointer);
+                pointer.truncate(len);
+            }
+        }
+        for subschema in schema.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+            self.validate(subschema, value, pointer);
+        }
+        if let (Some(condition), Some(then)) = (schema.get("if"), schema.get("then")) {
+            if self.matches(condition, value) {
+                self.validate(then, value, pointer);
+            }
+        }
+    }
+
+    fn validate_object(&mut self, schema: &'a Value, object: &serde_json::Map<String, Value>, pointer: &mut String) {
+        for name in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+            if let Some(name) = name.as_str() {
+                if !object.contains_key(name) {
+                    self.error(pointer, format!("missing required property \"{name}\""));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+        for (name, property) in object {
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(property_schema) => self.validate(property_schema, property, pointer),
+                None if closed => {
+                    let title = schema.get("title").and_then(Value::as_str).unwrap_or("the schema");
+                    self.error(pointer, format!("property is not defined by {title}"));
+                }
+                None => {}
+            }
+            pointer.truncate(len);
+        }
+    }
+
+    fn validate_ref(&mut self, reference: &str, value: &Value, pointer: &mut String) {
+        match reference.strip_prefix("#/$defs/").and_then(|name| self.root["$defs"].get(name)) {
+            Some(definition) => self.validate(definition, value, pointer),
+            None => self.error(pointer, format!("unresolved schema reference {reference}")),
+        }
+    }
+
+    /// Evaluate a condition without recording its failures.
+    fn matches(&self, schema: &'a Value, value: &Value) -> bool {
+        let mut validator = Validator { root: self.root, errors: vec![] };
+        validator.validate(schema, value, &mut String::new());
+        validator.errors.is_empty()
+    }
+
+    fn error(&mut self, pointer: &str, message: String) {
+        self.errors.push(ValidationError { pointer: pointer.to_string(), message });
+    }
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|number| number.fract() == 0.0),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// List the allowed values unless there are too many of them e.g. the `_typeName` values of the
+/// descendants of `DataObject`.
+fn enum_message(value: &Value, allowed: &[Value]) -> String {
+    if allowed.len() > 10 {
+        return format!("{value} is not an allowed value");
+    }
+    let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+    format!("expected one of {}, found {value}", allowed.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn errors(struct_type: StructType, value: Value) -> Vec<String> {
+        validate(struct_type, &value).iter().map(ValidationError::to_string).collect()
+    }
+
+    #[test]
+    fn test_definition_ref() {
+        assert_eq!(definition_ref(StructType::VirtualDevice), "#/$defs/VirtualDeviceTrait");
+        assert_eq!(definition_ref(StructType::VirtualHardware), "#/$defs/VirtualHardware");
+    }
+
+    #[test]
+    fn test_validate() {
+        let hardware = json!({
+            "_typeName": "VirtualHardware",
+            "numCPU": 2,
+            "memoryMB": 4096,
+            "device": [{
+                "_typeName": "VirtualDisk",
+                "key": 2000,
+                "capacityInKB": 16777216,
+                "backing": {
+                    "_typeName": "VirtualDiskFlatVer2BackingInfo",
+                    "fileName": "[datastore1] vm/vm.vmdk",
+                    "diskMode": "persistent",
+                    "thinProvisioned": true
+                }
+            }]
+        });
+        assert_eq!(errors(StructType::VirtualHardware, hardware), Vec::<String>::new());
+        // The discriminator is optional for types without descendants
+        assert!(is_valid(StructType::ManagedObjectReference, &json!({"type": "VirtualMachine", "value": "vm-42"})));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let hardware = json!({
+            "numCPU": "two",
+            "memoryMB": 8589934592i64,
+            "device": [
+                {"_typeName": "VirtualDisk", "capacityInKB": 1, "backing": {"_typeName": "VirtualDiskFlatVer2BackingInfo", "diskMode": "persistent"}},
+                {"_typeName": "NoSuchDevice", "key": 1},
+                {"key": 2},
+                {"_typeName": "VirtualDisk", "key": 3, "capacityInKB": 1, "size~/name": 1}
+            ]
+        });
+        let mut failures = errors(StructType::VirtualHardware, hardware);
+        failures.sort();
+        let unknown_type = failures.remove(2);
+        assert!(unknown_type.starts_with("/device/1/_typeName: "), "{unknown_type}");
+        assert_eq!(failures, vec![
+            "/device/0/backing: missing required property \"fileName\"",
+            "/device/0: missing required property \"key\"",
+            "/device/2: missing required property \"_typeName\"",
+            "/device/3/size~0~1name: property is not defined by VirtualDisk",
+            "/memoryMB: 8589934592 is greater than the maximum 2147483647",
+            "/numCPU: expected integer, found string",
+        ]);
+        assert_eq!(errors(StructType::ManagedObjectReference, json!([])), vec!["/: expected object, found array"]);
+    }
+}
@@ -0,0 +1,155 @@
+//! Building blocks of the generated deserializers.
+//!
+//! Property collector results can be tens of megabytes so the decoder avoids copies where it can.
+//! Map keys borrow from the input unless they contain escape sequences. Polymorphic objects are
+//! dispatched on the `_typeName` discriminator that vCenter sends as the first entry. Entries that
+//! precede the discriminator are buffered as `serde_json::Value` and replayed to the type specific
+//! deserializer followed by the remaining entries that are read from the input directly. Nothing
+//! borrows from the input beyond the keys so the deserializers also work on `serde_json::Value`
+//! and on readers such as the streamed response bodies of the client.
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::{self, DeserializeSeed, MapAccess};
+use serde_json::Value;
+
+/// Map key that borrows from the input when possible.
+pub struct FieldKey<'de>(pub Cow<'de, str>);
+
+impl Deref for FieldKey<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> de::Deserialize<'de> for FieldKey<'de> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FieldKeyVisitor)
+    }
+}
+
+struct FieldKeyVisitor;
+
+impl<'de> de::Visitor<'de> for FieldKeyVisitor {
+    type Value = FieldKey<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Borrowed(value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Owned(value.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(FieldKey(Cow::Owned(value)))
+    }
+}
+
+/// Map entries buffered before the discriminator was found.
+pub type MapPrefix<'de> = Vec<(Cow<'de, str>, Value)>;
+
+/// `MapAccess` that yields the buffered `prefix` entries and then the remaining entries of `map`.
+pub struct PrefixedMapAccess<'de, A> {
+    prefix: std::vec::IntoIter<(Cow<'de, str>, Value)>,
+    value: Option<Value>,
+    map: A,
+}
+
+impl<'de, A> PrefixedMapAccess<'de, A> {
+    pub fn new(prefix: MapPrefix<'de>, map: A) -> Self {
+        PrefixedMapAccess {
+            prefix: prefix.into_iter(),
+            value: None,
+            map,
+        }
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for PrefixedMapAccess<'de, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some((key, value)) = self.prefix.next() else {
+            return self.map.next_key_seed(seed);
+        };
+        self.value = Some(value);
+        match key {
+            Cow::Borrowed(key) => seed.deserialize(de::value::BorrowedStrDeserializer::new(key)).map(Some),
+            Cow::Owned(key) => seed.deserialize(de::value::StringDeserializer::new(key)).map(Some),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value).map_err(de::Error::custom),
+            None => self.map.next_value_seed(seed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserializer;
+
+    struct PrefixedVisitor;
+
+    impl<'de> de::Visitor<'de> for PrefixedVisitor {
+        type Value = Vec<(String, serde_json::Value)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut prefix = MapPrefix::new();
+            loop {
+                let key: FieldKey = map.next_key()?.ok_or_else(|| de::Error::missing_field("_typeName"))?;
+                if &*key == "_typeName" {
+                    let _: de::IgnoredAny = map.next_value()?;
+                    break;
+                }
+                prefix.push((key.0, map.next_value()?));
+            }
+            let mut map = PrefixedMapAccess::new(prefix, map);
+            let mut entries = Vec::new();
+            while let Some(key) = map.next_key::<FieldKey>()? {
+                entries.push((key.to_string(), map.next_value()?));
+            }
+            Ok(entries)
+        }
+    }
+
+    #[test]
+    fn test_field_key_borrows() {
+        let key: FieldKey = serde_json::from_str(r#""name""#).unwrap();
+        assert!(matches!(key.0, Cow::Borrowed("name")));
+        let key: FieldKey = serde_json::from_str(r#""na\u006de""#).unwrap();
+        assert!(matches!(key.0, Cow::Owned(ref key) if key == "name"));
+    }
+
+    #[test]
+    fn test_prefixed_map_access() {
+        let json = r#"{"key": 1, "label": {"text": "a"}, "_typeName": "T", "unit": 2}"#;
+        let expected = vec![
+            ("key".to_string(), serde_json::json!(1)),
+            ("label".to_string(), serde_json::json!({"text": "a"})),
+            ("unit".to_string(), serde_json::json!(2)),
+        ];
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        assert_eq!(deserializer.deserialize_map(PrefixedVisitor).unwrap(), expected);
+        // Owned input
+        let mut deserializer = serde_json::Deserializer::from_reader(json.as_bytes());
+        assert_eq!(deserializer.deserialize_map(PrefixedVisitor).unwrap(), expected);
+        let value: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(value.deserialize_map(PrefixedVisitor).unwrap(), expected);
+    }
+}
@@ -7,6 +7,20 @@ pub mod struct_enum;
 pub mod boxed_types;
 pub mod vim_any;
 pub mod as_any;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+pub mod builder;
 pub mod convert;
+pub mod date_time;
+pub mod diff;
+pub mod json;
+#[cfg(feature = "json-schema")]
+pub mod json_schema;
+mod map_access;
+pub mod path;
+#[cfg(feature = "reflection")]
+pub mod reflection;
+#[cfg(feature = "reflection")]
+mod type_tables;
 pub mod vim_object_trait;
 
@@ -0,0 +1,319 @@
+//! Evaluation of property paths and application of property collector changes on data objects.
+//!
+//! Paths use the property collector notation e.g. `config.hardware.device[4000].backing`. Array
+//! elements are selected by their `key` property e.g. `device[4000]` or `extraConfig["guestinfo.ip"]`.
+//! Elements of arrays without keys are selected by index. Both functions operate on the JSON wire
+//! format of the object and are available on every data object through `VimObjectTrait`.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::diff::{element_key, key_matches};
+use super::enums::PropertyChangeOpEnum;
+use super::json::from_value;
+#[cfg(feature = "reflection")]
+use super::reflection::{self, DataType};
+use super::structs::PropertyChange;
+use super::vim_any::VimAny;
+
+/// Error applying a property change.
+#[derive(Debug, thiserror::Error)]
+pub enum PathError {
+    #[error("Invalid property path: {0}")]
+    InvalidPath(String),
+    #[error("Property path not found: {0}")]
+    NotFound(String),
+    #[error("Missing value for property change of {0}")]
+    MissingValue(String),
+    #[error("Unsupported property change operation: {0}")]
+    UnsupportedOperation(String),
+    #[error("Serde error: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+struct Segment<'a> {
+    name: &'a str,
+    selector: Option<String>,
+}
+
+/// Evaluate a property path against an object. Use `VimObjectTrait::get_by_path` instead.
+pub(crate) fn get_path<T: Serialize + ?Sized>(object: &T, path: &str) -> Option<Value> {
+    let segments = parse_path(path).ok()?;
+    let mut value = serde_json::to_value(object).ok()?;
+    for segment in &segments {
+        value = lookup_mut(&mut value, segment)?.take();
+    }
+    Some(value)
+}
+
+/// Apply property changes to an object in order. Use `VimObjectTrait::apply_changes` instead.
+///
+/// The object is converted to its wire format once for all the changes. It is left unchanged if
+/// any of the changes cannot be applied.
+pub(crate) fn apply_changes<T: Serialize + DeserializeOwned>(object: &mut T, changes: &[PropertyChange]) -> Result<(), PathError> {
+    let mut value = serde_json::to_value(&*object)?;
+    for change in changes {
+        apply_to_value(&mut value, change)?;
+    }
+    *object = from_value(value)?;
+    Ok(())
+}
+
+/// Apply a property change to the wire format of an object.
+///
+/// `assign` and `add` set the property or replace the selected array element, appending it if
+/// there is no element with the key. `remove` and `indirectRemove` unset the property or remove
+/// the selected array element. Removing a property that is not set is not an error.
+fn apply_to_value(value: &mut Value, change: &PropertyChange) -> Result<(), PathError> {
+    let segments = parse_path(&change.name)?;
+    let Some((last, parents)) = segments.split_last() else {
+        return Err(PathError::InvalidPath(change.name.clone()));
+    };
+    let mut target = value;
+    for segment in parents {
+        target = lookup_mut(target, segment).ok_or_else(|| PathError::NotFound(change.name.clone()))?;
+    }
+    let Some(properties) = target.as_object_mut() else {
+        return Err(PathError::NotFound(change.name.clone()));
+    };
+    match (&change.op, &change.val) {
+        (PropertyChangeOpEnum::Assign | PropertyChangeOpEnum::Add, Some(val)) => {
+            let any = holds_any(properties, last);
+            set_property(properties, last, wire_value(val, any)?, &change.name)?
+        }
+        (PropertyChangeOpEnum::Assign, None) => remove_property(properties, last),
+        (PropertyChangeOpEnum::Add, None) => return Err(PathError::MissingValue(change.name.clone())),
+        (PropertyChangeOpEnum::Remove | PropertyChangeOpEnum::IndirectRemove, _) => remove_property(properties, last),
+        (PropertyChangeOpEnum::Other_(op), _) => return Err(PathError::UnsupportedOperation(op.clone())),
+    }
+    Ok(())
+}
+
+/// Whether the property or selected array element is of type `Any` and thus keeps the
+/// `_typeName` of its values e.g. `OptionValue.value` or `DynamicProperty.val`.
+#[cfg(feature = "reflection")]
+fn holds_any(properties: &Map<String, Value>, segment: &Segment) -> bool {
+    let field = properties
+        .get("_typeName")
+        .and_then(Value::as_str)
+        .and_then(reflection::type_info_by_name)
+        .and_then(|info| info.field(segment.name));
+    field.is_some_and(|field| field.data_type == DataType::Any && (!field.array || segment.selector.is_some()))
+}
+
+/// Without the `reflection` feature the type of the property is not known. Its values keep the
+/// `_typeName` only if the current value has it.
+#[cfg(not(feature = "reflection"))]
+fn holds_any(properties: &Map<String, Value>, segment: &Segment) -> bool {
+    let current = properties.get(segment.name);
+    let current = match &segment.selector {
+        Some(key) => current.and_then(Value::as_array).and_then(|array| Some(&array[find_element(array, key)?])),
+        None => current,
+    };
+    current.is_some_and(|value| value.get("_value").is_some())
+}
+
+/// Convert a property value to the wire format of a field i.e. unwrap boxed primitives and arrays
+/// unless the field is of type `Any`.
+fn wire_value(val: &VimAny, any: bool) -> Result<Value, PathError> {
+    let mut value = serde_json::to_value(val)?;
+    if let (VimAny::Value(_), false) = (val, any) {
+        if let Some(inner) = value.get_mut("_value") {
+            return Ok(inner.take());
+        }
+    }
+    Ok(value)
+}
+
+fn set_property(properties: &mut Map<String, Value>, segment: &Segment, value: Value, path: &str) -> Result<(), PathError> {
+    let Some(key) = &segment.selector else {
+        properties.insert(segment.name.to_string(), value);
+        return Ok(());
+    };
+    let array = properties
+        .entry(segment.name)
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .ok_or_else(|| PathError::NotFound(path.to_string()))?;
+    match find_element(array, key) {
+        Some(index) => array[index] = value,
+        None => array.push(value),
+    }
+    Ok(())
+}
+
+fn remove_property(properties: &mut Map<String, Value>, segment: &Segment) {
+    let Some(key) = &segment.selector else {
+        properties.remove(segment.name);
+        return;
+    };
+    if let Some(Value::Array(array)) = properties.get_mut(segment.name) {
+        if let Some(index) = find_element(array, key) {
+            array.remove(index);
+        }
+    }
+}
+
+fn lookup_mut<'a>(value: &'a mut Value, segment: &Segment) -> Option<&'a mut Value> {
+    let value = value.as_object_mut()?.get_mut(segment.name)?;
+    let Some(key) = &segment.selector else {
+        return Some(value);
+    };
+    let array = value.as_array_mut()?;
+    let index = find_element(array, key)?;
+    Some(&mut array[index])
+}
+
+/// Find an array element by its `key` property or by index if the elements have no keys.
+fn find_element(array: &[Value], key: &str) -> Option<usize> {
+    if array.iter().any(|element| element_key(element).is_some()) {
+        return array
+            .iter()
+            .position(|element| element_key(element).is_some_and(|element_key| key_matches(element_key, key)));
+    }
+    key.parse().ok().filter(|index| *index < array.len())
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment<'_>>, PathError> {
+    let invalid = || PathError::InvalidPath(path.to_string());
+    let mut segments = Vec::new();
+    let mut rest = path;
+    loop {
+        let end = rest.find(['.', '[']).unwrap_or(rest.len());
+        let name = &rest[..end];
+        if name.is_empty() {
+            return Err(invalid());
+        }
+        rest = &rest[end..];
+        let mut selector = None;
+        if let Some(text) = rest.strip_prefix('[') {
+            let (key, remaining) = parse_selector(text).ok_or_else(invalid)?;
+            selector = Some(key);
+            rest = remaining;
+        }
+        segments.push(Segment { name, selector });
+        if rest.is_empty() {
+            return Ok(segments);
+        }
+        rest = rest.strip_prefix('.').ok_or_else(invalid)?;
+    }
+}
+
+/// Parse the key of a selector up to and including the closing bracket. String keys may be
+/// quoted.
+fn parse_selector(text: &str) -> Option<(String, &str)> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let end = text.find(']')?;
+        return Some((text[..end].to_string(), &text[end + 1..]));
+    };
+    let mut key = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => key.push(chars.next()?.1),
+            '"' => return Some((key, quoted[index + 1..].strip_prefix(']')?)),
+            c => key.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::boxed_types::ValueElements;
+
+    fn segments(path: &str) -> Vec<(String, Option<String>)> {
+        parse_path(path)
+            .unwrap()
+            .into_iter()
+            .map(|segment| (segment.name.to_string(), segment.selector))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(segments("config.hardware.device[4000].backing"), vec![
+            ("config".to_string(), None),
+            ("hardware".to_string(), None),
+            ("device".to_string(), Some("4000".to_string())),
+            ("backing".to_string(), None),
+        ]);
+        assert_eq!(segments(r#"config.extraConfig["guestinfo.ip"]"#), vec![
+            ("config".to_string(), None),
+            ("extraConfig".to_string(), Some("guestinfo.ip".to_string())),
+        ]);
+        assert!(parse_path("config..name").is_err());
+        assert!(parse_path("device[4000").is_err());
+        assert!(parse_path("device[4000]name").is_err());
+    }
+
+    fn change(name: &str, op: PropertyChangeOpEnum, val: Option<VimAny>) -> PropertyChange {
+        PropertyChange { name: name.to_string(), op, val }
+    }
+
+    #[test]
+    fn test_apply_to_value() {
+        let mut value = serde_json::json!({"name": "vm", "extraConfig": [{"_typeName": "OptionValue", "key": "a"}]});
+        apply_to_value(&mut value, &change("name", PropertyChangeOpEnum::Remove, None)).unwrap();
+        apply_to_value(&mut value, &change(r#"extraConfig["a"]"#, PropertyChangeOpEnum::Remove, None)).unwrap();
+        assert_eq!(value, serde_json::json!({"extraConfig": []}));
+        assert!(matches!(apply_to_value(&mut value, &change("config.name", PropertyChangeOpEnum::Remove, None)), Err(PathError::NotFound(_))));
+        assert!(matches!(apply_to_value(&mut value, &change("name", PropertyChangeOpEnum::Add, None)), Err(PathError::MissingValue(_))));
+    }
+
+    #[cfg(feature = "reflection")]
+    #[test]
+    fn test_apply_any_value() {
+        let int = || Some(VimAny::Value(ValueElements::PrimitiveInt(4)));
+        let mut value = serde_json::json!({"_typeName": "OptionValue", "key": "a"});
+        apply_to_value(&mut value, &change("value", PropertyChangeOpEnum::Assign, int())).unwrap();
+        assert_eq!(value, serde_json::json!({"_typeName": "OptionValue", "key": "a", "value": {"_typeName": "int", "_value": 4}}));
+        let mut value = serde_json::json!({"_typeName": "VirtualDevice", "key": 1});
+        apply_to_value(&mut value, &change("unitNumber", PropertyChangeOpEnum::Assign, int())).unwrap();
+        assert_eq!(value, serde_json::json!({"_typeName": "VirtualDevice", "key": 1, "unitNumber": 4}));
+    }
+
+    #[cfg(not(feature = "reflection"))]
+    #[test]
+    fn test_apply_any_value() {
+        let int = || Some(VimAny::Value(ValueElements::PrimitiveInt(4)));
+        let mut value = serde_json::json!({"value": {"_typeName": "int", "_value": 1}, "values": [{"_typeName": "int", "_value": 1}]});
+        apply_to_value(&mut value, &change("value", PropertyChangeOpEnum::Assign, int())).unwrap();
+        apply_to_value(&mut value, &change("values[0]", PropertyChangeOpEnum::Assign, int())).unwrap();
+        apply_to_value(&mut value, &change("unitNumber", PropertyChangeOpEnum::Assign, int())).unwrap();
+        assert_eq!(value, serde_json::json!({
+            "value": {"_typeName": "int", "_value": 4},
+            "values": [{"_typeName": "int", "_value": 4}],
+            "unitNumber": 4
+        }));
+    }
+
+    #[test]
+    fn test_find_element() {
+        let keyed = [serde_json::json!({"key": 2000}), serde_json::json!({"key": 4000})];
+        assert_eq!(find_element(&keyed, "4000"), Some(1));
+        assert_eq!(find_element(&keyed, "1"), None);
+        let plain = [serde_json::json!("a"), serde_json::json!("b")];
+        assert_eq!(find_element(&plain, "1"), Some(1));
+        assert_eq!(find_element(&plain, "2"), None);
+    }
+
+    #[test]
+    fn test_diff_paths_resolve() {
+        let old = serde_json::json!({"extraConfig": [
+            {"_typeName": "OptionValue", "key": "say \"hi\"", "value": "a"},
+            {"_typeName": "OptionValue", "key": "1", "value": "b"}
+        ]});
+        let new = serde_json::json!({"extraConfig": [
+            {"_typeName": "OptionValue", "key": "say \"hi\"", "value": "c"},
+            {"_typeName": "OptionValue", "key": "1", "value": "d"}
+        ]});
+        for change in super::super::diff::diff(&old, &new).unwrap() {
+            let segments = parse_path(&change.path).unwrap();
+            let selector = segments[0].selector.as_deref().unwrap();
+            let index = find_element(old["extraConfig"].as_array().unwrap(), selector).unwrap();
+            assert_eq!(old["extraConfig"][index]["key"], new["extraConfig"][index]["key"]);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Runtime metadata of the VIM data types. The tables are generated from the API specification
+//! and describe the type hierarchy and the fields of every data type as they appear on the wire.
+//! They allow generic tooling such as validators, pretty printers and UI forms to work over any
+//! data type without knowing it at compile time.
+//!
+//! Example: `let info = reflection::type_info_by_name("VirtualDisk")?;`
+use super::struct_enum::StructType;
+use super::type_tables::{TYPES, TYPE_NAMES};
+
+/// Data type of a field. Arrays are described by the element type and [`FieldInfo::array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataType {
+    Boolean,
+    String,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float,
+    Double,
+    /// Date and time in ISO 8601 format.
+    DateTime,
+    /// Base64 encoded binary data.
+    Binary,
+    /// Any VIM type, represented as `VimAny`.
+    Any,
+    /// Enumeration with the given VIM name e.g. `VirtualMachinePowerState_enum`.
+    Enum(&'static str),
+    /// Data object of the given type or any of its descendants.
+    Struct(StructType),
+}
+
+/// Metadata of a field declared by a data type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Property name on the wire e.g. `unitNumber`.
+    pub name: &'static str,
+    /// Name of the field in the generated Rust struct e.g. `unit_number`.
+    pub rust_name: &'static str,
+    /// Field data type or element data type of array fields.
+    pub data_type: DataType,
+    /// The field may be unset.
+    pub optional: bool,
+    /// The field is an array of `data_type` values.
+    pub array: bool,
+    /// First sentence of the field documentation.
+    pub summary: &'static str,
+}
+
+/// Metadata of a data type.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// Type name on the wire as found in `_typeName` e.g. `VirtualDisk`.
+    pub name: &'static str,
+    pub struct_type: StructType,
+    /// Parent type. `None` for the root types `DataObject` and `ManagedObjectReference`.
+    pub parent: Option<StructType>,
+    /// Direct child types.
+    pub children: &'static [StructType],
+    /// `StructType` value of the last descendant in the full API. All descendants of a type follow
+    /// it up to and including this value. Types without children end their own subtree.
+    pub(crate) subtree_end: u32,
+    /// Fields declared by the type itself. See [`TypeInfo::all_fields`] for inherited fields.
+    pub fields: &'static [FieldInfo],
+    /// First sentence of the type documentation.
+    pub summary: &'static str,
+}
+
+impl TypeInfo {
+    /// Metadata of the parent type if any.
+    pub fn parent_info(&self) -> Option<&'static TypeInfo> {
+        self.parent.map(StructType::type_info)
+    }
+
+    /// Metadata of all descendants in `StructType` order i.e. depth first.
+    pub fn descendants(&self) -> &'static [TypeInfo] {
+        let start = index_of(self.struct_type) + 1;
+        let end = start + TYPES[start..].partition_point(|info| info.struct_type as u32 <= self.subtree_end);
+        &TYPES[start..end]
+    }
+
+    /// The last descendant in `StructType` order. All descendants of a type form the contiguous
+    /// range that follows it up to and including the last descendant. Types without children are
+    /// their own last descendant.
+    pub fn last_descendant(&self) -> StructType {
+        self.descendants().last().map_or(self.struct_type, |info| info.struct_type)
+    }
+
+    /// Fields of the type including the inherited ones, starting with the fields of the root
+    /// type.
+    pub fn all_fields(&self) -> Vec<&'static FieldInfo> {
+        let mut ancestors = vec![self.struct_type.type_info()];
+        while let Some(parent) = ancestors[ancestors.len() - 1].parent_info() {
+            ancestors.push(parent);
+        }
+        ancestors.iter().rev().flat_map(|info| info.fields).collect()
+    }
+
+    /// Find a field including inherited ones by its wire name.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        let mut info = self.struct_type.type_info();
+        loop {
+            if let Some(field) = info.fields.iter().find(|field| field.name == name) {
+                return Some(field);
+            }
+            info = info.parent_info()?;
+        }
+    }
+}
+
+impl StructType {
+    /// Reflection metadata of the type.
+    pub fn type_info(self) -> &'static TypeInfo {
+        &TYPES[index_of(self)]
+    }
+}
+
+/// Position of a type in `TYPES`. It is the `StructType` value unless managed object features
+/// left some types out.
+///
+/// The direct lookup relies on `StructType` values being the positions of the types in the full
+/// API, which vim_build keeps when emitting `#[cfg(vim_group = "N")]` variants. `TYPES` is sorted
+/// by value either way so the binary search covers the feature gated build.
+fn index_of(struct_type: StructType) -> usize {
+    match TYPES.get(struct_type as usize) {
+        Some(info) if info.struct_type == struct_type => struct_type as usize,
+        _ => TYPES.partition_point(|info| info.struct_type < struct_type),
+    }
+}
+
+/// Metadata of all data types in `StructType` order.
+pub fn types() -> &'static [TypeInfo] {
+    TYPES
+}
+
+/// Find the metadata of a data type by its wire name as found in `_typeName`.
+pub fn type_info_by_name(name: &str) -> Option<&'static TypeInfo> {
+    let index = TYPE_NAMES.binary_search_by(|(type_name, _)| (*type_name).cmp(name)).ok()?;
+    Some(TYPE_NAMES[index].1.type_info())
+}
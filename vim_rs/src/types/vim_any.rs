@@ -1,11 +1,15 @@
 use super::boxed_types::ValueElements;
 use super::deserialize::VimAnyVisitor;
+use super::json::from_value;
+use super::struct_enum::StructType;
 use super::vim_object_trait::VimObjectTrait;
 
 /// Base type for all VIM objects. This is used in APIs that can return arbitrary type.
 /// Structure data types are under the `Object` variant. Primitives and arrays are under the `Value`
 /// variant.
 #[derive(Debug, serde::Serialize)]
+#[cfg_attr(feature = "clone", derive(Clone))]
+#[cfg_attr(feature = "partial-eq", derive(PartialEq))]
 #[serde(untagged)]
 pub enum VimAny {
     Object(Box<dyn VimObjectTrait>),
@@ -17,4 +21,22 @@ impl<'de> serde::Deserialize<'de> for VimAny {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         deserializer.deserialize_map(VimAnyVisitor)
     }
-}
\ No newline at end of file
+}
+
+impl VimAny {
+    /// Create a value from its JSON representation without the `_typeName` discriminator e.g.
+    /// `VimAny::from_typed_json("int", json!(5))` or
+    /// `VimAny::from_typed_json("ManagedObjectReference", json!({"type": "Folder", "value": "group-d1"}))`.
+    /// Data objects are expected as JSON objects, boxed values such as `int` or `ArrayOfString` as
+    /// the plain value.
+    pub fn from_typed_json(type_name: &str, value: serde_json::Value) -> serde_json::Result<Self> {
+        let value = match value {
+            serde_json::Value::Object(mut properties) if from_value::<StructType>(type_name.into()).is_ok() => {
+                properties.insert("_typeName".to_string(), type_name.into());
+                serde_json::Value::Object(properties)
+            }
+            value => serde_json::json!({"_typeName": type_name, "_value": value}),
+        };
+        from_value(value)
+    }
+}
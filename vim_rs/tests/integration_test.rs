@@ -4,6 +4,7 @@ use std::convert::AsRef;
 use vim_rs::types::boxed_types::ValueElements;
 use vim_rs::types::convert::CastInto;
 use vim_rs::types::enums::MoTypesEnum;
+use vim_rs::types::struct_enum::StructType;
 use vim_rs::types::structs::VirtualE1000;
 use vim_rs::types::traits::VirtualDeviceTrait;
 use vim_rs::types::traits::VirtualEthernetCardTrait;
@@ -11,6 +12,7 @@ use vim_rs::types::vim_any::VimAny;
 use vim_rs::types::vim_object_trait::VimObjectTrait;
 
 fn init() {
+    #[cfg(not(target_arch = "wasm32"))]
     let _ = env_logger::builder().is_test(true).try_init();
 }
 
@@ -64,6 +66,20 @@ fn test_ethernet_ref() {
     info!("{:?}", eth);
 }
 
+#[test]
+fn test_type_name_not_first() {
+    init();
+    let json = r#"{"key": 1000, "macAddress": "00:50:56:aa:bb:cc", "_typeName": "VirtualE1000", "unitNumber": 7}"#;
+    let vd: Box<dyn VirtualDeviceTrait> = serde_json::from_str(json).unwrap();
+    assert_eq!(vd.get_key(), 1000);
+    assert_eq!(vd.get_unit_number(), Some(7));
+    let json = r#"{"_value": [1, 2], "_typeName": "ArrayOfInt"}"#;
+    let VimAny::Value(ValueElements::ArrayOfInt(values)) = serde_json::from_str(json).unwrap() else {
+        panic!("Unexpected type");
+    };
+    assert_eq!(values, vec![1, 2]);
+}
+
 #[test]
 fn consume_vim_any_as_box() {
     init();
@@ -153,6 +169,64 @@ fn type_name() {
     assert_eq!(e1000_type, "VirtualE1000");
 }
 
+#[test]
+fn test_trait_mut_accessors() {
+    init();
+    let mut vd: Box<dyn VirtualDeviceTrait> = Box::new(create_virtual_e1000());
+    vd.set_unit_number(Some(7));
+    let json = r#"{"_typeName": "VirtualEthernetCardNetworkBackingInfo", "deviceName": "VM Network"}"#;
+    *vd.get_backing_mut() = Some(serde_json::from_str(json).unwrap());
+    *vd.get_key_mut() += 1;
+    assert_eq!(vd.get_unit_number(), Some(7));
+    assert_eq!(vd.get_key(), 1001);
+    let backing = vd.get_backing().as_ref().unwrap();
+    assert_eq!(backing.data_type(), StructType::VirtualEthernetCardNetworkBackingInfo);
+    let json = serde_json::to_value(&vd).unwrap();
+    assert_eq!(json["_typeName"], "VirtualE1000");
+    assert_eq!(json["unitNumber"], 7);
+    assert_eq!(json["backing"]["deviceName"], "VM Network");
+    assert_eq!(json["macAddress"], "00:50:56:aa:bb:cc");
+}
+
+#[cfg(feature = "clone")]
+#[test]
+fn test_clone_trait_box() {
+    init();
+    let mut e1000 = create_virtual_e1000();
+    let json = r#"{"_typeName": "VirtualEthernetCardNetworkBackingInfo", "deviceName": "VM Network"}"#;
+    e1000.backing = Some(serde_json::from_str(json).unwrap());
+    let vd: Box<dyn VirtualDeviceTrait> = Box::new(e1000);
+    let mut copy = vd.clone();
+    assert_eq!(copy.data_type(), StructType::VirtualE1000);
+    assert_eq!(copy.get_key(), 1000);
+    assert_eq!(serde_json::to_value(&copy).unwrap(), serde_json::to_value(&vd).unwrap());
+    copy.set_unit_number(Some(7));
+    assert_eq!(vd.get_unit_number(), None);
+    let backing = copy.get_backing().as_ref().unwrap().clone();
+    assert_eq!(backing.data_type(), StructType::VirtualEthernetCardNetworkBackingInfo);
+    let eth: Box<dyn VirtualEthernetCardTrait> = copy.into_box().unwrap();
+    let eth_copy = eth.clone();
+    assert_eq!(eth_copy.data_type(), StructType::VirtualE1000);
+    assert_eq!(*eth_copy.get_mac_address(), Some("00:50:56:aa:bb:cc".to_string()));
+    assert_eq!(eth_copy.get_unit_number(), Some(7));
+}
+
+#[cfg(feature = "partial-eq")]
+#[test]
+fn test_eq_trait_box() {
+    init();
+    let object = |e1000: VirtualE1000| -> Box<dyn VimObjectTrait> { Box::new(e1000) };
+    assert!(object(create_virtual_e1000()) == object(create_virtual_e1000()));
+    let mut other = create_virtual_e1000();
+    other.unit_number = Some(7);
+    assert!(object(create_virtual_e1000()) != object(other));
+    let json = serde_json::to_value(create_virtual_e1000()).unwrap().to_string();
+    let card: Box<dyn VimObjectTrait> = serde_json::from_str(&json.replace("VirtualE1000", "VirtualEthernetCard")).unwrap();
+    assert!(object(create_virtual_e1000()) != card);
+    let vd: Box<dyn VirtualDeviceTrait> = serde_json::from_str(&json).unwrap();
+    assert!(vd == Box::new(create_virtual_e1000()) as Box<dyn VirtualDeviceTrait>);
+}
+
 fn create_virtual_device_array() -> VimAny {
     VimAny::Value(ValueElements::ArrayOfVirtualDevice(vec![Box::new(
         create_virtual_e1000(),
@@ -0,0 +1,40 @@
+//! Tests of the `wasm` build against the stub server in `wasm_stub_server.js`:
+//!
+//! ```sh
+//! node tests/wasm_stub_server.js &
+//! wasm-pack test --node --no-default-features --features wasm,full
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use vim_rs::core::client::ClientBuilder;
+use vim_rs::mo::ServiceInstance;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+async fn connect() -> std::sync::Arc<vim_rs::core::client::Client> {
+    ClientBuilder::new("localhost")
+        .scheme("http")
+        .port(8989)
+        .basic_authn("admin", "secret")
+        .build()
+        .await
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_service_content() {
+    let client = connect().await;
+    assert_eq!(client.api_release(), "8.0.2.0");
+    let content = client.service_content();
+    assert_eq!(content.about.api_version, "8.0.2.0");
+    assert_eq!(content.root_folder.value, "group-d1");
+}
+
+#[wasm_bindgen_test]
+async fn test_current_time() {
+    let client = connect().await;
+    let service_instance = ServiceInstance::new(client.clone(), "ServiceInstance");
+    service_instance.current_time().await.unwrap();
+    // The logout is queued on the event loop when the client is dropped
+    drop(service_instance);
+    drop(client);
+}
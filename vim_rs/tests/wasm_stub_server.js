@@ -0,0 +1,88 @@
+// Stub vCenter for the wasm32 test suite in `wasm.rs`. Serves the few VI JSON API calls the
+// client makes on `http://localhost:8989`:
+//
+//     node tests/wasm_stub_server.js &
+//     wasm-pack test --node --no-default-features --features wasm,full
+const http = require("http");
+
+const PORT = 8989;
+const SESSION_ID = "wasm-session";
+const API_RELEASE = "8.0.2.0";
+
+const SERVICE_CONTENT = {
+    _typeName: "ServiceContent",
+    rootFolder: { _typeName: "ManagedObjectReference", type: "Folder", value: "group-d1" },
+    propertyCollector: { _typeName: "ManagedObjectReference", type: "PropertyCollector", value: "propertyCollector" },
+    sessionManager: { _typeName: "ManagedObjectReference", type: "SessionManager", value: "SessionManager" },
+    about: {
+        _typeName: "AboutInfo",
+        name: "VMware vCenter Server",
+        fullName: "VMware vCenter Server 8.0.2",
+        vendor: "VMware, Inc.",
+        version: "8.0.2",
+        build: "1",
+        osType: "linux-x64",
+        productLineId: "vpx",
+        apiType: "VirtualCenter",
+        apiVersion: API_RELEASE,
+    },
+};
+
+const USER_SESSION = {
+    _typeName: "UserSession",
+    key: "52a1",
+    userName: "admin",
+    fullName: "Admin",
+    loginTime: "2024-05-01T10:00:00Z",
+    lastActiveTime: "2024-05-01T10:00:00Z",
+    locale: "en",
+    messageLocale: "en",
+    extensionSession: false,
+};
+
+const CORS_HEADERS = {
+    "Access-Control-Allow-Origin": "*",
+    "Access-Control-Allow-Methods": "GET, POST, OPTIONS",
+    "Access-Control-Allow-Headers": "content-type, vmware-api-session-id",
+    "Access-Control-Expose-Headers": "vmware-api-session-id",
+};
+
+function reply(res, status, body, headers = {}) {
+    res.writeHead(status, { ...CORS_HEADERS, ...headers, ...(body === undefined ? {} : { "Content-Type": "application/json" }) });
+    res.end(body === undefined ? undefined : JSON.stringify(body));
+}
+
+function authenticated(req) {
+    return req.headers["vmware-api-session-id"] === SESSION_ID;
+}
+
+const vim = `/sdk/vim25/${API_RELEASE}`;
+
+http.createServer((req, res) => {
+    // Drain the request body before answering
+    req.resume();
+    req.on("end", () => {
+        const url = new URL(req.url, `http://localhost:${PORT}`);
+        const route = `${req.method} ${url.pathname}${url.search}`;
+        console.log(route);
+        switch (route.startsWith("OPTIONS ") ? "OPTIONS" : route) {
+            case "OPTIONS":
+                return reply(res, 204);
+            case "POST /api/vcenter/system?action=hello":
+                return reply(res, 200, { api_release: API_RELEASE });
+            case `GET ${vim}/ServiceInstance/ServiceInstance/content`:
+                return reply(res, 200, SERVICE_CONTENT);
+            case `POST ${vim}/SessionManager/SessionManager/Login`:
+                return reply(res, 200, USER_SESSION, { "vmware-api-session-id": SESSION_ID });
+            case `POST ${vim}/ServiceInstance/ServiceInstance/CurrentTime`:
+                if (!authenticated(req)) {
+                    return reply(res, 401, { _typeName: "NotAuthenticated" });
+                }
+                return reply(res, 200, new Date().toISOString());
+            case `POST ${vim}/SessionManager/SessionManager/Logout`:
+                return reply(res, 204);
+            default:
+                return reply(res, 404, { error: `unexpected request ${route}` });
+        }
+    });
+}).listen(PORT, () => console.log(`vim_rs wasm stub server on port ${PORT}`));